@@ -0,0 +1,106 @@
+//! Pre-generates upcoming daily words for servers that must know them
+//! ahead of time, e.g. to prepare clues. Run it regularly (say, daily from
+//! cron) to keep a window of days ahead in the export. The export is
+//! encrypted, and the game itself never reads it.
+
+mod schedule;
+mod sealed;
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use wordle_game::{Language, load_wordlist};
+
+use sealed::SecretKey;
+
+#[derive(Parser)]
+#[command(
+    name = "wordle-daily-export",
+    about = "Export upcoming daily words, encrypted"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Write a new random key file, readable only by its owner
+    Key {
+        /// File to write the key to (must not exist yet)
+        path: PathBuf,
+    },
+    /// Encrypt the daily words of the next days into a file, replacing it
+    Export {
+        /// Key file, as written by `key`
+        #[arg(long, value_name = "PATH")]
+        key: PathBuf,
+        /// File to write the export to
+        #[arg(long, value_name = "PATH")]
+        out: PathBuf,
+        /// Number of days to export
+        #[arg(long, default_value_t = 30)]
+        days: u64,
+        /// First day to export as `YYYY-MM-DD` (UTC), instead of today
+        #[arg(long, value_name = "DATE", value_parser = parse_day)]
+        from: Option<u64>,
+        /// Language of the words, by name or code
+        #[arg(long, default_value_t = Language::default())]
+        language: Language,
+    },
+    /// Decrypt an export and print its words as JSON lines
+    Show {
+        /// Key file the export was written with
+        #[arg(long, value_name = "PATH")]
+        key: PathBuf,
+        /// The export file
+        file: PathBuf,
+    },
+}
+
+fn main() -> io::Result<()> {
+    match Cli::parse().command {
+        Command::Key { path } => write_key(&path, &SecretKey::generate()),
+        Command::Export {
+            key,
+            out,
+            days,
+            from,
+            language,
+        } => {
+            let key = SecretKey::load(key)?;
+            let word_pool = load_wordlist(language)?;
+            let words = schedule::upcoming(&word_pool, from.unwrap_or_else(schedule::today), days);
+            sealed::write(&out, &sealed::seal(&key, &words)?)?;
+            eprintln!("Exported {} days to {}", words.len(), out.display());
+            Ok(())
+        }
+        Command::Show { key, file } => {
+            let key = SecretKey::load(key)?;
+            for word in sealed::open(&key, &fs::read(file)?)? {
+                let line = serde_json::to_string(&word)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                println!("{line}");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Create a key file that only its owner can read
+fn write_key(path: &PathBuf, key: &SecretKey) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    io::Write::write_all(
+        &mut options.open(path)?,
+        format!("{}\n", key.to_hex()).as_bytes(),
+    )
+}
+
+fn parse_day(date: &str) -> Result<u64, String> {
+    schedule::parse_date(date).ok_or_else(|| format!("'{date}' is not a date like 2026-10-17"))
+}
@@ -0,0 +1,87 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use wordle_game::{DailySelector, SecretSelector, WordPool, civil};
+
+/// The daily word of one day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyWord {
+    /// Days since the Unix epoch, as used by the game's daily selector
+    pub day: u64,
+    /// The (UTC) day as `YYYY-MM-DD`
+    pub date: String,
+    /// The word as shown to players, e.g. with its hyphens
+    pub word: String,
+}
+
+/// The daily words of `days` days starting with `first_day`, picked from
+/// `word_pool` exactly as the game picks them
+pub fn upcoming(word_pool: &WordPool, first_day: u64, days: u64) -> Vec<DailyWord> {
+    (first_day..first_day + days)
+        .filter_map(|day| {
+            let word = DailySelector::for_day(day).select(word_pool)?;
+            Some(DailyWord {
+                day,
+                date: civil::format_day(day as i64),
+                word: word_pool.display_form(&word),
+            })
+        })
+        .collect()
+}
+
+/// Days since the Unix epoch of today (UTC)
+pub fn today() -> u64 {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    seconds / (24 * 60 * 60)
+}
+
+/// Days since the Unix epoch of a `YYYY-MM-DD` date. None if it isn't a
+/// valid date on or after 1970-01-01.
+pub fn parse_date(date: &str) -> Option<u64> {
+    let mut parts = date.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || !(1..=12).contains(&month) || day == 0 {
+        return None;
+    }
+    let days = civil::days_from_civil(year, month, day);
+    // Rejects e.g. February 30, which would land on a day in March
+    (civil::civil_from_days(days) == (year, month, day))
+        .then(|| u64::try_from(days).ok())
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordle_game::Word;
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2000-02-29"), Some(11_016));
+        assert_eq!(parse_date("2026-10-17"), Some(20_743));
+        assert_eq!(civil::format_day(20_743), "2026-10-17");
+        assert_eq!(parse_date("2026-02-30"), None);
+        assert_eq!(parse_date("1969-12-31"), None);
+        assert_eq!(parse_date("2026-10"), None);
+    }
+
+    #[test]
+    fn test_same_words_as_the_game() {
+        let pool =
+            WordPool::from_display_strings(["hello", "world", "crane", "e-mail"].map(String::from));
+        let words = upcoming(&pool, 20_743, 5);
+        assert_eq!(words.len(), 5);
+        for entry in &words {
+            let picked = DailySelector::for_day(entry.day).select(&pool).unwrap();
+            assert_eq!(entry.word, pool.display_form(&picked));
+        }
+        assert_eq!(words[1].date, "2026-10-18");
+        assert!(Word::parse(&words[0].word.replace('-', "")).is_some());
+    }
+}
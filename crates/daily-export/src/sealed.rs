@@ -0,0 +1,155 @@
+//! The encrypted export file: a magic header, a random nonce, and the
+//! daily words as JSON sealed with ChaCha20-Poly1305, so the file can sit
+//! on a server without giving the words away to whoever reads it.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+use crate::schedule::DailyWord;
+
+/// Start of every export file, including the format version. Also
+/// authenticated, so a file can't be passed off as another version.
+const MAGIC: &[u8] = b"wordle-daily\x01";
+/// Bytes of the nonce following the magic header
+const NONCE_LEN: usize = 12;
+
+/// A 256-bit key, written to key files as 64 hex digits
+pub struct SecretKey(Key);
+
+impl SecretKey {
+    /// A new random key
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    /// Parse the hex digits of a key file, ignoring surrounding whitespace
+    pub fn from_hex(hex: &str) -> io::Result<Self> {
+        let hex = hex.trim();
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "key must be 64 hex digits");
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+        let mut key = Key::default();
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).map_err(|_| invalid())?;
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| invalid())?;
+        }
+        Ok(Self(key))
+    }
+
+    /// Read a key file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_hex(&fs::read_to_string(path)?)
+    }
+
+    /// The key as 64 hex digits
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
+
+/// Encrypt daily words into the contents of an export file
+pub fn seal(key: &SecretKey, words: &[DailyWord]) -> io::Result<Vec<u8>> {
+    let json =
+        serde_json::to_vec(words).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(&key.0)
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: &json,
+                aad: MAGIC,
+            },
+        )
+        .map_err(|_| io::Error::other("encryption failed"))?;
+    let mut sealed = MAGIC.to_vec();
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+/// Decrypt the contents of an export file
+///
+/// # Errors
+///
+/// Returns an error if the file isn't an export file, was encrypted with
+/// another key, or was changed after it was written.
+pub fn open(key: &SecretKey, sealed: &[u8]) -> io::Result<Vec<DailyWord>> {
+    let invalid = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+    let rest = sealed
+        .strip_prefix(MAGIC)
+        .ok_or_else(|| invalid("not a daily word export"))?;
+    if rest.len() < NONCE_LEN {
+        return Err(invalid("export file is truncated"));
+    }
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let json = ChaCha20Poly1305::new(&key.0)
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: MAGIC,
+            },
+        )
+        .map_err(|_| invalid("wrong key, or the export file was changed"))?;
+    serde_json::from_slice(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Replace an export file atomically, so a server reading it never sees a
+/// half-written file
+pub fn write(path: &Path, sealed: &[u8]) -> io::Result<()> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    fs::write(&temp, sealed)?;
+    fs::rename(&temp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words() -> Vec<DailyWord> {
+        vec![DailyWord {
+            day: 20_743,
+            date: "2026-10-17".to_string(),
+            word: "hello".to_string(),
+        }]
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let key = SecretKey::generate();
+        let sealed = seal(&key, &words()).unwrap();
+        assert!(sealed.starts_with(MAGIC));
+        // The words can't be read without the key
+        assert!(!sealed.windows(5).any(|window| window == b"hello"));
+        assert_eq!(open(&key, &sealed).unwrap(), words());
+    }
+
+    #[test]
+    fn test_wrong_key_or_tampering() {
+        let key = SecretKey::generate();
+        let mut sealed = seal(&key, &words()).unwrap();
+        let other = SecretKey::generate();
+        assert_eq!(
+            open(&other, &sealed).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        *sealed.last_mut().unwrap() ^= 1;
+        assert!(open(&key, &sealed).is_err());
+        assert!(open(&key, b"something else").is_err());
+    }
+
+    #[test]
+    fn test_key_hex() {
+        let key = SecretKey::generate();
+        let parsed = SecretKey::from_hex(&format!("{}\n", key.to_hex())).unwrap();
+        assert_eq!(parsed.to_hex(), key.to_hex());
+        assert!(SecretKey::from_hex("abcd").is_err());
+        assert!(SecretKey::from_hex(&"zz".repeat(32)).is_err());
+    }
+}
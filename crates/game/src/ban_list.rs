@@ -0,0 +1,239 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::GameConfig;
+use crate::letter::{Letter, Word};
+use crate::rules::{BannedLetterRule, BannedOpenerRule, GuessRule};
+
+/// How strictly a ban list is enforced
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BanEnforcement {
+    /// Banned guesses are rejected
+    #[default]
+    Reject,
+    /// Banned guesses are accepted with a warning
+    Warn,
+}
+
+/// Openers and letters players ban for themselves, as a self-imposed
+/// challenge
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BanList {
+    openers: Vec<Word>,
+    letters: Vec<Letter>,
+    enforcement: BanEnforcement,
+}
+
+/// How a ban list is stored: words and letters as plain strings
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct BanListFile {
+    openers: Vec<String>,
+    letters: Vec<char>,
+    enforcement: BanEnforcement,
+}
+
+impl BanList {
+    /// Create an empty ban list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ban a word as first guess (banning it twice does nothing)
+    pub fn ban_opener(&mut self, word: Word) {
+        if !self.openers.contains(&word) {
+            self.openers.push(word);
+        }
+    }
+
+    /// Ban a letter from all guesses (banning it twice does nothing)
+    pub fn ban_letter(&mut self, letter: Letter) {
+        if !self.letters.contains(&letter) {
+            self.letters.push(letter);
+        }
+    }
+
+    pub fn set_enforcement(&mut self, enforcement: BanEnforcement) {
+        self.enforcement = enforcement;
+    }
+
+    /// Words banned as first guess
+    pub fn openers(&self) -> &[Word] {
+        &self.openers
+    }
+
+    /// Letters banned from all guesses
+    pub fn letters(&self) -> &[Letter] {
+        &self.letters
+    }
+
+    pub fn enforcement(&self) -> BanEnforcement {
+        self.enforcement
+    }
+
+    /// Is nothing banned
+    pub fn is_empty(&self) -> bool {
+        self.openers.is_empty() && self.letters.is_empty()
+    }
+
+    /// Rules enforcing the bans
+    pub fn rules(&self) -> Vec<Arc<dyn GuessRule>> {
+        let mut rules: Vec<Arc<dyn GuessRule>> = Vec::new();
+        if !self.openers.is_empty() {
+            rules.push(Arc::new(BannedOpenerRule::new(self.openers.clone())));
+        }
+        if !self.letters.is_empty() {
+            rules.push(Arc::new(BannedLetterRule::new(self.letters.clone())));
+        }
+        rules
+    }
+
+    /// Add the rules to `config`, as hard or soft rules depending on the
+    /// enforcement
+    pub fn apply(&self, config: &mut GameConfig) {
+        let rules = match self.enforcement {
+            BanEnforcement::Reject => &mut config.rules,
+            BanEnforcement::Warn => &mut config.soft_rules,
+        };
+        rules.extend(self.rules());
+    }
+
+    /// Write the ban list to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let file = BanListFile {
+            openers: self.openers.iter().map(Word::as_str).collect(),
+            letters: self.letters.iter().map(Letter::char).collect(),
+            enforcement: self.enforcement,
+        };
+        let json = serde_json::to_string_pretty(&file).expect("ban lists are always serializable");
+        fs::write(path, json)
+    }
+
+    /// Read a ban list from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        let file: BanListFile = serde_json::from_str(&json)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let invalid = |what: String| io::Error::new(io::ErrorKind::InvalidData, what);
+
+        let mut bans = Self::new();
+        for opener in &file.openers {
+            let word =
+                Word::parse(opener).ok_or_else(|| invalid(format!("invalid word '{opener}'")))?;
+            bans.ban_opener(word);
+        }
+        for &letter in &file.letters {
+            let letter =
+                Letter::new(letter).ok_or_else(|| invalid(format!("invalid letter '{letter}'")))?;
+            bans.ban_letter(letter);
+        }
+        bans.enforcement = file.enforcement;
+        Ok(bans)
+    }
+}
+
+impl fmt::Display for BanList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "Nothing banned");
+        }
+        let join = |items: Vec<String>| {
+            if items.is_empty() {
+                "none".to_string()
+            } else {
+                items.join(", ")
+            }
+        };
+        let openers = join(
+            self.openers
+                .iter()
+                .map(|w| w.as_str().to_uppercase())
+                .collect(),
+        );
+        let letters = join(self.letters.iter().map(Letter::to_uppercase).collect());
+        let enforcement = match self.enforcement {
+            BanEnforcement::Reject => "rejected",
+            BanEnforcement::Warn => "warned about",
+        };
+        write!(
+            f,
+            "Banned openers: {openers}. Banned letters: {letters}. Banned guesses are {enforcement}."
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{GuessError, GuessWarning};
+    use crate::game::Game;
+    use crate::word_pool::WordPool;
+
+    fn pool() -> WordPool {
+        WordPool::from_strings(["hello", "world", "crane", "slate"].map(String::from))
+    }
+
+    fn bans(enforcement: BanEnforcement) -> BanList {
+        let mut bans = BanList::new();
+        bans.ban_opener(Word::parse("crane").unwrap());
+        bans.ban_letter(Letter::new('s').unwrap());
+        bans.set_enforcement(enforcement);
+        bans
+    }
+
+    fn game(bans: &BanList) -> Game {
+        let mut config = GameConfig::default();
+        bans.apply(&mut config);
+        Game::with_secret_and_config(pool(), Word::parse("hello").unwrap(), config)
+    }
+
+    #[test]
+    fn test_reject() {
+        let mut game = game(&bans(BanEnforcement::Reject));
+        assert_eq!(game.guess("crane"), Err(GuessError::BannedOpener));
+        assert_eq!(
+            game.guess("slate"),
+            Err(GuessError::BannedLetter {
+                letter: Letter::new('s').unwrap()
+            })
+        );
+        game.guess("world").unwrap();
+        // Only the opener is banned
+        assert!(game.guess("crane").is_ok());
+    }
+
+    #[test]
+    fn test_warn() {
+        let mut game = game(&bans(BanEnforcement::Warn));
+        let feedback = game.guess("crane").unwrap();
+        assert_eq!(
+            feedback.warnings(),
+            &[GuessWarning::BrokenRule(GuessError::BannedOpener)]
+        );
+        assert_eq!(
+            feedback.warnings()[0].to_string(),
+            "Not allowed as first guess, but accepted"
+        );
+        assert!(game.guess("world").unwrap().warnings().is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("ban_list_test_{}.json", std::process::id()));
+        let bans = bans(BanEnforcement::Warn);
+        bans.save(&path).unwrap();
+        assert_eq!(BanList::load(&path).unwrap(), bans);
+        std::fs::remove_file(path).ok();
+
+        assert_eq!(
+            bans.to_string(),
+            "Banned openers: CRANE. Banned letters: S. Banned guesses are warned about."
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::error::GameError;
+use crate::letter::Word;
+
+/// Format version, so the encoding can change without misreading old codes
+const VERSION: u8 = 1;
+/// Key the payload is XORed with. This only keeps the secret from being
+/// readable at a glance, it is not meant to be secure.
+const KEY: &[u8] = b"wordle-challenge";
+/// Base32 alphabet without easily confused characters (0/o, 1/l)
+const ALPHABET: &[u8; 32] = b"abcdefghijkmnpqrstuvwxyz23456789";
+
+/// Encode a secret into a shareable challenge code, so a friend can play a
+/// word picked by someone else. The word doesn't have to be in the word list.
+pub fn challenge_code_for(word: &Word) -> String {
+    let mut payload = vec![VERSION];
+    payload.extend_from_slice(word.as_str().as_bytes());
+    encode_code(payload)
+}
+
+/// Decode a challenge code created by `challenge_code_for`. Case, surrounding
+/// whitespace and dashes are ignored, so codes survive being retyped.
+pub fn decode_challenge_code(code: &str) -> Result<Word, GameError> {
+    let payload = decode_code(code).ok_or(GameError::InvalidChallengeCode)?;
+    if payload.first() != Some(&VERSION) {
+        return Err(GameError::InvalidChallengeCode);
+    }
+    std::str::from_utf8(&payload[1..])
+        .ok()
+        .and_then(Word::parse)
+        .ok_or(GameError::InvalidChallengeCode)
+}
+
+/// Turn a payload into a code: checksummed, obfuscated and base32 encoded.
+/// The first byte of the payload should tell apart the kinds of codes.
+pub(crate) fn encode_code(mut payload: Vec<u8>) -> String {
+    payload.push(checksum(&payload));
+    obfuscate(&mut payload);
+    encode_base32(&payload)
+}
+
+/// Get the payload back from a code created by `encode_code`. Case,
+/// surrounding whitespace and dashes are ignored. Returns None if the code
+/// is malformed or the checksum doesn't match.
+pub(crate) fn decode_code(code: &str) -> Option<Vec<u8>> {
+    let cleaned: String = code
+        .trim()
+        .chars()
+        .filter(|&c| c != '-')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    let mut payload = decode_base32(&cleaned)?;
+    obfuscate(&mut payload);
+
+    let check = payload.pop()?;
+    (checksum(&payload) == check).then_some(payload)
+}
+
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes
+        .iter()
+        .fold(0u8, |sum, &b| sum.wrapping_mul(31).wrapping_add(b))
+}
+
+/// XOR with the key (its own inverse)
+fn obfuscate(bytes: &mut [u8]) {
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte ^= KEY[i % KEY.len()] ^ (i as u8).wrapping_mul(37);
+    }
+}
+
+fn encode_base32(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for &byte in bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+    out
+}
+
+fn decode_base32(code: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in code.bytes() {
+        let value = ALPHABET.iter().position(|&a| a == c)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        for word in ["hello", "crane", "äpfel", "straß"] {
+            let word = Word::parse(word).unwrap();
+            let code = challenge_code_for(&word);
+            assert_eq!(decode_challenge_code(&code), Ok(word));
+        }
+    }
+
+    #[test]
+    fn test_code_does_not_contain_word() {
+        let code = challenge_code_for(&Word::parse("hello").unwrap());
+        assert!(!code.contains("hello"));
+        assert!(code.bytes().all(|c| ALPHABET.contains(&c)));
+    }
+
+    #[test]
+    fn test_lenient_input() {
+        let word = Word::parse("crane").unwrap();
+        let code = challenge_code_for(&word);
+        let retyped = format!("  {}-{}\n", &code[..4], &code[4..]).to_uppercase();
+        assert_eq!(decode_challenge_code(&retyped), Ok(word));
+    }
+
+    #[test]
+    fn test_invalid_codes() {
+        assert_eq!(
+            decode_challenge_code(""),
+            Err(GameError::InvalidChallengeCode)
+        );
+        assert_eq!(
+            decode_challenge_code("not a code!"),
+            Err(GameError::InvalidChallengeCode)
+        );
+
+        // A typo breaks the checksum
+        let mut code = challenge_code_for(&Word::parse("hello").unwrap()).into_bytes();
+        code[3] = if code[3] == b'a' { b'b' } else { b'a' };
+        let code = String::from_utf8(code).unwrap();
+        assert_eq!(
+            decode_challenge_code(&code),
+            Err(GameError::InvalidChallengeCode)
+        );
+    }
+}
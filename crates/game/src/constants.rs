@@ -3,3 +3,21 @@ pub const WORD_LENGTH: usize = 5;
 
 /// Maximum number of guesses allowed (default)
 pub const MAX_GUESSES: usize = 6;
+
+/// Default number of letters in the letter bank (letter-bank variant)
+pub const LETTER_BANK_SIZE: usize = 20;
+
+/// Guess budget shared by both words of a cross game
+pub const CROSS_MAX_GUESSES: usize = 8;
+
+/// Number of boards in Quordle
+pub const QUORDLE_BOARDS: usize = 4;
+
+/// Guesses added to the shared budget for every word solved in survival mode
+pub const SURVIVAL_BONUS_GUESSES: usize = 1;
+
+/// Hints a game in the terminal UI gives
+pub const HINTS_PER_GAME: usize = 2;
+
+/// Number of puzzles in a weekly challenge, one per day
+pub const WEEKLY_CHALLENGE_PUZZLES: usize = 7;
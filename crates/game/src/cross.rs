@@ -0,0 +1,411 @@
+use rand::seq::SliceRandom;
+
+use crate::constants::WORD_LENGTH;
+use crate::error::GuessError;
+use crate::feedback::{GuessFeedback, LetterFeedback};
+use crate::game::{GameState, GuessResult, parse_guess};
+use crate::letter::{Letter, Word};
+use crate::playable::Playable;
+use crate::summary::GameSummary;
+use crate::timer::Timer;
+use crate::word_pool::WordPool;
+
+/// How many random across words to try before giving up on finding a crossing word
+const MAX_GENERATION_ATTEMPTS: usize = 100;
+
+/// Which of the two words of a cross game a guess is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrossTarget {
+    /// The horizontal word
+    Across,
+    /// The vertical word
+    Down,
+}
+
+/// A crosswordle game: two intersecting words that share one letter,
+/// both solved with a common guess budget
+#[derive(Debug, Clone)]
+pub struct CrossGame {
+    across: Word,
+    down: Word,
+    across_index: usize,
+    down_index: usize,
+    guesses: Vec<(CrossTarget, GuessFeedback)>,
+    max_guesses: usize,
+    word_pool: WordPool,
+    forfeited: bool,
+    timer: Timer,
+}
+
+impl CrossGame {
+    /// Create a new game with two random intersecting secret words.
+    /// Returns None if no intersecting pair could be found in the pool.
+    pub fn new(word_pool: WordPool, max_guesses: usize) -> Option<Self> {
+        let words: Vec<Word> = word_pool.iter().cloned().collect();
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..MAX_GENERATION_ATTEMPTS {
+            let across = words.choose(&mut rng)?;
+            let crossing: Vec<&Word> = words
+                .iter()
+                .filter(|&w| w != across && find_intersection(across, w).is_some())
+                .collect();
+            if let Some(&down) = crossing.choose(&mut rng) {
+                let (across, down) = (across.clone(), down.clone());
+                return Self::with_secrets(word_pool, across, down, max_guesses);
+            }
+        }
+        None
+    }
+
+    /// Create with specific secrets, crossing at their first shared letter.
+    /// Returns None if the words don't share a letter.
+    pub fn with_secrets(
+        word_pool: WordPool,
+        across: Word,
+        down: Word,
+        max_guesses: usize,
+    ) -> Option<Self> {
+        let (across_index, down_index) = find_intersection(&across, &down)?;
+        Some(Self {
+            across,
+            down,
+            across_index,
+            down_index,
+            guesses: Vec::new(),
+            max_guesses,
+            word_pool,
+            forfeited: false,
+            timer: Timer::start(),
+        })
+    }
+
+    /// Make a guess for one of the two words
+    pub fn guess_on(&mut self, target: CrossTarget, input: &str) -> GuessResult {
+        let word = parse_guess(input)?;
+        self.guess_word_on(target, &word)
+    }
+
+    /// Make a guess for one of the two words with a pre-parsed Word
+    pub fn guess_word_on(&mut self, target: CrossTarget, word: &Word) -> GuessResult {
+        if self.state() != GameState::Playing {
+            return Err(GuessError::GameOver);
+        }
+        if self.is_solved(target) {
+            return Err(GuessError::AlreadySolved);
+        }
+        if !self.word_pool.contains(word) {
+            return Err(GuessError::NotInWordList);
+        }
+
+        self.timer.resume();
+        let feedback = GuessFeedback::evaluate(word, self.secret_for(target));
+        self.guesses.push((target, feedback.clone()));
+        if self.state() != GameState::Playing {
+            self.timer.pause();
+        }
+        Ok(feedback)
+    }
+
+    fn secret_for(&self, target: CrossTarget) -> &Word {
+        match target {
+            CrossTarget::Across => &self.across,
+            CrossTarget::Down => &self.down,
+        }
+    }
+
+    /// Check if a word has been solved
+    pub fn is_solved(&self, target: CrossTarget) -> bool {
+        self.guesses_for(target).any(|g| g.is_win())
+    }
+
+    /// The first word that is not solved yet (guesses via `Playable` go there)
+    pub fn active_target(&self) -> Option<CrossTarget> {
+        [CrossTarget::Across, CrossTarget::Down]
+            .into_iter()
+            .find(|&t| !self.is_solved(t))
+    }
+
+    /// All guesses made so far, with the word they were for
+    pub fn guesses(&self) -> &[(CrossTarget, GuessFeedback)] {
+        &self.guesses
+    }
+
+    /// Guesses made for one of the two words
+    pub fn guesses_for(&self, target: CrossTarget) -> impl Iterator<Item = &GuessFeedback> + '_ {
+        self.guesses
+            .iter()
+            .filter(move |(t, _)| *t == target)
+            .map(|(_, g)| g)
+    }
+
+    /// Position of the shared letter as (index in across word, index in down word)
+    pub fn intersection(&self) -> (usize, usize) {
+        (self.across_index, self.down_index)
+    }
+
+    /// Letters of a word confirmed by correct guesses. The shared letter is
+    /// also known if it was confirmed in the other word.
+    pub fn known_letters(&self, target: CrossTarget) -> [Option<Letter>; WORD_LENGTH] {
+        let own = self.confirmed_letters(target);
+        let other = match target {
+            CrossTarget::Across => self.confirmed_letters(CrossTarget::Down),
+            CrossTarget::Down => self.confirmed_letters(CrossTarget::Across),
+        };
+        let (own_index, other_index) = match target {
+            CrossTarget::Across => (self.across_index, self.down_index),
+            CrossTarget::Down => (self.down_index, self.across_index),
+        };
+
+        let mut known = own;
+        if known[own_index].is_none() {
+            known[own_index] = other[other_index];
+        }
+        known
+    }
+
+    fn confirmed_letters(&self, target: CrossTarget) -> [Option<Letter>; WORD_LENGTH] {
+        let mut known = [None; WORD_LENGTH];
+        for guess in self.guesses_for(target) {
+            for (i, (letter, feedback)) in guess.iter().enumerate() {
+                if feedback == LetterFeedback::Correct {
+                    known[i] = Some(letter);
+                }
+            }
+        }
+        known
+    }
+
+    /// Get a secret word (only available after game ends)
+    pub fn secret(&self, target: CrossTarget) -> Option<&Word> {
+        match self.state() {
+            GameState::Playing => None,
+            _ => Some(self.secret_for(target)),
+        }
+    }
+
+    /// Summaries of the across and the down word once the game is over,
+    /// e.g. for the statistics. Empty while the game is being played.
+    pub fn summaries(&self) -> Vec<GameSummary> {
+        let state = self.state();
+        if state == GameState::Playing {
+            return Vec::new();
+        }
+        [CrossTarget::Across, CrossTarget::Down]
+            .into_iter()
+            .map(|target| {
+                GameSummary::of_board(
+                    &state,
+                    self.secret_for(target),
+                    self.guesses_for(target),
+                    self.timer.elapsed(),
+                    self.max_guesses,
+                    &self.word_pool,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Playable for CrossGame {
+    fn guess(&mut self, input: &str) -> GuessResult {
+        match self.active_target() {
+            Some(target) => self.guess_on(target, input),
+            None => Err(GuessError::GameOver),
+        }
+    }
+
+    fn forfeit(&mut self) {
+        if self.state() == GameState::Playing {
+            self.forfeited = true;
+            self.timer.pause();
+        }
+    }
+
+    fn state(&self) -> GameState {
+        if self.forfeited {
+            return GameState::Forfeited;
+        }
+        if self.active_target().is_none() {
+            return GameState::Won {
+                guesses_used: self.guesses.len(),
+            };
+        }
+        if self.guesses.len() >= self.max_guesses {
+            return GameState::Lost;
+        }
+        GameState::Playing
+    }
+
+    fn guesses_remaining(&self) -> usize {
+        self.max_guesses.saturating_sub(self.guesses.len())
+    }
+
+    fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    fn guesses_made(&self) -> usize {
+        self.guesses.len()
+    }
+
+    fn secrets(&self) -> Option<Vec<Word>> {
+        match self.state() {
+            GameState::Playing => None,
+            _ => Some(vec![self.across.clone(), self.down.clone()]),
+        }
+    }
+
+    fn pause(&mut self) {
+        self.timer.pause();
+    }
+
+    fn resume(&mut self) {
+        if self.state() == GameState::Playing {
+            self.timer.resume();
+        }
+    }
+}
+
+/// Find the first letter the two words share, as (index in a, index in b)
+fn find_intersection(a: &Word, b: &Word) -> Option<(usize, usize)> {
+    a.letters().enumerate().find_map(|(i, letter)| {
+        b.letters()
+            .position(|other| other == letter)
+            .map(|j| (i, j))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_pool() -> WordPool {
+        WordPool::from_strings(
+            ["crane", "slate", "hello", "world", "audio"]
+                .iter()
+                .map(|s| s.to_string()),
+        )
+    }
+
+    fn test_game() -> CrossGame {
+        CrossGame::with_secrets(
+            test_pool(),
+            Word::parse("crane").unwrap(),
+            Word::parse("slate").unwrap(),
+            8,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_intersection() {
+        let game = test_game();
+        // 'a' is the first shared letter: crane[2], slate[2]
+        assert_eq!(game.intersection(), (2, 2));
+    }
+
+    #[test]
+    fn test_no_intersection() {
+        let game = CrossGame::with_secrets(
+            test_pool(),
+            Word::parse("hello").unwrap(),
+            Word::parse("crane").unwrap(),
+            8,
+        );
+        // hello and crane share 'e'
+        assert!(game.is_some());
+
+        let game = CrossGame::with_secrets(
+            test_pool(),
+            Word::parse("audio").unwrap(),
+            Word::parse("crwth").unwrap(),
+            8,
+        );
+        assert!(game.is_none());
+    }
+
+    #[test]
+    fn test_win_both_words() {
+        let mut game = test_game();
+
+        assert_eq!(game.active_target(), Some(CrossTarget::Across));
+        game.guess("crane").unwrap();
+        assert_eq!(game.active_target(), Some(CrossTarget::Down));
+        assert_eq!(game.state(), GameState::Playing);
+
+        game.guess("slate").unwrap();
+        assert_eq!(game.state(), GameState::Won { guesses_used: 2 });
+        assert_eq!(
+            game.secrets(),
+            Some(vec![
+                Word::parse("crane").unwrap(),
+                Word::parse("slate").unwrap()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_summaries() {
+        let mut game = test_game();
+        game.guess_on(CrossTarget::Down, "hello").unwrap();
+        game.guess_on(CrossTarget::Down, "slate").unwrap();
+        assert!(game.summaries().is_empty());
+        for _ in 0..6 {
+            game.guess_on(CrossTarget::Across, "world").unwrap();
+        }
+
+        let summaries = game.summaries();
+        assert_eq!(summaries[0].secret.as_str(), "crane");
+        assert_eq!(summaries[0].outcome, GameState::Lost);
+        assert_eq!(summaries[0].guesses.len(), 6);
+        assert_eq!(summaries[1].outcome, GameState::Won { guesses_used: 2 });
+    }
+
+    #[test]
+    fn test_shared_budget() {
+        let mut game = test_game();
+
+        for _ in 0..4 {
+            game.guess_on(CrossTarget::Across, "hello").unwrap();
+            game.guess_on(CrossTarget::Down, "world").unwrap();
+        }
+        assert_eq!(game.guesses_remaining(), 0);
+        assert_eq!(game.state(), GameState::Lost);
+        assert_eq!(
+            game.guess_on(CrossTarget::Down, "slate"),
+            Err(GuessError::GameOver)
+        );
+    }
+
+    #[test]
+    fn test_solved_word_rejects_guesses() {
+        let mut game = test_game();
+
+        game.guess_on(CrossTarget::Down, "slate").unwrap();
+        assert_eq!(
+            game.guess_on(CrossTarget::Down, "slate"),
+            Err(GuessError::AlreadySolved)
+        );
+        assert_eq!(game.active_target(), Some(CrossTarget::Across));
+    }
+
+    #[test]
+    fn test_known_letters_share_intersection() {
+        let mut game = test_game();
+
+        // "slate" vs "slate" solves the down word, revealing the shared 'a'
+        game.guess_on(CrossTarget::Down, "slate").unwrap();
+        let known = game.known_letters(CrossTarget::Across);
+        assert_eq!(known[2], Letter::new('a'));
+        assert_eq!(known.iter().filter(|l| l.is_some()).count(), 1);
+    }
+
+    #[test]
+    fn test_random_generation() {
+        let game = CrossGame::new(test_pool(), 8).unwrap();
+        let (i, j) = game.intersection();
+        assert_eq!(game.across.letter(i), game.down.letter(j));
+        assert_ne!(game.across, game.down);
+    }
+}
@@ -0,0 +1,173 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::feedback::LetterFeedback;
+use crate::game::GameState;
+use crate::letter::Word;
+use crate::summary::GameSummary;
+
+/// Fun facts about how the player guesses, updated with every finished game
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CuriosityStats {
+    /// How often each letter was typed in a guess
+    letter_counts: BTreeMap<char, usize>,
+    /// How often each word was used as the first guess
+    opener_counts: HashMap<Word, usize>,
+    guesses: usize,
+    greens: usize,
+    fastest_solve: Option<Duration>,
+}
+
+impl CuriosityStats {
+    /// Create empty statistics
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the guesses of a finished game. Games still in progress are ignored.
+    pub fn record(&mut self, game: &GameSummary) {
+        if game.outcome == GameState::Playing {
+            return;
+        }
+
+        if let Some(opener) = game.guesses.first() {
+            *self.opener_counts.entry(opener.word.clone()).or_default() += 1;
+        }
+        for guess in &game.guesses {
+            self.guesses += 1;
+            for (letter, feedback) in guess.iter() {
+                *self.letter_counts.entry(letter.char()).or_default() += 1;
+                if feedback == LetterFeedback::Correct {
+                    self.greens += 1;
+                }
+            }
+        }
+        if game.is_won() {
+            let time = game.duration;
+            if self.fastest_solve.is_none_or(|fastest| time < fastest) {
+                self.fastest_solve = Some(time);
+            }
+        }
+    }
+
+    /// Add the counts of `other`, e.g. of games another instance of the
+    /// game recorded
+    pub fn merge(&mut self, other: &CuriosityStats) {
+        for (&letter, count) in &other.letter_counts {
+            *self.letter_counts.entry(letter).or_default() += count;
+        }
+        for (opener, count) in &other.opener_counts {
+            *self.opener_counts.entry(opener.clone()).or_default() += count;
+        }
+        self.guesses += other.guesses;
+        self.greens += other.greens;
+        self.fastest_solve = match (self.fastest_solve, other.fastest_solve) {
+            (Some(mine), Some(theirs)) => Some(mine.min(theirs)),
+            (mine, theirs) => mine.or(theirs),
+        };
+    }
+
+    /// The `n` letters typed most often with their counts, most used first
+    pub fn most_used_letters(&self, n: usize) -> Vec<(char, usize)> {
+        let mut letters: Vec<(char, usize)> = self
+            .letter_counts
+            .iter()
+            .map(|(&c, &count)| (c, count))
+            .collect();
+        // Stable sort keeps ties in alphabetical order
+        letters.sort_by_key(|&(_, count)| Reverse(count));
+        letters.truncate(n);
+        letters
+    }
+
+    /// The most frequent first guess and how often it was used.
+    /// Ties go to the alphabetically first word.
+    pub fn favorite_opener(&self) -> Option<(&Word, usize)> {
+        self.opener_counts
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.as_str().cmp(&a.0.as_str())))
+            .map(|(word, &count)| (word, count))
+    }
+
+    /// Average number of green letters per guess (0 if nothing was guessed)
+    pub fn average_greens_per_guess(&self) -> f64 {
+        if self.guesses == 0 {
+            return 0.0;
+        }
+        self.greens as f64 / self.guesses as f64
+    }
+
+    /// Shortest time from the start of a game to solving it
+    pub fn fastest_solve(&self) -> Option<Duration> {
+        self.fastest_solve
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Game;
+    use crate::word_pool::WordPool;
+
+    fn game(secret: &str, guesses: &[&str]) -> GameSummary {
+        let pool = WordPool::from_strings(["hello", "world", "crane", "slate"].map(String::from));
+        let mut game = Game::with_secret(pool, Word::parse(secret).unwrap());
+        for guess in guesses {
+            game.guess(guess).unwrap();
+        }
+        game.summary()
+    }
+
+    #[test]
+    fn test_records_finished_games() {
+        let mut stats = CuriosityStats::new();
+        stats.record(&game("hello", &["crane", "hello"]));
+        stats.record(&game("world", &["crane", "world"]));
+        stats.record(&game("slate", &["hello", "slate"]));
+
+        assert_eq!(
+            stats.favorite_opener(),
+            Some((&Word::parse("crane").unwrap(), 2))
+        );
+        // l: 2 in hello twice, 1 in world, 1 in slate
+        assert_eq!(stats.most_used_letters(1), vec![('l', 6)]);
+        // The openers share no position with the secrets, the 3 wins are all green
+        assert_eq!(stats.average_greens_per_guess(), 15.0 / 6.0);
+        assert!(stats.fastest_solve().is_some());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut first = CuriosityStats::new();
+        first.record(&game("hello", &["crane", "hello"]));
+        let mut second = CuriosityStats::new();
+        second.record(&game("world", &["crane", "world"]));
+        second.record(&game("slate", &["hello", "slate"]));
+
+        let mut all = CuriosityStats::new();
+        all.record(&game("hello", &["crane", "hello"]));
+        all.record(&game("world", &["crane", "world"]));
+        all.record(&game("slate", &["hello", "slate"]));
+        first.merge(&second);
+        assert_eq!(first.most_used_letters(3), all.most_used_letters(3));
+        assert_eq!(first.favorite_opener(), all.favorite_opener());
+        assert_eq!(
+            first.average_greens_per_guess(),
+            all.average_greens_per_guess()
+        );
+        assert!(first.fastest_solve() <= second.fastest_solve());
+    }
+
+    #[test]
+    fn test_ignores_games_in_progress() {
+        let mut stats = CuriosityStats::new();
+        stats.record(&game("hello", &["crane"]));
+        assert_eq!(stats, CuriosityStats::new());
+        assert_eq!(stats.favorite_opener(), None);
+        assert_eq!(stats.average_greens_per_guess(), 0.0);
+    }
+}
@@ -1,12 +1,18 @@
 use std::fmt;
+use std::time::Duration;
+
+use crate::letter::Letter;
 
 /// Errors that can occur in game logic
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum GameError {
     /// Word list could not be loaded
     WordListLoadError(String),
     /// Word pool is empty
     EmptyWordPool,
+    /// Challenge code is malformed or mistyped
+    InvalidChallengeCode,
 }
 
 impl fmt::Display for GameError {
@@ -14,6 +20,7 @@ impl fmt::Display for GameError {
         match self {
             GameError::WordListLoadError(msg) => write!(f, "Failed to load word list: {}", msg),
             GameError::EmptyWordPool => write!(f, "Word pool is empty"),
+            GameError::InvalidChallengeCode => write!(f, "Invalid challenge code"),
         }
     }
 }
@@ -25,3 +32,134 @@ impl From<std::io::Error> for GameError {
         GameError::WordListLoadError(err.to_string())
     }
 }
+
+/// Reasons a guess can be rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GuessError {
+    /// Fewer letters than the word length
+    TooShort { got: usize },
+    /// More letters than the word length
+    TooLong { got: usize },
+    /// Input contains a character that is not a letter
+    NonAlphabetic { char: char },
+    /// Word not in dictionary
+    NotInWordList,
+    /// Word can't be formed from the remaining letter bank
+    NotInLetterBank { letter: Letter },
+    /// Game already over
+    GameOver,
+    /// The targeted word is already solved
+    AlreadySolved,
+    /// The same word was guessed before
+    AlreadyGuessed,
+    /// Guess contains a letter the player may not use
+    BannedLetter { letter: Letter },
+    /// First guess is a word the player may not open with
+    BannedOpener,
+    /// Guess ignores a revealed hint in hard mode.
+    /// `position` is set if the letter must be placed at that position,
+    /// otherwise the letter must appear anywhere in the guess.
+    HardModeViolation {
+        letter: Letter,
+        position: Option<usize>,
+    },
+    /// Guessed too soon after the previous guess, see
+    /// `GameConfig::min_guess_interval`
+    TooFast { retry_after: Duration },
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessError::TooShort { .. } => write!(f, "Not enough letters"),
+            GuessError::TooLong { .. } => write!(f, "Too many letters"),
+            GuessError::NonAlphabetic { char } => write!(f, "'{}' is not a letter", char),
+            GuessError::NotInWordList => write!(f, "Not in word list"),
+            GuessError::NotInLetterBank { letter } => {
+                write!(
+                    f,
+                    "Not enough {} left in the letter bank",
+                    letter.to_uppercase()
+                )
+            }
+            GuessError::GameOver => write!(f, "Game is over"),
+            GuessError::AlreadySolved => write!(f, "Word already solved"),
+            GuessError::AlreadyGuessed => write!(f, "Already guessed"),
+            GuessError::BannedLetter { letter } => {
+                write!(f, "{} is not allowed", letter.to_uppercase())
+            }
+            GuessError::BannedOpener => write!(f, "Not allowed as first guess"),
+            GuessError::HardModeViolation {
+                letter,
+                position: Some(position),
+            } => write!(
+                f,
+                "{} must be in position {}",
+                letter.to_uppercase(),
+                position + 1
+            ),
+            GuessError::HardModeViolation {
+                letter,
+                position: None,
+            } => write!(f, "Guess must contain {}", letter.to_uppercase()),
+            GuessError::TooFast { retry_after } => {
+                // Rounded up, so waiting that long is always enough
+                let tenths = retry_after.as_millis().div_ceil(100);
+                write!(f, "Too fast, try again in {}.{}s", tenths / 10, tenths % 10)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GuessError {}
+
+/// Ways a recorded guess history can contradict its secret, found by
+/// [`verify_history`](crate::feedback::verify_history)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InconsistencyError {
+    /// The feedback of guess `guess` differs from the real feedback at
+    /// `position` (both 0-based)
+    WrongFeedback { guess: usize, position: usize },
+    /// Guess `guess` (0-based) comes after the secret was already found
+    GuessAfterWin { guess: usize },
+}
+
+impl fmt::Display for InconsistencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InconsistencyError::WrongFeedback { guess, position } => write!(
+                f,
+                "Guess {} has wrong feedback for letter {}",
+                guess + 1,
+                position + 1
+            ),
+            InconsistencyError::GuessAfterWin { guess } => {
+                write!(f, "Guess {} was made after the game was won", guess + 1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InconsistencyError {}
+
+/// Problems with an accepted guess that the game's config turned from
+/// errors into warnings
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GuessWarning {
+    /// Word not in dictionary (with `DictionaryCheck::WarnOnly`)
+    NotInWordList,
+    /// The guess breaks one of `GameConfig::soft_rules`
+    BrokenRule(GuessError),
+}
+
+impl fmt::Display for GuessWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessWarning::NotInWordList => write!(f, "Not in word list, but accepted"),
+            GuessWarning::BrokenRule(error) => write!(f, "{}, but accepted", error),
+        }
+    }
+}
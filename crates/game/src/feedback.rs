@@ -1,8 +1,14 @@
+use std::fmt;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
 use crate::constants::WORD_LENGTH;
+use crate::error::{GuessWarning, InconsistencyError};
 use crate::letter::{Letter, Word};
 
 /// Feedback for a single letter position
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LetterFeedback {
     /// Correct letter in correct position (green)
     Correct,
@@ -12,11 +18,35 @@ pub enum LetterFeedback {
     NotInWord,
 }
 
+impl LetterFeedback {
+    /// Colored square for sharing results without spoilers
+    pub fn emoji(&self) -> char {
+        match self {
+            LetterFeedback::Correct => '🟩',
+            LetterFeedback::WrongPosition => '🟨',
+            LetterFeedback::NotInWord => '⬜',
+        }
+    }
+}
+
+impl fmt::Display for LetterFeedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LetterFeedback::Correct => write!(f, "correct"),
+            LetterFeedback::WrongPosition => write!(f, "wrong position"),
+            LetterFeedback::NotInWord => write!(f, "not in word"),
+        }
+    }
+}
+
 /// Complete feedback for a guess
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GuessFeedback {
     word: Word,
     feedback: [LetterFeedback; WORD_LENGTH],
+    warnings: Vec<GuessWarning>,
+    /// Time the player took for the guess (only for guesses made in a game)
+    duration: Option<Duration>,
 }
 
 impl GuessFeedback {
@@ -56,9 +86,38 @@ impl GuessFeedback {
         Self {
             word: guess.clone(),
             feedback,
+            warnings: Vec::new(),
+            duration: None,
+        }
+    }
+
+    /// Feedback as recorded elsewhere, e.g. in a shared result. It isn't
+    /// checked against any secret, see [`verify_history`] for that.
+    pub fn new(word: Word, feedback: [LetterFeedback; WORD_LENGTH]) -> Self {
+        Self {
+            word,
+            feedback,
+            warnings: Vec::new(),
+            duration: None,
         }
     }
 
+    /// Attach a warning about the guess
+    pub(crate) fn add_warning(&mut self, warning: GuessWarning) {
+        self.warnings.push(warning);
+    }
+
+    /// Record how long the player took for the guess
+    pub(crate) fn set_duration(&mut self, duration: Duration) {
+        self.duration = Some(duration);
+    }
+
+    /// Time since the previous guess (or the start of the game), excluding
+    /// pauses. None for feedback evaluated outside of a game.
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
     /// Get the guessed word
     pub fn word(&self) -> &Word {
         &self.word
@@ -69,21 +128,103 @@ impl GuessFeedback {
         &self.feedback
     }
 
+    /// Warnings about the guess (e.g. it is not in the word list, but the
+    /// game accepts such guesses)
+    pub fn warnings(&self) -> &[GuessWarning] {
+        &self.warnings
+    }
+
     /// Check if this is a winning guess (all Correct)
     pub fn is_win(&self) -> bool {
         self.feedback.iter().all(|&f| f == LetterFeedback::Correct)
     }
 
+    /// Describe the feedback in words instead of colors, for screen readers
+    /// and logs, e.g. `K [correct] R [wrong position] A [not in word] ...`
+    pub fn to_text(&self) -> String {
+        self.iter()
+            .map(|(letter, feedback)| format!("{} [{}]", letter.to_uppercase(), feedback))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Iterate over (Letter, LetterFeedback) pairs
     pub fn iter(&self) -> impl Iterator<Item = (Letter, LetterFeedback)> + '_ {
         self.word.letters().zip(self.feedback.iter().copied())
     }
 }
 
+/// Check that a recorded guess history is what playing against `secret`
+/// would have produced: every guess has the feedback the secret gives it,
+/// and no guess follows a win. Used to validate replays and shared results.
+pub fn verify_history(
+    secret: &Word,
+    history: &[GuessFeedback],
+) -> Result<(), InconsistencyError> {
+    for (index, guess) in history.iter().enumerate() {
+        if index > 0 && history[index - 1].is_win() {
+            return Err(InconsistencyError::GuessAfterWin { guess: index });
+        }
+        let expected = GuessFeedback::evaluate(guess.word(), secret);
+        let mismatch = (0..WORD_LENGTH).find(|&i| expected.feedback[i] != guess.feedback[i]);
+        if let Some(position) = mismatch {
+            return Err(InconsistencyError::WrongFeedback {
+                guess: index,
+                position,
+            });
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_verify_history() {
+        let secret = Word::parse("hello").unwrap();
+        let evaluate =
+            |guess: &str| GuessFeedback::evaluate(&Word::parse(guess).unwrap(), &secret);
+
+        let history = [evaluate("world"), evaluate("hello")];
+        assert_eq!(verify_history(&secret, &history), Ok(()));
+        assert_eq!(verify_history(&secret, &[]), Ok(()));
+
+        // Claiming the 'o' of "world" is green
+        let mut feedback = *history[0].feedback();
+        feedback[1] = LetterFeedback::Correct;
+        let forged = GuessFeedback::new(history[0].word().clone(), feedback);
+        assert_eq!(
+            verify_history(&secret, &[forged]),
+            Err(InconsistencyError::WrongFeedback {
+                guess: 0,
+                position: 1
+            })
+        );
+
+        // The right feedback, but for another secret
+        let other = Word::parse("world").unwrap();
+        assert!(verify_history(&other, &history).is_err());
+
+        let too_long = [evaluate("hello"), evaluate("world")];
+        assert_eq!(
+            verify_history(&secret, &too_long),
+            Err(InconsistencyError::GuessAfterWin { guess: 1 })
+        );
+    }
+
+    #[test]
+    fn test_to_text() {
+        let guess = Word::parse("krank").unwrap();
+        let secret = Word::parse("roses").unwrap();
+        let feedback = GuessFeedback::evaluate(&guess, &secret);
+        assert_eq!(
+            feedback.to_text(),
+            "K [not in word] R [wrong position] A [not in word] N [not in word] K [not in word]"
+        );
+    }
+
     #[test]
     fn test_all_correct() {
         let guess = Word::parse("hello").unwrap();
@@ -1,6 +1,25 @@
-use crate::constants::MAX_GUESSES;
-use crate::feedback::GuessFeedback;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::challenge::decode_challenge_code;
+use crate::constants::{MAX_GUESSES, WORD_LENGTH};
+use crate::error::{GameError, GuessError, GuessWarning};
+use crate::feedback::{GuessFeedback, LetterFeedback};
+use crate::hint::Hint;
 use crate::letter::Word;
+use crate::letter_bank::LetterBank;
+use crate::letter_usage::LetterUsageHistogram;
+use crate::observer::GameObserver;
+use crate::phonetic;
+use crate::playable::Playable;
+use crate::rules::{self, Guess, GuessRule, RuleContext, WordValidator};
+use crate::score::{Score, word_difficulty};
+use crate::selector::{SecretSelector, UniformSelector};
+use crate::summary::GameSummary;
+use crate::timer::Timer;
 use crate::word_pool::WordPool;
 
 /// Configuration for a game
@@ -8,47 +27,115 @@ use crate::word_pool::WordPool;
 pub struct GameConfig {
     /// Maximum number of guesses allowed
     pub max_guesses: usize,
+    /// Size of the letter bank guesses must be formed from
+    /// (None disables the letter-bank variant)
+    pub letter_bank_size: Option<usize>,
+    /// Revealed hints must be used in subsequent guesses
+    pub hard_mode: bool,
+    /// Observers notified about events of every game created with this config
+    pub observers: Vec<Arc<dyn GameObserver>>,
+    /// Additional rules guesses must pass, checked after the built-in ones
+    pub rules: Vec<Arc<dyn GuessRule>>,
+    /// Rules that don't reject guesses, but put a `GuessWarning::BrokenRule`
+    /// on the feedback of guesses breaking them
+    pub soft_rules: Vec<Arc<dyn GuessRule>>,
+    /// How guesses that are not in the word list are treated
+    pub dictionary_check: DictionaryCheck,
+    /// Reject a word guessed before with `GuessError::AlreadyGuessed`
+    /// instead of spending a guess on it
+    pub reject_repeated_guesses: bool,
+    /// Number of hints a game gives (None for no limit)
+    pub max_hints: Option<usize>,
+    /// Asked about guesses that aren't in the word pool, to accept words
+    /// from another dictionary
+    pub extra_validator: Option<Arc<dyn WordValidator>>,
+    /// Shortest time between two guesses of a game, so scripts can't try
+    /// guesses as fast as they like (None for no limit). Guesses coming
+    /// sooner are rejected with `GuessError::TooFast`, and rejected guesses
+    /// count as well.
+    pub min_guess_interval: Option<Duration>,
+}
+
+/// How guesses that are not in the word list are treated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DictionaryCheck {
+    /// Reject them
+    #[default]
+    Strict,
+    /// Accept them, with a `GuessWarning::NotInWordList` on the feedback
+    WarnOnly,
+    /// Accept them silently (free play)
+    Off,
 }
 
 impl Default for GameConfig {
     fn default() -> Self {
         Self {
             max_guesses: MAX_GUESSES,
+            letter_bank_size: None,
+            hard_mode: false,
+            observers: Vec::new(),
+            rules: Vec::new(),
+            soft_rules: Vec::new(),
+            dictionary_check: DictionaryCheck::default(),
+            reject_repeated_guesses: false,
+            max_hints: None,
+            extra_validator: None,
+            min_guess_interval: None,
         }
     }
 }
 
-/// Current state of the game
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Current state of the game. New ways for a game to end may be added, so
+/// matches outside this crate need a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum GameState {
     /// Game in progress
     Playing,
     /// Player won
     Won { guesses_used: usize },
-    /// Player lost (exhausted all guesses)
+    /// Player lost (exhausted all guesses, or the letter bank can no longer form the secret)
     Lost,
+    /// Player gave up and had the secret revealed
+    Forfeited,
 }
 
-/// Result of a guess attempt
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum GuessResult {
-    /// Guess accepted, here's the feedback
-    Accepted(GuessFeedback),
-    /// Word not in dictionary
-    NotInWordList,
-    /// Game already over
-    GameOver,
-    /// Invalid input (not 5 letters, non-alphabetic)
-    InvalidInput,
+impl fmt::Display for GameState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameState::Playing => write!(f, "Playing"),
+            GameState::Won { guesses_used: 1 } => write!(f, "Won in 1 guess"),
+            GameState::Won { guesses_used } => write!(f, "Won in {} guesses", guesses_used),
+            GameState::Lost => write!(f, "Lost"),
+            GameState::Forfeited => write!(f, "Gave up"),
+        }
+    }
 }
 
+/// Result of a guess attempt: the feedback if accepted, otherwise why it was
+/// rejected (see [`GuessError`], which may gain new reasons)
+pub type GuessResult = Result<GuessFeedback, GuessError>;
+
 /// The main game struct
 #[derive(Debug, Clone)]
 pub struct Game {
     secret: Word,
     guesses: Vec<GuessFeedback>,
+    hints: Vec<Hint>,
+    letter_bank: Option<LetterBank>,
+    /// Rules guesses are validated with, in order
+    rules: Vec<Arc<dyn GuessRule>>,
     config: GameConfig,
     word_pool: WordPool,
+    /// Playing time, paused when the game ends
+    timer: Timer,
+    /// Playing time at the most recent guess
+    last_guess_at: Duration,
+    /// When the most recent guess was tried, for the rate limit
+    last_attempt: Option<Instant>,
+    forfeited: bool,
 }
 
 impl Game {
@@ -59,54 +146,166 @@ impl Game {
 
     /// Create with custom config
     pub fn with_config(word_pool: WordPool, config: GameConfig) -> Self {
-        let secret = word_pool.random().clone();
-        Self {
-            secret,
-            guesses: Vec::new(),
-            config,
-            word_pool,
-        }
+        Self::with_selector(word_pool, &mut UniformSelector, config)
+            .expect("WordPool should not be empty")
+    }
+
+    /// Create with a secret picked by a selection strategy
+    pub fn with_selector(
+        word_pool: WordPool,
+        selector: &mut dyn SecretSelector,
+        config: GameConfig,
+    ) -> Result<Self, GameError> {
+        let secret = selector
+            .select(&word_pool)
+            .ok_or(GameError::EmptyWordPool)?;
+        Ok(Self::with_secret_and_config(word_pool, secret, config))
     }
 
     /// Create with specific secret (for testing)
     pub fn with_secret(word_pool: WordPool, secret: Word) -> Self {
-        Self {
+        Self::with_secret_and_config(word_pool, secret, GameConfig::default())
+    }
+
+    /// Create with specific secret and custom config
+    pub fn with_secret_and_config(word_pool: WordPool, secret: Word, config: GameConfig) -> Self {
+        let letter_bank = config
+            .letter_bank_size
+            .map(|size| LetterBank::generate(&secret, &word_pool, size));
+        let game = Self {
             secret,
             guesses: Vec::new(),
-            config: GameConfig::default(),
+            hints: Vec::new(),
+            letter_bank,
+            rules: rules::rules_for(&config),
+            config,
             word_pool,
+            timer: Timer::start(),
+            last_guess_at: Duration::ZERO,
+            last_attempt: None,
+            forfeited: false,
+        };
+        for observer in &game.config.observers {
+            observer.on_new_game(&game);
         }
+        game
+    }
+
+    /// Create a game for a secret a friend encoded with `challenge_code_for`.
+    /// The secret doesn't have to be in the word pool.
+    pub fn with_challenge_code(word_pool: WordPool, code: &str) -> Result<Self, GameError> {
+        let secret = decode_challenge_code(code)?;
+        Ok(Self::with_secret(word_pool, secret))
     }
 
-    /// Make a guess (string input for convenience)
+    /// Make a guess (string input for convenience). The input is spelled
+    /// the way the word pool spells 'ß' first, so "gross" and "groß" match
+    /// the same word.
     pub fn guess(&mut self, input: &str) -> GuessResult {
-        match Word::parse(input) {
-            Some(word) => self.guess_word(&word),
-            None => GuessResult::InvalidInput,
-        }
+        let input = self.word_pool.sharp_s().apply(input);
+        self.submit(Guess::new(&input), Instant::now())
     }
 
     /// Make a guess with a pre-parsed Word
     pub fn guess_word(&mut self, word: &Word) -> GuessResult {
+        self.submit(Guess::new(&word.as_str()), Instant::now())
+    }
+
+    /// Reject a guess tried sooner than `min_guess_interval` after the
+    /// previous one, otherwise note when it was tried
+    fn check_guess_interval(&mut self, now: Instant) -> Result<(), GuessError> {
+        if let (Some(interval), Some(last)) = (self.config.min_guess_interval, self.last_attempt) {
+            let since = now.saturating_duration_since(last);
+            if since < interval {
+                return Err(GuessError::TooFast {
+                    retry_after: interval - since,
+                });
+            }
+        }
+        self.last_attempt = Some(now);
+        Ok(())
+    }
+
+    /// Make a guess at `now`
+    fn submit(&mut self, guess: Guess<'_>, now: Instant) -> GuessResult {
         // Check if game is already over
         if self.state() != GameState::Playing {
-            return GuessResult::GameOver;
+            return Err(GuessError::GameOver);
         }
+        self.check_guess_interval(now)?;
 
-        // Check if word is in the word list
-        if !self.word_pool.contains(word) {
-            return GuessResult::NotInWordList;
+        let context = RuleContext {
+            word_pool: &self.word_pool,
+            secret: &self.secret,
+            guesses: &self.guesses,
+            letter_bank: self.letter_bank.as_ref(),
+            extra_validator: self.config.extra_validator.as_deref(),
+        };
+        let broken_rules = rules::check_soft_rules(&self.config.soft_rules, &guess, &context);
+        let word = rules::check_rules(&self.rules, guess, &context)?;
+
+        // Consume the letters from the letter bank, if playing with one
+        if let Some(bank) = &mut self.letter_bank {
+            bank.consume(&word)
+                .map_err(|letter| GuessError::NotInLetterBank { letter })?;
         }
 
         // Evaluate the guess
-        let feedback = GuessFeedback::evaluate(word, &self.secret);
+        let mut feedback = GuessFeedback::evaluate(&word, &self.secret);
+        self.timer.resume_at(now);
+        let elapsed = self.timer.elapsed_at(now);
+        feedback.set_duration(elapsed - self.last_guess_at);
+        self.last_guess_at = elapsed;
+        if self.config.dictionary_check == DictionaryCheck::WarnOnly
+            && !self.is_valid_word(&word)
+            && word != self.secret
+        {
+            feedback.add_warning(GuessWarning::NotInWordList);
+        }
+        for error in broken_rules {
+            feedback.add_warning(GuessWarning::BrokenRule(error));
+        }
         self.guesses.push(feedback.clone());
+        if self.state() != GameState::Playing {
+            self.timer.pause_at(now);
+        }
+        self.notify_guess(&feedback);
+
+        Ok(feedback)
+    }
 
-        GuessResult::Accepted(feedback)
+    fn notify_guess(&self, feedback: &GuessFeedback) {
+        let state = self.state();
+        for observer in &self.config.observers {
+            observer.on_guess(self, feedback);
+            match state {
+                GameState::Won { .. } => observer.on_win(self),
+                GameState::Lost => observer.on_lose(self),
+                GameState::Forfeited | GameState::Playing => {}
+            }
+        }
+    }
+
+    /// Give up and reveal the secret. Returns None if the game is already over.
+    pub fn forfeit(&mut self) -> Option<&Word> {
+        if self.state() != GameState::Playing {
+            return None;
+        }
+
+        self.forfeited = true;
+        self.timer.pause();
+        for observer in &self.config.observers {
+            observer.on_forfeit(self);
+        }
+        Some(&self.secret)
     }
 
     /// Current game state
     pub fn state(&self) -> GameState {
+        if self.forfeited {
+            return GameState::Forfeited;
+        }
+
         // Check if the last guess was correct
         if self.guesses.last().is_some_and(|last| last.is_win()) {
             return GameState::Won {
@@ -119,6 +318,15 @@ impl Game {
             return GameState::Lost;
         }
 
+        // Check if the letter bank can still form the secret
+        if self
+            .letter_bank
+            .as_ref()
+            .is_some_and(|bank| !bank.can_form(&self.secret))
+        {
+            return GameState::Lost;
+        }
+
         GameState::Playing
     }
 
@@ -145,20 +353,228 @@ impl Game {
         }
     }
 
-    /// Check if a word is in the valid word list
+    /// The secret word as the word list writes it, e.g. with a hyphen
+    /// (only available after game ends)
+    pub fn revealed_secret(&self) -> Option<String> {
+        self.secret()
+            .map(|secret| self.word_pool.display_form(secret))
+    }
+
+    /// Check if a word is in the valid word list, or accepted by the
+    /// config's extra validator
     pub fn is_valid_word(&self, word: &Word) -> bool {
         self.word_pool.contains(word)
+            || self
+                .config
+                .extra_validator
+                .as_ref()
+                .is_some_and(|validator| validator.accepts(word))
     }
 
     /// Get max guesses allowed
     pub fn max_guesses(&self) -> usize {
         self.config.max_guesses
     }
+
+    /// Reveal the letter at the first position that is not yet known.
+    /// Returns None if the game is over, no hints are left or every
+    /// position is already known.
+    pub fn hint(&mut self) -> Option<Hint> {
+        if self.state() != GameState::Playing || self.hints_left() == Some(0) {
+            return None;
+        }
+
+        let position = (0..WORD_LENGTH).find(|&i| {
+            let guessed = self
+                .guesses
+                .iter()
+                .any(|g| g.feedback()[i] == LetterFeedback::Correct);
+            let revealed = self
+                .hints
+                .iter()
+                .any(|h| matches!(h, Hint::Letter { position, .. } if *position == i));
+            !guessed && !revealed
+        })?;
+
+        let hint = Hint::Letter {
+            position,
+            letter: self.secret.letter(position),
+        };
+        self.hints.push(hint.clone());
+        Some(hint)
+    }
+
+    /// Reveal whether the secret rhymes with or sounds like a reference word,
+    /// as an alternative to revealing letters.
+    /// Returns None if the game is over or no hints are left.
+    pub fn phonetic_hint(&mut self, reference: &str) -> Option<Hint> {
+        if self.state() != GameState::Playing || self.hints_left() == Some(0) {
+            return None;
+        }
+
+        let secret = self.secret.as_str();
+        let hint = Hint::Phonetic {
+            reference: reference.to_lowercase(),
+            rhymes: phonetic::rhymes(&secret, reference),
+            sounds_like: phonetic::sounds_like(&secret, reference),
+        };
+        self.hints.push(hint.clone());
+        Some(hint)
+    }
+
+    /// All hints given so far
+    pub fn hints(&self) -> &[Hint] {
+        &self.hints
+    }
+
+    /// Number of hints the game still gives (None if there is no limit)
+    pub fn hints_left(&self) -> Option<usize> {
+        self.config
+            .max_hints
+            .map(|max| max.saturating_sub(self.hints.len()))
+    }
+
+    /// How often each letter was guessed so far and with what feedback
+    pub fn letter_usage_histogram(&self) -> LetterUsageHistogram {
+        let mut histogram = LetterUsageHistogram::new();
+        for guess in &self.guesses {
+            histogram.record(guess);
+        }
+        histogram
+    }
+
+    /// Spoiler-free summary to share the result: the number of guesses
+    /// (`X` if not solved) and a row of colored squares per guess
+    pub fn share_text(&self) -> String {
+        self.summary().share_text()
+    }
+
+    /// The game as shown and kept once it is over. Includes the secret, so
+    /// don't show it while the game is still being played.
+    pub fn summary(&self) -> GameSummary {
+        GameSummary::new(self)
+    }
+
+    /// The secret word, regardless of the game state (for recording)
+    pub(crate) fn secret_word(&self) -> &Word {
+        &self.secret
+    }
+
+    /// The words the game is played with
+    pub(crate) fn word_pool(&self) -> &WordPool {
+        &self.word_pool
+    }
+
+    /// The config this game was created with
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Score of a finished game (None while playing). Lost and forfeited games score 0.
+    pub fn score(&self) -> Option<Score> {
+        match self.state() {
+            GameState::Playing => None,
+            GameState::Lost | GameState::Forfeited => Some(Score::default()),
+            GameState::Won { guesses_used } => Some(Score::for_win(
+                guesses_used,
+                self.config.max_guesses,
+                self.elapsed(),
+                self.config.hard_mode,
+                word_difficulty(&self.secret, &self.word_pool),
+            )),
+        }
+    }
+
+    /// Playing time from the start of the game until it ended (or until
+    /// now), excluding pauses
+    pub fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+
+    /// Stop the clock, e.g. while the player is away. Guessing resumes it.
+    pub fn pause(&mut self) {
+        self.timer.pause();
+    }
+
+    /// Restart the clock after `pause`. Does nothing once the game is over.
+    pub fn resume(&mut self) {
+        if self.state() == GameState::Playing {
+            self.timer.resume();
+        }
+    }
+
+    /// Whether the clock is stopped, because the game is paused or over
+    pub fn is_paused(&self) -> bool {
+        self.timer.is_paused()
+    }
+
+    /// Remaining letter bank (only in the letter-bank variant)
+    pub fn letter_bank(&self) -> Option<&LetterBank> {
+        self.letter_bank.as_ref()
+    }
+}
+
+impl Playable for Game {
+    fn guess(&mut self, input: &str) -> GuessResult {
+        Game::guess(self, input)
+    }
+
+    fn state(&self) -> GameState {
+        Game::state(self)
+    }
+
+    fn forfeit(&mut self) {
+        Game::forfeit(self);
+    }
+
+    fn guesses_remaining(&self) -> usize {
+        Game::guesses_remaining(self)
+    }
+
+    fn max_guesses(&self) -> usize {
+        Game::max_guesses(self)
+    }
+
+    fn guesses_made(&self) -> usize {
+        self.guesses().len()
+    }
+
+    fn secrets(&self) -> Option<Vec<Word>> {
+        self.secret().map(|secret| vec![secret.clone()])
+    }
+
+    fn pause(&mut self) {
+        Game::pause(self);
+    }
+
+    fn resume(&mut self) {
+        Game::resume(self);
+    }
+}
+
+/// Parse user input into a Word, explaining what's wrong with invalid input
+pub(crate) fn parse_guess(input: &str) -> Result<Word, GuessError> {
+    if let Some(char) = input.chars().find(|c| !c.is_alphabetic()) {
+        return Err(GuessError::NonAlphabetic { char });
+    }
+
+    let got = input.chars().count();
+    if got < WORD_LENGTH {
+        return Err(GuessError::TooShort { got });
+    }
+    if got > WORD_LENGTH {
+        return Err(GuessError::TooLong { got });
+    }
+
+    Ok(Word::parse(input).expect("input was validated above"))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::letter::Letter;
+    use std::collections::HashSet;
+    use wordle_wordlists_processing::sharp_s::SharpSPolicy;
 
     fn test_pool() -> WordPool {
         WordPool::from_strings(vec![
@@ -176,7 +592,7 @@ mod tests {
         let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
 
         let result = game.guess("hello");
-        assert!(matches!(result, GuessResult::Accepted(f) if f.is_win()));
+        assert!(matches!(result, Ok(f) if f.is_win()));
         assert_eq!(game.state(), GameState::Won { guesses_used: 1 });
     }
 
@@ -185,21 +601,159 @@ mod tests {
         let pool = test_pool();
         let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
 
-        game.guess("world");
-        game.guess("crane");
+        game.guess("world").unwrap();
+        game.guess("crane").unwrap();
         let result = game.guess("hello");
 
-        assert!(matches!(result, GuessResult::Accepted(f) if f.is_win()));
+        assert!(matches!(result, Ok(f) if f.is_win()));
         assert_eq!(game.state(), GameState::Won { guesses_used: 3 });
     }
 
+    #[test]
+    fn test_forfeit_reveals_secret() {
+        let pool = test_pool();
+        let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+        game.guess("world").unwrap();
+        assert_eq!(game.secret(), None);
+
+        assert_eq!(
+            game.forfeit().map(Word::to_string),
+            Some("hello".to_string())
+        );
+        assert_eq!(game.state(), GameState::Forfeited);
+        assert_eq!(
+            game.secret().map(Word::to_string),
+            Some("hello".to_string())
+        );
+        assert_eq!(game.score(), Some(Score::default()));
+        assert_eq!(game.guess("hello"), Err(GuessError::GameOver));
+        assert_eq!(game.forfeit(), None);
+    }
+
+    #[test]
+    fn test_reject_repeated_guesses() {
+        let mut game = Game::with_secret(test_pool(), Word::parse("hello").unwrap());
+        game.guess("world").unwrap();
+        game.guess("world").unwrap();
+        assert_eq!(game.guesses().len(), 2);
+
+        let config = GameConfig {
+            reject_repeated_guesses: true,
+            ..GameConfig::default()
+        };
+        let mut game =
+            Game::with_secret_and_config(test_pool(), Word::parse("hello").unwrap(), config);
+        game.guess("world").unwrap();
+        assert_eq!(game.guess("WORLD"), Err(GuessError::AlreadyGuessed));
+        assert_eq!(game.guesses().len(), 1);
+        assert_eq!(game.guesses_remaining(), MAX_GUESSES - 1);
+    }
+
+    #[test]
+    fn test_timer() {
+        let pool = test_pool();
+        let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        game.timer = Timer::started_at(at(0));
+        game.timer.pause_at(at(10));
+        assert!(game.is_paused());
+        assert_eq!(game.timer.elapsed_at(at(50)), Duration::from_millis(10));
+
+        // Guessing resumes the clock
+        let first = game.submit(Guess::new("world"), at(100)).unwrap();
+        assert!(!game.is_paused());
+        assert_eq!(first.duration(), Some(Duration::from_millis(10)));
+        let second = game.submit(Guess::new("hello"), at(130)).unwrap();
+        assert_eq!(second.duration(), Some(Duration::from_millis(30)));
+
+        // The clock stops when the game ends
+        assert!(game.is_paused());
+        game.resume();
+        assert!(game.is_paused());
+        assert_eq!(game.timer.elapsed_at(at(500)), Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_share_text() {
+        let mut game = Game::with_secret(test_pool(), Word::parse("hello").unwrap());
+        game.guess("world").unwrap();
+        assert_eq!(game.share_text(), "Wordle X/6\n⬜🟨⬜🟩⬜");
+        game.guess("hello").unwrap();
+        assert_eq!(game.share_text(), "Wordle 2/6\n⬜🟨⬜🟩⬜\n🟩🟩🟩🟩🟩");
+    }
+
+    #[test]
+    fn test_letter_usage_histogram() {
+        let mut game = Game::with_secret(test_pool(), Word::parse("hello").unwrap());
+        assert!(game.letter_usage_histogram().is_empty());
+        game.guess("world").unwrap();
+        game.guess("hello").unwrap();
+
+        let histogram = game.letter_usage_histogram();
+        let l = histogram.get(Letter::new('l').unwrap());
+        assert_eq!((l.correct, l.wrong_position, l.not_in_word), (3, 0, 0));
+        let w = histogram.get(Letter::new('w').unwrap());
+        assert_eq!(w.not_in_word, 1);
+    }
+
+    #[test]
+    fn test_state_display() {
+        assert_eq!(GameState::Playing.to_string(), "Playing");
+        assert_eq!(
+            GameState::Won { guesses_used: 1 }.to_string(),
+            "Won in 1 guess"
+        );
+        assert_eq!(
+            GameState::Won { guesses_used: 4 }.to_string(),
+            "Won in 4 guesses"
+        );
+        assert_eq!(GameState::Forfeited.to_string(), "Gave up");
+    }
+
+    #[test]
+    fn test_dictionary_check() {
+        let new_game = |dictionary_check| {
+            let config = GameConfig {
+                dictionary_check,
+                ..GameConfig::default()
+            };
+            Game::with_secret_and_config(test_pool(), Word::parse("hello").unwrap(), config)
+        };
+
+        let mut game = new_game(DictionaryCheck::Strict);
+        assert_eq!(game.guess("zzzzz"), Err(GuessError::NotInWordList));
+
+        let mut game = new_game(DictionaryCheck::WarnOnly);
+        let feedback = game.guess("zzzzz").unwrap();
+        assert_eq!(feedback.warnings(), &[GuessWarning::NotInWordList]);
+        assert!(game.guess("world").unwrap().warnings().is_empty());
+
+        let mut game = new_game(DictionaryCheck::Off);
+        assert!(game.guess("zzzzz").unwrap().warnings().is_empty());
+    }
+
+    #[test]
+    fn test_extra_validator() {
+        let guest_words = HashSet::from([Word::parse("qwert").unwrap()]);
+        let config = GameConfig {
+            extra_validator: Some(Arc::new(guest_words)),
+            ..GameConfig::default()
+        };
+        let mut game =
+            Game::with_secret_and_config(test_pool(), Word::parse("hello").unwrap(), config);
+        assert!(game.is_valid_word(&Word::parse("qwert").unwrap()));
+        assert!(game.guess("qwert").unwrap().warnings().is_empty());
+        assert_eq!(game.guess("zzzzz"), Err(GuessError::NotInWordList));
+    }
+
     #[test]
     fn test_lose_after_max_guesses() {
         let pool = test_pool();
         let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
 
         for _ in 0..MAX_GUESSES {
-            game.guess("world");
+            game.guess("world").unwrap();
         }
 
         assert_eq!(game.state(), GameState::Lost);
@@ -212,10 +766,17 @@ mod tests {
         let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
 
         let result = game.guess("hi");
-        assert_eq!(result, GuessResult::InvalidInput);
+        assert_eq!(result, Err(GuessError::TooShort { got: 2 }));
+
+        let result = game.guess("toolong");
+        assert_eq!(result, Err(GuessError::TooLong { got: 7 }));
 
         let result = game.guess("12345");
-        assert_eq!(result, GuessResult::InvalidInput);
+        assert_eq!(result, Err(GuessError::NonAlphabetic { char: '1' }));
+
+        let result = game.guess("he");
+        assert_eq!(result, Err(GuessError::TooShort { got: 2 }));
+        assert!(game.guesses().is_empty());
     }
 
     #[test]
@@ -224,7 +785,75 @@ mod tests {
         let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
 
         let result = game.guess("zzzzz");
-        assert_eq!(result, GuessResult::NotInWordList);
+        assert_eq!(result, Err(GuessError::NotInWordList));
+    }
+
+    #[test]
+    fn test_guess_follows_sharp_s_policy() {
+        let game_for = |word: &str, sharp_s| {
+            let pool = WordPool::from_strings(vec![word.to_string()]).with_sharp_s(sharp_s);
+            Game::with_secret(pool, Word::parse(word).unwrap())
+        };
+
+        let mut game = game_for("füßen", SharpSPolicy::NormalizeToSharpS);
+        let feedback = game.guess("füssen").unwrap();
+        assert_eq!(feedback.word(), &Word::parse("füßen").unwrap());
+        assert!(feedback.is_win());
+
+        let mut game = game_for("füsse", SharpSPolicy::NormalizeToSs);
+        assert!(game.guess("füße").unwrap().is_win());
+
+        let mut game = game_for("maßen", SharpSPolicy::PreserveBoth);
+        assert_eq!(game.guess("massen"), Err(GuessError::TooLong { got: 6 }));
+    }
+
+    #[test]
+    fn test_with_selector() {
+        let mut selector = crate::selector::DailySelector::for_day(1);
+        let a = Game::with_selector(test_pool(), &mut selector, GameConfig::default()).unwrap();
+        let b = Game::with_selector(test_pool(), &mut selector, GameConfig::default()).unwrap();
+        assert_eq!(a.secret_word(), b.secret_word());
+
+        let empty = WordPool::from_words(Vec::new());
+        assert_eq!(
+            Game::with_selector(empty, &mut UniformSelector, GameConfig::default()).unwrap_err(),
+            GameError::EmptyWordPool
+        );
+    }
+
+    #[test]
+    fn test_score() {
+        let mut game = Game::with_secret(test_pool(), Word::parse("hello").unwrap());
+        assert_eq!(game.score(), None);
+
+        game.guess("world").unwrap();
+        game.guess("hello").unwrap();
+        let score = game.score().unwrap();
+        assert_eq!(score.guesses, 833);
+        assert!(score.total() > score.guesses);
+
+        let mut game = Game::with_secret(test_pool(), Word::parse("hello").unwrap());
+        for _ in 0..MAX_GUESSES {
+            game.guess("world").unwrap();
+        }
+        assert_eq!(game.score().map(|s| s.total()), Some(0));
+    }
+
+    #[test]
+    fn test_challenge_code() {
+        // The secret of a challenge may be outside the word list
+        let code = crate::challenge::challenge_code_for(&Word::parse("pizza").unwrap());
+        let mut game = Game::with_challenge_code(test_pool(), &code).unwrap();
+
+        assert_eq!(game.guess("zzzzz"), Err(GuessError::NotInWordList));
+        game.guess("hello").unwrap();
+        game.guess("pizza").unwrap();
+        assert_eq!(game.state(), GameState::Won { guesses_used: 2 });
+
+        assert_eq!(
+            Game::with_challenge_code(test_pool(), "bogus").unwrap_err(),
+            GameError::InvalidChallengeCode
+        );
     }
 
     #[test]
@@ -232,9 +861,9 @@ mod tests {
         let pool = test_pool();
         let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
 
-        game.guess("hello"); // Win
+        game.guess("hello").unwrap(); // Win
         let result = game.guess("world");
-        assert_eq!(result, GuessResult::GameOver);
+        assert_eq!(result, Err(GuessError::GameOver));
     }
 
     #[test]
@@ -243,7 +872,292 @@ mod tests {
         let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
 
         assert_eq!(game.guesses_remaining(), MAX_GUESSES);
-        game.guess("world");
+        game.guess("world").unwrap();
         assert_eq!(game.guesses_remaining(), MAX_GUESSES - 1);
     }
+
+    fn hard_mode_game(secret: &str) -> Game {
+        let pool = WordPool::from_strings(
+            ["hello", "hallo", "holly", "world", "lemon", "olive"]
+                .iter()
+                .map(|s| s.to_string()),
+        );
+        let config = GameConfig {
+            hard_mode: true,
+            ..GameConfig::default()
+        };
+        Game::with_secret_and_config(pool, Word::parse(secret).unwrap(), config)
+    }
+
+    #[test]
+    fn test_hard_mode_requires_correct_letters_in_place() {
+        let mut game = hard_mode_game("hello");
+
+        game.guess("hallo").unwrap(); // h, l, l, o correct
+        assert_eq!(
+            game.guess("world"),
+            Err(GuessError::HardModeViolation {
+                letter: Letter::new('h').unwrap(),
+                position: Some(0),
+            })
+        );
+        assert!(game.guess("hello").is_ok());
+    }
+
+    #[test]
+    fn test_hard_mode_requires_wrong_position_letters() {
+        let mut game = hard_mode_game("olive");
+
+        game.guess("lemon").unwrap(); // l, e, o in wrong position
+        assert_eq!(
+            game.guess("world"),
+            Err(GuessError::HardModeViolation {
+                letter: Letter::new('e').unwrap(),
+                position: None,
+            })
+        );
+        assert!(game.guess("olive").is_ok());
+    }
+
+    #[test]
+    fn test_hard_mode_off_by_default() {
+        let pool = WordPool::from_strings(vec![
+            "hello".to_string(),
+            "hallo".to_string(),
+            "world".to_string(),
+        ]);
+        let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+
+        game.guess("hallo").unwrap();
+        assert!(game.guess("world").is_ok());
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingObserver {
+        events: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl GameObserver for RecordingObserver {
+        fn on_new_game(&self, _game: &Game) {
+            self.events.lock().unwrap().push("new".to_string());
+        }
+
+        fn on_guess(&self, _game: &Game, feedback: &GuessFeedback) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("guess {}", feedback.word()));
+        }
+
+        fn on_win(&self, game: &Game) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("win {}", game.guesses().len()));
+        }
+
+        fn on_lose(&self, _game: &Game) {
+            self.events.lock().unwrap().push("lose".to_string());
+        }
+    }
+
+    fn observed_game(max_guesses: usize) -> (Game, Arc<RecordingObserver>) {
+        let observer = Arc::new(RecordingObserver::default());
+        let config = GameConfig {
+            max_guesses,
+            observers: vec![observer.clone()],
+            ..GameConfig::default()
+        };
+        let game = Game::with_secret_and_config(test_pool(), Word::parse("hello").unwrap(), config);
+        (game, observer)
+    }
+
+    #[test]
+    fn test_observer_win() {
+        let (mut game, observer) = observed_game(MAX_GUESSES);
+
+        game.guess("world").unwrap();
+        game.guess("zzzzz").unwrap_err(); // rejected guesses are not reported
+        game.guess("hello").unwrap();
+
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            vec!["new", "guess world", "guess hello", "win 2"]
+        );
+    }
+
+    #[test]
+    fn test_observer_lose() {
+        let (mut game, observer) = observed_game(1);
+
+        game.guess("world").unwrap();
+
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            vec!["new", "guess world", "lose"]
+        );
+    }
+
+    #[test]
+    fn test_letter_hint_skips_known_positions() {
+        let pool = WordPool::from_strings(vec!["hello".to_string(), "hallo".to_string()]);
+        let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+
+        game.guess("hallo").unwrap(); // h, l, l, o correct; e unknown
+        let hint = game.hint();
+        assert_eq!(
+            hint,
+            Some(Hint::Letter {
+                position: 1,
+                letter: Word::parse("hello").unwrap().letter(1),
+            })
+        );
+        assert_eq!(game.hint(), None);
+        assert_eq!(game.hints().len(), 1);
+    }
+
+    #[test]
+    fn test_hint_budget() {
+        let config = GameConfig {
+            max_hints: Some(1),
+            ..GameConfig::default()
+        };
+        let mut game =
+            Game::with_secret_and_config(test_pool(), Word::parse("hello").unwrap(), config);
+        assert_eq!(game.hints_left(), Some(1));
+        assert!(game.hint().is_some());
+        assert_eq!(game.hints_left(), Some(0));
+        assert_eq!(game.hint(), None);
+        assert_eq!(game.phonetic_hint("jello"), None);
+        assert_eq!(game.hints().len(), 1);
+    }
+
+    #[test]
+    fn test_phonetic_hint() {
+        let pool = WordPool::from_strings(vec!["kater".to_string()]);
+        let mut game = Game::with_secret(pool, Word::parse("kater").unwrap());
+
+        let hint = game.phonetic_hint("Vater");
+        assert_eq!(
+            hint,
+            Some(Hint::Phonetic {
+                reference: "vater".to_string(),
+                rhymes: true,
+                sounds_like: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_letter_bank_consumed_by_guesses() {
+        let pool = test_pool();
+        let config = GameConfig {
+            letter_bank_size: Some(15),
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_secret_and_config(pool, Word::parse("hello").unwrap(), config);
+
+        let bank = game.letter_bank().unwrap().clone();
+        assert_eq!(bank.len(), 15);
+
+        let result = game.guess("hello");
+        assert!(matches!(result, Ok(f) if f.is_win()));
+        assert_eq!(game.letter_bank().unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_letter_bank_rejects_unformable_guess() {
+        let pool = test_pool();
+        let config = GameConfig {
+            letter_bank_size: Some(5),
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_secret_and_config(pool, Word::parse("hello").unwrap(), config);
+
+        assert_eq!(
+            game.guess("crane"),
+            Err(GuessError::NotInLetterBank {
+                letter: Letter::new('c').unwrap()
+            })
+        );
+        assert!(game.guesses().is_empty());
+        assert_eq!(game.state(), GameState::Playing);
+    }
+
+    #[test]
+    fn test_letter_bank_lost_when_secret_unformable() {
+        let pool = WordPool::from_strings(vec!["hello".to_string(), "holle".to_string()]);
+        let config = GameConfig {
+            letter_bank_size: Some(5),
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_secret_and_config(pool, Word::parse("hello").unwrap(), config);
+
+        assert!(game.guess("holle").is_ok());
+        assert_eq!(game.state(), GameState::Lost);
+    }
+
+    #[test]
+    fn test_no_hints_after_game_over() {
+        let pool = test_pool();
+        let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+
+        game.guess("hello").unwrap();
+        assert_eq!(game.hint(), None);
+        assert_eq!(game.phonetic_hint("jello"), None);
+    }
+
+    #[test]
+    fn test_min_guess_interval() {
+        let config = GameConfig {
+            min_guess_interval: Some(Duration::from_secs(60)),
+            ..GameConfig::default()
+        };
+        let mut game =
+            Game::with_secret_and_config(test_pool(), Word::parse("hello").unwrap(), config);
+
+        game.guess("world").unwrap();
+        assert!(matches!(
+            game.guess("crane"),
+            Err(GuessError::TooFast { .. })
+        ));
+        assert_eq!(game.guesses().len(), 1);
+
+        // Rejected guesses count too
+        game.last_attempt = Some(Instant::now() - Duration::from_secs(61));
+        assert_eq!(game.guess("zzzzz"), Err(GuessError::NotInWordList));
+        assert!(matches!(
+            game.guess("crane"),
+            Err(GuessError::TooFast { .. })
+        ));
+    }
+
+    #[test]
+    fn test_guess_interval_check() {
+        let config = GameConfig {
+            min_guess_interval: Some(Duration::from_millis(50)),
+            ..GameConfig::default()
+        };
+        let mut game =
+            Game::with_secret_and_config(test_pool(), Word::parse("hello").unwrap(), config);
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        assert_eq!(game.check_guess_interval(at(0)), Ok(()));
+        assert_eq!(
+            game.check_guess_interval(at(20)),
+            Err(GuessError::TooFast {
+                retry_after: Duration::from_millis(30)
+            })
+        );
+        // Too fast attempts don't restart the interval
+        assert_eq!(game.check_guess_interval(at(50)), Ok(()));
+        assert_eq!(
+            game.check_guess_interval(at(60)),
+            Err(GuessError::TooFast {
+                retry_after: Duration::from_millis(40)
+            })
+        );
+        assert_eq!(game.check_guess_interval(at(100)), Ok(()));
+    }
 }
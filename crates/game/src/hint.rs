@@ -0,0 +1,14 @@
+use crate::letter::Letter;
+
+/// A hint about the secret word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hint {
+    /// Reveals the letter of the secret at a position
+    Letter { position: usize, letter: Letter },
+    /// Reveals whether the secret rhymes with or sounds like a reference word
+    Phonetic {
+        reference: String,
+        rhymes: bool,
+        sounds_like: bool,
+    },
+}
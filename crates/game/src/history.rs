@@ -0,0 +1,287 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::MAX_GUESSES;
+use crate::game::{DictionaryCheck, Game, GameConfig, GameState};
+use crate::letter::Word;
+use crate::statistics::Statistics;
+use crate::store::update_json;
+use crate::summary::GameSummary;
+use crate::word_pool::WordPool;
+
+/// A finished game, identified by a random id so copies of the same game
+/// from different devices can be told apart from new games
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameRecord {
+    /// Random UUID (version 4)
+    pub id: String,
+    /// Seconds since the Unix epoch
+    pub finished_at: u64,
+    pub secret: String,
+    pub guesses: Vec<String>,
+    pub state: GameState,
+    /// Playing time in seconds, excluding pauses. None for games recorded
+    /// before it was.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seconds: Option<u64>,
+}
+
+impl GameRecord {
+    /// Record a finished game. None if the game is still in progress.
+    pub fn new(game: &Game, finished_at: SystemTime) -> Option<Self> {
+        Self::from_summary(&game.summary(), finished_at)
+    }
+
+    /// Record a finished game from its summary, e.g. one board of a
+    /// Quordle. None if the game is still in progress.
+    pub fn from_summary(summary: &GameSummary, finished_at: SystemTime) -> Option<Self> {
+        if summary.outcome == GameState::Playing {
+            return None;
+        }
+        Some(Self {
+            id: random_uuid(),
+            finished_at: finished_at
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            secret: summary.secret.to_string(),
+            guesses: summary
+                .guesses
+                .iter()
+                .map(|guess| guess.word.to_string())
+                .collect(),
+            state: summary.outcome.clone(),
+            seconds: Some(summary.duration.as_secs()),
+        })
+    }
+
+    /// The game played again from the record, e.g. to show its board. None
+    /// if the record doesn't hold valid words.
+    pub fn replay(&self) -> Option<Game> {
+        let secret = Word::parse(&self.secret)?;
+        let guesses: Vec<Word> = self
+            .guesses
+            .iter()
+            .map(|guess| Word::parse(guess))
+            .collect::<Option<_>>()?;
+        // Whatever the rules and word list were, the guesses were accepted
+        let config = GameConfig {
+            max_guesses: guesses.len().max(MAX_GUESSES),
+            dictionary_check: DictionaryCheck::Off,
+            ..GameConfig::default()
+        };
+        let pool = WordPool::from_words([secret.clone()]);
+        let mut game = Game::with_secret_and_config(pool, secret, config);
+        for guess in &guesses {
+            game.guess(&guess.to_string()).ok()?;
+        }
+        game.forfeit();
+        Some(game)
+    }
+}
+
+/// All finished games of a player, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    pub games: Vec<GameRecord>,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a game, keeping the games in order
+    pub fn add(&mut self, record: GameRecord) {
+        self.merge(&Self {
+            games: vec![record],
+        });
+    }
+
+    /// Add the games of `other` that aren't in this history yet, e.g. the
+    /// history of another device
+    pub fn merge(&mut self, other: &History) {
+        for record in &other.games {
+            if !self.games.iter().any(|game| game.id == record.id) {
+                self.games.push(record.clone());
+            }
+        }
+        self.games
+            .sort_by(|a, b| (a.finished_at, &a.id).cmp(&(b.finished_at, &b.id)));
+    }
+
+    /// First guesses of the last `n` games, most recent first
+    pub fn recent_openers(&self, n: usize) -> Vec<Word> {
+        self.games
+            .iter()
+            .rev()
+            .filter_map(|record| record.guesses.first())
+            .filter_map(|opener| Word::parse(opener))
+            .take(n)
+            .collect()
+    }
+
+    /// Games played, won and streaks over the whole history
+    pub fn statistics(&self) -> Statistics {
+        let mut statistics = Statistics::new();
+        for record in &self.games {
+            statistics.record_game(record);
+        }
+        statistics
+    }
+
+    /// Add a game to the history saved at `path`, keeping the games that
+    /// other instances saved meanwhile. Returns the merged history.
+    pub fn record_merged(path: impl AsRef<Path>, record: GameRecord) -> io::Result<History> {
+        update_json(path, |saved: Option<History>| {
+            let mut history = saved.unwrap_or_default();
+            history.add(record);
+            history
+        })
+    }
+
+    /// Merge `other` into the history saved at `path`. Returns the merged
+    /// history.
+    pub fn save_merged(&self, path: impl AsRef<Path>) -> io::Result<History> {
+        update_json(path, |saved: Option<History>| {
+            let mut history = saved.unwrap_or_default();
+            history.merge(self);
+            history
+        })
+    }
+
+    /// Deserialize from JSON
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        serde_json::from_str(json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Read a history from a JSON file, empty if there is none yet
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => Self::from_json(&json),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Random UUID in the usual 8-4-4-4-12 hex format
+fn random_uuid() -> String {
+    // Version 4, variant 1
+    let bits = (rand::random::<u128>() & !(0xf << 76) & !(0x3 << 62)) | (0x4 << 76) | (0x2 << 62);
+    let hex = format!("{bits:032x}");
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_pool::WordPool;
+    use std::time::Duration;
+
+    /// Record of a game, forfeited if the guesses don't solve it
+    fn record(guesses: &[&str], secret: &str, finished_at: u64) -> GameRecord {
+        let pool = WordPool::from_strings(["hello", "world", "crane"].map(String::from));
+        let mut game = Game::with_secret(pool, Word::parse(secret).unwrap());
+        for guess in guesses {
+            game.guess(guess).unwrap();
+        }
+        // Give up on games the guesses didn't finish
+        game.forfeit();
+        GameRecord::new(&game, UNIX_EPOCH + Duration::from_secs(finished_at)).unwrap()
+    }
+
+    #[test]
+    fn test_record() {
+        let record = record(&["crane", "hello"], "hello", 60);
+        assert_eq!(record.finished_at, 60);
+        assert_eq!(record.guesses, vec!["crane", "hello"]);
+        assert_eq!(record.state, GameState::Won { guesses_used: 2 });
+
+        let id = record.id.as_bytes();
+        assert_eq!(id.len(), 36);
+        assert_eq!(id[14], b'4');
+        assert!(matches!(id[19], b'8' | b'9' | b'a' | b'b'));
+    }
+
+    #[test]
+    fn test_replay() {
+        let game = record(&["crane", "hello"], "hello", 60).replay().unwrap();
+        assert_eq!(game.state(), GameState::Won { guesses_used: 2 });
+        assert_eq!(game.guesses()[0].word(), &Word::parse("crane").unwrap());
+
+        let game = record(&["crane"], "hello", 60).replay().unwrap();
+        assert_eq!(game.state(), GameState::Forfeited);
+    }
+
+    #[test]
+    fn test_records_without_time() {
+        let json = r#"{"games": [{"id": "a", "finished_at": 1, "secret": "hello",
+            "guesses": ["hello"], "state": {"won": {"guesses_used": 1}}}]}"#;
+        let history = History::from_json(json).unwrap();
+        assert_eq!(history.games[0].seconds, None);
+    }
+
+    #[test]
+    fn test_merge_by_id() {
+        let early = record(&["hello"], "hello", 10);
+        let late = record(&["crane"], "world", 20);
+
+        // Device A played `late` after syncing `early` from device B
+        let mut a = History::new();
+        a.add(late.clone());
+        a.add(early.clone());
+        let mut b = History::new();
+        b.add(early.clone());
+        b.merge(&a);
+        a.merge(&b);
+
+        assert_eq!(a, b);
+        assert_eq!(a.games, vec![early, late]);
+    }
+
+    #[test]
+    fn test_statistics_follow_order() {
+        let mut history = History::new();
+        history.add(record(&["hello"], "hello", 30));
+        history.add(record(&["hello"], "hello", 10));
+        history.add(record(&[], "hello", 20));
+
+        let statistics = history.statistics();
+        assert_eq!(statistics.games_played(), 3);
+        assert_eq!(statistics.current_streak(), 1);
+        assert_eq!(statistics.max_streak(), 1);
+    }
+
+    #[test]
+    fn test_recent_openers() {
+        let mut history = History::new();
+        history.add(record(&["crane", "hello"], "hello", 10));
+        history.add(record(&[], "hello", 20));
+        history.add(record(&["world"], "world", 30));
+        let word = |s| Word::parse(s).unwrap();
+        assert_eq!(
+            history.recent_openers(5),
+            vec![word("world"), word("crane")]
+        );
+        assert_eq!(history.recent_openers(1), vec![word("world")]);
+    }
+
+    #[test]
+    fn test_unfinished_games_are_not_recorded() {
+        let pool = WordPool::from_strings(["hello"].map(String::from));
+        let game = Game::with_secret(pool, Word::parse("hello").unwrap());
+        assert_eq!(GameRecord::new(&game, SystemTime::now()), None);
+    }
+}
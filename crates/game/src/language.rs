@@ -0,0 +1,189 @@
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::word_pool::WordPool;
+
+/// Language of the words to play with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    #[default]
+    German,
+    English,
+}
+
+impl Language {
+    /// All supported languages
+    pub const ALL: [Language; 2] = [Language::German, Language::English];
+
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::German => "German",
+            Language::English => "English",
+        }
+    }
+
+    /// ISO 639-1 code
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::German => "de",
+            Language::English => "en",
+        }
+    }
+
+    /// Letters words of this language may consist of (lowercase)
+    pub fn alphabet(&self) -> &'static str {
+        match self {
+            Language::German => "abcdefghijklmnopqrstuvwxyzäöüß",
+            Language::English => "abcdefghijklmnopqrstuvwxyz",
+        }
+    }
+
+    /// Check if a (lowercase) letter is part of the alphabet
+    pub fn is_in_alphabet(&self, c: char) -> bool {
+        self.alphabet().contains(c)
+    }
+
+    /// Compressed word list embedded in the binary and its metadata, if
+    /// there is one
+    pub(crate) fn embedded_wordlist(&self) -> Option<(&'static [u8], &'static str)> {
+        match self {
+            Language::German => Some((crate::wordlists::DE, crate::wordlists::DE_METADATA)),
+            Language::English => None,
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    /// Parse a language from its name or code, ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::ALL
+            .into_iter()
+            .find(|l| l.name().eq_ignore_ascii_case(s) || l.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown language '{s}'"))
+    }
+}
+
+/// Load the embedded word list of a language. Words with letters outside
+/// the language's alphabet are skipped. Guesses are spelled the way the
+/// list spells 'ß', as recorded in its metadata.
+pub fn load_wordlist(language: Language) -> io::Result<WordPool> {
+    use wordle_wordlists_processing::sharp_s::ListMetadata;
+    use wordle_wordlists_processing::stream::from_txt_zstd;
+
+    let (data, metadata) = language.embedded_wordlist().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("No word list is available for {language} yet"),
+        )
+    })?;
+
+    let words = playable_words(from_txt_zstd(data)?, language)?;
+    let metadata = ListMetadata::parse(metadata)?;
+    Ok(WordPool::from_words(words).with_metadata(metadata))
+}
+
+/// Load the player's own word list from a text file with one word per
+/// line, compressed or not (by the file extension, e.g. `.zst`). Words
+/// that can't be played in `language`, like those of another length, are
+/// skipped.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read, or has no playable words.
+pub fn load_wordlist_file(path: &Path, language: Language) -> io::Result<WordPool> {
+    use wordle_wordlists_processing::stream::{codec_for_path, from_txt_compressed};
+
+    let file = File::open(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))?;
+    let words = playable_words(from_txt_compressed(file, codec_for_path(path))?, language)?;
+    if words.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} has no playable {language} words", path.display()),
+        ));
+    }
+    Ok(WordPool::from_words(words))
+}
+
+/// The words of a stream that can be played in `language`
+fn playable_words(
+    stream: impl Iterator<Item = io::Result<wordle_wordlists_processing::Word>>,
+    language: Language,
+) -> io::Result<Vec<crate::letter::Word>> {
+    let mut words = Vec::new();
+    for word_result in stream {
+        let word_str = word_result?.0;
+        if let Some(word) = crate::letter::Word::parse(&word_str)
+            && word.letters().all(|l| language.is_in_alphabet(l.char()))
+        {
+            words.push(word);
+        }
+    }
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("german".parse(), Ok(Language::German));
+        assert_eq!("DE".parse(), Ok(Language::German));
+        assert_eq!("English".parse(), Ok(Language::English));
+        assert!("klingon".parse::<Language>().is_err());
+    }
+
+    #[test]
+    fn test_alphabet() {
+        assert!(Language::German.is_in_alphabet('ß'));
+        assert!(!Language::English.is_in_alphabet('ä'));
+    }
+
+    #[test]
+    fn test_load_wordlist() {
+        let pool = load_wordlist(Language::German).unwrap();
+        assert!(!pool.is_empty());
+        assert!(pool.iter().all(|w| {
+            w.letters()
+                .all(|l| Language::German.is_in_alphabet(l.char()))
+        }));
+        // Words outside the alphabet are counted in the list, not the pool
+        let metadata = pool.metadata().unwrap();
+        assert!(metadata.words.unwrap() >= pool.len());
+        assert!(metadata.built.is_some());
+        assert!(metadata.recipe.is_some());
+
+        let err = load_wordlist(Language::English).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_load_wordlist_file() {
+        let path = std::env::temp_dir().join(format!("wordlist_{}.txt", std::process::id()));
+        std::fs::write(&path, "Crane\nslate\nhi\nfähre\n\n").unwrap();
+        let pool = load_wordlist_file(&path, Language::English).unwrap();
+        let words: Vec<String> = pool.iter().map(|word| word.to_string()).collect();
+        assert_eq!(words, ["crane", "slate"]);
+
+        std::fs::write(&path, "hi\n").unwrap();
+        let err = load_wordlist_file(&path, Language::English).unwrap_err();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -1,6 +1,74 @@
 use crate::constants::WORD_LENGTH;
+use crate::language::Language;
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::cmp::Ordering;
 use std::fmt;
 
+/// Relative frequency of letters in German text, in percent
+const GERMAN_FREQUENCIES: &[(char, f64)] = &[
+    ('e', 16.93),
+    ('n', 9.78),
+    ('i', 7.55),
+    ('s', 7.27),
+    ('r', 7.00),
+    ('a', 6.51),
+    ('t', 6.15),
+    ('d', 5.08),
+    ('h', 4.76),
+    ('u', 4.35),
+    ('l', 3.44),
+    ('c', 3.06),
+    ('g', 3.01),
+    ('m', 2.53),
+    ('o', 2.51),
+    ('b', 1.89),
+    ('w', 1.89),
+    ('f', 1.66),
+    ('k', 1.21),
+    ('z', 1.13),
+    ('p', 0.79),
+    ('v', 0.67),
+    ('ü', 0.65),
+    ('ä', 0.54),
+    ('ß', 0.31),
+    ('ö', 0.30),
+    ('j', 0.27),
+    ('y', 0.04),
+    ('x', 0.03),
+    ('q', 0.02),
+];
+
+/// Relative frequency of letters in English text, in percent
+const ENGLISH_FREQUENCIES: &[(char, f64)] = &[
+    ('e', 12.70),
+    ('t', 9.06),
+    ('a', 8.17),
+    ('o', 7.51),
+    ('i', 6.97),
+    ('n', 6.75),
+    ('s', 6.33),
+    ('h', 6.09),
+    ('r', 5.99),
+    ('d', 4.25),
+    ('l', 4.03),
+    ('c', 2.78),
+    ('u', 2.76),
+    ('m', 2.41),
+    ('w', 2.36),
+    ('f', 2.23),
+    ('g', 2.02),
+    ('y', 1.97),
+    ('p', 1.93),
+    ('b', 1.29),
+    ('v', 0.98),
+    ('k', 0.77),
+    ('j', 0.15),
+    ('x', 0.15),
+    ('q', 0.10),
+    ('z', 0.07),
+];
+
 /// A single letter in a word (always lowercase internally)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Letter(char);
@@ -20,6 +88,69 @@ impl Letter {
     pub fn char(&self) -> char {
         self.0
     }
+
+    /// Get the uppercase form for display
+    pub fn to_uppercase(&self) -> String {
+        self.0.to_uppercase().collect()
+    }
+
+    /// Whether this is a vowel, including umlauts and 'y'
+    pub fn is_vowel(&self) -> bool {
+        matches!(self.0, 'a' | 'e' | 'i' | 'o' | 'u' | 'ä' | 'ö' | 'ü' | 'y')
+    }
+
+    /// Whether this is a consonant, i.e. a letter that isn't a vowel
+    pub fn is_consonant(&self) -> bool {
+        !self.is_vowel()
+    }
+
+    /// How often this letter occurs in text of the given language, in
+    /// percent. Letters outside the language's alphabet have frequency 0.
+    pub fn frequency(&self, language: Language) -> f64 {
+        let table = match language {
+            Language::German => GERMAN_FREQUENCIES,
+            Language::English => ENGLISH_FREQUENCIES,
+        };
+        table
+            .iter()
+            .find(|(c, _)| *c == self.0)
+            .map_or(0.0, |&(_, frequency)| frequency)
+    }
+
+    /// The letter without diacritics, as used for alphabetical order:
+    /// 'ä' becomes 'a' and 'ß' becomes 's'
+    pub fn base(&self) -> Letter {
+        match self.0 {
+            'ä' => Letter('a'),
+            'ö' => Letter('o'),
+            'ü' => Letter('u'),
+            'ß' => Letter('s'),
+            c => Letter(c),
+        }
+    }
+
+    /// Order by frequency in the given language, most frequent first.
+    /// Ties are broken alphabetically.
+    pub fn cmp_by_frequency(&self, other: &Letter, language: Language) -> Ordering {
+        other
+            .frequency(language)
+            .total_cmp(&self.frequency(language))
+            .then_with(|| self.cmp(other))
+    }
+}
+
+/// Alphabetical order as in a German dictionary: umlauts and 'ß' sort
+/// right after their base letter ('a' < 'ä' < 'b')
+impl Ord for Letter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.base().0, self.0).cmp(&(other.base().0, other.0))
+    }
+}
+
+impl PartialOrd for Letter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl fmt::Display for Letter {
@@ -69,6 +200,34 @@ impl fmt::Display for Word {
     }
 }
 
+/// Saved as the character, so letters can be keys of JSON objects
+impl Serialize for Letter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_char(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Letter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let c = char::deserialize(deserializer)?;
+        Letter::new(c).ok_or_else(|| de::Error::custom(format!("invalid letter '{c}'")))
+    }
+}
+
+/// Saved as the lowercase string, so words can be keys of JSON objects
+impl Serialize for Word {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Word {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Word::parse(&s).ok_or_else(|| de::Error::custom(format!("invalid word '{s}'")))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,6 +240,46 @@ mod tests {
         assert_eq!(Letter::new(' '), None);
     }
 
+    #[test]
+    fn test_vowels() {
+        let vowels: String = "abcdefghijklmnopqrstuvwxyzäöüß"
+            .chars()
+            .filter_map(Letter::new)
+            .filter(Letter::is_vowel)
+            .map(|l| l.char())
+            .collect();
+        assert_eq!(vowels, "aeiouyäöü");
+        assert!(Letter::new('ß').unwrap().is_consonant());
+    }
+
+    #[test]
+    fn test_frequency() {
+        let e = Letter::new('e').unwrap();
+        let umlaut = Letter::new('ä').unwrap();
+        assert!(e.frequency(Language::German) > e.frequency(Language::English));
+        assert!(umlaut.frequency(Language::German) > 0.0);
+        assert_eq!(umlaut.frequency(Language::English), 0.0);
+
+        for language in Language::ALL {
+            for c in language.alphabet().chars() {
+                let letter = Letter::new(c).unwrap();
+                assert!(letter.frequency(language) > 0.0, "{c} in {language}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut letters: Vec<Letter> = "zßbäsa".chars().filter_map(Letter::new).collect();
+        letters.sort();
+        let sorted: String = letters.iter().map(|l| l.char()).collect();
+        assert_eq!(sorted, "aäbsßz");
+
+        letters.sort_by(|a, b| a.cmp_by_frequency(b, Language::German));
+        let sorted: String = letters.iter().map(|l| l.char()).collect();
+        assert_eq!(sorted, "sabzäß");
+    }
+
     #[test]
     fn test_word_parse() {
         let word = Word::parse("hello").unwrap();
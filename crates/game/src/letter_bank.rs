@@ -0,0 +1,153 @@
+use crate::letter::{Letter, Word};
+use crate::word_pool::WordPool;
+use std::collections::BTreeMap;
+
+/// A limited multiset of letters that guesses must be formed from.
+///
+/// Every accepted guess consumes its letters from the bank, so the
+/// bank shrinks over the course of a game (Scrabble-style).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LetterBank {
+    counts: BTreeMap<char, usize>,
+}
+
+impl LetterBank {
+    /// Create a bank containing the given letters
+    pub fn new(letters: impl IntoIterator<Item = Letter>) -> Self {
+        let mut counts = BTreeMap::new();
+        for letter in letters {
+            *counts.entry(letter.char()).or_insert(0) += 1;
+        }
+        Self { counts }
+    }
+
+    /// Generate a bank of `size` letters for a secret.
+    /// Always contains the letters of the secret; the rest is filled
+    /// with letters of random words from the pool.
+    pub fn generate(secret: &Word, word_pool: &WordPool, size: usize) -> Self {
+        let mut letters: Vec<Letter> = secret.letters().collect();
+        while letters.len() < size && !word_pool.is_empty() {
+            let filler = word_pool.random();
+            letters.extend(filler.letters().take(size - letters.len()));
+        }
+        Self::new(letters)
+    }
+
+    /// Number of copies of a letter left in the bank
+    pub fn count(&self, letter: Letter) -> usize {
+        self.counts.get(&letter.char()).copied().unwrap_or(0)
+    }
+
+    /// Total number of letters left in the bank
+    pub fn len(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Is the bank empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Find the first letter of a word that the bank can't supply
+    pub fn missing_letter(&self, word: &Word) -> Option<Letter> {
+        let mut remaining = self.counts.clone();
+        for letter in word.letters() {
+            match remaining.get_mut(&letter.char()) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => return Some(letter),
+            }
+        }
+        None
+    }
+
+    /// Check if a word can be formed from the letters in the bank
+    pub fn can_form(&self, word: &Word) -> bool {
+        self.missing_letter(word).is_none()
+    }
+
+    /// Remove the letters of a word from the bank.
+    /// Returns the first missing letter (leaving the bank unchanged) if the
+    /// word can't be formed.
+    pub fn consume(&mut self, word: &Word) -> Result<(), Letter> {
+        if let Some(letter) = self.missing_letter(word) {
+            return Err(letter);
+        }
+        for letter in word.letters() {
+            if let Some(count) = self.counts.get_mut(&letter.char()) {
+                *count -= 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Iterate over (Letter, count) pairs in alphabetical order,
+    /// including letters that have been used up
+    pub fn iter(&self) -> impl Iterator<Item = (Letter, usize)> + '_ {
+        self.counts
+            .iter()
+            .filter_map(|(&c, &count)| Letter::new(c).map(|l| (l, count)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bank(s: &str) -> LetterBank {
+        LetterBank::new(s.chars().filter_map(Letter::new))
+    }
+
+    #[test]
+    fn test_can_form() {
+        let bank = bank("helloworld");
+        assert!(bank.can_form(&Word::parse("hello").unwrap()));
+        assert!(bank.can_form(&Word::parse("world").unwrap()));
+        assert!(!bank.can_form(&Word::parse("crane").unwrap()));
+    }
+
+    #[test]
+    fn test_respects_multiplicity() {
+        let bank = bank("helo");
+        assert_eq!(
+            bank.missing_letter(&Word::parse("hello").unwrap()),
+            Letter::new('l')
+        );
+    }
+
+    #[test]
+    fn test_consume() {
+        let mut bank = bank("hellohello");
+        assert_eq!(bank.len(), 10);
+        bank.consume(&Word::parse("hello").unwrap()).unwrap();
+        assert_eq!(bank.len(), 5);
+        bank.consume(&Word::parse("hello").unwrap()).unwrap();
+        assert!(bank.is_empty());
+        assert_eq!(
+            bank.consume(&Word::parse("hello").unwrap()),
+            Err(Letter::new('h').unwrap())
+        );
+    }
+
+    #[test]
+    fn test_failed_consume_leaves_bank_unchanged() {
+        let mut bank = bank("hellx");
+        assert!(bank.consume(&Word::parse("hello").unwrap()).is_err());
+        assert_eq!(bank.len(), 5);
+    }
+
+    #[test]
+    fn test_generate_contains_secret() {
+        let pool = WordPool::from_strings(vec!["crane".to_string(), "slate".to_string()]);
+        let secret = Word::parse("hello").unwrap();
+        let bank = LetterBank::generate(&secret, &pool, 12);
+        assert_eq!(bank.len(), 12);
+        assert!(bank.can_form(&secret));
+    }
+
+    #[test]
+    fn test_iter_sorted() {
+        let bank = bank("cab");
+        let letters: Vec<char> = bank.iter().map(|(l, _)| l.char()).collect();
+        assert_eq!(letters, vec!['a', 'b', 'c']);
+    }
+}
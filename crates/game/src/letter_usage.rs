@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::feedback::{GuessFeedback, LetterFeedback};
+use crate::letter::Letter;
+use crate::summary::{GameSummary, GuessSummary};
+
+/// How often a letter was guessed, split by the feedback it got
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LetterUsage {
+    pub correct: usize,
+    pub wrong_position: usize,
+    pub not_in_word: usize,
+}
+
+impl LetterUsage {
+    /// Number of times the letter was guessed
+    pub fn total(&self) -> usize {
+        self.correct + self.wrong_position + self.not_in_word
+    }
+
+    fn add(&mut self, feedback: LetterFeedback) {
+        match feedback {
+            LetterFeedback::Correct => self.correct += 1,
+            LetterFeedback::WrongPosition => self.wrong_position += 1,
+            LetterFeedback::NotInWord => self.not_in_word += 1,
+        }
+    }
+}
+
+/// Per-letter guess counts, e.g. for a keyboard heatmap. Each occurrence of
+/// a letter in a guess counts once.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LetterUsageHistogram {
+    usage: BTreeMap<Letter, LetterUsage>,
+}
+
+impl LetterUsageHistogram {
+    /// Create an empty histogram
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count the letters of a guess
+    pub fn record(&mut self, guess: &GuessFeedback) {
+        for (letter, feedback) in guess.iter() {
+            self.usage.entry(letter).or_default().add(feedback);
+        }
+    }
+
+    /// Count the letters of all guesses of a game
+    pub fn record_game(&mut self, game: &GameSummary) {
+        for (letter, feedback) in game.guesses.iter().flat_map(GuessSummary::iter) {
+            self.usage.entry(letter).or_default().add(feedback);
+        }
+    }
+
+    /// Add the counts of another histogram, e.g. to aggregate games
+    pub fn merge(&mut self, other: &LetterUsageHistogram) {
+        for (&letter, usage) in &other.usage {
+            let entry = self.usage.entry(letter).or_default();
+            entry.correct += usage.correct;
+            entry.wrong_position += usage.wrong_position;
+            entry.not_in_word += usage.not_in_word;
+        }
+    }
+
+    /// Counts of a letter (all zero if it was never guessed)
+    pub fn get(&self, letter: Letter) -> LetterUsage {
+        self.usage.get(&letter).copied().unwrap_or_default()
+    }
+
+    /// Guessed letters with their counts, in alphabetical order
+    pub fn iter(&self) -> impl Iterator<Item = (Letter, LetterUsage)> + '_ {
+        self.usage.iter().map(|(&letter, &usage)| (letter, usage))
+    }
+
+    /// Number of times the most guessed letter was guessed, to scale a heatmap
+    pub fn max_total(&self) -> usize {
+        self.usage
+            .values()
+            .map(LetterUsage::total)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Whether no letter was guessed yet
+    pub fn is_empty(&self) -> bool {
+        self.usage.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::letter::Word;
+
+    fn letter(c: char) -> Letter {
+        Letter::new(c).unwrap()
+    }
+
+    fn guess(guess: &str, secret: &str) -> GuessFeedback {
+        GuessFeedback::evaluate(&Word::parse(guess).unwrap(), &Word::parse(secret).unwrap())
+    }
+
+    #[test]
+    fn test_record() {
+        let mut histogram = LetterUsageHistogram::new();
+        histogram.record(&guess("lolly", "hello"));
+        assert_eq!(
+            histogram.get(letter('l')),
+            LetterUsage {
+                correct: 2,
+                wrong_position: 0,
+                not_in_word: 1,
+            }
+        );
+        assert_eq!(histogram.get(letter('o')).wrong_position, 1);
+        assert_eq!(histogram.get(letter('z')).total(), 0);
+        assert_eq!(histogram.max_total(), 3);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut first = LetterUsageHistogram::new();
+        first.record(&guess("crane", "hello"));
+        let mut second = LetterUsageHistogram::new();
+        second.record(&guess("hello", "hello"));
+
+        first.merge(&second);
+        assert_eq!(first.get(letter('e')).total(), 2);
+        assert_eq!(first.get(letter('e')).correct, 1);
+        let letters: String = first.iter().map(|(letter, _)| letter.char()).collect();
+        assert_eq!(letters, "acehlnor");
+    }
+}
@@ -1,15 +1,91 @@
-pub mod constants;
-pub mod error;
-pub mod feedback;
-pub mod game;
-pub mod letter;
-pub mod word_pool;
-pub mod wordlists;
+//! Game logic of Wordle, independent of any user interface.
+//!
+//! The public API is what is re-exported here, plus the [`solver`]
+//! namespace. The other modules are private so they can be reorganized.
+//! Frontends usually only need the [`prelude`].
 
-// Re-exports for convenience
-pub use constants::{MAX_GUESSES, WORD_LENGTH};
-pub use error::GameError;
-pub use feedback::{GuessFeedback, LetterFeedback};
-pub use game::{Game, GameConfig, GameState, GuessResult};
+mod challenge;
+mod constants;
+mod ban_list;
+mod cross;
+mod curiosity;
+mod error;
+mod feedback;
+mod game;
+mod hint;
+mod history;
+mod language;
+mod letter;
+mod letter_bank;
+mod letter_usage;
+mod multi;
+mod observer;
+mod opener;
+mod pack;
+mod phonetic;
+mod playable;
+mod pool_cache;
+pub mod prelude;
+mod replay;
+mod rules;
+mod score;
+mod selector;
+mod sequence;
+pub mod solver;
+mod statistics;
+mod store;
+mod streak;
+mod summary;
+mod survival;
+mod weekly;
+mod timer;
+mod word_pool;
+mod wordlists;
+mod wordpack;
+
+// The public API
+pub use ban_list::{BanEnforcement, BanList};
+pub use challenge::{challenge_code_for, decode_challenge_code};
+pub use constants::{
+    CROSS_MAX_GUESSES, HINTS_PER_GAME, LETTER_BANK_SIZE, MAX_GUESSES, QUORDLE_BOARDS,
+    SURVIVAL_BONUS_GUESSES, WEEKLY_CHALLENGE_PUZZLES, WORD_LENGTH,
+};
+pub use cross::{CrossGame, CrossTarget};
+pub use curiosity::CuriosityStats;
+pub use error::{GameError, GuessError, GuessWarning, InconsistencyError};
+pub use feedback::{verify_history, GuessFeedback, LetterFeedback};
+pub use game::{DictionaryCheck, Game, GameConfig, GameState, GuessResult};
+pub use hint::Hint;
+pub use history::{GameRecord, History};
+pub use language::{load_wordlist, load_wordlist_file, Language};
 pub use letter::{Letter, Word};
-pub use word_pool::{load_german_wordlist, WordPool};
+pub use letter_bank::LetterBank;
+pub use letter_usage::{LetterUsage, LetterUsageHistogram};
+pub use multi::MultiGame;
+pub use observer::GameObserver;
+pub use opener::{opening_book, suggest_opener, OPENING_BOOK_SIZE};
+pub use pack::{Pack, PackProgress, PackResult};
+pub use playable::Playable;
+pub use pool_cache::load_wordlist_cached;
+pub use replay::{Replay, ReplayPlayback, ReplayRecorder, ReplayStep};
+pub use rules::{
+    BannedLetterRule, BannedOpenerRule, DictionaryRule, Guess, GuessRule, HardModeRule,
+    LengthRule, LetterBankRule, NoRepeatRule, RuleContext, WordValidator,
+};
+pub use score::{word_difficulty, Score};
+pub use selector::{
+    AdversarialSelector, DailySelector, FrequencyWeightedSelector, SecretSelector,
+    SpacedRepetitionSelector, UniformSelector,
+};
+pub use sequence::SequenceGame;
+pub use solver::{Candidates, EntropyStrategy, RandomStrategy, StepAnalysis, Strategy};
+pub use statistics::Statistics;
+pub use streak::StreakSession;
+pub use summary::{GameSummary, GuessSummary, ModeSummary};
+pub use survival::SurvivalGame;
+pub use timer::Timer;
+pub use weekly::{WeeklyChallenge, WeeklyTheme};
+pub use word_pool::{load_german_wordlist, WordPool, WordSampler};
+pub use wordle_wordlists_processing::civil;
+pub use wordle_wordlists_processing::sharp_s::SharpSPolicy;
+pub use wordpack::{load_wordpack, LoadedWordpack};
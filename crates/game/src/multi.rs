@@ -0,0 +1,301 @@
+use crate::constants::MAX_GUESSES;
+use crate::error::GuessError;
+use crate::feedback::GuessFeedback;
+use crate::game::{GameState, GuessResult, parse_guess};
+use crate::letter::Word;
+use crate::playable::Playable;
+use crate::summary::GameSummary;
+use crate::timer::Timer;
+use crate::word_pool::WordPool;
+
+/// Several boards solved at once, as in Dordle (2) or Quordle (4): every
+/// guess is played on all boards that aren't solved yet, and all of them
+/// have to be solved within one guess budget.
+#[derive(Debug, Clone)]
+pub struct MultiGame {
+    secrets: Vec<Word>,
+    /// Guesses of each board, up to the one that solved it
+    boards: Vec<Vec<GuessFeedback>>,
+    /// Number of guesses made, on any board
+    guesses_made: usize,
+    max_guesses: usize,
+    word_pool: WordPool,
+    forfeited: bool,
+    timer: Timer,
+}
+
+impl MultiGame {
+    /// Create a new game with `boards` different random secrets.
+    /// The budget is [`MultiGame::default_max_guesses`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pool has fewer words than boards.
+    pub fn new(word_pool: WordPool, boards: usize) -> Self {
+        let secrets: Vec<Word> = word_pool.sampler().take(boards).collect();
+        assert_eq!(
+            secrets.len(),
+            boards,
+            "WordPool has fewer words than boards"
+        );
+        let max_guesses = Self::default_max_guesses(boards);
+        Self::with_secrets(word_pool, secrets, max_guesses)
+    }
+
+    /// Create with specific secrets, one board each
+    pub fn with_secrets(word_pool: WordPool, secrets: Vec<Word>, max_guesses: usize) -> Self {
+        Self {
+            boards: vec![Vec::new(); secrets.len()],
+            secrets,
+            guesses_made: 0,
+            max_guesses,
+            word_pool,
+            forfeited: false,
+            timer: Timer::start(),
+        }
+    }
+
+    /// Budget for a number of boards: one more guess than a regular game
+    /// for every additional board, e.g. 9 for 4 boards
+    pub fn default_max_guesses(boards: usize) -> usize {
+        MAX_GUESSES + boards.saturating_sub(1)
+    }
+
+    /// Make a guess with a pre-parsed Word, on all unsolved boards. Returns
+    /// the feedback of the first board it was played on.
+    pub fn guess_word(&mut self, word: &Word) -> GuessResult {
+        if self.state() != GameState::Playing {
+            return Err(GuessError::GameOver);
+        }
+        if !self.word_pool.contains(word) {
+            return Err(GuessError::NotInWordList);
+        }
+
+        self.timer.resume();
+        let mut first = None;
+        for (board, secret) in self.boards.iter_mut().zip(&self.secrets) {
+            if board.last().is_some_and(GuessFeedback::is_win) {
+                continue;
+            }
+            let feedback = GuessFeedback::evaluate(word, secret);
+            first.get_or_insert_with(|| feedback.clone());
+            board.push(feedback);
+        }
+        self.guesses_made += 1;
+        if self.state() != GameState::Playing {
+            self.timer.pause();
+        }
+        Ok(first.expect("a game in progress has an unsolved board"))
+    }
+
+    /// Number of boards
+    pub fn board_count(&self) -> usize {
+        self.boards.len()
+    }
+
+    /// Guesses played on a board, up to the one that solved it
+    pub fn board_guesses(&self, board: usize) -> &[GuessFeedback] {
+        &self.boards[board]
+    }
+
+    /// Check if a board has been solved
+    pub fn is_solved(&self, board: usize) -> bool {
+        self.boards[board].last().is_some_and(GuessFeedback::is_win)
+    }
+
+    /// Number of guesses made so far
+    pub fn guesses_made(&self) -> usize {
+        self.guesses_made
+    }
+
+    /// Secret word of a board (only available after game ends)
+    pub fn secret(&self, board: usize) -> Option<&Word> {
+        match self.state() {
+            GameState::Playing => None,
+            _ => Some(&self.secrets[board]),
+        }
+    }
+
+    /// One summary per board once the game is over, e.g. for the
+    /// statistics. Empty while the game is being played.
+    pub fn summaries(&self) -> Vec<GameSummary> {
+        let state = self.state();
+        if state == GameState::Playing {
+            return Vec::new();
+        }
+        self.secrets
+            .iter()
+            .zip(&self.boards)
+            .map(|(secret, guesses)| {
+                GameSummary::of_board(
+                    &state,
+                    secret,
+                    guesses,
+                    self.timer.elapsed(),
+                    self.max_guesses,
+                    &self.word_pool,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Playable for MultiGame {
+    fn guess(&mut self, input: &str) -> GuessResult {
+        let word = parse_guess(input)?;
+        self.guess_word(&word)
+    }
+
+    fn forfeit(&mut self) {
+        if self.state() == GameState::Playing {
+            self.forfeited = true;
+            self.timer.pause();
+        }
+    }
+
+    fn state(&self) -> GameState {
+        if self.forfeited {
+            return GameState::Forfeited;
+        }
+        if (0..self.board_count()).all(|board| self.is_solved(board)) {
+            return GameState::Won {
+                guesses_used: self.guesses_made,
+            };
+        }
+        if self.guesses_made >= self.max_guesses {
+            return GameState::Lost;
+        }
+        GameState::Playing
+    }
+
+    fn guesses_remaining(&self) -> usize {
+        self.max_guesses.saturating_sub(self.guesses_made)
+    }
+
+    fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    fn guesses_made(&self) -> usize {
+        self.guesses_made
+    }
+
+    fn secrets(&self) -> Option<Vec<Word>> {
+        match self.state() {
+            GameState::Playing => None,
+            _ => Some(self.secrets.clone()),
+        }
+    }
+
+    fn pause(&mut self) {
+        self.timer.pause();
+    }
+
+    fn resume(&mut self) {
+        if self.state() == GameState::Playing {
+            self.timer.resume();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_pool() -> WordPool {
+        WordPool::from_strings(
+            ["crane", "slate", "hello", "world", "audio"]
+                .iter()
+                .map(|s| s.to_string()),
+        )
+    }
+
+    fn test_game() -> MultiGame {
+        let secrets = ["crane", "slate", "hello", "world"]
+            .map(|s| Word::parse(s).unwrap())
+            .to_vec();
+        MultiGame::with_secrets(test_pool(), secrets, 9)
+    }
+
+    #[test]
+    fn test_guess_plays_on_unsolved_boards() {
+        let mut game = test_game();
+
+        let feedback = game.guess("slate").unwrap();
+        assert!(!feedback.is_win());
+        assert!(game.is_solved(1));
+        assert_eq!(game.board_guesses(1).len(), 1);
+
+        game.guess("crane").unwrap();
+        assert!(game.is_solved(0));
+        // The solved board doesn't get more guesses
+        assert_eq!(game.board_guesses(1).len(), 1);
+        assert_eq!(game.board_guesses(3).len(), 2);
+        assert_eq!(game.guesses_remaining(), 7);
+    }
+
+    #[test]
+    fn test_win_all_boards() {
+        let mut game = test_game();
+        for guess in ["hello", "world", "crane", "slate"] {
+            assert_eq!(game.state(), GameState::Playing);
+            assert_eq!(game.secrets(), None);
+            game.guess(guess).unwrap();
+        }
+        assert_eq!(game.state(), GameState::Won { guesses_used: 4 });
+        assert_eq!(game.secrets().unwrap().len(), 4);
+        assert_eq!(game.guess("audio"), Err(GuessError::GameOver));
+    }
+
+    #[test]
+    fn test_shared_budget() {
+        let mut game = test_game();
+        for _ in 0..9 {
+            game.guess("audio").unwrap();
+        }
+        assert_eq!(game.state(), GameState::Lost);
+        assert_eq!(game.secret(2), Some(&Word::parse("hello").unwrap()));
+    }
+
+    #[test]
+    fn test_random_secrets_are_different() {
+        let game = MultiGame::new(test_pool(), 4);
+        assert_eq!(game.board_count(), 4);
+        assert_eq!(game.max_guesses(), 9);
+        let mut secrets = game.secrets.clone();
+        secrets.sort_by_key(|secret| secret.to_string());
+        secrets.dedup();
+        assert_eq!(secrets.len(), 4);
+    }
+
+    #[test]
+    fn test_summaries() {
+        let mut game = test_game();
+        game.guess("slate").unwrap();
+        assert!(game.summaries().is_empty());
+        game.guess("crane").unwrap();
+        game.forfeit();
+
+        let summaries = game.summaries();
+        let outcomes: Vec<_> = summaries.iter().map(|summary| &summary.outcome).collect();
+        assert_eq!(
+            outcomes,
+            [
+                &GameState::Won { guesses_used: 2 },
+                &GameState::Won { guesses_used: 1 },
+                &GameState::Forfeited,
+                &GameState::Forfeited,
+            ]
+        );
+        assert_eq!(summaries[2].secret.as_str(), "hello");
+        assert_eq!(summaries[2].guesses.len(), 2);
+        assert_eq!(summaries[2].mode.max_guesses, 9);
+    }
+
+    #[test]
+    fn test_not_in_word_list() {
+        let mut game = test_game();
+        assert_eq!(game.guess("zzzzz"), Err(GuessError::NotInWordList));
+        assert_eq!(game.guesses_made(), 0);
+    }
+}
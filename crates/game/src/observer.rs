@@ -0,0 +1,27 @@
+use std::fmt;
+
+use crate::feedback::GuessFeedback;
+use crate::game::Game;
+
+/// Hook for reacting to game events, so features like stats persistence,
+/// sound effects or replay recording can plug into any frontend.
+///
+/// Observers are registered in `GameConfig::observers` and shared between
+/// games, so all methods take `&self`; use interior mutability for state.
+/// All methods default to doing nothing.
+pub trait GameObserver: fmt::Debug + Send + Sync {
+    /// A new game was created
+    fn on_new_game(&self, _game: &Game) {}
+
+    /// A guess was accepted (called before `on_win`/`on_lose`)
+    fn on_guess(&self, _game: &Game, _feedback: &GuessFeedback) {}
+
+    /// The game was won
+    fn on_win(&self, _game: &Game) {}
+
+    /// The game was lost
+    fn on_lose(&self, _game: &Game) {}
+
+    /// The player gave up
+    fn on_forfeit(&self, _game: &Game) {}
+}
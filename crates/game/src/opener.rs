@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::letter::{Letter, Word};
+use crate::word_pool::WordPool;
+
+/// Number of words in the opening book the terminal UI suggests from
+pub const OPENING_BOOK_SIZE: usize = 30;
+
+/// Good first guesses for a word pool, best first: words with five
+/// different letters that are common among the words of the pool. Ties
+/// are broken alphabetically, so the book is the same every time.
+pub fn opening_book(word_pool: &WordPool, size: usize) -> Vec<Word> {
+    // In how many words each letter occurs
+    let mut frequencies: HashMap<Letter, usize> = HashMap::new();
+    for word in word_pool.iter() {
+        let mut letters: Vec<Letter> = word.letters().collect();
+        letters.sort_unstable_by_key(|letter| letter.char());
+        letters.dedup();
+        for letter in letters {
+            *frequencies.entry(letter).or_default() += 1;
+        }
+    }
+
+    let mut scored: Vec<(usize, &Word)> = word_pool
+        .iter()
+        .filter_map(|word| {
+            let mut letters: Vec<Letter> = word.letters().collect();
+            letters.sort_unstable_by_key(|letter| letter.char());
+            letters.dedup();
+            // Repeated letters tell less
+            (letters.len() == word.letters().count())
+                .then(|| (letters.iter().map(|letter| frequencies[letter]).sum(), word))
+        })
+        .collect();
+    scored.sort_by(|(a_score, a), (b_score, b)| {
+        b_score
+            .cmp(a_score)
+            .then_with(|| a.as_str().cmp(&b.as_str()))
+    });
+    scored
+        .into_iter()
+        .take(size)
+        .map(|(_, word)| word.clone())
+        .collect()
+}
+
+/// A word of the opening book the player didn't open with recently,
+/// picked at random. None if they used all of them.
+pub fn suggest_opener(book: &[Word], recent: &[Word], rng: &mut impl Rng) -> Option<Word> {
+    let fresh: Vec<&Word> = book.iter().filter(|word| !recent.contains(word)).collect();
+    fresh.choose(rng).map(|&word| word.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn word(s: &str) -> Word {
+        Word::parse(s).unwrap()
+    }
+
+    fn pool() -> WordPool {
+        WordPool::from_strings(
+            ["crane", "slate", "hello", "trace", "eerie", "fjord"].map(String::from),
+        )
+    }
+
+    #[test]
+    fn test_opening_book() {
+        let book = opening_book(&pool(), 3);
+        // Common letters first, words repeating letters never
+        assert_eq!(book, vec![word("trace"), word("crane"), word("slate")]);
+        assert_eq!(opening_book(&pool(), 10).len(), 4);
+    }
+
+    #[test]
+    fn test_suggest_skips_recent_openers() {
+        let book = opening_book(&pool(), 3);
+        let mut rng = StdRng::seed_from_u64(4054);
+        let recent = [word("trace"), word("crane")];
+        for _ in 0..10 {
+            assert_eq!(
+                suggest_opener(&book, &recent, &mut rng),
+                Some(word("slate"))
+            );
+        }
+        assert_eq!(suggest_opener(&book, &book, &mut rng), None);
+    }
+}
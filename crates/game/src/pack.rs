@@ -0,0 +1,307 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::{Game, GameConfig, GameState};
+use crate::letter::Word;
+use crate::store::update_json;
+use crate::word_pool::WordPool;
+
+/// A named set of puzzles generated from a seed. Everyone generating a pack
+/// with the same seed and word list gets the same secrets in the same order,
+/// so friends can race through identical packs asynchronously.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pack {
+    seed: String,
+    secrets: Vec<Word>,
+}
+
+impl Pack {
+    /// Generate a pack of `count` puzzles. Secrets don't repeat unless
+    /// `count` exceeds the size of the pool.
+    pub fn generate(word_pool: &WordPool, seed: &str, count: usize) -> Self {
+        let secrets = word_pool
+            .seeded_sampler(seed_from_str(seed))
+            .take(count)
+            .collect();
+        Self {
+            seed: seed.to_string(),
+            secrets,
+        }
+    }
+
+    /// The seed the pack was generated from
+    pub fn seed(&self) -> &str {
+        &self.seed
+    }
+
+    /// Number of puzzles in the pack
+    pub fn len(&self) -> usize {
+        self.secrets.len()
+    }
+
+    /// Is the pack empty
+    pub fn is_empty(&self) -> bool {
+        self.secrets.is_empty()
+    }
+
+    /// Start the puzzle at `index` (0-based)
+    pub fn game(&self, word_pool: WordPool, index: usize, config: GameConfig) -> Option<Game> {
+        let secret = self.secrets.get(index)?.clone();
+        Some(Game::with_secret_and_config(word_pool, secret, config))
+    }
+}
+
+/// Stable 64-bit FNV-1a hash (unlike `std::hash`). Together with the pinned
+/// generator behind `WordPool::seeded_sampler`, pack seeds map to the same
+/// secrets on every platform and toolchain.
+fn seed_from_str(seed: &str) -> u64 {
+    seed.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Result of a finished puzzle of a pack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PackResult {
+    Won { guesses_used: usize },
+    Lost,
+    Forfeited,
+}
+
+/// Which puzzles of a pack were played, and how they went
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackProgress {
+    pub seed: String,
+    /// Result per puzzle, None if not played yet
+    pub results: Vec<Option<PackResult>>,
+}
+
+impl PackProgress {
+    /// Progress of a pack nobody played yet
+    pub fn new(pack: &Pack) -> Self {
+        Self {
+            seed: pack.seed().to_string(),
+            results: vec![None; pack.len()],
+        }
+    }
+
+    /// Adapt progress saved for the same seed to a pack with a different
+    /// number of puzzles (they share their first puzzles)
+    pub fn resize(&mut self, count: usize) {
+        self.results.resize(count, None);
+    }
+
+    /// Record the outcome of a puzzle. Games still in progress are ignored.
+    pub fn record(&mut self, index: usize, state: &GameState) {
+        let result = match state {
+            GameState::Playing => return,
+            GameState::Won { guesses_used } => PackResult::Won {
+                guesses_used: *guesses_used,
+            },
+            GameState::Lost => PackResult::Lost,
+            GameState::Forfeited => PackResult::Forfeited,
+        };
+        if let Some(slot) = self.results.get_mut(index) {
+            *slot = Some(result);
+        }
+    }
+
+    /// Index of the first puzzle not played yet
+    pub fn next_unplayed(&self) -> Option<usize> {
+        self.results.iter().position(Option::is_none)
+    }
+
+    /// Number of puzzles played
+    pub fn played(&self) -> usize {
+        self.results.iter().filter(|r| r.is_some()).count()
+    }
+
+    /// Number of puzzles solved
+    pub fn solved(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| matches!(r, Some(PackResult::Won { .. })))
+            .count()
+    }
+
+    /// Have all puzzles been played
+    pub fn is_complete(&self) -> bool {
+        self.next_unplayed().is_none()
+    }
+
+    /// Take over results of puzzles this progress has no result for, e.g.
+    /// played by another instance of the game. Progress of other packs is
+    /// ignored.
+    pub fn merge(&mut self, other: &PackProgress) {
+        if other.seed != self.seed {
+            return;
+        }
+        if other.results.len() > self.results.len() {
+            self.results.resize(other.results.len(), None);
+        }
+        for (slot, result) in self.results.iter_mut().zip(&other.results) {
+            if slot.is_none() {
+                *slot = *result;
+            }
+        }
+    }
+
+    /// Write the progress to a JSON file, merged with what other instances
+    /// saved meanwhile, so playing a pack in two windows doesn't lose
+    /// results. Where both played a puzzle, the saved result wins. Returns
+    /// the merged progress.
+    pub fn save_merged(&self, path: impl AsRef<Path>) -> io::Result<PackProgress> {
+        update_json(path, |saved: Option<PackProgress>| {
+            let mut merged = saved
+                .filter(|saved| saved.seed == self.seed)
+                .unwrap_or_else(|| self.clone());
+            merged.merge(self);
+            merged
+        })
+    }
+
+    /// Write the progress to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("progress is always serializable");
+        fs::write(path, json)
+    }
+
+    /// Read progress from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_pool() -> WordPool {
+        WordPool::from_strings(
+            [
+                "hello", "world", "crane", "slate", "audio", "pious", "plumb",
+            ]
+            .iter()
+            .map(|s| s.to_string()),
+        )
+    }
+
+    #[test]
+    fn test_merge_progress() {
+        let pack = Pack::generate(&test_pool(), "autumn", 3);
+        let mut ours = PackProgress::new(&pack);
+        ours.record(0, &GameState::Lost);
+        let mut theirs = PackProgress::new(&pack);
+        theirs.record(0, &GameState::Won { guesses_used: 2 });
+        theirs.record(2, &GameState::Forfeited);
+
+        ours.merge(&theirs);
+        assert_eq!(
+            ours.results,
+            vec![Some(PackResult::Lost), None, Some(PackResult::Forfeited)]
+        );
+
+        // Another pack's progress is left alone
+        let other = PackProgress::new(&Pack::generate(&test_pool(), "winter", 3));
+        let mut merged = other.clone();
+        merged.merge(&theirs);
+        assert_eq!(merged, other);
+    }
+
+    #[test]
+    fn test_save_merged_keeps_results_of_other_instances() {
+        let dir = std::env::temp_dir().join(format!("wordle_pack_{}", std::process::id()));
+        let path = dir.join("autumn.json");
+        fs::remove_file(&path).ok();
+        let pack = Pack::generate(&test_pool(), "autumn", 3);
+
+        // Two instances start from the same (empty) progress
+        let mut first = PackProgress::new(&pack);
+        let mut second = first.clone();
+        first.record(0, &GameState::Won { guesses_used: 3 });
+        first.save_merged(&path).unwrap();
+        second.record(1, &GameState::Lost);
+        let merged = second.save_merged(&path).unwrap();
+
+        assert_eq!(merged.played(), 2);
+        assert_eq!(PackProgress::load(&path).unwrap(), merged);
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_same_seed_same_pack() {
+        let a = Pack::generate(&test_pool(), "autumn", 5);
+        let b = Pack::generate(&test_pool(), "autumn", 5);
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 5);
+
+        // A longer pack from the same seed starts with the same puzzles
+        let longer = Pack::generate(&test_pool(), "autumn", 7);
+        assert_eq!(longer.secrets[..5], a.secrets[..]);
+    }
+
+    #[test]
+    fn test_seed_hash_is_stable() {
+        // Changing this breaks packs shared between versions
+        assert_eq!(seed_from_str(""), 0xcbf29ce484222325);
+        assert_eq!(seed_from_str("a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_pack_secrets_are_stable() {
+        // Changing these breaks packs shared between versions
+        let pack = Pack::generate(&test_pool(), "autumn", 3);
+        let secrets: Vec<String> = pack.secrets.iter().map(Word::as_str).collect();
+        assert_eq!(secrets, ["world", "crane", "plumb"]);
+    }
+
+    #[test]
+    fn test_pack_game() {
+        let pack = Pack::generate(&test_pool(), "autumn", 2);
+        assert!(pack.game(test_pool(), 1, GameConfig::default()).is_some());
+        assert!(pack.game(test_pool(), 2, GameConfig::default()).is_none());
+    }
+
+    #[test]
+    fn test_progress() {
+        let pack = Pack::generate(&test_pool(), "autumn", 3);
+        let mut progress = PackProgress::new(&pack);
+        assert_eq!(progress.next_unplayed(), Some(0));
+
+        progress.record(0, &GameState::Won { guesses_used: 4 });
+        progress.record(1, &GameState::Playing);
+        assert_eq!(progress.next_unplayed(), Some(1));
+
+        progress.record(1, &GameState::Lost);
+        progress.record(2, &GameState::Won { guesses_used: 2 });
+        assert!(progress.is_complete());
+        assert_eq!(progress.played(), 3);
+        assert_eq!(progress.solved(), 2);
+
+        progress.resize(4);
+        assert_eq!(progress.next_unplayed(), Some(3));
+    }
+
+    #[test]
+    fn test_progress_save_load() {
+        let path = std::env::temp_dir().join(format!(
+            "test_pack_progress_{}.json",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let pack = Pack::generate(&test_pool(), "autumn", 3);
+        let mut progress = PackProgress::new(&pack);
+        progress.record(0, &GameState::Won { guesses_used: 4 });
+        progress.save(&path).unwrap();
+        assert_eq!(PackProgress::load(&path).unwrap(), progress);
+
+        std::fs::remove_file(path).ok();
+    }
+}
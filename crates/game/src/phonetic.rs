@@ -0,0 +1,150 @@
+//! Rough German grapheme-to-phoneme mapping.
+//!
+//! This is not a real pronunciation model. It maps common German spellings
+//! onto a coarse set of sounds, which is good enough to decide whether two
+//! words rhyme or sound alike for hint purposes.
+//!
+//! Each sound is represented by a single char:
+//! - vowels: `a e i o u ö ü`, plus `A` (ei/ai), `Y` (eu/äu), `W` (au)
+//! - consonants: mostly their own letter, plus `S` (sch), `C` (tsch),
+//!   `X` (ch), `Z` (z/tz), `N` (ng)
+
+use crate::letter::Letter;
+
+/// Sounds that form the nucleus of a syllable
+const VOWEL_SOUNDS: &[char] = &['a', 'e', 'i', 'o', 'u', 'ö', 'ü', 'A', 'Y', 'W'];
+
+fn is_vowel_letter(c: char) -> bool {
+    Letter::new(c).is_some_and(|letter| letter.is_vowel())
+}
+
+fn is_vowel_sound(c: char) -> bool {
+    VOWEL_SOUNDS.contains(&c)
+}
+
+/// Transcribe a word into its coarse sound sequence
+pub fn transcribe(word: &str) -> String {
+    let chars: Vec<char> = word.to_lowercase().chars().collect();
+    let mut sounds: Vec<char> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let rest = &chars[i..];
+        let (emitted, consumed): (&[char], usize) = match rest {
+            ['t', 's', 'c', 'h', ..] => (&['C'], 4),
+            ['s', 'c', 'h', ..] => (&['S'], 3),
+            ['c', 'h', 's', ..] => (&['k', 's'], 3),
+            ['s', 'p' | 't', ..] if i == 0 => (&['S'], 1),
+            ['c', 'h', ..] => (&['X'], 2),
+            ['c', 'k', ..] => (&['k'], 2),
+            ['p', 'h', ..] => (&['f'], 2),
+            ['t', 'h', ..] | ['d', 't', ..] => (&['t'], 2),
+            ['q', 'u', ..] => (&['k', 'v'], 2),
+            ['n', 'g', ..] => (&['N'], 2),
+            ['t', 'z', ..] => (&['Z'], 2),
+            ['e' | 'a', 'i' | 'y', ..] => (&['A'], 2),
+            ['e' | 'ä', 'u', ..] => (&['Y'], 2),
+            ['a', 'u', ..] => (&['W'], 2),
+            ['i', 'e', ..] => (&['i'], 2),
+            ['h', next, ..] if is_vowel_letter(*next) => (&['h'], 1),
+            // 'h' not followed by a vowel only lengthens the previous vowel
+            ['h', ..] => (&[], 1),
+            ['ä', ..] => (&['e'], 1),
+            ['y', ..] => (&['ü'], 1),
+            ['ß', ..] => (&['s'], 1),
+            ['z', ..] => (&['Z'], 1),
+            ['c', ..] => (&['k'], 1),
+            ['x', ..] => (&['k', 's'], 1),
+            ['v', ..] => (&['f'], 1),
+            ['w', ..] => (&['v'], 1),
+            [c, ..] => (std::slice::from_ref(c), 1),
+            [] => unreachable!(),
+        };
+
+        for &sound in emitted {
+            // Doubled letters (mm, ss, ee, ...) are pronounced once
+            if sounds.last() != Some(&sound) {
+                sounds.push(sound);
+            }
+        }
+        i += consumed;
+    }
+
+    // Final devoicing: voiced stops and fricatives lose their voice at the end
+    if let Some(last) = sounds.last_mut() {
+        *last = match *last {
+            'b' => 'p',
+            'd' => 't',
+            'g' => 'k',
+            'v' => 'f',
+            other => other,
+        };
+    }
+
+    sounds.into_iter().collect()
+}
+
+/// The rhyming part of a transcription: everything from the last vowel sound on
+fn rhyme_part(sounds: &str) -> Option<&str> {
+    let (index, _) = sounds.char_indices().rfind(|&(_, c)| is_vowel_sound(c))?;
+    Some(&sounds[index..])
+}
+
+/// Check whether two words rhyme (same sounds from the last vowel onward)
+pub fn rhymes(a: &str, b: &str) -> bool {
+    let (a, b) = (transcribe(a), transcribe(b));
+    match (rhyme_part(&a), rhyme_part(&b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Check whether two words sound alike (identical sound sequence)
+pub fn sounds_like(a: &str, b: &str) -> bool {
+    transcribe(a) == transcribe(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transcribe_consonant_clusters() {
+        assert_eq!(transcribe("schaf"), "Saf");
+        assert_eq!(transcribe("deutsch"), "dYC");
+        assert_eq!(transcribe("stein"), "StAn");
+        assert_eq!(transcribe("fuchs"), "fuks");
+        assert_eq!(transcribe("quark"), "kvark");
+    }
+
+    #[test]
+    fn test_transcribe_silent_h_and_doubles() {
+        assert_eq!(transcribe("mehr"), "mer");
+        assert_eq!(transcribe("meer"), "mer");
+        assert_eq!(transcribe("hallo"), "halo");
+    }
+
+    #[test]
+    fn test_transcribe_final_devoicing() {
+        assert_eq!(transcribe("rad"), "rat");
+        assert_eq!(transcribe("rat"), "rat");
+        assert_eq!(transcribe("berg"), "berk");
+    }
+
+    #[test]
+    fn test_rhymes() {
+        assert!(rhymes("haus", "maus"));
+        assert!(rhymes("kater", "vater"));
+        assert!(rhymes("stein", "bein"));
+        assert!(!rhymes("haus", "hand"));
+        assert!(!rhymes("brr", "haus"));
+    }
+
+    #[test]
+    fn test_sounds_like() {
+        assert!(sounds_like("mehr", "meer"));
+        assert!(sounds_like("rad", "rat"));
+        assert!(sounds_like("seite", "saite"));
+        assert!(!sounds_like("haus", "maus"));
+    }
+}
@@ -0,0 +1,34 @@
+use crate::game::{GameState, GuessResult};
+use crate::letter::Word;
+
+/// Common interface of all game types, so frontends can drive any variant
+/// without knowing its rules
+pub trait Playable {
+    /// Make a guess (string input)
+    fn guess(&mut self, input: &str) -> GuessResult;
+
+    /// Give up and reveal the secrets (does nothing if the game is over)
+    fn forfeit(&mut self);
+
+    /// Current game state
+    fn state(&self) -> GameState;
+
+    /// Number of guesses remaining
+    fn guesses_remaining(&self) -> usize;
+
+    /// Get max guesses allowed
+    fn max_guesses(&self) -> usize;
+
+    /// Number of guesses made on the current puzzle
+    fn guesses_made(&self) -> usize;
+
+    /// The secret words (only available after the game ends)
+    fn secrets(&self) -> Option<Vec<Word>>;
+
+    /// Stop the clock while the player can't play, e.g. behind a menu.
+    /// Variants without a clock ignore it.
+    fn pause(&mut self) {}
+
+    /// Restart the clock after `pause`
+    fn resume(&mut self) {}
+}
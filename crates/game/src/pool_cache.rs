@@ -0,0 +1,150 @@
+//! The words of an embedded word list kept in a file, so later starts skip
+//! decompressing and checking the list. The cache is tied to the exact
+//! list it was made from and is simply written again once that changes.
+//!
+//! The file holds a magic header, a fingerprint of the embedded list, the
+//! list's metadata as TOML and then the words, one per line.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use wordle_wordlists_processing::sharp_s::ListMetadata;
+
+use crate::language::{Language, load_wordlist};
+use crate::letter::Word;
+use crate::word_pool::WordPool;
+
+/// Start of every cache file, including the format version
+const MAGIC: &[u8] = b"wordle-pool\x01";
+
+/// Load the word list of a language like [`load_wordlist`], but from a
+/// cache in `cache_dir` if it was made from the same list. Otherwise the
+/// list is loaded and the cache written for the next time. Problems with
+/// the cache only make loading slower.
+///
+/// # Errors
+///
+/// Returns an error if there is no word list for the language, or it
+/// can't be read.
+pub fn load_wordlist_cached(language: Language, cache_dir: &Path) -> io::Result<WordPool> {
+    let Some(fingerprint) = fingerprint(language) else {
+        return load_wordlist(language);
+    };
+    let path = cache_path(language, cache_dir);
+    if let Some(pool) = fs::read(&path)
+        .ok()
+        .and_then(|bytes| decode(&bytes, fingerprint))
+    {
+        return Ok(pool);
+    }
+    let pool = load_wordlist(language)?;
+    fs::create_dir_all(cache_dir)
+        .and_then(|()| write(&path, &encode(&pool, fingerprint)))
+        .ok();
+    Ok(pool)
+}
+
+fn cache_path(language: Language, cache_dir: &Path) -> PathBuf {
+    cache_dir.join(format!("words-{}.cache", language.code()))
+}
+
+/// FNV-1a hash of the embedded list and its metadata, None if the
+/// language has no embedded list
+fn fingerprint(language: Language) -> Option<u64> {
+    let (data, metadata) = language.embedded_wordlist()?;
+    let hash = data
+        .iter()
+        .chain(metadata.as_bytes())
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    Some(hash)
+}
+
+fn encode(pool: &WordPool, fingerprint: u64) -> Vec<u8> {
+    let metadata = pool
+        .metadata()
+        .map(ListMetadata::to_toml)
+        .unwrap_or_default();
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend_from_slice(&fingerprint.to_le_bytes());
+    bytes.extend_from_slice(&(metadata.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(metadata.as_bytes());
+    let words: Vec<String> = pool.iter().map(Word::to_string).collect();
+    bytes.extend_from_slice(words.join("\n").as_bytes());
+    bytes
+}
+
+/// The pool in a cache file, None if the file is damaged or was made
+/// from another list
+fn decode(bytes: &[u8], fingerprint: u64) -> Option<WordPool> {
+    let rest = bytes.strip_prefix(MAGIC)?;
+    let (cached, rest) = rest.split_first_chunk::<8>()?;
+    if u64::from_le_bytes(*cached) != fingerprint {
+        return None;
+    }
+    let (len, rest) = rest.split_first_chunk::<4>()?;
+    let len = usize::try_from(u32::from_le_bytes(*len)).ok()?;
+    if rest.len() < len {
+        return None;
+    }
+    let (metadata, words) = rest.split_at(len);
+    let metadata = ListMetadata::parse(std::str::from_utf8(metadata).ok()?).ok()?;
+    let words = std::str::from_utf8(words)
+        .ok()?
+        .lines()
+        .map(Word::parse)
+        .collect::<Option<Vec<Word>>>()?;
+    Some(WordPool::from_words(words).with_metadata(metadata))
+}
+
+/// Replace a cache file atomically, so a start running at the same time
+/// never reads half of it
+fn write(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(format!(".{}.tmp", std::process::id()));
+    fs::write(&temp, bytes)?;
+    fs::rename(&temp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(pool: &WordPool) -> Vec<Word> {
+        pool.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let pool = WordPool::from_strings(["hallo", "welt", "krane"].map(String::from))
+            .with_metadata(ListMetadata {
+                words: Some(2),
+                ..ListMetadata::default()
+            });
+        let bytes = encode(&pool, 7);
+        let cached = decode(&bytes, 7).unwrap();
+        assert_eq!(words(&cached), words(&pool));
+        assert_eq!(cached.metadata(), pool.metadata());
+
+        // Made from another list, or damaged
+        assert!(decode(&bytes, 8).is_none());
+        assert!(decode(&bytes[..bytes.len() - 2], 7).is_none());
+        assert!(decode(b"wordle-pool", 7).is_none());
+    }
+
+    #[test]
+    fn test_load_wordlist_cached() {
+        let dir = std::env::temp_dir().join(format!("pool_cache_{}", std::process::id()));
+        let first = load_wordlist_cached(Language::German, &dir).unwrap();
+        assert!(cache_path(Language::German, &dir).exists());
+        let second = load_wordlist_cached(Language::German, &dir).unwrap();
+        fs::remove_dir_all(&dir).ok();
+
+        let loaded = load_wordlist(Language::German).unwrap();
+        assert_eq!(words(&first), words(&loaded));
+        assert_eq!(words(&second), words(&loaded));
+        assert_eq!(second.metadata(), loaded.metadata());
+    }
+}
@@ -0,0 +1,17 @@
+//! What most frontends need to play games, for a glob import:
+//!
+//! ```
+//! use wordle_game::prelude::*;
+//!
+//! let pool = WordPool::from_strings(["hello", "world"].map(String::from));
+//! let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+//! let feedback = game.guess("world").unwrap();
+//! assert_eq!(feedback.feedback()[3], LetterFeedback::Correct);
+//! assert_eq!(game.state(), GameState::Playing);
+//! ```
+
+pub use crate::{
+    DictionaryCheck, Game, GameConfig, GameError, GameRecord, GameState, GuessError, GuessFeedback,
+    GuessResult, GuessWarning, History, Language, Letter, LetterFeedback, MAX_GUESSES, Playable,
+    SecretSelector, Statistics, WORD_LENGTH, Word, WordPool, load_wordlist,
+};
@@ -0,0 +1,260 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::feedback::{GuessFeedback, LetterFeedback};
+use crate::game::{Game, GameConfig, GuessResult};
+use crate::letter::Word;
+use crate::observer::GameObserver;
+use crate::word_pool::WordPool;
+
+/// Recording of a single game: its secret, rules, and every accepted guess
+/// with the time it was made, e.g. to watch a game again or attach it to a
+/// bug report.
+///
+/// The letter bank of the letter-bank variant is not recorded, so playback
+/// always uses the regular rules.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Replay {
+    pub secret: String,
+    pub max_guesses: usize,
+    pub hard_mode: bool,
+    pub steps: Vec<ReplayStep>,
+}
+
+/// One accepted guess of a replay
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayStep {
+    /// Milliseconds since the game started
+    pub elapsed_ms: u64,
+    pub guess: String,
+    pub feedback: Vec<LetterFeedback>,
+}
+
+impl ReplayStep {
+    /// Time since the game started
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_millis(self.elapsed_ms)
+    }
+}
+
+impl Replay {
+    /// Start an empty recording of a game
+    fn start(game: &Game) -> Self {
+        Self {
+            secret: game.secret_word().to_string(),
+            max_guesses: game.max_guesses(),
+            hard_mode: game.config().hard_mode,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Serialize to JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("replays are always serializable")
+    }
+
+    /// Deserialize from JSON
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        serde_json::from_str(json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Write the replay to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+
+    /// Read a replay from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Re-drive a fresh game through the recorded guesses, one step per
+    /// iteration. Returns None if the recorded secret is not a valid word.
+    pub fn playback(&self, word_pool: WordPool) -> Option<ReplayPlayback<'_>> {
+        let secret = Word::parse(&self.secret)?;
+        let config = GameConfig {
+            max_guesses: self.max_guesses,
+            hard_mode: self.hard_mode,
+            ..GameConfig::default()
+        };
+        Some(ReplayPlayback {
+            game: Game::with_secret_and_config(word_pool, secret, config),
+            steps: self.steps.iter(),
+        })
+    }
+}
+
+/// Iterator re-playing the guesses of a replay on a game. Yields the
+/// recorded time of each guess together with the result of re-applying it.
+#[derive(Debug)]
+pub struct ReplayPlayback<'a> {
+    game: Game,
+    steps: std::slice::Iter<'a, ReplayStep>,
+}
+
+impl ReplayPlayback<'_> {
+    /// The game in its state after the steps played so far
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Iterator for ReplayPlayback<'_> {
+    type Item = (Duration, GuessResult);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let step = self.steps.next()?;
+        Some((step.elapsed(), self.game.guess(&step.guess)))
+    }
+}
+
+/// Observer that records every game it sees as a `Replay`
+#[derive(Debug, Default)]
+pub struct ReplayRecorder {
+    state: Mutex<RecorderState>,
+}
+
+#[derive(Debug, Default)]
+struct RecorderState {
+    current: Option<(Instant, Replay)>,
+    last_finished: Option<Replay>,
+}
+
+impl ReplayRecorder {
+    /// Create a recorder that has not seen any game yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recording of the game in progress
+    pub fn current_replay(&self) -> Option<Replay> {
+        let state = self.state.lock().unwrap();
+        state.current.as_ref().map(|(_, replay)| replay.clone())
+    }
+
+    /// Recording of the most recently finished game
+    pub fn last_replay(&self) -> Option<Replay> {
+        self.state.lock().unwrap().last_finished.clone()
+    }
+
+    fn finish(&self) {
+        let mut state = self.state.lock().unwrap();
+        if let Some((_, replay)) = state.current.take() {
+            state.last_finished = Some(replay);
+        }
+    }
+}
+
+impl GameObserver for ReplayRecorder {
+    fn on_new_game(&self, game: &Game) {
+        self.state.lock().unwrap().current = Some((Instant::now(), Replay::start(game)));
+    }
+
+    fn on_guess(&self, _game: &Game, feedback: &GuessFeedback) {
+        let mut state = self.state.lock().unwrap();
+        if let Some((started, replay)) = &mut state.current {
+            replay.steps.push(ReplayStep {
+                elapsed_ms: started.elapsed().as_millis() as u64,
+                guess: feedback.word().to_string(),
+                feedback: feedback.feedback().to_vec(),
+            });
+        }
+    }
+
+    fn on_win(&self, _game: &Game) {
+        self.finish();
+    }
+
+    fn on_lose(&self, _game: &Game) {
+        self.finish();
+    }
+
+    fn on_forfeit(&self, _game: &Game) {
+        self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::game::GameState;
+
+    fn test_pool() -> WordPool {
+        WordPool::from_strings(["hello", "world", "crane"].iter().map(|s| s.to_string()))
+    }
+
+    fn recorded_game() -> Replay {
+        let recorder = Arc::new(ReplayRecorder::new());
+        let config = GameConfig {
+            observers: vec![recorder.clone()],
+            ..GameConfig::default()
+        };
+        let mut game =
+            Game::with_secret_and_config(test_pool(), Word::parse("hello").unwrap(), config);
+        game.guess("crane").unwrap();
+        assert_eq!(recorder.current_replay().unwrap().steps.len(), 1);
+        assert!(recorder.last_replay().is_none());
+
+        game.guess("world").unwrap();
+        game.guess("hello").unwrap();
+        assert!(recorder.current_replay().is_none());
+        recorder.last_replay().unwrap()
+    }
+
+    #[test]
+    fn test_record() {
+        let replay = recorded_game();
+
+        assert_eq!(replay.secret, "hello");
+        assert_eq!(replay.max_guesses, 6);
+        let guesses: Vec<&str> = replay.steps.iter().map(|s| s.guess.as_str()).collect();
+        assert_eq!(guesses, vec!["crane", "world", "hello"]);
+        assert_eq!(replay.steps[2].feedback, vec![LetterFeedback::Correct; 5]);
+        assert!(replay.steps[0].elapsed_ms <= replay.steps[2].elapsed_ms);
+    }
+
+    #[test]
+    fn test_playback() {
+        let replay = recorded_game();
+        let mut playback = replay.playback(test_pool()).unwrap();
+
+        for step in &replay.steps {
+            let (elapsed, result) = playback.next().unwrap();
+            assert_eq!(elapsed, step.elapsed());
+            assert_eq!(result.unwrap().feedback().to_vec(), step.feedback);
+        }
+        assert!(playback.next().is_none());
+        assert_eq!(playback.game().state(), GameState::Won { guesses_used: 3 });
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let replay = recorded_game();
+        assert_eq!(Replay::from_json(&replay.to_json()).unwrap(), replay);
+        assert!(Replay::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_save_load() {
+        let path = std::env::temp_dir().join(format!(
+            "test_replay_{}.json",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let replay = recorded_game();
+        replay.save(&path).unwrap();
+        assert_eq!(Replay::load(&path).unwrap(), replay);
+
+        std::fs::remove_file(path).ok();
+    }
+}
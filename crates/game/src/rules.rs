@@ -0,0 +1,439 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::error::GuessError;
+use crate::feedback::{GuessFeedback, LetterFeedback};
+use crate::game::{DictionaryCheck, GameConfig, parse_guess};
+use crate::letter::{Letter, Word};
+use crate::letter_bank::LetterBank;
+use crate::word_pool::WordPool;
+
+/// A guess being validated: the raw input, and the parsed word if the
+/// input is well-formed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess<'a> {
+    input: &'a str,
+    word: Result<Word, GuessError>,
+}
+
+impl<'a> Guess<'a> {
+    /// Validate user input
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            word: parse_guess(input),
+        }
+    }
+
+    /// The raw input
+    pub fn input(&self) -> &str {
+        self.input
+    }
+
+    /// The parsed word, None if the input is malformed
+    pub fn word(&self) -> Option<&Word> {
+        self.word.as_ref().ok()
+    }
+
+    /// The parsed word, or why the input is malformed
+    pub(crate) fn into_word(self) -> Result<Word, GuessError> {
+        self.word
+    }
+}
+
+/// State of the game a guess is checked against
+#[derive(Debug, Clone, Copy)]
+pub struct RuleContext<'a> {
+    pub word_pool: &'a WordPool,
+    pub secret: &'a Word,
+    /// Guesses accepted so far
+    pub guesses: &'a [GuessFeedback],
+    pub letter_bank: Option<&'a LetterBank>,
+    /// Accepts words that aren't in the pool, see `GameConfig::extra_validator`
+    pub extra_validator: Option<&'a dyn WordValidator>,
+}
+
+impl RuleContext<'_> {
+    /// Whether a word may be guessed as far as the dictionary is
+    /// concerned: it is in the pool, or the extra validator accepts it
+    pub fn is_known_word(&self, word: &Word) -> bool {
+        self.word_pool.contains(word)
+            || self
+                .extra_validator
+                .is_some_and(|validator| validator.accepts(word))
+    }
+}
+
+/// Accepts words that aren't in the word pool, e.g. from an external
+/// dictionary service or a player's own allowlist, without rebuilding the
+/// pool. Only asked about guesses the pool doesn't have.
+pub trait WordValidator: fmt::Debug + Send + Sync {
+    fn accepts(&self, word: &Word) -> bool;
+}
+
+/// A fixed set of extra words, e.g. a player's allowlist
+impl WordValidator for HashSet<Word> {
+    fn accepts(&self, word: &Word) -> bool {
+        self.contains(word)
+    }
+}
+
+/// One aspect of guess validation. Rules are evaluated in order and the
+/// first rejection wins, so variants can add or drop rules instead of
+/// patching `Game::guess_word`.
+///
+/// Only `LengthRule` looks at malformed input; other rules should accept
+/// guesses without a parsed word and leave them to it.
+pub trait GuessRule: fmt::Debug + Send + Sync {
+    fn check(&self, guess: &Guess<'_>, context: &RuleContext<'_>) -> Result<(), GuessError>;
+}
+
+/// Guesses must be exactly `WORD_LENGTH` letters
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LengthRule;
+
+impl GuessRule for LengthRule {
+    fn check(&self, guess: &Guess<'_>, _context: &RuleContext<'_>) -> Result<(), GuessError> {
+        guess.word.as_ref().map(|_| ()).map_err(Clone::clone)
+    }
+}
+
+/// Guesses must be in the word list. The secret is always accepted, since
+/// the secret of a challenge may be outside the list.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DictionaryRule;
+
+impl GuessRule for DictionaryRule {
+    fn check(&self, guess: &Guess<'_>, context: &RuleContext<'_>) -> Result<(), GuessError> {
+        match guess.word() {
+            Some(word) if !context.is_known_word(word) && word != context.secret => {
+                Err(GuessError::NotInWordList)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Guesses must use all hints revealed by previous guesses: correct letters
+/// must stay in place, and letters in the wrong position must be used again
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HardModeRule;
+
+impl GuessRule for HardModeRule {
+    fn check(&self, guess: &Guess<'_>, context: &RuleContext<'_>) -> Result<(), GuessError> {
+        let Some(word) = guess.word() else {
+            return Ok(());
+        };
+
+        for previous in context.guesses {
+            let mut required: HashMap<Letter, usize> = HashMap::new();
+            for (position, (letter, feedback)) in previous.iter().enumerate() {
+                match feedback {
+                    LetterFeedback::Correct => {
+                        if word.letter(position) != letter {
+                            return Err(GuessError::HardModeViolation {
+                                letter,
+                                position: Some(position),
+                            });
+                        }
+                        *required.entry(letter).or_insert(0) += 1;
+                    }
+                    LetterFeedback::WrongPosition => {
+                        *required.entry(letter).or_insert(0) += 1;
+                    }
+                    LetterFeedback::NotInWord => {}
+                }
+            }
+
+            // Check in word order, so the reported letter is deterministic
+            for letter in previous.word().letters() {
+                let needed = required.get(&letter).copied().unwrap_or(0);
+                if word.letters().filter(|&l| l == letter).count() < needed {
+                    return Err(GuessError::HardModeViolation {
+                        letter,
+                        position: None,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The same word can't be guessed twice
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoRepeatRule;
+
+impl GuessRule for NoRepeatRule {
+    fn check(&self, guess: &Guess<'_>, context: &RuleContext<'_>) -> Result<(), GuessError> {
+        match guess.word() {
+            Some(word) if context.guesses.iter().any(|g| g.word() == word) => {
+                Err(GuessError::AlreadyGuessed)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Guesses must not contain any of the given letters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BannedLetterRule {
+    letters: Vec<Letter>,
+}
+
+impl BannedLetterRule {
+    pub fn new(letters: impl IntoIterator<Item = Letter>) -> Self {
+        Self {
+            letters: letters.into_iter().collect(),
+        }
+    }
+}
+
+impl GuessRule for BannedLetterRule {
+    fn check(&self, guess: &Guess<'_>, _context: &RuleContext<'_>) -> Result<(), GuessError> {
+        let Some(word) = guess.word() else {
+            return Ok(());
+        };
+        match word.letters().find(|letter| self.letters.contains(letter)) {
+            Some(letter) => Err(GuessError::BannedLetter { letter }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The first guess must not be one of the given words
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BannedOpenerRule {
+    words: Vec<Word>,
+}
+
+impl BannedOpenerRule {
+    pub fn new(words: impl IntoIterator<Item = Word>) -> Self {
+        Self {
+            words: words.into_iter().collect(),
+        }
+    }
+}
+
+impl GuessRule for BannedOpenerRule {
+    fn check(&self, guess: &Guess<'_>, context: &RuleContext<'_>) -> Result<(), GuessError> {
+        match guess.word() {
+            Some(word) if context.guesses.is_empty() && self.words.contains(word) => {
+                Err(GuessError::BannedOpener)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Guesses must be formed from the letters left in the letter bank
+/// (if playing with one)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LetterBankRule;
+
+impl GuessRule for LetterBankRule {
+    fn check(&self, guess: &Guess<'_>, context: &RuleContext<'_>) -> Result<(), GuessError> {
+        let (Some(word), Some(bank)) = (guess.word(), context.letter_bank) else {
+            return Ok(());
+        };
+        match bank.missing_letter(word) {
+            Some(letter) => Err(GuessError::NotInLetterBank { letter }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The rules a game with the given config enforces: the built-in rules
+/// enabled by the config, followed by `GameConfig::rules`
+pub fn rules_for(config: &GameConfig) -> Vec<Arc<dyn GuessRule>> {
+    let mut rules: Vec<Arc<dyn GuessRule>> = vec![Arc::new(LengthRule)];
+    if config.dictionary_check == DictionaryCheck::Strict {
+        rules.push(Arc::new(DictionaryRule));
+    }
+    if config.hard_mode {
+        rules.push(Arc::new(HardModeRule));
+    }
+    if config.letter_bank_size.is_some() {
+        rules.push(Arc::new(LetterBankRule));
+    }
+    if config.reject_repeated_guesses {
+        rules.push(Arc::new(NoRepeatRule));
+    }
+    rules.extend(config.rules.iter().cloned());
+    rules
+}
+
+/// Check a guess against rules that only warn, returning what each broken
+/// rule would have rejected the guess for
+pub fn check_soft_rules(
+    rules: &[Arc<dyn GuessRule>],
+    guess: &Guess<'_>,
+    context: &RuleContext<'_>,
+) -> Vec<GuessError> {
+    rules
+        .iter()
+        .filter_map(|rule| rule.check(guess, context).err())
+        .collect()
+}
+
+/// Check a guess against rules in order, returning the parsed word if all accept it
+pub fn check_rules(
+    rules: &[Arc<dyn GuessRule>],
+    guess: Guess<'_>,
+    context: &RuleContext<'_>,
+) -> Result<Word, GuessError> {
+    for rule in rules {
+        rule.check(&guess, context)?;
+    }
+    guess.into_word()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(s: &str) -> Word {
+        Word::parse(s).unwrap()
+    }
+
+    fn test_pool() -> WordPool {
+        WordPool::from_strings(["hello", "world", "crane"].iter().map(|s| s.to_string()))
+    }
+
+    fn check(
+        rule: &dyn GuessRule,
+        input: &str,
+        guesses: &[GuessFeedback],
+    ) -> Result<(), GuessError> {
+        let pool = test_pool();
+        let secret = word("hello");
+        let context = RuleContext {
+            word_pool: &pool,
+            secret: &secret,
+            guesses,
+            letter_bank: None,
+            extra_validator: None,
+        };
+        rule.check(&Guess::new(input), &context)
+    }
+
+    #[test]
+    fn test_length_rule() {
+        assert_eq!(check(&LengthRule, "crane", &[]), Ok(()));
+        assert_eq!(
+            check(&LengthRule, "cran", &[]),
+            Err(GuessError::TooShort { got: 4 })
+        );
+    }
+
+    #[test]
+    fn test_rules_skip_malformed_input() {
+        assert_eq!(check(&DictionaryRule, "cran", &[]), Ok(()));
+        assert_eq!(check(&HardModeRule, "cr4ne", &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_dictionary_rule() {
+        assert_eq!(check(&DictionaryRule, "world", &[]), Ok(()));
+        assert_eq!(
+            check(&DictionaryRule, "zzzzz", &[]),
+            Err(GuessError::NotInWordList)
+        );
+    }
+
+    #[test]
+    fn test_dictionary_rule_asks_extra_validator() {
+        let pool = test_pool();
+        let secret = word("hello");
+        let allowlist = HashSet::from([word("qwert")]);
+        let context = RuleContext {
+            word_pool: &pool,
+            secret: &secret,
+            guesses: &[],
+            letter_bank: None,
+            extra_validator: Some(&allowlist),
+        };
+        assert_eq!(DictionaryRule.check(&Guess::new("qwert"), &context), Ok(()));
+        assert_eq!(
+            DictionaryRule.check(&Guess::new("zzzzz"), &context),
+            Err(GuessError::NotInWordList)
+        );
+    }
+
+    #[test]
+    fn test_no_repeat_rule() {
+        let guesses = [GuessFeedback::evaluate(&word("crane"), &word("hello"))];
+        assert_eq!(
+            check(&NoRepeatRule, "crane", &guesses),
+            Err(GuessError::AlreadyGuessed)
+        );
+        assert_eq!(check(&NoRepeatRule, "world", &guesses), Ok(()));
+    }
+
+    #[test]
+    fn test_letter_bank_rule() {
+        let pool = test_pool();
+        let secret = word("hello");
+        let bank = LetterBank::new("helo".chars().filter_map(Letter::new));
+        let context = RuleContext {
+            word_pool: &pool,
+            secret: &secret,
+            guesses: &[],
+            letter_bank: Some(&bank),
+            extra_validator: None,
+        };
+        assert_eq!(
+            LetterBankRule.check(&Guess::new("hello"), &context),
+            Err(GuessError::NotInLetterBank {
+                letter: Letter::new('l').unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn test_rules_for_config() {
+        let config = GameConfig {
+            hard_mode: true,
+            rules: vec![Arc::new(NoRepeatRule)],
+            ..GameConfig::default()
+        };
+        let rules = rules_for(&config);
+        let names: Vec<String> = rules.iter().map(|r| format!("{r:?}")).collect();
+        assert_eq!(
+            names,
+            vec![
+                "LengthRule",
+                "DictionaryRule",
+                "HardModeRule",
+                "NoRepeatRule"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_first_rejection_wins() {
+        let pool = test_pool();
+        let secret = word("hello");
+        let context = RuleContext {
+            word_pool: &pool,
+            secret: &secret,
+            guesses: &[],
+            letter_bank: None,
+            extra_validator: None,
+        };
+        let rules = rules_for(&GameConfig::default());
+        assert_eq!(
+            check_rules(&rules, Guess::new("zz"), &context),
+            Err(GuessError::TooShort { got: 2 })
+        );
+        assert_eq!(
+            check_rules(&rules, Guess::new("zzzzz"), &context),
+            Err(GuessError::NotInWordList)
+        );
+        assert_eq!(
+            check_rules(&rules, Guess::new("World"), &context),
+            Ok(word("world"))
+        );
+    }
+}
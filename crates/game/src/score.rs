@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::letter::Word;
+use crate::word_pool::WordPool;
+
+/// Points for solving with the first guess; later guesses earn proportionally less
+const GUESS_POINTS: u32 = 1000;
+/// Points for solving instantly, dropping linearly to 0 at `TIME_LIMIT`
+const TIME_POINTS: u32 = 500;
+const TIME_LIMIT: Duration = Duration::from_secs(5 * 60);
+/// Bonus for hard mode, in percent of the guess points
+const HARD_MODE_PERCENT: u32 = 20;
+/// Points for the hardest possible word
+const DIFFICULTY_POINTS: u32 = 500;
+
+/// Score of a finished game, broken down by what it was awarded for.
+/// Lost games score 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Score {
+    /// Points for using few guesses
+    pub guesses: u32,
+    /// Points for solving quickly
+    pub time: u32,
+    /// Bonus for playing in hard mode
+    pub hard_mode: u32,
+    /// Points for solving a hard word
+    pub difficulty: u32,
+}
+
+impl Score {
+    /// Score a won game. `difficulty` is the word difficulty from 0 to 1
+    /// (see `word_difficulty`).
+    pub fn for_win(
+        guesses_used: usize,
+        max_guesses: usize,
+        elapsed: Duration,
+        hard_mode: bool,
+        difficulty: f64,
+    ) -> Self {
+        let max_guesses = max_guesses.max(1) as u32;
+        let unused = max_guesses.saturating_sub(guesses_used as u32) + 1;
+        let guesses = GUESS_POINTS * unused.min(max_guesses) / max_guesses;
+
+        let remaining = TIME_LIMIT.saturating_sub(elapsed);
+        let time =
+            (u128::from(TIME_POINTS) * remaining.as_millis() / TIME_LIMIT.as_millis()) as u32;
+
+        let hard_mode = if hard_mode {
+            guesses * HARD_MODE_PERCENT / 100
+        } else {
+            0
+        };
+
+        let difficulty = (difficulty.clamp(0.0, 1.0) * f64::from(DIFFICULTY_POINTS)).round() as u32;
+
+        Self {
+            guesses,
+            time,
+            hard_mode,
+            difficulty,
+        }
+    }
+
+    /// Total number of points
+    pub fn total(&self) -> u32 {
+        self.guesses + self.time + self.hard_mode + self.difficulty
+    }
+}
+
+/// How hard a word is to find, from 0 (easy) to 1 (hard): words made of
+/// letters that are rare in the pool are harder, and repeated letters make
+/// it harder still since guesses tend to use distinct letters.
+pub fn word_difficulty(word: &Word, word_pool: &WordPool) -> f64 {
+    if word_pool.is_empty() {
+        return 0.0;
+    }
+
+    let letters: HashSet<_> = word.letters().collect();
+    // Fraction of words in the pool containing each letter
+    let commonness: f64 = letters
+        .iter()
+        .map(|&letter| {
+            let containing = word_pool
+                .iter()
+                .filter(|w| w.letters().any(|l| l == letter))
+                .count();
+            containing as f64 / word_pool.len() as f64
+        })
+        .sum::<f64>()
+        / letters.len() as f64;
+
+    let repeated = word.letters().count() - letters.len();
+    let repeat_penalty = 0.25 * repeated as f64;
+
+    ((1.0 - commonness) + repeat_penalty).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fewer_guesses_score_higher() {
+        let fast = Score::for_win(2, 6, Duration::ZERO, false, 0.0);
+        let slow = Score::for_win(5, 6, Duration::ZERO, false, 0.0);
+        assert!(fast.total() > slow.total());
+        assert_eq!(
+            Score::for_win(1, 6, Duration::ZERO, false, 0.0).guesses,
+            1000
+        );
+    }
+
+    #[test]
+    fn test_time_points() {
+        let instant = Score::for_win(3, 6, Duration::ZERO, false, 0.0);
+        assert_eq!(instant.time, TIME_POINTS);
+        let half = Score::for_win(3, 6, TIME_LIMIT / 2, false, 0.0);
+        assert_eq!(half.time, TIME_POINTS / 2);
+        let late = Score::for_win(3, 6, TIME_LIMIT * 2, false, 0.0);
+        assert_eq!(late.time, 0);
+    }
+
+    #[test]
+    fn test_hard_mode_and_difficulty_bonus() {
+        let score = Score::for_win(1, 6, TIME_LIMIT, true, 0.5);
+        assert_eq!(score.hard_mode, 200);
+        assert_eq!(score.difficulty, 250);
+        assert_eq!(score.total(), 1000 + 200 + 250);
+    }
+
+    #[test]
+    fn test_word_difficulty() {
+        let pool = WordPool::from_strings(
+            ["crane", "slate", "trace", "jazzy"]
+                .iter()
+                .map(|s| s.to_string()),
+        );
+        let easy = word_difficulty(&Word::parse("crane").unwrap(), &pool);
+        let hard = word_difficulty(&Word::parse("jazzy").unwrap(), &pool);
+        assert!(easy < hard, "{easy} < {hard}");
+        assert!((0.0..=1.0).contains(&easy));
+        // Only the shared "a" is common, plus a penalty for the repeated "z"
+        assert_eq!(hard, 1.0 - 0.4375 + 0.25);
+    }
+}
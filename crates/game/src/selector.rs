@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::seq::SliceRandom;
+
+use crate::constants::WORD_LENGTH;
+use crate::letter::Word;
+use crate::word_pool::{WordPool, WordSampler, stable_index, stable_rng};
+
+/// Day of daily word #0, 2021-06-19, counting puzzles like the original
+/// Wordle does
+const FIRST_DAILY_DAY: u64 = 18_797;
+
+/// Strategy for picking the secret of a new game, so game modes can differ
+/// in how secrets are chosen without special cases in `Game`
+pub trait SecretSelector: fmt::Debug {
+    /// Pick the secret for a new game. Returns None if no word can be picked
+    /// (e.g. the pool is empty).
+    fn select(&mut self, word_pool: &WordPool) -> Option<Word>;
+
+    /// Learn how the game with `secret` went, for selectors that adapt to
+    /// the player. Ignored by default.
+    fn record_result(&mut self, _secret: &Word, _won: bool) {}
+}
+
+/// Picks every word of the pool with the same probability
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UniformSelector;
+
+impl SecretSelector for UniformSelector {
+    fn select(&mut self, word_pool: &WordPool) -> Option<Word> {
+        let words: Vec<&Word> = word_pool.iter().collect();
+        words.choose(&mut rand::thread_rng()).map(|&w| w.clone())
+    }
+}
+
+/// Uniform without repeats until the sampler's pool is exhausted.
+/// The sampler draws from the pool it was created from.
+impl SecretSelector for WordSampler {
+    fn select(&mut self, _word_pool: &WordPool) -> Option<Word> {
+        self.next()
+    }
+}
+
+/// Picks words proportionally to a weight, e.g. their frequency in a corpus,
+/// so common words come up more often than obscure ones
+#[derive(Debug, Clone)]
+pub struct FrequencyWeightedSelector {
+    weights: HashMap<Word, f64>,
+    /// Weight of words without an explicit weight
+    default_weight: f64,
+}
+
+impl FrequencyWeightedSelector {
+    /// Create from weights per word. Words of the pool without a weight get
+    /// `default_weight`; a weight of 0 means the word is never picked.
+    pub fn new(weights: HashMap<Word, f64>, default_weight: f64) -> Self {
+        Self {
+            weights,
+            default_weight,
+        }
+    }
+}
+
+impl SecretSelector for FrequencyWeightedSelector {
+    fn select(&mut self, word_pool: &WordPool) -> Option<Word> {
+        let words: Vec<&Word> = word_pool.iter().collect();
+        let weights = words.iter().map(|word| {
+            self.weights
+                .get(*word)
+                .copied()
+                .unwrap_or(self.default_weight)
+                .max(0.0)
+        });
+        let distribution = WeightedIndex::new(weights).ok()?;
+        Some(words[distribution.sample(&mut rand::thread_rng())].clone())
+    }
+}
+
+/// Brings back words the player failed to solve after a growing number of
+/// games, so they get practiced until they stick. Other games are uniform.
+#[derive(Debug, Clone, Default)]
+pub struct SpacedRepetitionSelector {
+    /// Number of games selected so far
+    games: usize,
+    /// Failed words with the game number they are due again and the
+    /// interval used to schedule them
+    due: HashMap<Word, (usize, usize)>,
+}
+
+impl SpacedRepetitionSelector {
+    /// Interval (in games) before a failed word comes up again
+    const FIRST_INTERVAL: usize = 3;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record how a game with `secret` went. Failed words are scheduled
+    /// again soon; solving a scheduled word doubles its interval, until it
+    /// was solved often enough to be dropped.
+    pub fn record(&mut self, secret: &Word, won: bool) {
+        const MAX_INTERVAL: usize = 48;
+
+        match (won, self.due.get(secret).copied()) {
+            (false, _) => {
+                self.due.insert(
+                    secret.clone(),
+                    (self.games + Self::FIRST_INTERVAL, Self::FIRST_INTERVAL),
+                );
+            }
+            (true, Some((_, interval))) if interval * 2 <= MAX_INTERVAL => {
+                self.due
+                    .insert(secret.clone(), (self.games + interval * 2, interval * 2));
+            }
+            (true, Some(_)) => {
+                self.due.remove(secret);
+            }
+            (true, None) => {}
+        }
+    }
+
+    /// Number of words scheduled for practice
+    pub fn scheduled(&self) -> usize {
+        self.due.len()
+    }
+}
+
+impl SecretSelector for SpacedRepetitionSelector {
+    fn select(&mut self, word_pool: &WordPool) -> Option<Word> {
+        self.games += 1;
+        let overdue = self
+            .due
+            .iter()
+            .filter(|(word, (due, _))| *due <= self.games && word_pool.contains(word))
+            .min_by_key(|(word, (due, _))| (*due, word.as_str()))
+            .map(|(word, _)| word.clone());
+        overdue.or_else(|| UniformSelector.select(word_pool))
+    }
+
+    fn record_result(&mut self, secret: &Word, won: bool) {
+        self.record(secret, won);
+    }
+}
+
+/// Picks the same word for everyone on the same (UTC) day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailySelector {
+    /// Days since the Unix epoch
+    day: u64,
+}
+
+impl DailySelector {
+    /// Select the word of the given day (days since the Unix epoch)
+    pub fn for_day(day: u64) -> Self {
+        Self { day }
+    }
+
+    /// Select today's word
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self::for_day(seconds / (24 * 60 * 60))
+    }
+
+    /// The day this selector picks the word for
+    pub fn day(&self) -> u64 {
+        self.day
+    }
+
+    /// Number of the daily word, e.g. for "Daily #123"
+    pub fn number(&self) -> u64 {
+        self.day.saturating_sub(FIRST_DAILY_DAY)
+    }
+}
+
+impl SecretSelector for DailySelector {
+    fn select(&mut self, word_pool: &WordPool) -> Option<Word> {
+        if word_pool.is_empty() {
+            return None;
+        }
+        let index = stable_index(&mut stable_rng(self.day), word_pool.len());
+        word_pool.iter().nth(index).cloned()
+    }
+}
+
+/// Picks words that are hard to pin down: those with the most other words
+/// differing in a single position (like "hatch", "latch", "match", ...), so
+/// feedback keeps leaving several candidates
+#[derive(Debug, Clone, Copy)]
+pub struct AdversarialSelector {
+    /// How many of the hardest words to choose from
+    candidates: usize,
+}
+
+impl AdversarialSelector {
+    /// Choose randomly among the `candidates` hardest words
+    pub fn new(candidates: usize) -> Self {
+        Self {
+            candidates: candidates.max(1),
+        }
+    }
+
+    /// Number of other words in the pool differing from `word` in exactly one position
+    fn neighbors(word: &Word, patterns: &HashMap<(usize, String), usize>) -> usize {
+        (0..WORD_LENGTH)
+            .map(|i| patterns[&(i, Self::pattern(word, i))] - 1)
+            .sum()
+    }
+
+    /// The word with the letter at `position` left out
+    fn pattern(word: &Word, position: usize) -> String {
+        word.letters()
+            .enumerate()
+            .filter(|&(i, _)| i != position)
+            .map(|(_, l)| l.char())
+            .collect()
+    }
+}
+
+impl Default for AdversarialSelector {
+    fn default() -> Self {
+        Self::new(50)
+    }
+}
+
+impl SecretSelector for AdversarialSelector {
+    fn select(&mut self, word_pool: &WordPool) -> Option<Word> {
+        let mut patterns: HashMap<(usize, String), usize> = HashMap::new();
+        for word in word_pool.iter() {
+            for i in 0..WORD_LENGTH {
+                *patterns.entry((i, Self::pattern(word, i))).or_insert(0) += 1;
+            }
+        }
+
+        let mut words: Vec<(usize, &Word)> = word_pool
+            .iter()
+            .map(|word| (Self::neighbors(word, &patterns), word))
+            .collect();
+        words.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.as_str().cmp(&b.1.as_str())));
+        words.truncate(self.candidates);
+        words
+            .choose(&mut rand::thread_rng())
+            .map(|(_, word)| (*word).clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(s: &str) -> Word {
+        Word::parse(s).unwrap()
+    }
+
+    fn test_pool() -> WordPool {
+        WordPool::from_strings(
+            ["hatch", "latch", "match", "patch", "crane", "audio"]
+                .iter()
+                .map(|s| s.to_string()),
+        )
+    }
+
+    #[test]
+    fn test_uniform() {
+        let pool = test_pool();
+        assert!(pool.contains(&UniformSelector.select(&pool).unwrap()));
+        assert!(
+            UniformSelector
+                .select(&WordPool::from_words(Vec::new()))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_frequency_weighted() {
+        let pool = test_pool();
+        let weights = HashMap::from([(word("crane"), 1.0)]);
+        let mut selector = FrequencyWeightedSelector::new(weights, 0.0);
+        for _ in 0..10 {
+            assert_eq!(selector.select(&pool), Some(word("crane")));
+        }
+
+        // All weights zero: nothing can be picked
+        let mut selector = FrequencyWeightedSelector::new(HashMap::new(), 0.0);
+        assert!(selector.select(&pool).is_none());
+    }
+
+    #[test]
+    fn test_spaced_repetition() {
+        let pool = test_pool();
+        let mut selector = SpacedRepetitionSelector::new();
+
+        selector.select(&pool);
+        selector.record(&word("audio"), false);
+        assert_eq!(selector.scheduled(), 1);
+
+        // Due again after FIRST_INTERVAL games
+        let picks: Vec<Word> = (0..SpacedRepetitionSelector::FIRST_INTERVAL)
+            .map(|_| selector.select(&pool).unwrap())
+            .collect();
+        assert_eq!(picks.last(), Some(&word("audio")));
+
+        // Solving it often enough drops it from the schedule
+        for _ in 0..10 {
+            selector.record(&word("audio"), true);
+        }
+        assert_eq!(selector.scheduled(), 0);
+    }
+
+    #[test]
+    fn test_record_result_through_trait_object() {
+        let mut selector: Box<dyn SecretSelector> = Box::new(SpacedRepetitionSelector::new());
+        selector.record_result(&word("audio"), false);
+        // Other selectors ignore results
+        UniformSelector.record_result(&word("audio"), false);
+
+        let pool = test_pool();
+        let picks: Vec<Word> = (0..SpacedRepetitionSelector::FIRST_INTERVAL)
+            .map(|_| selector.select(&pool).unwrap())
+            .collect();
+        assert_eq!(picks.last(), Some(&word("audio")));
+    }
+
+    #[test]
+    fn test_daily_is_deterministic() {
+        let pool = test_pool();
+        let a = DailySelector::for_day(20000).select(&pool);
+        let b = DailySelector::for_day(20000).select(&pool);
+        assert_eq!(a, b);
+        assert!(a.is_some());
+        assert_eq!(DailySelector::for_day(18_797 + 123).number(), 123);
+    }
+
+    #[test]
+    fn test_daily_word_is_pinned() {
+        // Changing these picks would change everyone's daily word
+        let pool = test_pool();
+        let picks: Vec<Word> = [20000, 20001, 20002]
+            .into_iter()
+            .map(|day| DailySelector::for_day(day).select(&pool).unwrap())
+            .collect();
+        assert_eq!(picks, [word("audio"), word("crane"), word("crane")]);
+    }
+
+    #[test]
+    fn test_adversarial_prefers_neighbors() {
+        let pool = test_pool();
+        let mut selector = AdversarialSelector::new(4);
+        for _ in 0..10 {
+            let picked = selector.select(&pool).unwrap();
+            assert!(picked.as_str().ends_with("atch"), "{picked}");
+        }
+    }
+
+    #[test]
+    fn test_sampler_selector() {
+        let pool = test_pool();
+        let mut sampler = pool.sampler();
+        let mut picked: Vec<Word> = (0..pool.len())
+            .map(|_| sampler.select(&pool).unwrap())
+            .collect();
+        picked.sort_by_key(|w| w.as_str());
+        picked.dedup();
+        assert_eq!(picked.len(), pool.len());
+    }
+}
@@ -0,0 +1,182 @@
+use crate::game::{Game, GameConfig, GameState, GuessResult};
+use crate::hint::Hint;
+use crate::letter::Word;
+use crate::playable::Playable;
+use crate::word_pool::{WordPool, WordSampler};
+
+/// Chained puzzles in the style of "Hurdle": once a puzzle is solved,
+/// [`next_game`](Self::next_game) starts the next one with the previous
+/// answer already submitted as its first guess. The sequence ends on the
+/// first puzzle that isn't solved.
+#[derive(Debug, Clone)]
+pub struct SequenceGame {
+    current: Game,
+    /// Answers of the solved puzzles, in order
+    solved: Vec<Word>,
+    config: GameConfig,
+    word_pool: WordPool,
+    /// Draws the secrets, so no word repeats within a sequence
+    sampler: WordSampler,
+}
+
+impl SequenceGame {
+    /// Start a new sequence with random secrets
+    pub fn new(word_pool: WordPool, config: GameConfig) -> Self {
+        let mut sampler = word_pool.sampler();
+        let secret = sampler.next().expect("WordPool should not be empty");
+        Self::with_sampler(word_pool, secret, sampler, config)
+    }
+
+    /// Start a new sequence with a specific secret for the first puzzle (for testing)
+    pub fn with_secret(word_pool: WordPool, secret: Word, config: GameConfig) -> Self {
+        let sampler = word_pool.sampler();
+        Self::with_sampler(word_pool, secret, sampler, config)
+    }
+
+    fn with_sampler(
+        word_pool: WordPool,
+        secret: Word,
+        sampler: WordSampler,
+        config: GameConfig,
+    ) -> Self {
+        let current = Game::with_secret_and_config(word_pool.clone(), secret, config.clone());
+        Self {
+            current,
+            solved: Vec::new(),
+            config,
+            word_pool,
+            sampler,
+        }
+    }
+
+    /// The puzzle currently being played
+    pub fn current(&self) -> &Game {
+        &self.current
+    }
+
+    /// Reveal a letter of the current puzzle, see [`Game::hint`]
+    pub fn hint(&mut self) -> Option<Hint> {
+        self.current.hint()
+    }
+
+    /// Answers of the puzzles solved so far, in order
+    pub fn solved(&self) -> &[Word] {
+        &self.solved
+    }
+
+    /// Number of puzzles solved in this sequence
+    pub fn words_solved(&self) -> usize {
+        self.solved.len()
+    }
+
+    /// Start the next puzzle once the current one is won, carrying its
+    /// answer over as the first guess. The carried guess is skipped if the
+    /// next puzzle's rules reject it, e.g. when its letters are missing from
+    /// the letter bank.
+    ///
+    /// Returns `false` and keeps the current puzzle if it isn't won.
+    pub fn next_game(&mut self) -> bool {
+        if !matches!(self.current.state(), GameState::Won { .. }) {
+            return false;
+        }
+        let Some(previous) = self.current.secret().cloned() else {
+            return false;
+        };
+
+        let secret = self.sampler.next().expect("WordPool should not be empty");
+        self.current =
+            Game::with_secret_and_config(self.word_pool.clone(), secret, self.config.clone());
+        let _ = self.current.guess_word(&previous);
+        self.solved.push(previous);
+        true
+    }
+}
+
+impl Playable for SequenceGame {
+    fn guess(&mut self, input: &str) -> GuessResult {
+        self.current.guess(input)
+    }
+
+    fn forfeit(&mut self) {
+        self.current.forfeit();
+    }
+
+    fn state(&self) -> GameState {
+        self.current.state()
+    }
+
+    fn guesses_remaining(&self) -> usize {
+        self.current.guesses_remaining()
+    }
+
+    fn max_guesses(&self) -> usize {
+        self.current.max_guesses()
+    }
+
+    fn guesses_made(&self) -> usize {
+        self.current.guesses().len()
+    }
+
+    fn secrets(&self) -> Option<Vec<Word>> {
+        self.current.secret().map(|secret| vec![secret.clone()])
+    }
+
+    fn pause(&mut self) {
+        self.current.pause();
+    }
+
+    fn resume(&mut self) {
+        self.current.resume();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::GuessError;
+
+    fn pool() -> WordPool {
+        WordPool::from_strings(vec!["hello".to_string(), "world".to_string()])
+    }
+
+    #[test]
+    fn test_answer_carries_over_as_first_guess() {
+        let mut game =
+            SequenceGame::with_secret(pool(), Word::parse("hello").unwrap(), GameConfig::default());
+        game.guess("hello").unwrap();
+        assert!(matches!(game.state(), GameState::Won { .. }));
+
+        assert!(game.next_game());
+        assert_eq!(game.solved(), &[Word::parse("hello").unwrap()]);
+        let guesses = game.current().guesses();
+        assert_eq!(guesses.len(), 1);
+        assert_eq!(guesses[0].word(), &Word::parse("hello").unwrap());
+        assert_eq!(game.guesses_remaining(), game.max_guesses() - 1);
+    }
+
+    #[test]
+    fn test_next_game_needs_a_win() {
+        let config = GameConfig {
+            max_guesses: 1,
+            ..GameConfig::default()
+        };
+        let mut game = SequenceGame::with_secret(pool(), Word::parse("hello").unwrap(), config);
+        assert!(!game.next_game());
+
+        game.guess("world").unwrap();
+        assert_eq!(game.state(), GameState::Lost);
+        assert!(!game.next_game());
+        assert_eq!(game.words_solved(), 0);
+        assert_eq!(game.guess("hello"), Err(GuessError::GameOver));
+    }
+
+    #[test]
+    fn test_pause_stops_the_current_clock() {
+        let mut game =
+            SequenceGame::with_secret(pool(), Word::parse("hello").unwrap(), GameConfig::default());
+        Playable::pause(&mut game);
+        assert!(game.current().is_paused());
+        Playable::resume(&mut game);
+        assert!(!game.current().is_paused());
+    }
+}
@@ -0,0 +1,412 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use rand::seq::SliceRandom;
+
+use crate::constants::WORD_LENGTH;
+use crate::error::GuessError;
+use crate::feedback::{GuessFeedback, LetterFeedback};
+use crate::game::{Game, GameState};
+use crate::letter::{Letter, Word};
+use crate::opener::{OPENING_BOOK_SIZE, opening_book};
+use crate::word_pool::WordPool;
+
+/// Number of different feedbacks a guess can get
+const PATTERNS: usize = 3usize.pow(WORD_LENGTH as u32);
+
+/// The words that can still be the secret, given the feedback so far
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidates {
+    words: Vec<Word>,
+}
+
+impl Candidates {
+    /// Every word of the pool, before any guess
+    pub fn new(word_pool: &WordPool) -> Self {
+        Self {
+            words: word_pool.iter().cloned().collect(),
+        }
+    }
+
+    /// The words of the pool consistent with all of `guesses`
+    pub fn from_guesses(word_pool: &WordPool, guesses: &[GuessFeedback]) -> Self {
+        let mut candidates = Self::new(word_pool);
+        for guess in guesses {
+            candidates.update(guess);
+        }
+        candidates
+    }
+
+    /// Drop the words that would have given a guess different feedback
+    pub fn update(&mut self, guess: &GuessFeedback) {
+        let expected = pattern_of(guess.feedback());
+        self.words
+            .retain(|secret| pattern(guess.word(), secret) == expected);
+    }
+
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn contains(&self, word: &Word) -> bool {
+        self.words.contains(word)
+    }
+
+    /// In how many of the words each letter occurs (repeats count once).
+    /// Letters in none of them are left out.
+    pub fn letter_counts(&self) -> HashMap<Letter, usize> {
+        let mut counts = HashMap::new();
+        for word in &self.words {
+            let mut letters: Vec<Letter> = word.letters().collect();
+            letters.sort_unstable_by_key(|letter| letter.char());
+            letters.dedup();
+            for letter in letters {
+                *counts.entry(letter).or_default() += 1;
+            }
+        }
+        counts
+    }
+}
+
+/// Way of picking guesses, e.g. to suggest one or to play games without a
+/// player
+pub trait Strategy: fmt::Debug + Send + Sync {
+    /// The next guess, given the words of the pool that are still possible.
+    /// None if there are no candidates left.
+    fn next_guess(&self, word_pool: &WordPool, candidates: &Candidates) -> Option<Word>;
+}
+
+/// Picks the guess whose feedback is expected to tell the most about the
+/// secret, i.e. splits the candidates into the most even groups. Any word
+/// of the pool may be guessed; candidates win ties, since they might be
+/// the secret.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EntropyStrategy;
+
+impl EntropyStrategy {
+    /// The `limit` best guesses with their expected information in bits,
+    /// best first
+    pub fn ranked(
+        &self,
+        word_pool: &WordPool,
+        candidates: &Candidates,
+        limit: usize,
+    ) -> Vec<(Word, f64)> {
+        self.ranked_among(word_pool.iter(), candidates, limit)
+    }
+
+    /// Like [`EntropyStrategy::ranked`], but only considering `guesses`,
+    /// e.g. to keep the ranking fast for large pools
+    pub fn ranked_among<'w>(
+        &self,
+        guesses: impl IntoIterator<Item = &'w Word>,
+        candidates: &Candidates,
+        limit: usize,
+    ) -> Vec<(Word, f64)> {
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+        let mut ranked: Vec<(Word, f64)> = guesses
+            .into_iter()
+            .map(|guess| (guess.clone(), expected_information(guess, candidates)))
+            .collect();
+        ranked.sort_by(|(a, a_bits), (b, b_bits)| {
+            b_bits
+                .total_cmp(a_bits)
+                .then_with(|| candidates.contains(b).cmp(&candidates.contains(a)))
+                .then_with(|| a.as_str().cmp(&b.as_str()))
+        });
+        ranked.truncate(limit);
+        ranked
+    }
+}
+
+impl Strategy for EntropyStrategy {
+    fn next_guess(&self, word_pool: &WordPool, candidates: &Candidates) -> Option<Word> {
+        // Nothing left to learn
+        if candidates.len() <= 2 {
+            return candidates.words().first().cloned();
+        }
+        self.ranked(word_pool, candidates, 1)
+            .into_iter()
+            .next()
+            .map(|(word, _)| word)
+    }
+}
+
+/// Guesses a random candidate, like a player who uses all feedback but
+/// doesn't plan ahead
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomStrategy;
+
+impl Strategy for RandomStrategy {
+    fn next_guess(&self, _word_pool: &WordPool, candidates: &Candidates) -> Option<Word> {
+        candidates.words().choose(&mut rand::thread_rng()).cloned()
+    }
+}
+
+/// Expected information in bits that guessing `guess` gives about a secret
+/// picked uniformly from the candidates
+pub fn expected_information(guess: &Word, candidates: &Candidates) -> f64 {
+    let mut counts = [0usize; PATTERNS];
+    for secret in candidates.words() {
+        counts[pattern(guess, secret)] += 1;
+    }
+    let total = candidates.len() as f64;
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Play a game to the end with guesses from `strategy`, which may pick any
+/// word of `word_pool`
+///
+/// # Errors
+///
+/// Returns the error if the game rejects a guess, e.g. in hard mode.
+pub fn play(
+    game: &mut Game,
+    word_pool: &WordPool,
+    strategy: &dyn Strategy,
+) -> Result<GameState, GuessError> {
+    let mut candidates = Candidates::from_guesses(word_pool, game.guesses());
+    while game.state() == GameState::Playing {
+        let Some(guess) = strategy.next_guess(word_pool, &candidates) else {
+            break;
+        };
+        let feedback = game.guess_word(&guess)?;
+        candidates.update(&feedback);
+    }
+    Ok(game.state())
+}
+
+/// A guess of a finished game next to the one the solver would have made
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepAnalysis {
+    pub guess: Word,
+    /// Words that could still be the secret before the guess
+    pub candidates_before: usize,
+    /// Expected information of the guess in bits
+    pub bits: f64,
+    /// The solver's guess with its expected information, None if there
+    /// were no candidates left
+    pub best: Option<(Word, f64)>,
+}
+
+/// The guesses worth ranking with at most `budget` feedbacks, since
+/// scoring every word of a large pool against every candidate takes too
+/// long: the opening book for the first guess, and only the candidates
+/// themselves while there are many
+pub fn guesses_within_budget(
+    word_pool: &WordPool,
+    candidates: &Candidates,
+    first_guess: bool,
+    budget: usize,
+) -> Vec<Word> {
+    if first_guess {
+        opening_book(word_pool, OPENING_BOOK_SIZE)
+    } else if candidates.len() * word_pool.len() <= budget {
+        word_pool.iter().cloned().collect()
+    } else {
+        candidates.words().to_vec()
+    }
+}
+
+/// Look back at the guesses of a game: how many words were left before
+/// each of them, and what the solver would have guessed instead, ranking
+/// the guesses of [`guesses_within_budget`]
+pub fn analyze(
+    word_pool: &WordPool,
+    guesses: &[GuessFeedback],
+    budget: usize,
+) -> Vec<StepAnalysis> {
+    let mut candidates = Candidates::new(word_pool);
+    let mut steps = Vec::with_capacity(guesses.len());
+    for (index, feedback) in guesses.iter().enumerate() {
+        let options = guesses_within_budget(word_pool, &candidates, index == 0, budget);
+        steps.push(StepAnalysis {
+            guess: feedback.word().clone(),
+            candidates_before: candidates.len(),
+            bits: expected_information(feedback.word(), &candidates),
+            best: EntropyStrategy
+                .ranked_among(&options, &candidates, 1)
+                .into_iter()
+                .next(),
+        });
+        candidates.update(feedback);
+    }
+    steps
+}
+
+/// The feedback `guess` gets for `secret`, as a number below `PATTERNS`
+fn pattern(guess: &Word, secret: &Word) -> usize {
+    pattern_of(GuessFeedback::evaluate(guess, secret).feedback())
+}
+
+fn pattern_of(feedback: &[LetterFeedback; WORD_LENGTH]) -> usize {
+    feedback.iter().fold(0, |code, feedback| {
+        code * 3
+            + match feedback {
+                LetterFeedback::NotInWord => 0,
+                LetterFeedback::WrongPosition => 1,
+                LetterFeedback::Correct => 2,
+            }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_pool::load_german_wordlist;
+
+    fn word(s: &str) -> Word {
+        Word::parse(s).unwrap()
+    }
+
+    fn pool(words: &[&str]) -> WordPool {
+        WordPool::from_strings(words.iter().map(|w| w.to_string()))
+    }
+
+    #[test]
+    fn test_candidates_follow_feedback() {
+        let pool = pool(&["hatch", "latch", "match", "crane", "hello"]);
+        let feedback = GuessFeedback::evaluate(&word("patch"), &word("latch"));
+        let candidates = Candidates::from_guesses(&pool, &[feedback]);
+        assert_eq!(
+            candidates.words(),
+            &[word("hatch"), word("latch"), word("match")]
+        );
+    }
+
+    #[test]
+    fn test_letter_counts() {
+        let candidates = Candidates::new(&pool(&["hatch", "latch", "hello"]));
+        let counts = candidates.letter_counts();
+        assert_eq!(counts[&Letter::new('h').unwrap()], 3);
+        assert_eq!(counts[&Letter::new('l').unwrap()], 2);
+        assert_eq!(counts.get(&Letter::new('z').unwrap()), None);
+    }
+
+    #[test]
+    fn test_expected_information() {
+        let pool = pool(&["hatch", "latch", "match", "patch"]);
+        let candidates = Candidates::new(&pool);
+        // Only tells "hatch" apart from the others
+        let bits = expected_information(&word("hatch"), &candidates);
+        assert!((bits - 0.811).abs() < 0.001, "{bits}");
+        // Tells all four apart
+        assert_eq!(expected_information(&word("mlpyz"), &candidates), 2.0);
+        assert_eq!(expected_information(&word("crane"), &candidates), 0.0);
+    }
+
+    #[test]
+    fn test_entropy_prefers_splitting_guesses() {
+        // Guessing a candidate only tells it apart from the others,
+        // "mlpyz" tells all of them apart
+        let pool = pool(&["hatch", "latch", "match", "patch", "mlpyz"]);
+        let candidates = Candidates::from_guesses(
+            &pool,
+            &[GuessFeedback::evaluate(&word("patch"), &word("hatch"))],
+        );
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(
+            EntropyStrategy.next_guess(&pool, &candidates),
+            Some(word("mlpyz"))
+        );
+    }
+
+    #[test]
+    fn test_ranked_among_only_scores_given_guesses() {
+        let pool = pool(&["hatch", "latch", "match", "patch", "mlpyz"]);
+        let candidates = Candidates::new(&pool);
+        let ranked = EntropyStrategy.ranked_among([&word("hatch"), &word("mlpyz")], &candidates, 5);
+        let words: Vec<&Word> = ranked.iter().map(|(word, _)| word).collect();
+        assert_eq!(words, [&word("mlpyz"), &word("hatch")]);
+    }
+
+    #[test]
+    fn test_play_wins() {
+        let pool = pool(&["hatch", "latch", "match", "patch", "crane", "hello"]);
+        for secret in pool.iter() {
+            let mut game = Game::with_secret(pool.clone(), secret.clone());
+            let state = play(&mut game, &pool, &EntropyStrategy).unwrap();
+            assert!(matches!(state, GameState::Won { .. }), "{secret:?}");
+        }
+    }
+
+    #[test]
+    fn test_analyze() {
+        let pool = pool(&["hatch", "latch", "match", "patch", "mlpyz"]);
+        let guesses = [
+            GuessFeedback::evaluate(&word("hatch"), &word("patch")),
+            GuessFeedback::evaluate(&word("latch"), &word("patch")),
+        ];
+        let steps = analyze(&pool, &guesses, usize::MAX);
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].guess, word("hatch"));
+        assert_eq!(steps[0].candidates_before, 5);
+        assert_eq!(steps[1].candidates_before, 3);
+        // "mlpyz" would have told the last three apart
+        let (best, bits) = steps[1].best.clone().unwrap();
+        assert_eq!(best, word("mlpyz"));
+        assert!(bits > steps[1].bits);
+
+        // Over budget, only candidates are considered
+        let steps = analyze(&pool, &guesses, 0);
+        assert_ne!(steps[1].best.as_ref().unwrap().0, word("mlpyz"));
+    }
+
+    #[test]
+    fn test_random_strategy_guesses_candidates() {
+        let pool = pool(&["hatch", "latch", "match", "crane"]);
+        let mut candidates = Candidates::new(&pool);
+        candidates.update(&GuessFeedback::evaluate(&word("crane"), &word("hatch")));
+        for _ in 0..10 {
+            let guess = RandomStrategy.next_guess(&pool, &candidates).unwrap();
+            assert!(candidates.contains(&guess));
+        }
+        // No word of the pool gives "crane" all gray
+        let mut none = Candidates::new(&pool);
+        none.update(&GuessFeedback::evaluate(&word("crane"), &word("zzzzz")));
+        assert_eq!(RandomStrategy.next_guess(&pool, &none), None);
+    }
+
+    /// Regression test for the quality of the solver: plays against a
+    /// sample of the German word list and checks the average number of
+    /// guesses, so changes that make the solver worse fail here
+    #[test]
+    fn test_entropy_strategy_quality() {
+        let full = load_german_wordlist().unwrap();
+        let step = full.len() / 300;
+        let pool = WordPool::from_words(full.iter().step_by(step).cloned());
+        let opener = EntropyStrategy
+            .next_guess(&pool, &Candidates::new(&pool))
+            .unwrap();
+
+        let mut total_guesses = 0;
+        let secrets: Vec<&Word> = pool.iter().step_by(3).collect();
+        for &secret in &secrets {
+            let mut game = Game::with_secret(pool.clone(), secret.clone());
+            game.guess_word(&opener).unwrap();
+            let state = play(&mut game, &pool, &EntropyStrategy).unwrap();
+            assert!(matches!(state, GameState::Won { .. }), "lost on {secret:?}");
+            total_guesses += game.guesses().len();
+        }
+        let average = total_guesses as f64 / secrets.len() as f64;
+        // 2.80 when this test was written
+        assert!(average <= 3.0, "average of {average:.3} guesses");
+    }
+}
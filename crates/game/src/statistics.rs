@@ -0,0 +1,428 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::curiosity::CuriosityStats;
+use crate::game::{Game, GameState};
+use crate::history::GameRecord;
+use crate::letter_usage::LetterUsageHistogram;
+use crate::store::update_json;
+use crate::summary::GameSummary;
+
+/// Counts and streaks of finished games
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+struct Tally {
+    games_played: usize,
+    games_won: usize,
+    games_forfeited: usize,
+    current_streak: usize,
+    max_streak: usize,
+}
+
+impl Tally {
+    /// Count a game after the ones counted so far. Games still in progress
+    /// are ignored.
+    fn record(&mut self, state: &GameState) {
+        match state {
+            GameState::Playing => {}
+            GameState::Won { .. } => {
+                self.games_played += 1;
+                self.games_won += 1;
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+            }
+            GameState::Lost => {
+                self.games_played += 1;
+                self.current_streak = 0;
+            }
+            GameState::Forfeited => {
+                self.games_played += 1;
+                self.games_forfeited += 1;
+                self.current_streak = 0;
+            }
+        }
+    }
+}
+
+/// A game the statistics count, by the id of its [`GameRecord`], so a game
+/// is counted once however many instances saved it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CountedGame {
+    id: String,
+    /// Seconds since the Unix epoch
+    finished_at: u64,
+    state: GameState,
+}
+
+/// Aggregated results of played games
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Statistics {
+    /// Counts and streaks of all games, `earlier` followed by `games`
+    #[serde(flatten)]
+    tally: Tally,
+    /// Counts and streaks of the games recorded before the statistics kept
+    /// their ids
+    earlier: Tally,
+    /// Games counted since, oldest first
+    games: Vec<CountedGame>,
+    longest_survival_run: usize,
+    curiosity: CuriosityStats,
+    letter_usage: LetterUsageHistogram,
+}
+
+impl Statistics {
+    /// Create empty statistics
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a finished game by its record. Games still in progress and
+    /// games counted already are ignored.
+    pub fn record_game(&mut self, record: &GameRecord) {
+        if record.state == GameState::Playing || self.counts(&record.id) {
+            return;
+        }
+        self.games.push(CountedGame {
+            id: record.id.clone(),
+            finished_at: record.finished_at,
+            state: record.state.clone(),
+        });
+        self.recount();
+    }
+
+    /// Record a finished game with its `record`, including the guesses for
+    /// the curiosity stats. Games still in progress and games counted
+    /// already are ignored.
+    pub fn record_finished_game(&mut self, record: &GameRecord, game: &Game) {
+        self.record_summary(record, &game.summary());
+    }
+
+    /// Record a finished game by its summary, e.g. one board of a Quordle,
+    /// see [`Statistics::record_finished_game`]
+    pub fn record_summary(&mut self, record: &GameRecord, summary: &GameSummary) {
+        if summary.outcome == GameState::Playing || self.counts(&record.id) {
+            return;
+        }
+        self.record_game(record);
+        self.curiosity.record(summary);
+        self.letter_usage.record_game(summary);
+    }
+
+    /// Record a finished survival run by the number of words solved
+    pub fn record_survival_run(&mut self, words_solved: usize) {
+        self.longest_survival_run = self.longest_survival_run.max(words_solved);
+    }
+
+    /// Number of finished games
+    pub fn games_played(&self) -> usize {
+        self.tally.games_played
+    }
+
+    /// Number of won games
+    pub fn games_won(&self) -> usize {
+        self.tally.games_won
+    }
+
+    /// Number of games the player gave up on (counted as played, not won)
+    pub fn games_forfeited(&self) -> usize {
+        self.tally.games_forfeited
+    }
+
+    /// Percentage of finished games that were won (0 if none were played)
+    pub fn win_percentage(&self) -> f64 {
+        if self.tally.games_played == 0 {
+            return 0.0;
+        }
+        self.tally.games_won as f64 * 100.0 / self.tally.games_played as f64
+    }
+
+    /// Number of games won in a row, up to the most recent one
+    pub fn current_streak(&self) -> usize {
+        self.tally.current_streak
+    }
+
+    /// Longest streak of games won in a row
+    pub fn max_streak(&self) -> usize {
+        self.tally.max_streak
+    }
+
+    /// Most words solved in a single survival run
+    pub fn longest_survival_run(&self) -> usize {
+        self.longest_survival_run
+    }
+
+    /// Fun facts about the guesses of the games recorded with
+    /// [`record_finished_game`](Self::record_finished_game)
+    pub fn curiosity(&self) -> &CuriosityStats {
+        &self.curiosity
+    }
+
+    /// How often each letter was guessed across the games recorded with
+    /// [`record_finished_game`](Self::record_finished_game)
+    pub fn letter_usage(&self) -> &LetterUsageHistogram {
+        &self.letter_usage
+    }
+
+    /// Take the game counts and streaks of `other` if it counted more
+    /// games, e.g. statistics of a history that includes games played on
+    /// other devices. The details about guesses stay as they are.
+    pub fn catch_up(&mut self, other: &Statistics) {
+        if other.tally.games_played > self.tally.games_played {
+            self.tally = other.tally;
+            self.earlier = other.earlier;
+            self.games = other.games.clone();
+        }
+    }
+
+    /// Add the games of `other` that these statistics don't count yet.
+    /// Counts and streaks are worked out again over all games in the order
+    /// they finished. The details about guesses of `other` are added as
+    /// they are, so `other` should hold only games not counted here, e.g.
+    /// the ones finished since the statistics were last saved.
+    pub fn merge(&mut self, other: &Statistics) {
+        for game in &other.games {
+            if !self.counts(&game.id) {
+                self.games.push(game.clone());
+            }
+        }
+        self.games
+            .sort_by(|a, b| (a.finished_at, &a.id).cmp(&(b.finished_at, &b.id)));
+        if other.earlier.games_played > self.earlier.games_played {
+            self.earlier = other.earlier;
+        }
+        self.recount();
+        self.record_survival_run(other.longest_survival_run);
+        self.curiosity.merge(&other.curiosity);
+        self.letter_usage.merge(&other.letter_usage);
+    }
+
+    /// Add these statistics, of the games finished since the ones saved at
+    /// `path` were loaded, to the ones saved there, keeping the games that
+    /// other instances saved meanwhile (see [`merge`](Self::merge)).
+    /// Returns the merged statistics.
+    pub fn save_merged(&self, path: impl AsRef<Path>) -> io::Result<Statistics> {
+        update_json(path, |saved: Option<Statistics>| {
+            let mut statistics = saved.map(Statistics::upgraded).unwrap_or_default();
+            statistics.merge(self);
+            statistics
+        })
+    }
+
+    /// Read statistics from a JSON file, empty if there is none yet
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map(Statistics::upgraded)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Whether the game with this record id is counted
+    fn counts(&self, id: &str) -> bool {
+        self.games.iter().any(|game| game.id == id)
+    }
+
+    /// Work out the counts and streaks of all games again
+    fn recount(&mut self) {
+        self.tally = self.earlier;
+        for game in &self.games {
+            self.tally.record(&game.state);
+        }
+    }
+
+    /// Statistics saved before they kept the ids of games have counts but
+    /// no games, which become the earlier games
+    fn upgraded(mut self) -> Self {
+        if self.games.is_empty() && self.earlier == Tally::default() {
+            self.earlier = self.tally;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::letter::{Letter, Word};
+    use crate::word_pool::WordPool;
+
+    fn record(id: &str, finished_at: u64, state: GameState) -> GameRecord {
+        GameRecord {
+            id: id.to_string(),
+            finished_at,
+            secret: "hello".to_string(),
+            guesses: Vec::new(),
+            state,
+            seconds: None,
+        }
+    }
+
+    /// A game of "world" then "hello", and its record
+    fn won_game(id: &str, finished_at: u64) -> (GameRecord, Game) {
+        let pool = WordPool::from_strings(["hello", "world"].map(String::from));
+        let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+        game.guess("world").unwrap();
+        game.guess("hello").unwrap();
+        (record(id, finished_at, game.state()), game)
+    }
+
+    #[test]
+    fn test_streaks() {
+        let mut stats = Statistics::new();
+        stats.record_game(&record("a", 1, GameState::Won { guesses_used: 3 }));
+        stats.record_game(&record("b", 2, GameState::Won { guesses_used: 4 }));
+        stats.record_game(&record("c", 3, GameState::Lost));
+        stats.record_game(&record("d", 4, GameState::Won { guesses_used: 2 }));
+        stats.record_game(&record("e", 5, GameState::Playing));
+        // Counted already
+        stats.record_game(&record("d", 4, GameState::Won { guesses_used: 2 }));
+
+        assert_eq!(stats.games_played(), 4);
+        assert_eq!(stats.games_won(), 3);
+        assert_eq!(stats.current_streak(), 1);
+        assert_eq!(stats.max_streak(), 2);
+        assert_eq!(stats.win_percentage(), 75.0);
+    }
+
+    #[test]
+    fn test_forfeit_breaks_streak() {
+        let mut stats = Statistics::new();
+        stats.record_game(&record("a", 1, GameState::Won { guesses_used: 3 }));
+        stats.record_game(&record("b", 2, GameState::Forfeited));
+
+        assert_eq!(stats.games_played(), 2);
+        assert_eq!(stats.games_forfeited(), 1);
+        assert_eq!(stats.current_streak(), 0);
+        assert_eq!(stats.win_percentage(), 50.0);
+    }
+
+    #[test]
+    fn test_longest_survival_run() {
+        let mut stats = Statistics::new();
+        assert_eq!(stats.longest_survival_run(), 0);
+
+        stats.record_survival_run(4);
+        stats.record_survival_run(2);
+        assert_eq!(stats.longest_survival_run(), 4);
+    }
+
+    #[test]
+    fn test_letter_usage_across_games() {
+        let mut stats = Statistics::new();
+        for id in ["a", "b"] {
+            let (record, game) = won_game(id, 1);
+            stats.record_finished_game(&record, &game);
+        }
+        // Games in progress don't count
+        let pool = WordPool::from_strings(["hello", "world"].map(String::from));
+        let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+        game.guess("world").unwrap();
+        stats.record_finished_game(&record("c", 2, game.state()), &game);
+
+        let usage = stats.letter_usage().get(Letter::new('o').unwrap());
+        assert_eq!(usage.correct, 2);
+        assert_eq!(usage.wrong_position, 2);
+        assert_eq!(usage.total(), 4);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("wordle_statistics_{}", std::process::id()));
+        let path = dir.join("stats.json");
+        assert_eq!(Statistics::load(&path).unwrap(), Statistics::new());
+
+        let (record, game) = won_game("a", 1);
+        let mut stats = Statistics::new();
+        stats.record_finished_game(&record, &game);
+        stats.record_survival_run(3);
+        assert_eq!(stats.save_merged(&path).unwrap(), stats);
+        assert_eq!(Statistics::load(&path).unwrap(), stats);
+
+        fs::write(&path, "{ \"games_played\": ").unwrap();
+        assert_eq!(
+            Statistics::load(&path).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_writers_keep_each_others_games() {
+        let dir = std::env::temp_dir().join(format!("wordle_stats_merge_{}", std::process::id()));
+        let path = dir.join("stats.json");
+        fs::remove_dir_all(&dir).ok();
+        let mut start = Statistics::new();
+        start.record_game(&record("a", 1, GameState::Lost));
+        start.save_merged(&path).unwrap();
+
+        // Two instances start from the same statistics, and each saves the
+        // games finished since
+        let loaded = Statistics::load(&path).unwrap();
+        assert_eq!(loaded, start);
+        let (first_record, first_game) = won_game("b", 3);
+        let mut first = Statistics::new();
+        first.record_finished_game(&first_record, &first_game);
+        first.record_survival_run(2);
+        let (second_record, second_game) = won_game("c", 2);
+        let mut second = Statistics::new();
+        second.record_finished_game(&second_record, &second_game);
+        first.save_merged(&path).unwrap();
+        let merged = second.save_merged(&path).unwrap();
+
+        assert_eq!(Statistics::load(&path).unwrap(), merged);
+        assert_eq!(merged.games_played(), 3);
+        assert_eq!(merged.games_won(), 2);
+        // In the order the games finished: lost, then won twice
+        assert_eq!(merged.current_streak(), 2);
+        assert_eq!(merged.longest_survival_run(), 2);
+        let usage = merged.letter_usage().get(Letter::new('o').unwrap());
+        assert_eq!(usage.correct, 2);
+        assert_eq!(merged.curiosity().favorite_opener().unwrap().1, 2);
+
+        // Saving a game again doesn't count it twice
+        let merged = first.save_merged(&path).unwrap();
+        assert_eq!(merged.games_played(), 3);
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_statistics_without_ids() {
+        let json = r#"{"games_played": 3, "games_won": 2, "current_streak": 2, "max_streak": 2}"#;
+        let mut stats = serde_json::from_str::<Statistics>(json)
+            .map(Statistics::upgraded)
+            .unwrap();
+        let mut session = Statistics::new();
+        session.record_game(&record("a", 1, GameState::Won { guesses_used: 3 }));
+        stats.merge(&session);
+        assert_eq!(stats.games_played(), 4);
+        assert_eq!(stats.games_won(), 3);
+        assert_eq!(stats.current_streak(), 3);
+        assert_eq!(stats.max_streak(), 3);
+    }
+
+    #[test]
+    fn test_catch_up_with_more_games() {
+        let mut stats = Statistics::new();
+        stats.record_game(&record("a", 1, GameState::Won { guesses_used: 3 }));
+        stats.record_survival_run(5);
+        let mut history = Statistics::new();
+        history.record_game(&record("a", 1, GameState::Won { guesses_used: 3 }));
+        history.record_game(&record("b", 2, GameState::Won { guesses_used: 4 }));
+
+        stats.catch_up(&history);
+        assert_eq!(stats.games_played(), 2);
+        assert_eq!(stats.current_streak(), 2);
+        assert_eq!(stats.longest_survival_run(), 5);
+
+        // Fewer games change nothing
+        let before = stats.clone();
+        stats.catch_up(&Statistics::new());
+        assert_eq!(stats, before);
+    }
+}
@@ -0,0 +1,147 @@
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Update a JSON file that other instances of the game may write at the
+/// same time, without losing their changes.
+///
+/// Holds an advisory lock on `<path>.lock` while reading what is saved
+/// (None if the file doesn't exist yet), passing it to `merge`, and writing
+/// back the result. The file is replaced atomically, so readers that don't
+/// lock never see a half-written file. Returns what was written.
+///
+/// # Errors
+///
+/// Returns an error if the lock can't be taken, or the file can't be read,
+/// parsed or written.
+pub(crate) fn update_json<T, F>(path: impl AsRef<Path>, merge: F) -> io::Result<T>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce(Option<T>) -> T,
+{
+    let path = path.as_ref();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let lock = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(sibling(path, "lock"))?;
+    // Released when `lock` is dropped
+    lock.lock()?;
+
+    let saved = match fs::read_to_string(path) {
+        Ok(json) => Some(
+            serde_json::from_str(&json)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        ),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err),
+    };
+    let merged = merge(saved);
+    write_json(path, &merged)?;
+    Ok(merged)
+}
+
+/// Replace a JSON file atomically, so readers never see a half-written
+/// file. Unlike [`update_json`], changes other instances make at the same
+/// time are lost.
+pub(crate) fn write_json<T: Serialize>(path: impl AsRef<Path>, value: &T) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let temp = sibling(path, "tmp");
+    fs::write(&temp, json)?;
+    fs::rename(&temp, path)
+}
+
+/// `path` with another extension appended
+fn sibling(path: &Path, extension: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(".");
+    name.push(extension);
+    name.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("wordle_store_{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn test_update_json() {
+        let path = temp_path("update.json");
+        fs::remove_file(&path).ok();
+
+        let first: Vec<u32> = update_json(&path, |saved| {
+            assert_eq!(saved, None);
+            vec![1]
+        })
+        .unwrap();
+        assert_eq!(first, vec![1]);
+
+        let second = update_json(&path, |saved: Option<Vec<u32>>| {
+            let mut numbers = saved.unwrap();
+            numbers.push(2);
+            numbers
+        })
+        .unwrap();
+        assert_eq!(second, vec![1, 2]);
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_concurrent_writers_keep_all_changes() {
+        let path = temp_path("concurrent.json");
+        fs::remove_file(&path).ok();
+
+        let writers: Vec<_> = (0..8)
+            .map(|writer| {
+                let path = path.clone();
+                thread::spawn(move || {
+                    for i in 0..10 {
+                        update_json(&path, |saved: Option<Vec<u32>>| {
+                            let mut numbers = saved.unwrap_or_default();
+                            numbers.push(writer * 100 + i);
+                            numbers
+                        })
+                        .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let saved: Vec<u32> = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.len(), 80);
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_corrupt_file_is_an_error() {
+        let path = temp_path("corrupt.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{ not json").unwrap();
+        let result = update_json(&path, |_: Option<Vec<u32>>| Vec::new());
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        // Nothing was overwritten
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{ not json");
+        fs::remove_file(&path).ok();
+    }
+}
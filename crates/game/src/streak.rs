@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+use crate::game::{Game, GameConfig, GameState, GuessResult};
+use crate::letter::Word;
+use crate::playable::Playable;
+use crate::word_pool::{WordPool, WordSampler};
+
+/// Endless streak mode: regular games are played one after another until
+/// the first one that is lost or forfeited. Score and playing time add up
+/// over the whole streak.
+#[derive(Debug, Clone)]
+pub struct StreakSession {
+    current: Game,
+    /// Games won so far, including the current one once it is won
+    streak: usize,
+    total_score: u32,
+    /// Time spent on the games won so far
+    time_won: Duration,
+    config: GameConfig,
+    word_pool: WordPool,
+    /// Draws the secrets, so no word repeats within a streak
+    sampler: WordSampler,
+}
+
+impl StreakSession {
+    /// Start a new streak with random secrets
+    pub fn new(word_pool: WordPool, config: GameConfig) -> Self {
+        let mut sampler = word_pool.sampler();
+        let secret = sampler.next().expect("WordPool should not be empty");
+        Self::with_sampler(word_pool, secret, sampler, config)
+    }
+
+    /// Start a new streak with a specific secret for the first game (for testing)
+    pub fn with_secret(word_pool: WordPool, secret: Word, config: GameConfig) -> Self {
+        let sampler = word_pool.sampler();
+        Self::with_sampler(word_pool, secret, sampler, config)
+    }
+
+    fn with_sampler(
+        word_pool: WordPool,
+        secret: Word,
+        sampler: WordSampler,
+        config: GameConfig,
+    ) -> Self {
+        let current = Game::with_secret_and_config(word_pool.clone(), secret, config.clone());
+        Self {
+            current,
+            streak: 0,
+            total_score: 0,
+            time_won: Duration::ZERO,
+            config,
+            word_pool,
+            sampler,
+        }
+    }
+
+    /// The game currently being played
+    pub fn current(&self) -> &Game {
+        &self.current
+    }
+
+    /// Number of games won in a row
+    pub fn streak(&self) -> usize {
+        self.streak
+    }
+
+    /// Sum of the scores of the games won so far
+    pub fn total_score(&self) -> u32 {
+        self.total_score
+    }
+
+    /// Time spent playing the streak, including the current game
+    pub fn total_time(&self) -> Duration {
+        match self.current.state() {
+            // Already counted when it was won
+            GameState::Won { .. } => self.time_won,
+            _ => self.time_won + self.current.elapsed(),
+        }
+    }
+
+    /// Whether the streak ended with a lost or forfeited game
+    pub fn is_over(&self) -> bool {
+        matches!(self.current.state(), GameState::Lost | GameState::Forfeited)
+    }
+
+    /// Start the next game once the current one is won.
+    ///
+    /// Returns `false` and keeps the current game if it isn't won.
+    pub fn next_game(&mut self) -> bool {
+        if !matches!(self.current.state(), GameState::Won { .. }) {
+            return false;
+        }
+        let secret = self.sampler.next().expect("WordPool should not be empty");
+        self.current =
+            Game::with_secret_and_config(self.word_pool.clone(), secret, self.config.clone());
+        true
+    }
+}
+
+impl Playable for StreakSession {
+    fn guess(&mut self, input: &str) -> GuessResult {
+        let feedback = self.current.guess(input)?;
+        if let GameState::Won { .. } = self.current.state() {
+            self.streak += 1;
+            self.total_score += self.current.score().map_or(0, |score| score.total());
+            self.time_won += self.current.elapsed();
+        }
+        Ok(feedback)
+    }
+
+    fn forfeit(&mut self) {
+        self.current.forfeit();
+    }
+
+    fn state(&self) -> GameState {
+        self.current.state()
+    }
+
+    fn guesses_remaining(&self) -> usize {
+        self.current.guesses_remaining()
+    }
+
+    fn max_guesses(&self) -> usize {
+        self.current.max_guesses()
+    }
+
+    fn guesses_made(&self) -> usize {
+        self.current.guesses().len()
+    }
+
+    fn secrets(&self) -> Option<Vec<Word>> {
+        self.current.secret().map(|secret| vec![secret.clone()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::GuessError;
+
+    fn pool() -> WordPool {
+        WordPool::from_strings(vec!["hello".to_string(), "world".to_string()])
+    }
+
+    #[test]
+    fn test_wins_add_up() {
+        let mut session = StreakSession::with_secret(
+            pool(),
+            Word::parse("hello").unwrap(),
+            GameConfig::default(),
+        );
+        assert_eq!(session.streak(), 0);
+
+        session.guess("hello").unwrap();
+        assert_eq!(session.streak(), 1);
+        let score = session.current().score().unwrap().total();
+        assert!(score > 0);
+        assert_eq!(session.total_score(), score);
+        let time = session.total_time();
+        // Time stands still between a win and the next game
+        assert_eq!(session.total_time(), time);
+
+        assert!(session.next_game());
+        assert_eq!(session.state(), GameState::Playing);
+        assert!(session.total_time() >= time);
+        assert_eq!(session.streak(), 1);
+    }
+
+    #[test]
+    fn test_loss_ends_streak() {
+        let config = GameConfig {
+            max_guesses: 1,
+            ..GameConfig::default()
+        };
+        let mut session = StreakSession::with_secret(pool(), Word::parse("hello").unwrap(), config);
+        assert!(!session.next_game());
+
+        session.guess("world").unwrap();
+        assert!(session.is_over());
+        assert!(!session.next_game());
+        assert_eq!(session.streak(), 0);
+        assert_eq!(session.total_score(), 0);
+        assert_eq!(session.guess("hello"), Err(GuessError::GameOver));
+    }
+
+    #[test]
+    fn test_forfeit_ends_streak() {
+        let mut session = StreakSession::with_secret(
+            pool(),
+            Word::parse("hello").unwrap(),
+            GameConfig::default(),
+        );
+        session.forfeit();
+        assert!(session.is_over());
+        assert_eq!(session.secrets(), Some(vec![Word::parse("hello").unwrap()]));
+    }
+}
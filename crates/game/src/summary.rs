@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::WORD_LENGTH;
+use crate::feedback::{GuessFeedback, LetterFeedback};
+use crate::game::{Game, GameState};
+use crate::letter::{Letter, Word};
+use crate::word_pool::WordPool;
+
+/// Everything about a game that is shown or kept once it is over, so
+/// frontends, statistics and records describe games the same way. See
+/// [`Game::summary`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameSummary {
+    pub outcome: GameState,
+    pub secret: Word,
+    pub guesses: Vec<GuessSummary>,
+    /// Playing time, excluding pauses
+    pub duration: Duration,
+    pub mode: ModeSummary,
+    /// Fingerprint of the word list recipe, or else the day the list was
+    /// built. None for word pools that weren't loaded from a word list.
+    pub wordlist_version: Option<String>,
+}
+
+/// A guess and the colors it got
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuessSummary {
+    pub word: Word,
+    pub pattern: [LetterFeedback; WORD_LENGTH],
+}
+
+/// The rules a game was played with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModeSummary {
+    pub max_guesses: usize,
+    pub hard_mode: bool,
+    /// Guesses were formed from a letter bank
+    pub letter_bank: bool,
+}
+
+impl GameSummary {
+    pub(crate) fn new(game: &Game) -> Self {
+        let config = game.config();
+        Self {
+            outcome: game.state(),
+            secret: game.secret_word().clone(),
+            guesses: game.guesses().iter().map(GuessSummary::from).collect(),
+            duration: game.elapsed(),
+            mode: ModeSummary {
+                max_guesses: config.max_guesses,
+                hard_mode: config.hard_mode,
+                letter_bank: config.letter_bank_size.is_some(),
+            },
+            wordlist_version: wordlist_version(game.word_pool()),
+        }
+    }
+
+    /// One board of a game played on several boards, e.g. Quordle. The
+    /// board is won if one of its `guesses` solved it, otherwise it ended
+    /// the way the whole game did.
+    pub(crate) fn of_board<'a>(
+        state: &GameState,
+        secret: &Word,
+        guesses: impl IntoIterator<Item = &'a GuessFeedback>,
+        duration: Duration,
+        max_guesses: usize,
+        word_pool: &WordPool,
+    ) -> Self {
+        let guesses: Vec<GuessSummary> = guesses.into_iter().map(GuessSummary::from).collect();
+        let solved = guesses
+            .last()
+            .is_some_and(|guess| guess.pattern == [LetterFeedback::Correct; WORD_LENGTH]);
+        let outcome = match state {
+            _ if solved => GameState::Won {
+                guesses_used: guesses.len(),
+            },
+            GameState::Forfeited => GameState::Forfeited,
+            _ => GameState::Lost,
+        };
+        Self {
+            outcome,
+            secret: secret.clone(),
+            guesses,
+            duration,
+            mode: ModeSummary {
+                max_guesses,
+                hard_mode: false,
+                letter_bank: false,
+            },
+            wordlist_version: wordlist_version(word_pool),
+        }
+    }
+
+    /// Whether the game was won
+    pub fn is_won(&self) -> bool {
+        matches!(self.outcome, GameState::Won { .. })
+    }
+
+    /// Spoiler-free text to share the result: the number of guesses (`X`
+    /// if not solved) and a row of colored squares per guess
+    pub fn share_text(&self) -> String {
+        let result = match self.outcome {
+            GameState::Won { guesses_used } => guesses_used.to_string(),
+            _ => "X".to_string(),
+        };
+        let mut text = format!("Wordle {}/{}", result, self.mode.max_guesses);
+        for guess in &self.guesses {
+            text.push('\n');
+            text.extend(guess.pattern.iter().map(LetterFeedback::emoji));
+        }
+        text
+    }
+}
+
+impl From<&GuessFeedback> for GuessSummary {
+    fn from(guess: &GuessFeedback) -> Self {
+        Self {
+            word: guess.word().clone(),
+            pattern: *guess.feedback(),
+        }
+    }
+}
+
+/// Fingerprint of the word list recipe, or else the day the list was built
+fn wordlist_version(word_pool: &WordPool) -> Option<String> {
+    word_pool
+        .metadata()
+        .and_then(|metadata| metadata.recipe.clone().or(metadata.built.clone()))
+}
+
+impl GuessSummary {
+    /// Each letter with its color
+    pub fn iter(&self) -> impl Iterator<Item = (Letter, LetterFeedback)> + '_ {
+        self.word.letters().zip(self.pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameConfig;
+    use crate::word_pool::WordPool;
+
+    #[test]
+    fn test_summary() {
+        let pool = WordPool::from_strings(["hello", "crane", "world"].map(String::from));
+        let config = GameConfig {
+            hard_mode: true,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_secret_and_config(pool, Word::parse("hello").unwrap(), config);
+        game.guess("crane").unwrap();
+        game.guess("hello").unwrap();
+
+        let summary = game.summary();
+        assert!(summary.is_won());
+        assert_eq!(summary.secret.as_str(), "hello");
+        assert_eq!(summary.guesses[0].word.as_str(), "crane");
+        assert_eq!(
+            summary.guesses[1].pattern,
+            [LetterFeedback::Correct; WORD_LENGTH]
+        );
+        assert!(summary.mode.hard_mode);
+        assert_eq!(summary.wordlist_version, None);
+        assert_eq!(summary.share_text(), game.share_text());
+
+        let json = serde_json::to_string(&summary).unwrap();
+        assert_eq!(serde_json::from_str::<GameSummary>(&json).unwrap(), summary);
+    }
+}
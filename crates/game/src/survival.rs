@@ -0,0 +1,208 @@
+use crate::constants::SURVIVAL_BONUS_GUESSES;
+use crate::error::GuessError;
+use crate::game::{Game, GameConfig, GameState, GuessResult};
+use crate::hint::Hint;
+use crate::letter::Word;
+use crate::playable::Playable;
+use crate::word_pool::{WordPool, WordSampler};
+
+/// Endless survival mode: puzzles are chained back-to-back and share one
+/// guess budget. Every guess costs one guess, every solved word grants
+/// `SURVIVAL_BONUS_GUESSES` back, and the run ends on the first word that
+/// isn't solved before the budget runs out.
+#[derive(Debug, Clone)]
+pub struct SurvivalGame {
+    current: Game,
+    /// Budget available when the current puzzle started
+    budget_at_start: usize,
+    words_solved: usize,
+    last_solved: Option<Word>,
+    config: GameConfig,
+    word_pool: WordPool,
+    /// Draws the secrets, so no word repeats within a run
+    sampler: WordSampler,
+}
+
+impl SurvivalGame {
+    /// Start a new run with random secrets. The starting budget is
+    /// `config.max_guesses`.
+    pub fn new(word_pool: WordPool, config: GameConfig) -> Self {
+        let mut sampler = word_pool.sampler();
+        let secret = sampler.next().expect("WordPool should not be empty");
+        Self::with_sampler(word_pool, secret, sampler, config)
+    }
+
+    /// Start a new run with a specific secret for the first puzzle (for testing)
+    pub fn with_secret(word_pool: WordPool, secret: Word, config: GameConfig) -> Self {
+        let sampler = word_pool.sampler();
+        Self::with_sampler(word_pool, secret, sampler, config)
+    }
+
+    fn with_sampler(
+        word_pool: WordPool,
+        secret: Word,
+        sampler: WordSampler,
+        config: GameConfig,
+    ) -> Self {
+        let budget = config.max_guesses;
+        let current = Self::puzzle(&word_pool, secret, &config, budget);
+        Self {
+            current,
+            budget_at_start: budget,
+            words_solved: 0,
+            last_solved: None,
+            config,
+            word_pool,
+            sampler,
+        }
+    }
+
+    fn puzzle(word_pool: &WordPool, secret: Word, config: &GameConfig, budget: usize) -> Game {
+        let config = GameConfig {
+            max_guesses: budget,
+            ..config.clone()
+        };
+        Game::with_secret_and_config(word_pool.clone(), secret, config)
+    }
+
+    /// The puzzle currently being played
+    pub fn current(&self) -> &Game {
+        &self.current
+    }
+
+    /// Reveal a letter of the current puzzle, see [`Game::hint`]
+    pub fn hint(&mut self) -> Option<Hint> {
+        self.current.hint()
+    }
+
+    /// Guesses left in the shared budget
+    pub fn budget(&self) -> usize {
+        self.budget_at_start
+            .saturating_sub(self.current.guesses().len())
+    }
+
+    /// Number of words solved in this run
+    pub fn words_solved(&self) -> usize {
+        self.words_solved
+    }
+
+    /// The most recently solved word
+    pub fn last_solved(&self) -> Option<&Word> {
+        self.last_solved.as_ref()
+    }
+}
+
+impl Playable for SurvivalGame {
+    fn guess(&mut self, input: &str) -> GuessResult {
+        if self.state() != GameState::Playing {
+            return Err(GuessError::GameOver);
+        }
+
+        let feedback = self.current.guess(input)?;
+        if let GameState::Won { .. } = self.current.state() {
+            self.words_solved += 1;
+            self.last_solved = Some(feedback.word().clone());
+            self.budget_at_start = self.budget() + SURVIVAL_BONUS_GUESSES;
+            let secret = self.sampler.next().expect("WordPool should not be empty");
+            self.current =
+                Self::puzzle(&self.word_pool, secret, &self.config, self.budget_at_start);
+        }
+        Ok(feedback)
+    }
+
+    fn forfeit(&mut self) {
+        self.current.forfeit();
+    }
+
+    fn state(&self) -> GameState {
+        match self.current.state() {
+            GameState::Lost => GameState::Lost,
+            GameState::Forfeited => GameState::Forfeited,
+            _ => GameState::Playing,
+        }
+    }
+
+    fn guesses_remaining(&self) -> usize {
+        self.budget()
+    }
+
+    fn max_guesses(&self) -> usize {
+        self.budget_at_start
+    }
+
+    fn guesses_made(&self) -> usize {
+        self.current.guesses().len()
+    }
+
+    fn secrets(&self) -> Option<Vec<Word>> {
+        self.current.secret().map(|secret| vec![secret.clone()])
+    }
+
+    fn pause(&mut self) {
+        self.current.pause();
+    }
+
+    fn resume(&mut self) {
+        self.current.resume();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(max_guesses: usize) -> GameConfig {
+        GameConfig {
+            max_guesses,
+            ..GameConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_solved_word_grants_guess() {
+        // With a single word in the pool, every puzzle has the same secret
+        let pool = WordPool::from_strings(vec!["hello".to_string()]);
+        let mut game = SurvivalGame::with_secret(pool, Word::parse("hello").unwrap(), config(3));
+
+        game.guess("hello").unwrap();
+        assert_eq!(game.words_solved(), 1);
+        assert_eq!(game.last_solved(), Word::parse("hello").as_ref());
+        // 3 - 1 + 1
+        assert_eq!(game.budget(), 3);
+        assert!(game.current().guesses().is_empty());
+
+        game.guess("hello").unwrap();
+        assert_eq!(game.words_solved(), 2);
+        assert_eq!(game.state(), GameState::Playing);
+    }
+
+    #[test]
+    fn test_budget_carries_over() {
+        let pool = WordPool::from_strings(vec!["hello".to_string(), "world".to_string()]);
+        let mut game = SurvivalGame::with_secret(pool, Word::parse("hello").unwrap(), config(6));
+
+        game.guess("world").unwrap();
+        game.guess("world").unwrap();
+        assert_eq!(game.budget(), 4);
+        assert_eq!(game.guesses_made(), 2);
+        game.guess("hello").unwrap();
+        // 6 - 3 + 1
+        assert_eq!(game.budget(), 4);
+        assert_eq!(game.max_guesses(), 4);
+        assert_eq!(game.guesses_made(), 0);
+    }
+
+    #[test]
+    fn test_miss_ends_run() {
+        let pool = WordPool::from_strings(vec!["hello".to_string(), "world".to_string()]);
+        let mut game = SurvivalGame::with_secret(pool, Word::parse("hello").unwrap(), config(2));
+
+        game.guess("world").unwrap();
+        game.guess("world").unwrap();
+        assert_eq!(game.state(), GameState::Lost);
+        assert_eq!(game.budget(), 0);
+        assert_eq!(game.words_solved(), 0);
+        assert_eq!(game.secrets(), Some(vec![Word::parse("hello").unwrap()]));
+        assert_eq!(game.guess("hello"), Err(GuessError::GameOver));
+    }
+}
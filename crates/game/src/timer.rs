@@ -0,0 +1,95 @@
+use std::time::{Duration, Instant};
+
+/// Stopwatch for the playing time of a game, which can be paused
+#[derive(Debug, Clone)]
+pub struct Timer {
+    /// Time counted before the timer was last resumed
+    accumulated: Duration,
+    /// Set while the timer is running
+    running_since: Option<Instant>,
+}
+
+impl Timer {
+    /// Create a running timer
+    pub fn start() -> Self {
+        Self::started_at(Instant::now())
+    }
+
+    /// Create a timer running since `now`
+    pub(crate) fn started_at(now: Instant) -> Self {
+        Self {
+            accumulated: Duration::ZERO,
+            running_since: Some(now),
+        }
+    }
+
+    /// Time counted so far, excluding pauses
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed_at(Instant::now())
+    }
+
+    /// Time counted until `now`, excluding pauses
+    pub(crate) fn elapsed_at(&self, now: Instant) -> Duration {
+        self.accumulated
+            + self
+                .running_since
+                .map_or(Duration::ZERO, |since| now.saturating_duration_since(since))
+    }
+
+    /// Stop counting until `resume` is called. Does nothing if already paused.
+    pub fn pause(&mut self) {
+        self.pause_at(Instant::now());
+    }
+
+    /// Stop counting at `now`, see [`pause`](Self::pause)
+    pub(crate) fn pause_at(&mut self, now: Instant) {
+        self.accumulated = self.elapsed_at(now);
+        self.running_since = None;
+    }
+
+    /// Continue counting after a pause. Does nothing if already running.
+    pub fn resume(&mut self) {
+        self.resume_at(Instant::now());
+    }
+
+    /// Continue counting from `now`, see [`resume`](Self::resume)
+    pub(crate) fn resume_at(&mut self, now: Instant) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+
+    /// Whether the timer is paused
+    pub fn is_paused(&self) -> bool {
+        self.running_since.is_none()
+    }
+}
+
+impl Default for Timer {
+    fn default() -> Self {
+        Self::start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_stops_counting() {
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timer = Timer::started_at(at(0));
+        timer.pause_at(at(5));
+        assert!(timer.is_paused());
+        assert_eq!(timer.elapsed_at(at(5)), Duration::from_millis(5));
+
+        assert_eq!(timer.elapsed_at(at(10)), Duration::from_millis(5));
+        // Pausing twice doesn't lose time
+        timer.pause_at(at(15));
+        assert_eq!(timer.elapsed_at(at(15)), Duration::from_millis(5));
+
+        timer.resume_at(at(20));
+        assert_eq!(timer.elapsed_at(at(25)), Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::challenge::{decode_code, encode_code};
+use crate::constants::WEEKLY_CHALLENGE_PUZZLES;
+use crate::error::GameError;
+use crate::game::{Game, GameConfig};
+use crate::letter::{Letter, Word};
+use crate::rules::{BannedLetterRule, GuessRule};
+use crate::word_pool::WordPool;
+
+/// First byte of weekly challenge codes, telling them apart from the
+/// single-word codes of `challenge_code_for`
+const CODE_TAG: u8 = b'w';
+
+/// The twist of a weekly challenge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeeklyTheme {
+    /// Every secret contains the letter
+    SecretsContain(Letter),
+    /// Guesses may not contain the letter (and neither do the secrets)
+    BannedLetter(Letter),
+}
+
+impl WeeklyTheme {
+    /// Rules enforcing the theme on guesses
+    pub fn rules(&self) -> Vec<Arc<dyn GuessRule>> {
+        match self {
+            WeeklyTheme::SecretsContain(_) => Vec::new(),
+            WeeklyTheme::BannedLetter(letter) => vec![Arc::new(BannedLetterRule::new([*letter]))],
+        }
+    }
+
+    /// Whether a word fits the theme as a secret
+    pub fn allows_secret(&self, word: &Word) -> bool {
+        match self {
+            WeeklyTheme::SecretsContain(letter) => word.letters().any(|l| l == *letter),
+            WeeklyTheme::BannedLetter(letter) => word.letters().all(|l| l != *letter),
+        }
+    }
+}
+
+impl fmt::Display for WeeklyTheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeeklyTheme::SecretsContain(letter) => {
+                write!(f, "All secrets contain {}", letter.to_uppercase())
+            }
+            WeeklyTheme::BannedLetter(letter) => {
+                write!(f, "No {} allowed in guesses", letter.to_uppercase())
+            }
+        }
+    }
+}
+
+/// A themed set of puzzles for a week. The theme and secrets are generated
+/// from the week number, so everyone with the same word list gets the same
+/// challenge, and it can be shared as a code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeeklyChallenge {
+    /// Weeks since the Unix epoch
+    week: u64,
+    theme: WeeklyTheme,
+    secrets: Vec<Word>,
+}
+
+impl WeeklyChallenge {
+    /// The current (UTC) week, counted since the Unix epoch
+    pub fn current_week() -> u64 {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        seconds / (7 * 24 * 60 * 60)
+    }
+
+    /// Generate the challenge of a week. Prefers themes that leave enough
+    /// words for `WEEKLY_CHALLENGE_PUZZLES` different secrets.
+    pub fn generate(word_pool: &WordPool, week: u64) -> Result<Self, GameError> {
+        let mut rng = StdRng::seed_from_u64(week);
+        let mut letters: Vec<Letter> = word_pool
+            .iter()
+            .flat_map(Word::letters)
+            .map(|letter| letter.char())
+            .collect::<BTreeSet<char>>()
+            .into_iter()
+            .filter_map(Letter::new)
+            .collect();
+        letters.shuffle(&mut rng);
+
+        let mut themes: Vec<WeeklyTheme> = letters
+            .iter()
+            .flat_map(|&letter| {
+                [
+                    WeeklyTheme::SecretsContain(letter),
+                    WeeklyTheme::BannedLetter(letter),
+                ]
+            })
+            .collect();
+        themes.shuffle(&mut rng);
+
+        let count = |theme: &WeeklyTheme| {
+            word_pool
+                .iter()
+                .filter(|word| theme.allows_secret(word))
+                .count()
+        };
+        let theme = themes
+            .iter()
+            .find(|theme| count(theme) >= WEEKLY_CHALLENGE_PUZZLES)
+            .or_else(|| themes.iter().find(|theme| count(theme) > 0))
+            .ok_or(GameError::EmptyWordPool)?;
+        Self::with_theme(word_pool, week, *theme)
+    }
+
+    /// Recreate a challenge from a code created by [`code`](Self::code)
+    pub fn from_code(word_pool: &WordPool, code: &str) -> Result<Self, GameError> {
+        let payload = decode_code(code).ok_or(GameError::InvalidChallengeCode)?;
+        let [CODE_TAG, w0, w1, w2, w3, kind, letter @ ..] = payload.as_slice() else {
+            return Err(GameError::InvalidChallengeCode);
+        };
+        let week = u64::from(u32::from_be_bytes([*w0, *w1, *w2, *w3]));
+        let letter = std::str::from_utf8(letter)
+            .ok()
+            .and_then(|s| {
+                let mut chars = s.chars();
+                chars.next().filter(|_| chars.next().is_none())
+            })
+            .and_then(Letter::new)
+            .ok_or(GameError::InvalidChallengeCode)?;
+        let theme = match kind {
+            0 => WeeklyTheme::SecretsContain(letter),
+            1 => WeeklyTheme::BannedLetter(letter),
+            _ => return Err(GameError::InvalidChallengeCode),
+        };
+        Self::with_theme(word_pool, week, theme)
+    }
+
+    fn with_theme(word_pool: &WordPool, week: u64, theme: WeeklyTheme) -> Result<Self, GameError> {
+        let mut candidates: Vec<&Word> = word_pool
+            .iter()
+            .filter(|word| theme.allows_secret(word))
+            .collect();
+        if candidates.is_empty() {
+            return Err(GameError::EmptyWordPool);
+        }
+        // Independent of how the theme was picked, so codes give the same secrets
+        let mut rng = StdRng::seed_from_u64(week.wrapping_add(1));
+        candidates.shuffle(&mut rng);
+        let secrets = candidates
+            .into_iter()
+            .cycle()
+            .take(WEEKLY_CHALLENGE_PUZZLES)
+            .cloned()
+            .collect();
+        Ok(Self {
+            week,
+            theme,
+            secrets,
+        })
+    }
+
+    /// Shareable code for this challenge
+    pub fn code(&self) -> String {
+        let (kind, letter) = match self.theme {
+            WeeklyTheme::SecretsContain(letter) => (0, letter),
+            WeeklyTheme::BannedLetter(letter) => (1, letter),
+        };
+        let mut payload = vec![CODE_TAG];
+        payload.extend_from_slice(&(self.week as u32).to_be_bytes());
+        payload.push(kind);
+        payload.extend_from_slice(letter.char().to_string().as_bytes());
+        encode_code(payload)
+    }
+
+    /// The week of the challenge, counted since the Unix epoch
+    pub fn week(&self) -> u64 {
+        self.week
+    }
+
+    /// The twist of the challenge
+    pub fn theme(&self) -> WeeklyTheme {
+        self.theme
+    }
+
+    /// The secrets, one per puzzle
+    pub fn secrets(&self) -> &[Word] {
+        &self.secrets
+    }
+
+    /// `config` with the rules of the theme added
+    pub fn config(&self, config: GameConfig) -> GameConfig {
+        let mut config = config;
+        config.rules.extend(self.theme.rules());
+        config
+    }
+
+    /// Start the puzzle at `index` (0-based), enforcing the theme
+    pub fn game(&self, word_pool: WordPool, index: usize, config: GameConfig) -> Option<Game> {
+        let secret = self.secrets.get(index)?.clone();
+        Some(Game::with_secret_and_config(
+            word_pool,
+            secret,
+            self.config(config),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::GuessError;
+
+    fn pool() -> WordPool {
+        WordPool::from_strings(
+            [
+                "hello", "world", "crane", "slate", "audio", "über", "grünt", "spiel",
+            ]
+            .map(String::from),
+        )
+    }
+
+    #[test]
+    fn test_same_week_same_challenge() {
+        let pool = pool();
+        let challenge = WeeklyChallenge::generate(&pool, 2900).unwrap();
+        assert_eq!(WeeklyChallenge::generate(&pool, 2900).unwrap(), challenge);
+        assert_eq!(challenge.secrets().len(), WEEKLY_CHALLENGE_PUZZLES);
+        assert!(
+            challenge
+                .secrets()
+                .iter()
+                .all(|secret| challenge.theme().allows_secret(secret))
+        );
+    }
+
+    #[test]
+    fn test_code_roundtrip() {
+        let pool = pool();
+        for week in 2900..2910 {
+            let challenge = WeeklyChallenge::generate(&pool, week).unwrap();
+            let code = challenge.code().to_uppercase();
+            assert_eq!(WeeklyChallenge::from_code(&pool, &code), Ok(challenge));
+        }
+
+        let theme = WeeklyTheme::SecretsContain(Letter::new('ü').unwrap());
+        let challenge = WeeklyChallenge::with_theme(&pool, 3000, theme).unwrap();
+        assert_eq!(theme.to_string(), "All secrets contain Ü");
+        assert_eq!(
+            WeeklyChallenge::from_code(&pool, &challenge.code()),
+            Ok(challenge)
+        );
+    }
+
+    #[test]
+    fn test_invalid_codes() {
+        let pool = pool();
+        let word_code = crate::challenge_code_for(&Word::parse("hello").unwrap());
+        assert_eq!(
+            WeeklyChallenge::from_code(&pool, &word_code),
+            Err(GameError::InvalidChallengeCode)
+        );
+        assert_eq!(
+            WeeklyChallenge::from_code(&pool, "abc"),
+            Err(GameError::InvalidChallengeCode)
+        );
+    }
+
+    #[test]
+    fn test_banned_letter_enforced() {
+        let pool = pool();
+        let theme = WeeklyTheme::BannedLetter(Letter::new('e').unwrap());
+        let challenge = WeeklyChallenge::with_theme(&pool, 2900, theme).unwrap();
+        assert_eq!(theme.to_string(), "No E allowed in guesses");
+
+        let mut game = challenge
+            .game(pool.clone(), 0, GameConfig::default())
+            .unwrap();
+        assert_eq!(
+            game.guess("hello"),
+            Err(GuessError::BannedLetter {
+                letter: Letter::new('e').unwrap()
+            })
+        );
+        assert!(game.guess("audio").is_ok());
+    }
+}
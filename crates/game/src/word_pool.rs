@@ -1,13 +1,25 @@
+use crate::language::{load_wordlist, Language};
 use crate::letter::Word;
 use rand::seq::SliceRandom;
-use std::collections::HashSet;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{HashMap, HashSet};
 use std::io;
+use wordle_wordlists_processing::sharp_s::{ListMetadata, SharpSPolicy};
+use wordle_wordlists_processing::stream::play_form;
 
 /// A pool of valid words for the game
 #[derive(Debug, Clone)]
 pub struct WordPool {
     words: Vec<Word>,
     word_set: HashSet<Word>,
+    /// How the words are spelled, and guesses should be
+    sharp_s: SharpSPolicy,
+    /// How words are written where that isn't just their letters, e.g.
+    /// "x-ray" for "xray"
+    display_forms: HashMap<Word, String>,
+    /// How the embedded word list the words come from was built
+    metadata: Option<ListMetadata>,
 }
 
 impl WordPool {
@@ -15,7 +27,77 @@ impl WordPool {
     pub fn from_words(words: impl IntoIterator<Item = Word>) -> Self {
         let words: Vec<Word> = words.into_iter().collect();
         let word_set: HashSet<Word> = words.iter().cloned().collect();
-        Self { words, word_set }
+        Self {
+            words,
+            word_set,
+            sharp_s: SharpSPolicy::default(),
+            display_forms: HashMap::new(),
+            metadata: None,
+        }
+    }
+
+    /// Create from words as they are written, e.g. with hyphens or
+    /// apostrophes. They are played without those, and shown as written
+    /// when revealed (see [`WordPool::display_form`]).
+    pub fn from_display_strings(strings: impl IntoIterator<Item = String>) -> Self {
+        let pairs: Vec<(Word, String)> = strings
+            .into_iter()
+            .filter_map(|display| Some((Self::play_form_of(&display)?, display)))
+            .collect();
+        Self::from_words(pairs.iter().map(|(word, _)| word.clone())).with_display_forms(pairs)
+    }
+
+    /// The word played for one as it is written, e.g. "xray" for "X-ray".
+    /// None if it isn't a valid word without its punctuation.
+    pub(crate) fn play_form_of(display: &str) -> Option<Word> {
+        Word::parse(&play_form(display)?.to_lowercase())
+    }
+
+    /// Show words the way they are written, e.g. the pairs of
+    /// `display_forms` in the word list pipeline. Pairs for words that
+    /// aren't in the pool are ignored.
+    pub fn with_display_forms(mut self, pairs: impl IntoIterator<Item = (Word, String)>) -> Self {
+        for (word, display) in pairs {
+            if self.contains(&word) && display != word.to_string() {
+                self.display_forms.insert(word, display);
+            }
+        }
+        self
+    }
+
+    /// How a word is written, for revealing it: its display form if it has
+    /// one, otherwise just its letters
+    pub fn display_form(&self, word: &Word) -> String {
+        self.display_forms
+            .get(word)
+            .cloned()
+            .unwrap_or_else(|| word.to_string())
+    }
+
+    /// Set how the words spell 'ß', so guesses can be spelled the same way
+    pub fn with_sharp_s(mut self, sharp_s: SharpSPolicy) -> Self {
+        self.sharp_s = sharp_s;
+        self
+    }
+
+    /// How the words spell 'ß'
+    pub fn sharp_s(&self) -> SharpSPolicy {
+        self.sharp_s
+    }
+
+    /// Remember the metadata of the word list the words were loaded from,
+    /// and spell guesses the way it spells 'ß'
+    pub fn with_metadata(self, metadata: ListMetadata) -> Self {
+        let mut pool = self.with_sharp_s(metadata.sharp_s);
+        pool.metadata = Some(metadata);
+        pool
+    }
+
+    /// How the word list was built (word count, build date and recipe), so
+    /// bug reports can tell which dictionary they are about. None for
+    /// pools that weren't loaded from a word list.
+    pub fn metadata(&self) -> Option<&ListMetadata> {
+        self.metadata.as_ref()
     }
 
     /// Create from string iterator (convenience)
@@ -27,6 +109,21 @@ impl WordPool {
         Self::from_words(words)
     }
 
+    /// The words matching `predicate`, e.g. to practice on a part of the
+    /// pool. Keeps how the words are written and spelled, but not the
+    /// metadata, since that describes the whole list.
+    pub fn filtered(&self, predicate: impl Fn(&Word) -> bool) -> WordPool {
+        let mut pool = Self::from_words(self.words.iter().filter(|word| predicate(word)).cloned())
+            .with_sharp_s(self.sharp_s);
+        pool.display_forms = self
+            .display_forms
+            .iter()
+            .filter(|(word, _)| pool.contains(word))
+            .map(|(word, display)| (word.clone(), display.clone()))
+            .collect();
+        pool
+    }
+
     /// Check if a word is valid
     pub fn contains(&self, word: &Word) -> bool {
         self.word_set.contains(word)
@@ -39,6 +136,22 @@ impl WordPool {
             .expect("WordPool should not be empty")
     }
 
+    /// Sampler that never repeats a word until the whole pool was drawn
+    pub fn sampler(&self) -> WordSampler {
+        WordSampler::new(self.words.clone(), ChaCha8Rng::from_entropy())
+    }
+
+    /// Like `sampler`, but drawing a reproducible sequence for a seed. The
+    /// sequence stays the same across versions of the game and of rand.
+    pub fn seeded_sampler(&self, seed: u64) -> WordSampler {
+        WordSampler::new(self.words.clone(), stable_rng(seed))
+    }
+
+    /// Iterate over all words in the pool
+    pub fn iter(&self) -> impl Iterator<Item = &Word> + '_ {
+        self.words.iter()
+    }
+
     /// Number of words in the pool
     pub fn len(&self) -> usize {
         self.words.len()
@@ -50,21 +163,85 @@ impl WordPool {
     }
 }
 
-/// Load the embedded German wordlist
-pub fn load_german_wordlist() -> io::Result<WordPool> {
-    use wordle_wordlists_processing::stream::from_txt_zstd;
+/// Draws secrets from a word pool in shuffled cycles: every word is drawn
+/// once before any word repeats, and a new cycle never starts with the word
+/// that ended the previous one.
+///
+/// Never runs out, so iterating it is infinite (unless the pool is empty).
+#[derive(Debug, Clone)]
+pub struct WordSampler {
+    words: Vec<Word>,
+    /// Index of the next word to draw in the current cycle
+    next: usize,
+    rng: ChaCha8Rng,
+}
+
+impl WordSampler {
+    fn new(words: Vec<Word>, rng: ChaCha8Rng) -> Self {
+        let mut sampler = Self {
+            words,
+            next: 0,
+            rng,
+        };
+        shuffle(&mut sampler.words, &mut sampler.rng);
+        sampler
+    }
+
+    /// Number of words left before the current cycle is exhausted
+    pub fn remaining_in_cycle(&self) -> usize {
+        self.words.len() - self.next
+    }
 
-    let stream = from_txt_zstd(crate::wordlists::DE)?;
-    let mut words = Vec::new();
+    fn start_cycle(&mut self) {
+        let previous = self.words.last().cloned();
+        shuffle(&mut self.words, &mut self.rng);
+        if self.words.len() > 1 && self.words.first() == previous.as_ref() {
+            let last = self.words.len() - 1;
+            self.words.swap(0, last);
+        }
+        self.next = 0;
+    }
+}
 
-    for word_result in stream {
-        let word_str = word_result?.0;
-        if let Some(word) = Word::parse(&word_str) {
-            words.push(word);
+impl Iterator for WordSampler {
+    type Item = Word;
+
+    fn next(&mut self) -> Option<Word> {
+        if self.words.is_empty() {
+            return None;
+        }
+        if self.next == self.words.len() {
+            self.start_cycle();
         }
+        let word = self.words[self.next].clone();
+        self.next += 1;
+        Some(word)
     }
+}
+
+/// Random numbers for picks that have to be reproducible, e.g. the daily
+/// word. ChaCha8 and its seeding are specified, unlike `StdRng`, whose
+/// output may change with any rand release.
+pub(crate) fn stable_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
 
-    Ok(WordPool::from_words(words))
+/// Random index below `len`, drawn the same way in every version (unlike
+/// rand's `choose` and `gen_range`, which may change how they draw)
+pub(crate) fn stable_index(rng: &mut ChaCha8Rng, len: usize) -> usize {
+    (rng.next_u64() % len as u64) as usize
+}
+
+/// Fisher-Yates shuffle with [`stable_index`]
+fn shuffle<T>(items: &mut [T], rng: &mut ChaCha8Rng) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, stable_index(rng, i + 1));
+    }
+}
+
+/// Load the embedded German wordlist
+pub fn load_german_wordlist() -> io::Result<WordPool> {
+    load_wordlist(Language::German)
 }
 
 #[cfg(test)]
@@ -85,6 +262,28 @@ mod tests {
         assert!(!pool.contains(&Word::parse("other").unwrap()));
     }
 
+    #[test]
+    fn test_filtered() {
+        let pool = WordPool::from_display_strings(["E-Mail", "hello", "crane"].map(String::from));
+        let filtered = pool.filtered(|word| word.to_string().contains('a'));
+        let words: Vec<String> = filtered.iter().map(|word| word.to_string()).collect();
+        assert_eq!(words, ["email", "crane"]);
+        assert_eq!(filtered.display_form(&Word::parse("email").unwrap()), "E-Mail");
+        assert!(pool.filtered(|_| false).is_empty());
+    }
+
+    #[test]
+    fn test_display_forms() {
+        let pool = WordPool::from_display_strings(
+            ["E-Mail", "t'bone", "hello", "r2-d2d"].map(String::from),
+        );
+        assert_eq!(pool.len(), 3);
+        let word = |s| Word::parse(s).unwrap();
+        assert_eq!(pool.display_form(&word("email")), "E-Mail");
+        assert_eq!(pool.display_form(&word("tbone")), "t'bone");
+        assert_eq!(pool.display_form(&word("hello")), "hello");
+    }
+
     #[test]
     fn test_word_pool_filters_invalid() {
         let pool = WordPool::from_strings(vec![
@@ -108,4 +307,48 @@ mod tests {
         let random = pool.random();
         assert!(pool.contains(random));
     }
+
+    #[test]
+    fn test_sampler_no_repeats_within_cycle() {
+        let pool = WordPool::from_strings(
+            ["hello", "world", "crane", "slate", "audio"]
+                .iter()
+                .map(|s| s.to_string()),
+        );
+        let mut sampler = pool.sampler();
+
+        for _ in 0..3 {
+            let cycle: HashSet<Word> = sampler.by_ref().take(pool.len()).collect();
+            assert_eq!(cycle.len(), pool.len());
+            assert_eq!(sampler.remaining_in_cycle(), 0);
+        }
+    }
+
+    #[test]
+    fn test_sampler_no_repeat_across_cycles() {
+        let pool = WordPool::from_strings(vec!["hello".to_string(), "world".to_string()]);
+        let drawn: Vec<Word> = pool.seeded_sampler(1).take(50).collect();
+
+        for pair in drawn.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_seeded_sampler_reproducible() {
+        let pool = WordPool::from_strings(
+            ["hello", "world", "crane", "slate", "audio"]
+                .iter()
+                .map(|s| s.to_string()),
+        );
+        let a: Vec<Word> = pool.seeded_sampler(42).take(12).collect();
+        let b: Vec<Word> = pool.seeded_sampler(42).take(12).collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_sampler_empty_pool() {
+        let pool = WordPool::from_words(Vec::new());
+        assert!(pool.sampler().next().is_none());
+    }
 }
@@ -1 +1,3 @@
-pub const DE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/wordlists/de.txt.zst"));
+pub(crate) const DE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/wordlists/de.txt.zst"));
+pub(crate) const DE_METADATA: &str =
+    include_str!(concat!(env!("OUT_DIR"), "/wordlists/de.txt.zst.meta.toml"));
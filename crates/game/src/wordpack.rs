@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use wordle_wordlists_processing::wordpack::Wordpack;
+
+use crate::game::GameConfig;
+use crate::letter::Word;
+use crate::word_pool::WordPool;
+
+/// The words of a language, loaded from a wordpack file (see
+/// [`Wordpack`] for the format)
+#[derive(Debug, Clone)]
+pub struct LoadedWordpack {
+    /// The answers, shown in their display forms and spelled as the pack's
+    /// metadata says
+    pub word_pool: WordPool,
+    /// Words accepted as guesses besides the answers
+    pub guesses: Arc<HashSet<Word>>,
+    /// Good first guesses, best first. Empty if the pack has none.
+    pub opening_book: Vec<Word>,
+}
+
+impl LoadedWordpack {
+    /// `config` accepting the pack's guesses as well as the answers
+    pub fn config(&self, config: GameConfig) -> GameConfig {
+        GameConfig {
+            extra_validator: Some(self.guesses.clone()),
+            ..config
+        }
+    }
+}
+
+/// Load a language from a wordpack file. Words that aren't valid words
+/// of the game are skipped.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or is damaged.
+pub fn load_wordpack(path: impl AsRef<Path>) -> io::Result<LoadedWordpack> {
+    let pack = Wordpack::from_file(path)?;
+    let parse = |words: &[String]| -> Vec<Word> {
+        words.iter().filter_map(|word| Word::parse(word)).collect()
+    };
+    let word_pool = WordPool::from_words(parse(&pack.answers))
+        .with_display_forms(pack.display_forms.iter().filter_map(|display| {
+            WordPool::play_form_of(display).map(|word| (word, display.clone()))
+        }))
+        .with_metadata(pack.metadata);
+    Ok(LoadedWordpack {
+        word_pool,
+        guesses: Arc::new(parse(&pack.guesses).into_iter().collect()),
+        opening_book: parse(&pack.opening_book),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Game, GameState};
+
+    #[test]
+    fn test_load_wordpack() {
+        let pack = Wordpack {
+            answers: vec!["hallo".into(), "xrayy".into()],
+            guesses: vec!["zzzzz".into()],
+            display_forms: vec!["x-rayy".into()],
+            opening_book: vec!["hallo".into()],
+            ..Wordpack::default()
+        };
+        let path = std::env::temp_dir().join(format!("wordpack_{}.wordpack", std::process::id()));
+        pack.write_to_file(&path).unwrap();
+        let loaded = load_wordpack(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let word = |s| Word::parse(s).unwrap();
+        assert_eq!(loaded.word_pool.len(), 2);
+        assert_eq!(loaded.word_pool.display_form(&word("xrayy")), "x-rayy");
+        assert!(loaded.word_pool.metadata().is_some());
+        assert_eq!(loaded.opening_book, vec![word("hallo")]);
+
+        let config = loaded.config(GameConfig::default());
+        let mut game = Game::with_secret_and_config(loaded.word_pool, word("hallo"), config);
+        // Accepted, though it is never the answer
+        game.guess("zzzzz").unwrap();
+        game.guess("hallo").unwrap();
+        assert_eq!(game.state(), GameState::Won { guesses_used: 2 });
+    }
+}
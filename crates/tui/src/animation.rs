@@ -0,0 +1,118 @@
+//! Short visual effects, advanced by the ticks of the main loop.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// How long each step of a shake is shown
+const SHAKE_STEP: Duration = Duration::from_millis(60);
+/// Horizontal offset of the input row in each step of a shake
+const SHAKE_OFFSETS: [i16; 6] = [-2, 2, -1, 1, -1, 1];
+/// How long the screen is shown in reverse video by a flash
+const FLASH_DURATION: Duration = Duration::from_millis(120);
+
+/// How the player is alerted when a guess is rejected, besides the toast
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Alert {
+    #[default]
+    Off,
+    /// Ring the terminal's bell
+    Bell,
+    /// Flash the screen
+    Flash,
+}
+
+impl Alert {
+    /// All alerts, in the order the settings cycle through them
+    pub const ALL: [Alert; 3] = [Alert::Off, Alert::Bell, Alert::Flash];
+
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Alert::Off => "Off",
+            Alert::Bell => "Bell",
+            Alert::Flash => "Flash",
+        }
+    }
+}
+
+/// The input row shaking and flashing after a rejected guess
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shake {
+    started: Instant,
+}
+
+impl Shake {
+    pub fn new(now: Instant) -> Self {
+        Self { started: now }
+    }
+
+    /// Horizontal offset of the input row, None once the shake is over
+    pub fn offset(&self, now: Instant) -> Option<i16> {
+        let step = now.saturating_duration_since(self.started).as_millis() / SHAKE_STEP.as_millis();
+        SHAKE_OFFSETS.get(step as usize).copied()
+    }
+
+    /// When the next step starts (or the shake ends), None once it's over
+    pub fn next_step(&self, now: Instant) -> Option<Instant> {
+        self.offset(now)?;
+        let step = now.saturating_duration_since(self.started).as_millis() / SHAKE_STEP.as_millis();
+        Some(self.started + SHAKE_STEP * (step as u32 + 1))
+    }
+
+    pub fn is_done(&self, now: Instant) -> bool {
+        self.offset(now).is_none()
+    }
+}
+
+/// The whole screen shown in reverse video for a moment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Flash {
+    started: Instant,
+}
+
+impl Flash {
+    pub fn new(now: Instant) -> Self {
+        Self { started: now }
+    }
+
+    /// When the flash is over
+    pub fn end(&self) -> Instant {
+        self.started + FLASH_DURATION
+    }
+
+    pub fn is_done(&self, now: Instant) -> bool {
+        now >= self.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shake_steps() {
+        let start = Instant::now();
+        let shake = Shake::new(start);
+        assert_eq!(shake.offset(start), Some(-2));
+        assert_eq!(shake.offset(start + SHAKE_STEP), Some(2));
+        assert_eq!(
+            shake.next_step(start + SHAKE_STEP / 2),
+            Some(start + SHAKE_STEP)
+        );
+        assert!(!shake.is_done(start + SHAKE_STEP * 5));
+        assert_eq!(shake.next_step(start + SHAKE_STEP * 6), None);
+        assert_eq!(shake.offset(start + SHAKE_STEP * 6), None);
+        assert!(shake.is_done(start + SHAKE_STEP * 6));
+    }
+
+    #[test]
+    fn test_flash_ends() {
+        let start = Instant::now();
+        let flash = Flash::new(start);
+        assert!(!flash.is_done(start + FLASH_DURATION / 2));
+        assert_eq!(flash.end(), start + FLASH_DURATION);
+        assert!(flash.is_done(start + FLASH_DURATION));
+    }
+}
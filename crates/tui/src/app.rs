@@ -1,198 +1,2177 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Modifier, Style},
+    text::Line,
     widgets::{Block, Paragraph},
     Frame,
 };
-use wordle_game::{Game, GameState, GuessResult, WordPool};
+use wordle_game::{
+    opening_book, solver, suggest_opener, AdversarialSelector, BanList, Candidates, CrossGame,
+    DailySelector, DictionaryCheck, EntropyStrategy, Game, GameConfig, GameRecord, GameState,
+    GameSummary, GuessError, Hint, History, Language, MultiGame, Playable, SecretSelector, SequenceGame,
+    SpacedRepetitionSelector, Statistics, StepAnalysis, SurvivalGame, Word, WordPool,
+    CROSS_MAX_GUESSES,
+    HINTS_PER_GAME, LETTER_BANK_SIZE, OPENING_BOOK_SIZE, QUORDLE_BOARDS, SURVIVAL_BONUS_GUESSES,
+};
+use wordle_wordlists_data::definitions::{self, Definition};
 
+use crate::animation::{Alert, Flash, Shake};
+use crate::changelog::{self, Release};
+use crate::clipboard;
+use crate::demo::Demo;
 use crate::input::InputState;
-use crate::theme::Theme;
-use crate::widgets::{BoardWidget, KeyboardState, KeyboardWidget};
+use crate::journal::Journal;
+use crate::output::{ResultsRecorder, SessionResults};
+use crate::pack::PackSession;
+use crate::practice::PracticeFilter;
+use crate::profile::Settings;
+use crate::i18n::{Strings, TextKey};
+use crate::message::{Message, MessageKey};
+use crate::screen::{FilterItem, GameMode, MenuItem, Screen, Selection, SettingItem};
+use crate::simulate::SimulationStrategy;
+use crate::theme::{ColorDepth, ContrastWarning, Theme, ThemeName, ThemeOverrides};
+use crate::toast::{Severity, ToastKey, Toasts};
+use crate::widgets::{
+    BoardWidget, CrossBoardWidget, DialogWidget, KeyboardState, KeyboardWidget, LetterBankWidget,
+    MessageWidget, MultiBoardWidget, ScrollListWidget, SolverPanelWidget, StatusBarWidget,
+    TileStyle, ToastWidget, SOLVER_PANEL_WIDTH,
+};
+
+/// Number of recent first guesses that aren't suggested as openers
+const RECENT_OPENERS: usize = 10;
+
+/// Games Page Up and Page Down skip in the history list
+const HISTORY_PAGE: usize = 10;
+
+/// Width of the board, to tell if it stays centered next to the solver
+/// panel
+const BOARD_WIDTH: u16 = 19;
+
+/// Number of next guesses the solver panel shows
+const SOLVER_SUGGESTIONS: usize = 5;
+
+/// Most feedbacks the solver panel computes for one ranking, so it keeps up
+/// with typing. Also bounds each step of the analysis after a loss.
+const SOLVER_BUDGET: usize = 2_000_000;
+
+/// Number of synonyms shown with the answer
+const MAX_SYNONYMS: usize = 4;
+
+/// How often the main loop checks for results of worker threads
+const BUSY_POLL: Duration = Duration::from_millis(20);
 
 /// Main application state
 pub struct App {
     game: Game,
+    /// Set while playing the cross variant, which replaces `game`
+    cross: Option<CrossGame>,
+    /// Play the cross variant from the next game
+    cross_mode: bool,
+    /// Set while playing Quordle, which replaces `game`
+    multi: Option<MultiGame>,
+    /// Set while playing a survival run, which replaces `game`
+    survival: Option<SurvivalGame>,
+    /// Play survival runs from the next game
+    survival_mode: bool,
+    /// Set while playing a sequence, whose current puzzle replaces `game`
+    sequence: Option<SequenceGame>,
+    /// Play sequences from the next game
+    sequence_mode: bool,
+    /// Set while playing a practice pack, whose puzzles replace random games
+    pack: Option<PackSession>,
+    statistics: Statistics,
+    /// Statistics of the games finished since `statistics` were last saved,
+    /// which saving adds to those other instances saved meanwhile
+    unsaved_statistics: Statistics,
+    /// Open screens, the active one last. The game is always at the bottom.
+    screens: Vec<Screen>,
+    /// The player's settings, saved when the app exits
+    settings: Settings,
+    /// Language of `word_pool`
+    language: Language,
+    /// Language chosen in the settings, to switch to with the next game
+    pending_language: Option<Language>,
+    /// Collects finished games for the session summary
+    results: Arc<ResultsRecorder>,
+    /// Where finished games are written down, if the player keeps a journal
+    journal: Option<Journal>,
+    /// Where finished games are saved, to keep streaks between sessions
+    history: Option<PathBuf>,
+    /// Finished games, oldest first: those of the saved history, then
+    /// those of this session
+    past_games: Vec<GameRecord>,
+    /// Number of `past_games` from before this session
+    games_before_session: usize,
+    /// Where `statistics` are saved after every game
+    stats_path: Option<PathBuf>,
+    /// First guesses of the last games, most recent first, so they aren't
+    /// suggested again
+    recent_openers: Vec<Word>,
+    /// First guess proposed for the current game, if the player wants one
+    suggested_opener: Option<Word>,
+    /// Meaning of the secret, looked up once the game is over
+    definition: Option<Definition>,
+    /// Show the solver's suggestions next to the board
+    solver_panel: bool,
+    /// Best next guesses with their expected information, while the solver
+    /// panel is shown
+    suggestions: Vec<(Word, f64)>,
+    /// Delivers the suggestions being ranked on a worker thread. Replaced
+    /// when the puzzle changes meanwhile, which drops the stale result.
+    pending_suggestions: Option<Receiver<Vec<(Word, f64)>>>,
+    /// The guesses of the last lost game next to the solver's, for the
+    /// analysis screen
+    analysis: Vec<StepAnalysis>,
+    /// Delivers the analysis being computed on a worker thread
+    pending_analysis: Option<Receiver<Vec<StepAnalysis>>>,
+    /// The solver panel was shown during the current game, so it doesn't
+    /// count in the statistics
+    solver_used: bool,
     word_pool: WordPool,
+    /// How secrets of new games are picked
+    mode: GameMode,
+    /// Picks the secrets of new games, depending on `mode`
+    selector: Box<dyn SecretSelector>,
+    config: GameConfig,
     input: InputState,
     keyboard_state: KeyboardState,
-    message: Option<String>,
+    /// Notifications floating over the screen
+    toasts: Toasts,
+    /// Set while the input row shakes after a rejected guess
+    shake: Option<Shake>,
+    /// Set while the screen flashes after a rejected guess
+    flash: Option<Flash>,
+    /// The terminal's bell should ring, see [`App::take_bell`]
+    bell: bool,
     should_quit: bool,
+    /// Something changed since the screen was last drawn
+    dirty: bool,
+    /// Whole seconds on the clock of the current puzzle, as last drawn
+    clock: Option<u64>,
+    /// Number of today's word while it is being played, for the status bar
+    daily_number: Option<u64>,
+    /// Set while a bot plays instead of the player
+    demo: Option<Demo>,
+    /// Which words to practice on, as last set on the practice filter
+    /// screen
+    practice_filter: PracticeFilter,
+    /// The secrets are drawn from the words of `practice_filter`
+    practicing: bool,
     theme: Theme,
+    /// The player's own colors, applied over the built-in theme in use
+    theme_overrides: ThemeOverrides,
+    /// Colors the terminal can show, which the theme is reduced to
+    color_depth: ColorDepth,
+    /// Colors of the overrides the text is hard to read on
+    contrast_warnings: Vec<ContrastWarning>,
 }
 
 impl App {
-    /// Create a new app with the given word pool
-    pub fn new(word_pool: WordPool) -> Self {
-        let game = Game::new(word_pool.clone());
-        Self {
+    /// Create a new app with the given word pool in `language`, enforcing
+    /// the player's bans and settings
+    pub fn new(word_pool: WordPool, language: Language, bans: &BanList, settings: Settings) -> Self {
+        let results = Arc::new(ResultsRecorder::new());
+        let mut config = GameConfig {
+            observers: vec![results.clone()],
+            hard_mode: settings.hard_mode,
+            max_hints: Some(HINTS_PER_GAME),
+            ..GameConfig::default()
+        };
+        bans.apply(&mut config);
+        let mut selector = Self::selector_for(GameMode::Classic, &word_pool);
+        let game = Self::next_game(&word_pool, selector.as_mut(), &config);
+        let mut app = Self {
             game,
+            cross: None,
+            cross_mode: false,
+            multi: None,
+            survival: None,
+            survival_mode: false,
+            sequence: None,
+            sequence_mode: false,
+            pack: None,
+            statistics: Statistics::new(),
+            unsaved_statistics: Statistics::new(),
+            screens: vec![Screen::Playing],
+            theme: Theme::named(settings.theme),
+            settings,
+            language,
+            pending_language: None,
+            results,
+            journal: None,
+            history: None,
+            past_games: Vec::new(),
+            games_before_session: 0,
+            stats_path: None,
+            recent_openers: Vec::new(),
+            suggested_opener: None,
+            definition: None,
+            solver_panel: false,
+            suggestions: Vec::new(),
+            pending_suggestions: None,
+            analysis: Vec::new(),
+            pending_analysis: None,
+            solver_used: false,
             word_pool,
+            mode: GameMode::Classic,
+            selector,
+            config,
             input: InputState::new(),
             keyboard_state: KeyboardState::new(),
-            message: None,
+            toasts: Toasts::new(),
+            shake: None,
+            flash: None,
+            bell: false,
             should_quit: false,
-            theme: Theme::default(),
+            dirty: true,
+            clock: None,
+            daily_number: None,
+            demo: None,
+            practice_filter: PracticeFilter::default(),
+            practicing: false,
+            theme_overrides: ThemeOverrides::default(),
+            color_depth: ColorDepth::default(),
+            contrast_warnings: Vec::new(),
+        };
+        app.suggest_opener();
+        app
+    }
+
+    /// Create an app playing through a practice pack
+    pub fn with_pack(
+        word_pool: WordPool,
+        language: Language,
+        pack: PackSession,
+        bans: &BanList,
+        settings: Settings,
+    ) -> Self {
+        let mut app = Self::new(word_pool, language, bans, settings);
+        app.pack = Some(pack);
+        app.new_game();
+        app
+    }
+
+    /// Create an app starting with a given secret, e.g. a friend's challenge.
+    /// Later games are random again.
+    pub fn with_secret(
+        word_pool: WordPool,
+        language: Language,
+        secret: Word,
+        bans: &BanList,
+        settings: Settings,
+    ) -> Self {
+        let mut app = Self::new(word_pool, language, bans, settings);
+        app.game = Game::with_secret_and_config(app.word_pool.clone(), secret, app.config.clone());
+        app
+    }
+
+    /// Append every finished game to a journal
+    pub fn set_journal(&mut self, journal: Journal) {
+        self.journal = Some(journal);
+    }
+
+    /// Continue the statistics saved at `path`, and save them there after
+    /// every game
+    pub fn set_statistics(&mut self, path: PathBuf, statistics: Statistics) {
+        self.statistics = statistics;
+        self.stats_path = Some(path);
+    }
+
+    /// Save the statistics, if they have a place to be saved, keeping the
+    /// games other instances saved meanwhile
+    pub fn save_statistics(&mut self) -> io::Result<()> {
+        if let Some(path) = &self.stats_path {
+            self.unsaved_statistics.save_merged(path)?;
+            self.unsaved_statistics = Statistics::new();
+        }
+        Ok(())
+    }
+
+    /// Save every finished game to the history at `path`, and count the
+    /// games saved there that the statistics are missing
+    pub fn set_history(&mut self, path: PathBuf, history: &History) {
+        // Counts games synced from other devices
+        self.statistics.catch_up(&history.statistics());
+        self.recent_openers = history.recent_openers(RECENT_OPENERS);
+        self.past_games = history.games.clone();
+        self.games_before_session = self.past_games.len();
+        self.history = Some(path);
+        self.suggest_opener();
+    }
+
+    /// Use a color scheme for this session without changing the settings
+    pub fn use_theme(&mut self, name: ThemeName) {
+        self.theme = self.themed(name);
+    }
+
+    /// Apply the player's own colors over the built-in themes
+    pub fn set_theme_overrides(&mut self, overrides: ThemeOverrides) {
+        self.theme_overrides = overrides;
+        self.theme = self.themed(self.settings.theme);
+    }
+
+    /// Draw with the colors closest to the theme's that the terminal can
+    /// show
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.color_depth = depth;
+        self.theme = self.themed(self.settings.theme);
+    }
+
+    /// Show a notification for a while, in the language of the interface
+    pub fn toast(&mut self, severity: Severity, key: ToastKey) {
+        let text = self.strings().toast(&key);
+        self.toasts.push(severity, text, Instant::now());
+    }
+
+    /// The colors of a built-in theme with the player's overrides, or
+    /// without them if they would make the board unreadable. Text that is
+    /// hard to read on the overridden colors is switched to black or white
+    /// if the player asked for it. Colors the terminal can't show are
+    /// replaced by the closest ones it can.
+    fn themed(&mut self, name: ThemeName) -> Theme {
+        let theme = Theme::named(name);
+        self.contrast_warnings.clear();
+        let themed = match theme.with_overrides(&self.theme_overrides) {
+            Ok(themed) => {
+                self.contrast_warnings = themed.contrast_warnings(&self.theme_overrides);
+                if self.settings.readable_text && !self.contrast_warnings.is_empty() {
+                    themed.with_readable_text()
+                } else {
+                    themed
+                }
+            }
+            Err(err) => {
+                self.toast(Severity::Warning, ToastKey::ThemeFileIgnored(err.to_string()));
+                theme
+            }
+        };
+        themed.with_color_depth(self.color_depth)
+    }
+
+    /// Start with a "what's new" screen presenting the given releases
+    pub fn show_whats_new(&mut self, releases: Vec<&'static Release>) {
+        if !releases.is_empty() {
+            self.screens.push(Screen::WhatsNew(releases));
+        }
+    }
+
+    /// Let the player pick the game mode before the board appears
+    pub fn choose_mode(&mut self) {
+        self.screens
+            .push(Screen::ModeSelect(Selection::new(&GameMode::ALL)));
+    }
+
+    /// The player's settings, including changes made in the app
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// The active screen
+    fn screen(&self) -> &Screen {
+        self.screens.last().unwrap_or(&Screen::Playing)
+    }
+
+    /// Text of the interface, in the language of the words unless the
+    /// player chose another one
+    fn strings(&self) -> Strings {
+        Strings::new(self.settings.ui_language.unwrap_or(self.language))
+    }
+
+    /// Close the active screen, going back to the one below
+    fn close_screen(&mut self) {
+        if self.screens.len() > 1 {
+            self.screens.pop();
         }
     }
 
+    /// Results of the games finished so far
+    pub fn results(&self) -> SessionResults {
+        self.results.results()
+    }
+
     /// Check if the app should quit
     pub fn should_quit(&self) -> bool {
         self.should_quit
     }
 
+    /// Title for the terminal window, e.g. "Wordle – guess 3/6"
+    pub fn terminal_title(&self) -> String {
+        let playable = self.playable();
+        let guess = (playable.state() == GameState::Playing)
+            .then(|| (playable.guesses_made() + 1, playable.max_guesses()));
+        self.strings().terminal_title(guess)
+    }
+
+    /// Whether the terminal's bell should ring, which it then doesn't
+    /// until the next rejected guess
+    pub fn take_bell(&mut self) -> bool {
+        std::mem::take(&mut self.bell)
+    }
+
+    /// The game currently being played
+    fn playable(&self) -> &dyn Playable {
+        if let Some(survival) = &self.survival {
+            return survival;
+        }
+        if let Some(sequence) = &self.sequence {
+            return sequence;
+        }
+        if let Some(multi) = &self.multi {
+            return multi;
+        }
+        match &self.cross {
+            Some(cross) => cross,
+            None => &self.game,
+        }
+    }
+
+    fn playable_mut(&mut self) -> &mut dyn Playable {
+        if let Some(survival) = &mut self.survival {
+            return survival;
+        }
+        if let Some(sequence) = &mut self.sequence {
+            return sequence;
+        }
+        if let Some(multi) = &mut self.multi {
+            return multi;
+        }
+        match &mut self.cross {
+            Some(cross) => cross,
+            None => &mut self.game,
+        }
+    }
+
+    /// The single-word puzzle currently shown, if the variant has one
+    fn current_game(&self) -> Option<&Game> {
+        if self.multi.is_some() {
+            return None;
+        }
+        if let Some(sequence) = &self.sequence {
+            return Some(sequence.current());
+        }
+        match (&self.survival, &self.cross) {
+            (Some(survival), _) => Some(survival.current()),
+            (None, Some(_)) => None,
+            (None, None) => Some(&self.game),
+        }
+    }
+
+    /// Advance animations to `now`
+    pub fn tick(&mut self, now: Instant) {
+        if self.shake.is_some_and(|shake| shake.is_done(now)) {
+            self.shake = None;
+            self.dirty = true;
+        }
+        if self.flash.is_some_and(|flash| flash.is_done(now)) {
+            self.flash = None;
+            self.dirty = true;
+        }
+        if self.toasts.tick(now) {
+            self.dirty = true;
+        }
+        let clock = self.current_game().map(|game| game.elapsed().as_secs());
+        if clock != self.clock {
+            self.clock = clock;
+            self.dirty = true;
+        }
+        self.step_demo(now);
+    }
+
+    /// Let a bot play classic games instead of the player, making a move
+    /// every `delay`
+    pub fn start_demo(&mut self, strategy: SimulationStrategy, delay: Duration) {
+        self.demo = Some(Demo::new(strategy, delay, Instant::now()));
+        self.start_mode(GameMode::Classic);
+    }
+
+    /// Let the bot make its move once it is due: think of a guess, show
+    /// it in the input row, submit it, or start the next game
+    fn step_demo(&mut self, now: Instant) {
+        let Some(demo) = &mut self.demo else {
+            return;
+        };
+        if let Some(guess) = demo.receive() {
+            demo.wait(now);
+            match guess {
+                Some(word) => {
+                    self.input.clear();
+                    word.to_string().chars().for_each(|ch| self.input.push(ch));
+                }
+                // The bot ran out of ideas
+                None => {
+                    self.playable_mut().forfeit();
+                    self.record_finished();
+                }
+            }
+            self.dirty = true;
+            return;
+        }
+        if !demo.is_due(now) {
+            return;
+        }
+        demo.wait(now);
+        if self.playable().state() != GameState::Playing {
+            self.new_game();
+        } else if self.input.is_complete() {
+            self.submit_guess();
+        } else {
+            let guesses = self
+                .current_game()
+                .map(|game| game.guesses().to_vec())
+                .unwrap_or_default();
+            if let Some(demo) = &mut self.demo {
+                demo.think(&self.word_pool, &guesses);
+            }
+        }
+        self.dirty = true;
+    }
+
+    /// When [`App::tick`] should run next, even without input: the next
+    /// step of an animation, a toast to dismiss or the clock to advance.
+    /// None if nothing changes until the next input.
+    pub fn next_tick(&self, now: Instant) -> Option<Instant> {
+        let shake = self.shake.and_then(|shake| shake.next_step(now));
+        let flash = self.flash.map(|flash| flash.end());
+        let clock = self
+            .current_game()
+            .filter(|game| !game.is_paused())
+            .map(|game| {
+                let into_second = Duration::from_nanos(game.elapsed().subsec_nanos().into());
+                now + (Duration::from_secs(1) - into_second)
+            });
+        // Worker threads don't wake the loop, so check on them often
+        let busy = self.is_busy().then(|| now + BUSY_POLL);
+        let demo = self
+            .demo
+            .as_ref()
+            .filter(|demo| !demo.is_thinking())
+            .map(Demo::next_move);
+        [shake, flash, self.toasts.next_expiry(), clock, busy, demo]
+            .into_iter()
+            .flatten()
+            .min()
+    }
+
+    /// Whether the screen may look different than when it was last drawn
+    /// (see [`App::drawn`]), so slow terminals aren't sent identical frames
+    pub fn needs_redraw(&self) -> bool {
+        self.dirty || self.is_animating()
+    }
+
+    /// Note that the screen was drawn
+    pub fn drawn(&mut self) {
+        self.dirty = false;
+    }
+
+    /// Whether an animation is running, so the screen should be redrawn
+    /// often
+    pub fn is_animating(&self) -> bool {
+        self.shake.is_some() || self.flash.is_some()
+    }
+
     /// Handle an input event
     pub fn handle_event(&mut self, event: Event) {
-        if let Event::Key(key) = event {
-            self.handle_key(key);
+        match event {
+            Event::Key(key) => {
+                self.handle_key(key);
+                self.pause_behind_screens();
+            }
+            // Everything is laid out again
+            Event::Resize(..) => {}
+            _ => return,
+        }
+        self.dirty = true;
+    }
+
+    /// Stop the clock while a menu or overlay hides the board
+    fn pause_behind_screens(&mut self) {
+        if *self.screen() == Screen::Playing {
+            self.playable_mut().resume();
+        } else {
+            self.playable_mut().pause();
         }
     }
 
     fn handle_key(&mut self, key: KeyEvent) {
-        // Clear message on any key press
-        self.message = None;
+        // Quit from anywhere
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.should_quit = true;
+            return;
+        }
 
-        // Handle quit shortcuts
-        if key.code == KeyCode::Esc
-            || (key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL))
+        match self.screen() {
+            Screen::Playing => {}
+            Screen::Stats => return self.handle_stats_key(key),
+            Screen::WhatsNew(_) => return self.handle_whats_new_key(key),
+            Screen::Menu(_) => return self.handle_menu_key(key),
+            Screen::Settings(_) => return self.handle_settings_key(key),
+            Screen::ModeSelect(_) => return self.handle_mode_key(key),
+            Screen::ConfirmQuit => return self.handle_confirm_quit_key(key),
+            Screen::About => return self.handle_about_key(key),
+            Screen::History(_) => return self.handle_history_key(key),
+            Screen::PastGame(_) => return self.handle_past_game_key(key),
+            Screen::Analysis => return self.handle_about_key(key),
+            Screen::PracticeFilter(_) => return self.handle_practice_filter_key(key),
+        }
+
+        // The bot plays, spectators can only leave
+        if self.demo.is_some() {
+            if key.code == KeyCode::Esc {
+                self.should_quit = true;
+            }
+            return;
+        }
+
+        if key.code == KeyCode::Esc {
+            if self.game_in_progress() {
+                self.screens.push(Screen::ConfirmQuit);
+            } else {
+                self.should_quit = true;
+            }
+            return;
+        }
+
+        // Open the menu
+        if key.code == KeyCode::Char('o') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.screens.push(Screen::Menu(Selection::new(&MenuItem::ALL)));
+            return;
+        }
+
+        // Show the statistics page
+        if key.code == KeyCode::Char('t') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.screens.push(Screen::Stats);
+            return;
+        }
+
+        // Switch to the next color scheme, e.g. when the terminal's
+        // background changed. Plain T is a letter.
+        if key.code == KeyCode::F(4)
+            || (key.code == KeyCode::Char('t') && key.modifiers.contains(KeyModifiers::ALT))
         {
-            self.should_quit = true;
+            self.cycle_theme();
+            return;
+        }
+
+        // Toggle the letter-bank variant for the next game
+        if key.code == KeyCode::Char('b') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.toggle_letter_bank();
+            return;
+        }
+
+        // Cycle how words missing from the word list are treated in the next game
+        if key.code == KeyCode::Char('d') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.cycle_dictionary_check();
+            return;
+        }
+
+        // Toggle the cross variant for the next game
+        if key.code == KeyCode::Char('x')
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+            && self.has_variants()
+        {
+            self.toggle_cross_mode();
+            return;
+        }
+
+        // Toggle endless survival runs for the next game
+        if key.code == KeyCode::Char('e')
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+            && self.has_variants()
+        {
+            self.toggle_survival_mode();
+            return;
+        }
+
+        // Toggle sequences, where each answer is the next puzzle's first guess
+        if key.code == KeyCode::Char('s')
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+            && self.has_variants()
+        {
+            self.toggle_sequence_mode();
             return;
         }
 
-        match self.game.state() {
+        match self.playable().state() {
             GameState::Playing => self.handle_playing_key(key),
-            GameState::Won { .. } | GameState::Lost => self.handle_game_over_key(key),
+            _ => self.handle_game_over_key(key),
         }
     }
 
-    fn handle_playing_key(&mut self, key: KeyEvent) {
+    fn handle_stats_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Esc
+            || (key.code == KeyCode::Char('t') && key.modifiers.contains(KeyModifiers::CONTROL))
+        {
+            self.close_screen();
+        }
+    }
+
+    fn handle_history_key(&mut self, key: KeyEvent) {
+        let games = self.past_games.len();
+        let Some(Screen::History(selected)) = self.screens.last_mut() else {
+            return;
+        };
+        let last = games.saturating_sub(1);
         match key.code {
-            KeyCode::Char(c) if c.is_alphabetic() => {
-                self.input.push(c);
+            KeyCode::Up => *selected = selected.saturating_sub(1),
+            KeyCode::Down => *selected = (*selected + 1).min(last),
+            KeyCode::PageUp => *selected = selected.saturating_sub(HISTORY_PAGE),
+            KeyCode::PageDown => *selected = (*selected + HISTORY_PAGE).min(last),
+            KeyCode::Home => *selected = 0,
+            KeyCode::End => *selected = last,
+            KeyCode::Enter if games > 0 => {
+                let selected = *selected;
+                self.screens.push(Screen::PastGame(selected));
             }
-            KeyCode::Backspace => {
-                self.input.pop();
+            KeyCode::Esc => self.close_screen(),
+            _ => {}
+        }
+    }
+
+    fn handle_past_game_key(&mut self, key: KeyEvent) {
+        let last = self.past_games.len().saturating_sub(1);
+        let Some(Screen::PastGame(shown)) = self.screens.last_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Left | KeyCode::Up => *shown = shown.saturating_sub(1),
+            KeyCode::Right | KeyCode::Down => *shown = (*shown + 1).min(last),
+            KeyCode::Enter | KeyCode::Esc => {
+                // Keep the game selected in the list
+                let shown = *shown;
+                self.close_screen();
+                if let Some(Screen::History(selected)) = self.screens.last_mut() {
+                    *selected = shown;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// A finished game by its position in the history list, newest first
+    fn past_game(&self, index: usize) -> Option<&GameRecord> {
+        self.past_games.iter().rev().nth(index)
+    }
+
+    fn handle_about_key(&mut self, key: KeyEvent) {
+        if matches!(key.code, KeyCode::Enter | KeyCode::Esc) {
+            self.close_screen();
+        }
+    }
+
+    fn handle_whats_new_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter | KeyCode::Esc => self.close_screen(),
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                self.settings.show_whats_new = false;
+                self.close_screen();
             }
+            _ => {}
+        }
+    }
+
+    fn handle_menu_key(&mut self, key: KeyEvent) {
+        let Some(Screen::Menu(selection)) = self.screens.last_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Up => selection.up(),
+            KeyCode::Down => selection.down(),
+            KeyCode::Esc => self.close_screen(),
             KeyCode::Enter => {
-                if self.input.is_complete() {
-                    self.submit_guess();
-                } else {
-                    self.message = Some("Not enough letters".to_string());
+                let item = selection.selected();
+                self.close_screen();
+                match item {
+                    MenuItem::Resume => {}
+                    MenuItem::Settings => self
+                        .screens
+                        .push(Screen::Settings(Selection::new(&SettingItem::ALL))),
+                    MenuItem::Statistics => self.screens.push(Screen::Stats),
+                    MenuItem::GameMode => self.choose_mode(),
+                    MenuItem::PracticeFilter => self
+                        .screens
+                        .push(Screen::PracticeFilter(Selection::new(&FilterItem::ALL))),
+                    MenuItem::History => self.screens.push(Screen::History(0)),
+                    MenuItem::About => self.screens.push(Screen::About),
+                    MenuItem::Quit => self.should_quit = true,
                 }
             }
             _ => {}
         }
     }
 
-    fn handle_game_over_key(&mut self, key: KeyEvent) {
-        if key.code == KeyCode::Enter {
-            self.new_game();
+    fn handle_mode_key(&mut self, key: KeyEvent) {
+        let Some(Screen::ModeSelect(selection)) = self.screens.last_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Up => selection.up(),
+            KeyCode::Down => selection.down(),
+            // Keep playing as before
+            KeyCode::Esc => self.close_screen(),
+            KeyCode::Enter => {
+                let mode = selection.selected();
+                self.close_screen();
+                self.start_mode(mode);
+            }
+            _ => {}
         }
     }
 
-    fn submit_guess(&mut self) {
-        let input = self.input.as_str().to_string();
-        match self.game.guess(&input) {
-            GuessResult::Accepted(feedback) => {
-                self.keyboard_state.update(&feedback);
-                self.input.clear();
+    fn handle_practice_filter_key(&mut self, key: KeyEvent) {
+        let Some(Screen::PracticeFilter(selection)) = self.screens.last_mut() else {
+            return;
+        };
+        let item = selection.selected();
+        match key.code {
+            KeyCode::Up => selection.up(),
+            KeyCode::Down => selection.down(),
+            KeyCode::Esc => self.close_screen(),
+            KeyCode::Enter if item == FilterItem::Play => self.start_practice(),
+            KeyCode::Right | KeyCode::Enter => {
+                item.change(&mut self.practice_filter, self.language, true);
             }
-            GuessResult::NotInWordList => {
-                self.message = Some("Not in word list".to_string());
+            KeyCode::Left => {
+                item.change(&mut self.practice_filter, self.language, false);
             }
-            GuessResult::InvalidInput => {
-                self.message = Some("Invalid input".to_string());
+            KeyCode::Backspace | KeyCode::Delete => {
+                item.set_letter(&mut self.practice_filter, None);
             }
-            GuessResult::GameOver => {
-                self.message = Some("Game is over".to_string());
+            KeyCode::Char(ch) => {
+                let ch = ch.to_lowercase().next().unwrap_or(ch);
+                if self.language.is_in_alphabet(ch) {
+                    item.set_letter(&mut self.practice_filter, Some(ch));
+                }
             }
+            _ => {}
         }
     }
 
-    fn new_game(&mut self) {
-        self.game = Game::new(self.word_pool.clone());
-        self.input.clear();
-        self.keyboard_state.clear();
-        self.message = None;
-    }
-
-    /// Render the app to the frame
-    pub fn render(&self, frame: &mut Frame) {
-        let area = frame.area();
-
-        // Clear background
-        let block = Block::default().style(Style::default().bg(self.theme.background));
-        frame.render_widget(block, area);
-
-        // Layout: title, board, message, keyboard, help
-        let chunks = Layout::vertical([
-            Constraint::Length(2),  // Title
-            Constraint::Length(8),  // Board (6 rows + padding)
-            Constraint::Length(2),  // Message
-            Constraint::Length(5),  // Keyboard (3 rows + padding)
-            Constraint::Min(1),     // Help text
-        ])
-        .split(area);
-
-        self.render_title(frame, chunks[0]);
-        self.render_board(frame, chunks[1]);
-        self.render_message(frame, chunks[2]);
-        self.render_keyboard(frame, chunks[3]);
-        self.render_help(frame, chunks[4]);
+    /// Play classic games whose secrets match the practice filter
+    fn start_practice(&mut self) {
+        let secrets = self.practice_filter.apply(&self.word_pool);
+        if secrets.is_empty() {
+            self.toast(Severity::Warning, ToastKey::NoWordsMatchFilter);
+            return;
+        }
+        self.close_screen();
+        self.start_mode(GameMode::Classic);
+        self.selector = Box::new(secrets.sampler());
+        self.practicing = true;
+        self.new_game();
     }
 
-    fn render_title(&self, frame: &mut Frame, area: Rect) {
-        let title = Paragraph::new("WORDLE")
-            .style(
-                Style::default()
-                    .fg(self.theme.text)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .alignment(ratatui::layout::Alignment::Center);
-        frame.render_widget(title, area);
+    fn handle_confirm_quit_key(&mut self, key: KeyEvent) {
+        match key.code {
+            // J for "ja" in German
+            KeyCode::Char('y' | 'Y' | 'j' | 'J') | KeyCode::Enter => self.should_quit = true,
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => self.close_screen(),
+            _ => {}
+        }
     }
 
-    fn render_board(&self, frame: &mut Frame, area: Rect) {
-        let board = BoardWidget::new(&self.game, self.input.as_str(), &self.theme);
-        frame.render_widget(board, area);
+    /// Whether quitting now would lose a game the player started
+    fn game_in_progress(&self) -> bool {
+        let game = self.playable();
+        game.state() == GameState::Playing
+            && (game.guesses_remaining() < game.max_guesses() || !self.input.as_str().is_empty())
     }
 
-    fn render_message(&self, frame: &mut Frame, area: Rect) {
-        let text = match self.game.state() {
-            GameState::Won { guesses_used } => {
-                format!("You won in {} guess{}! Press Enter to play again.",
-                    guesses_used,
-                    if guesses_used == 1 { "" } else { "es" }
-                )
-            }
-            GameState::Lost => {
-                format!(
-                    "Game over! The word was {}. Press Enter to play again.",
-                    self.game.secret().map(|w| w.to_string().to_uppercase()).unwrap_or_default()
-                )
-            }
-            GameState::Playing => {
-                self.message.clone().unwrap_or_default()
+    fn handle_settings_key(&mut self, key: KeyEvent) {
+        let Some(Screen::Settings(selection)) = self.screens.last_mut() else {
+            return;
+        };
+        let item = selection.selected();
+        let forward = match key.code {
+            KeyCode::Up => return selection.up(),
+            KeyCode::Down => return selection.down(),
+            KeyCode::Esc => {
+                self.close_screen();
+                self.toast(Severity::Info, ToastKey::SettingsApplyNextGame);
+                return;
             }
+            KeyCode::Right | KeyCode::Enter => true,
+            KeyCode::Left => false,
+            _ => return,
         };
 
-        let paragraph = Paragraph::new(text)
-            .style(Style::default().fg(self.theme.text))
-            .alignment(ratatui::layout::Alignment::Center);
-        frame.render_widget(paragraph, area);
-    }
-
-    fn render_keyboard(&self, frame: &mut Frame, area: Rect) {
-        let keyboard = KeyboardWidget::new(&self.keyboard_state, &self.theme);
-        frame.render_widget(keyboard, area);
+        if !item.change(&mut self.settings, forward) {
+            return;
+        }
+        match item {
+            // Show the new colors right away
+            SettingItem::Theme | SettingItem::ReadableText => {
+                self.theme = self.themed(self.settings.theme)
+            }
+            SettingItem::Language => self.pending_language = Some(self.settings.language),
+            SettingItem::SuggestOpener => self.suggest_opener(),
+            _ => {}
+        }
+    }
+
+    fn handle_playing_key(&mut self, key: KeyEvent) {
+        // Give up and reveal the answer
+        if key.code == KeyCode::Char('r') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.playable_mut().forfeit();
+            self.input.clear();
+            self.record_finished();
+            return;
+        }
+
+        if key.code == KeyCode::F(3) {
+            self.toggle_solver_panel();
+            return;
+        }
+
+        // Plain H is a letter
+        if key.code == KeyCode::F(2)
+            || (key.code == KeyCode::Char('h') && key.modifiers.contains(KeyModifiers::ALT))
+        {
+            self.request_hint();
+            return;
+        }
+
+        // Editing keys of a shell's command line
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('u') => self.input.clear(),
+                KeyCode::Char('w') => self.input.delete_before_cursor(),
+                _ => {}
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char(c) if c.is_alphabetic() => {
+                self.input.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Delete => self.input.delete(),
+            KeyCode::Left => self.input.left(),
+            KeyCode::Right => self.input.right(),
+            KeyCode::Home => self.input.home(),
+            KeyCode::End => self.input.end(),
+            KeyCode::Enter => {
+                if self.input.is_complete() {
+                    self.submit_guess();
+                } else {
+                    let got = self.input.as_str().chars().count();
+                    let err = GuessError::TooShort { got };
+                    self.toast(Severity::Warning, ToastKey::GuessError(err));
+                    self.reject_guess();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Shake the input row after a rejected guess, unless animations are
+    /// off, and alert the player the way they chose
+    fn reject_guess(&mut self) {
+        let now = Instant::now();
+        if !self.settings.reduced_motion {
+            self.shake = Some(Shake::new(now));
+        }
+        match self.settings.invalid_guess_alert {
+            Alert::Off => {}
+            Alert::Bell => self.bell = true,
+            Alert::Flash => self.flash = Some(Flash::new(now)),
+        }
+    }
+
+    /// Switch to the next built-in theme, keeping the overrides of the
+    /// theme file. Saved with the other settings.
+    fn cycle_theme(&mut self) {
+        SettingItem::Theme.change(&mut self.settings, true);
+        self.theme = self.themed(self.settings.theme);
+        let name = self.settings.theme.name();
+        self.toast(Severity::Info, ToastKey::Theme(name));
+    }
+
+    /// Reveal a letter of the current puzzle, if it has hints left
+    fn request_hint(&mut self) {
+        let hint = if let Some(sequence) = &mut self.sequence {
+            sequence.hint()
+        } else if let Some(survival) = &mut self.survival {
+            survival.hint()
+        } else if self.cross.is_none() && self.multi.is_none() {
+            self.game.hint()
+        } else {
+            self.toast(Severity::Info, ToastKey::NoHintsInVariant);
+            return;
+        };
+        match hint {
+            Some(Hint::Letter { position, letter }) => {
+                self.keyboard_state.reveal(letter.char());
+                let letter = letter.char();
+                self.toast(Severity::Info, ToastKey::HintLetter { position, letter });
+            }
+            Some(Hint::Phonetic { .. }) => {}
+            None if self.current_game().and_then(Game::hints_left) == Some(0) => {
+                self.toast(Severity::Info, ToastKey::NoHintsLeft);
+            }
+            None => self.toast(Severity::Info, ToastKey::AllLettersKnown),
+        }
+    }
+
+    fn handle_game_over_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => self.new_game(),
+            KeyCode::Char('c') | KeyCode::Char('C') => self.copy_result(),
+            _ => {}
+        }
+    }
+
+    /// Copy the share text of the finished game to the clipboard
+    fn copy_result(&mut self) {
+        let Some(game) = self.current_game() else {
+            self.toast(Severity::Info, ToastKey::NothingToCopy);
+            return;
+        };
+        match clipboard::copy(&game.share_text()) {
+            Ok(()) => self.toast(Severity::Info, ToastKey::Copied),
+            Err(err) => self.toast(Severity::Error, ToastKey::CopyFailed(err.to_string())),
+        }
+    }
+
+    fn submit_guess(&mut self) {
+        let input = self.input.as_str().to_string();
+        let is_cross = self.cross.is_some();
+        let words_solved = self.survival.as_ref().map(SurvivalGame::words_solved);
+        match self.playable_mut().guess(&input) {
+            Ok(feedback) => {
+                // Letter colors are per word in the cross variant, so a
+                // combined keyboard would be misleading. Quordle combines
+                // the boards that are left.
+                if let Some(multi) = &self.multi {
+                    self.keyboard_state = KeyboardState::combined(multi);
+                } else if !is_cross {
+                    self.keyboard_state.update(&feedback);
+                }
+                if let Some(warning) = feedback.warnings().first() {
+                    self.toast(Severity::Warning, ToastKey::GuessWarning(warning.clone()));
+                }
+                self.input.clear();
+            }
+            Err(err) => {
+                self.toast(Severity::Warning, ToastKey::GuessError(err));
+                self.reject_guess();
+            }
+        }
+
+        if let Some(survival) = &self.survival
+            && Some(survival.words_solved()) != words_solved
+        {
+            // A new puzzle started, its letters are all unknown again
+            self.keyboard_state.clear();
+            let bonus = SURVIVAL_BONUS_GUESSES;
+            self.toast(Severity::Info, ToastKey::SurvivalSolved { bonus });
+        }
+
+        self.record_finished();
+        self.refresh_suggestions();
+    }
+
+    /// Save the outcome once the current game is over
+    fn record_finished(&mut self) {
+        if self.playable().state() != GameState::Playing
+            && let Some(game) = self.current_game()
+        {
+            let game = game.clone();
+            // Without a definition, the answer is shown on its own
+            self.definition = game.secret().and_then(|secret| {
+                definitions::lookup(self.language.code(), &secret.to_string()).ok()
+            });
+            let record = GameRecord::new(&game, SystemTime::now());
+            // Games played with the solver's help don't count
+            if !self.solver_used {
+                self.record_counted_game(&game.summary(), record.clone());
+            }
+            self.past_games.extend(record);
+            if game.state() == GameState::Lost && self.demo.is_none() {
+                self.analyze(&game);
+                self.screens.push(Screen::Analysis);
+            }
+        }
+
+        // Variants with several words are recorded word by word, which also
+        // reports them to the results (single games report themselves)
+        let summaries = match (&self.multi, &self.cross) {
+            (Some(multi), _) => multi.summaries(),
+            (None, Some(cross)) => cross.summaries(),
+            (None, None) => Vec::new(),
+        };
+        for summary in &summaries {
+            self.results.record(summary);
+            let record = GameRecord::from_summary(summary, SystemTime::now());
+            if !self.solver_used {
+                self.record_counted_game(summary, record.clone());
+            }
+            self.past_games.extend(record);
+        }
+
+        if let Some(survival) = &self.survival
+            && survival.state() != GameState::Playing
+            && !self.solver_used
+        {
+            let words_solved = survival.words_solved();
+            self.statistics.record_survival_run(words_solved);
+            self.unsaved_statistics.record_survival_run(words_solved);
+        }
+
+        if let Some(pack) = &mut self.pack {
+            let state = self.game.state();
+            if state != GameState::Playing
+                && let Err(err) = pack.record(&state)
+            {
+                self.toast(Severity::Error, ToastKey::PackProgressNotSaved(err.to_string()));
+            }
+        }
+
+        if self.playable().state() != GameState::Playing
+            && let Err(err) = self.save_statistics()
+        {
+            self.toast(Severity::Error, ToastKey::StatisticsNotSaved(err.to_string()));
+        }
+
+        // There is one daily word per day, so continue with classic games
+        if self.mode == GameMode::Daily && self.playable().state() != GameState::Playing {
+            self.mode = GameMode::Classic;
+            self.selector = Self::selector_for(self.mode, &self.word_pool);
+            self.toast(Severity::Info, ToastKey::ClassicUntilTomorrow);
+        }
+    }
+
+    /// Count a finished game in the statistics, the history and the journal
+    fn record_counted_game(&mut self, game: &GameSummary, record: Option<GameRecord>) {
+        if let Some(record) = &record {
+            self.statistics.record_summary(record, game);
+            self.unsaved_statistics.record_summary(record, game);
+        }
+        // Only plain games have secrets from the selector
+        if self.pack.is_none()
+            && self.survival.is_none()
+            && self.sequence.is_none()
+            && self.multi.is_none()
+            && self.cross.is_none()
+        {
+            self.selector.record_result(&game.secret, game.is_won());
+        }
+        if let Some(opener) = game.guesses.first() {
+            self.recent_openers.insert(0, opener.word.clone());
+            self.recent_openers.truncate(RECENT_OPENERS);
+        }
+        if let Some(journal) = &self.journal
+            && let Err(err) = journal.record(game)
+        {
+            self.toast(Severity::Error, ToastKey::JournalNotWritten(err.to_string()));
+        }
+        if let Some(path) = &self.history
+            && let Some(record) = record
+            && let Err(err) = History::record_merged(path, record)
+        {
+            self.toast(Severity::Error, ToastKey::GameNotSaved(err.to_string()));
+        }
+    }
+
+    /// Show or hide the solver's suggestions. The game in progress doesn't
+    /// count once they were shown.
+    fn toggle_solver_panel(&mut self) {
+        self.solver_panel = !self.solver_panel;
+        if self.solver_panel && self.playable().state() == GameState::Playing {
+            self.solver_used = true;
+        }
+        self.toast(Severity::Info, ToastKey::Solver(self.solver_panel));
+        self.refresh_suggestions();
+    }
+
+    /// Start ranking the next guesses of the current puzzle for the solver
+    /// panel. On large pools this takes longer than a frame, so it runs on
+    /// a worker thread (see [`App::receive_results`]).
+    fn refresh_suggestions(&mut self) {
+        self.suggestions.clear();
+        self.pending_suggestions = None;
+        let Some(game) = self.current_game() else {
+            return;
+        };
+        if !self.solver_panel || game.state() != GameState::Playing {
+            return;
+        }
+        let candidates = Candidates::from_guesses(&self.word_pool, game.guesses());
+        let guesses = solver::guesses_within_budget(
+            &self.word_pool,
+            &candidates,
+            game.guesses().is_empty(),
+            SOLVER_BUDGET,
+        );
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let ranked = EntropyStrategy.ranked_among(&guesses, &candidates, SOLVER_SUGGESTIONS);
+            // Nobody waits for them anymore if the puzzle changed meanwhile
+            sender.send(ranked).ok();
+        });
+        self.pending_suggestions = Some(receiver);
+    }
+
+    /// Start comparing the guesses of a lost game with the solver's on a
+    /// worker thread, like the suggestions of the solver panel
+    fn analyze(&mut self, game: &Game) {
+        self.analysis.clear();
+        let word_pool = self.word_pool.clone();
+        let guesses = game.guesses().to_vec();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            sender
+                .send(solver::analyze(&word_pool, &guesses, SOLVER_BUDGET))
+                .ok();
+        });
+        self.pending_analysis = Some(receiver);
+    }
+
+    /// Show the results of worker threads once they are ready
+    pub fn receive_results(&mut self) {
+        if let Some(suggestions) = receive(&mut self.pending_suggestions) {
+            self.suggestions = suggestions;
+            self.dirty = true;
+        }
+        if let Some(analysis) = receive(&mut self.pending_analysis) {
+            self.analysis = analysis;
+            self.dirty = true;
+        }
+    }
+
+    /// Whether work runs in the background whose result should be shown
+    /// as soon as it is ready
+    pub fn is_busy(&self) -> bool {
+        self.pending_suggestions.is_some()
+            || self.pending_analysis.is_some()
+            || self.demo.as_ref().is_some_and(Demo::is_thinking)
+    }
+
+    fn toggle_letter_bank(&mut self) {
+        self.config.letter_bank_size = match self.config.letter_bank_size {
+            Some(_) => None,
+            None => Some(LETTER_BANK_SIZE),
+        };
+        let on = self.config.letter_bank_size.is_some();
+        self.toast(Severity::Info, ToastKey::LetterBank(on));
+    }
+
+    fn cycle_dictionary_check(&mut self) {
+        let check = match self.config.dictionary_check {
+            DictionaryCheck::Strict => DictionaryCheck::WarnOnly,
+            DictionaryCheck::WarnOnly => DictionaryCheck::Off,
+            DictionaryCheck::Off => DictionaryCheck::Strict,
+        };
+        self.config.dictionary_check = check;
+        self.toast(Severity::Info, ToastKey::DictionaryCheck(check));
+    }
+
+    /// Whether the cross, survival and sequence variants can be turned on:
+    /// packs only have regular puzzles, and Quordle has its own boards
+    fn has_variants(&self) -> bool {
+        self.pack.is_none() && self.mode != GameMode::Quordle
+    }
+
+    fn toggle_cross_mode(&mut self) {
+        self.cross_mode = !self.cross_mode;
+        self.survival_mode = false;
+        self.sequence_mode = false;
+        self.toast(Severity::Info, ToastKey::Cross(self.cross_mode));
+    }
+
+    fn toggle_survival_mode(&mut self) {
+        self.survival_mode = !self.survival_mode;
+        self.cross_mode = false;
+        self.sequence_mode = false;
+        self.toast(Severity::Info, ToastKey::Survival(self.survival_mode));
+    }
+
+    fn toggle_sequence_mode(&mut self) {
+        self.sequence_mode = !self.sequence_mode;
+        self.cross_mode = false;
+        self.survival_mode = false;
+        self.toast(Severity::Info, ToastKey::Sequence(self.sequence_mode));
+    }
+
+    fn next_game(
+        word_pool: &WordPool,
+        selector: &mut dyn SecretSelector,
+        config: &GameConfig,
+    ) -> Game {
+        Game::with_selector(word_pool.clone(), selector, config.clone())
+            .expect("WordPool should not be empty")
+    }
+
+    /// Selector picking the secrets of games in `mode`
+    fn selector_for(mode: GameMode, word_pool: &WordPool) -> Box<dyn SecretSelector> {
+        match mode {
+            // Don't repeat words within a session
+            GameMode::Classic | GameMode::Hard => Box::new(word_pool.sampler()),
+            GameMode::Daily => Box::new(DailySelector::today()),
+            GameMode::Practice => Box::new(SpacedRepetitionSelector::new()),
+            GameMode::Adversarial => Box::new(AdversarialSelector::default()),
+            // Only the boards' secrets are random, see `new_game`
+            GameMode::Quordle => Box::new(word_pool.sampler()),
+        }
+    }
+
+    /// Draw the secrets of the next games in the same order as every run
+    /// with this seed, until another mode is chosen, and start over
+    pub fn use_seed(&mut self, seed: u64) {
+        self.selector = Box::new(self.word_pool.seeded_sampler(seed));
+        self.new_game();
+    }
+
+    /// Switch to `mode` and start a game in it
+    pub fn start_mode(&mut self, mode: GameMode) {
+        self.mode = mode;
+        self.practicing = false;
+        self.selector = Self::selector_for(mode, &self.word_pool);
+        if mode == GameMode::Quordle {
+            self.cross_mode = false;
+            self.survival_mode = false;
+            self.sequence_mode = false;
+        }
+        self.new_game();
+    }
+
+    fn new_game(&mut self) {
+        self.config.hard_mode = self.settings.hard_mode || self.mode == GameMode::Hard;
+        self.definition = None;
+        self.daily_number =
+            (self.mode == GameMode::Daily).then(|| DailySelector::today().number());
+        self.solver_used = self.solver_panel || self.demo.is_some();
+
+        if let Some(pack) = &mut self.pack {
+            // Stay on the last puzzle once the pack is complete
+            if let Some(game) = pack.next_game(&self.word_pool, &self.config) {
+                self.game = game;
+                self.input.clear();
+                self.keyboard_state.clear();
+                self.suggest_opener();
+                self.refresh_suggestions();
+            }
+            return;
+        }
+
+        // Carry the answer over while the sequence is unbroken
+        if self.sequence_mode
+            && let Some(sequence) = &mut self.sequence
+            && sequence.next_game()
+        {
+            self.input.clear();
+            self.keyboard_state.clear();
+            for feedback in sequence.current().guesses() {
+                self.keyboard_state.update(feedback);
+            }
+            self.suggest_opener();
+            self.refresh_suggestions();
+            return;
+        }
+
+        if let Some(language) = self.pending_language.take()
+            && language != self.language
+            && let Err(err) = self.switch_language(language)
+        {
+            let language = language.name();
+            let err = err.to_string();
+            self.toast(Severity::Error, ToastKey::WordListFailed { language, err });
+        }
+
+        self.game = Self::next_game(&self.word_pool, self.selector.as_mut(), &self.config);
+        self.cross = if self.cross_mode {
+            CrossGame::new(self.word_pool.clone(), CROSS_MAX_GUESSES)
+        } else {
+            None
+        };
+        self.multi = (self.mode == GameMode::Quordle)
+            .then(|| MultiGame::new(self.word_pool.clone(), QUORDLE_BOARDS));
+        self.survival = if self.survival_mode {
+            Some(SurvivalGame::new(self.word_pool.clone(), self.config.clone()))
+        } else {
+            None
+        };
+        self.sequence = if self.sequence_mode {
+            Some(SequenceGame::new(self.word_pool.clone(), self.config.clone()))
+        } else {
+            None
+        };
+        self.input.clear();
+        self.keyboard_state.clear();
+        self.suggest_opener();
+        self.refresh_suggestions();
+    }
+
+    /// Propose a first guess from the opening book for a game that hasn't
+    /// started yet, if the player turned suggestions on
+    fn suggest_opener(&mut self) {
+        self.suggested_opener = None;
+        if !self.settings.suggest_opener
+            || !self
+                .current_game()
+                .is_some_and(|game| game.guesses().is_empty())
+        {
+            return;
+        }
+        let book = opening_book(&self.word_pool, OPENING_BOOK_SIZE);
+        self.suggested_opener =
+            suggest_opener(&book, &self.recent_openers, &mut rand::thread_rng());
+    }
+
+    /// Play with the words of another language from now on (packs keep
+    /// their language, since their puzzles come from the word list)
+    fn switch_language(&mut self, language: Language) -> io::Result<()> {
+        let word_pool = crate::load_words(language)?;
+        self.selector = Self::selector_for(self.mode, &word_pool);
+        self.practicing = false;
+        self.word_pool = word_pool;
+        self.language = language;
+        Ok(())
+    }
+
+    /// Render the app to the frame
+    pub fn render(&self, frame: &mut Frame) {
+        let area = frame.area();
+
+        // Clear background
+        let block = Block::default().style(Style::default().bg(self.theme.background));
+        frame.render_widget(block, area);
+
+        // Layout: title, board, letter bank, message, keyboard, help, status bar
+        let bank_height = if self
+            .current_game()
+            .is_some_and(|game| game.letter_bank().is_some())
+        {
+            2
+        } else {
+            0
+        };
+        // Quordle's boards are taller, and need more rows on narrow terminals.
+        // Bordered tiles get their rows if the rest of the screen still fits.
+        let bordered_height = TileStyle::Bordered.board_size().1 + 2;
+        let board_height = match &self.multi {
+            Some(multi) => MultiBoardWidget::height(multi, area.width) + 2,
+            None if self.settings.tile_style == TileStyle::Bordered
+                && self.cross.is_none()
+                && area.height >= bordered_height + bank_height + 11 =>
+            {
+                bordered_height
+            }
+            None => 8,
+        };
+        // Messages wrap on narrow terminals, up to a few rows
+        let message = self.message();
+        let message_height = message.as_ref().map_or(2, |message| {
+            MessageWidget::new(message, self.strings(), &self.theme)
+                .height(area.width)
+                .clamp(2, 4)
+        });
+        let chunks = Layout::vertical([
+            Constraint::Length(2),              // Title
+            Constraint::Length(board_height),   // Board (6 rows + padding)
+            Constraint::Length(bank_height),    // Letter bank (only in the letter-bank variant)
+            Constraint::Length(message_height), // Message
+            Constraint::Length(5),              // Keyboard (3 rows + padding)
+            Constraint::Min(1),                 // Help text
+            Constraint::Length(1),              // Status bar
+        ])
+        .split(area);
+
+        self.render_title(frame, chunks[0]);
+        // Full-page screens use the space of everything between title and help
+        let page = chunks[1].union(chunks[4]);
+        // Overlays are drawn over the screen below them
+        let base = self
+            .screens
+            .iter()
+            .rev()
+            .find(|screen| !screen.is_overlay())
+            .unwrap_or(&Screen::Playing);
+        let full_page = match base {
+            Screen::Playing => {
+                self.render_board(frame, chunks[1]);
+                false
+            }
+            Screen::Stats => {
+                self.render_stats(frame, chunks[1]);
+                false
+            }
+            Screen::WhatsNew(releases) => {
+                self.render_whats_new(frame, page, releases);
+                true
+            }
+            Screen::About => {
+                self.render_about(frame, page);
+                true
+            }
+            Screen::History(selected) => {
+                self.render_history(frame, page, *selected);
+                true
+            }
+            Screen::PastGame(index) => {
+                self.render_past_game(frame, page, *index);
+                true
+            }
+            Screen::Analysis => {
+                self.render_analysis(frame, page);
+                true
+            }
+            Screen::Menu(selection) => {
+                let items = selection.items().iter().map(|item| item.label().to_string());
+                self.render_list(frame, page, "MENU", items, selection.index());
+                true
+            }
+            Screen::Settings(selection) => {
+                let items = selection.items().iter().map(|item| {
+                    format!("{}: {}", item.label(), item.value(&self.settings))
+                });
+                self.render_list(frame, page, "SETTINGS", items, selection.index());
+                self.render_contrast_warnings(frame, page);
+                true
+            }
+            Screen::PracticeFilter(selection) => {
+                let matching = self
+                    .word_pool
+                    .iter()
+                    .filter(|word| self.practice_filter.matches(word))
+                    .count();
+                let items = selection.items().iter().map(|item| {
+                    format!("{}: {}", item.label(), item.value(&self.practice_filter, matching))
+                });
+                self.render_list(frame, page, "PRACTICE FILTER", items, selection.index());
+                true
+            }
+            Screen::ModeSelect(selection) => {
+                let items = selection
+                    .items()
+                    .iter()
+                    .map(|mode| format!("{}: {}", mode.label(), mode.description()));
+                self.render_list(frame, page, "GAME MODE", items, selection.index());
+                true
+            }
+            Screen::ConfirmQuit => false,
+        };
+        if !full_page {
+            self.render_letter_bank(frame, chunks[2]);
+            if let Some(message) = &message {
+                let widget = MessageWidget::new(message, self.strings(), &self.theme);
+                frame.render_widget(widget, chunks[3]);
+            }
+            self.render_keyboard(frame, chunks[4]);
+        }
+        self.render_help(frame, chunks[5]);
+        self.render_status_bar(frame, chunks[6]);
+        if *self.screen() == Screen::ConfirmQuit {
+            let question = self.strings().text(&TextKey::ConfirmQuit);
+            frame.render_widget(DialogWidget::new(&question, &self.theme), area);
+        }
+        if self.flash.is_some() {
+            let reversed = Style::default().add_modifier(Modifier::REVERSED);
+            frame.buffer_mut().set_style(area, reversed);
+        }
+        // Over everything else
+        frame.render_widget(ToastWidget::new(&self.toasts, &self.theme), area);
+    }
+
+    fn render_title(&self, frame: &mut Frame, area: Rect) {
+        let strings = self.strings();
+        let title = match self.mode {
+            GameMode::Classic => "WORDLE".to_string(),
+            mode => format!("WORDLE  {}", mode.label().to_uppercase()),
+        };
+        let mut lines = vec![Line::styled(
+            title,
+            Style::default()
+                .fg(self.theme.text)
+                .add_modifier(Modifier::BOLD),
+        )];
+        if let Some(pack) = &self.pack {
+            lines.push(Line::styled(
+                strings.text(&TextKey::PackProgress {
+                    seed: pack.pack().seed().to_string(),
+                    puzzle: pack.index().map_or(0, |index| index + 1),
+                    puzzles: pack.pack().len(),
+                    solved: pack.progress().solved(),
+                }),
+                Style::default()
+                    .fg(self.theme.correct)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(survival) = &self.survival {
+            // The shared budget decides the run, so keep it in plain sight
+            lines.push(Line::styled(
+                strings.text(&TextKey::SurvivalProgress {
+                    budget: survival.budget(),
+                    solved: survival.words_solved(),
+                    best: self.statistics.longest_survival_run(),
+                }),
+                Style::default()
+                    .fg(self.theme.correct)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(multi) = &self.multi {
+            let solved = (0..multi.board_count())
+                .filter(|&board| multi.is_solved(board))
+                .count();
+            lines.push(Line::styled(
+                strings.text(&TextKey::BoardsProgress {
+                    solved,
+                    boards: multi.board_count(),
+                    guesses_left: multi.guesses_remaining(),
+                }),
+                Style::default()
+                    .fg(self.theme.correct)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(sequence) = &self.sequence {
+            lines.push(Line::styled(
+                strings.text(&TextKey::SequenceProgress {
+                    solved: sequence.words_solved(),
+                }),
+                Style::default()
+                    .fg(self.theme.correct)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        let title = Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(title, area);
+
+        // Playing time on the right, for speed runs
+        if let Some(game) = self.current_game() {
+            let clock = Paragraph::new(format!("{} ", clock(game.elapsed())))
+                .style(Style::default().fg(self.theme.text))
+                .alignment(ratatui::layout::Alignment::Right);
+            frame.render_widget(clock, Rect { height: 1, ..area });
+        }
+    }
+
+    fn render_board(&self, frame: &mut Frame, area: Rect) {
+        let shake = self.shake.and_then(|shake| shake.offset(Instant::now()));
+        match (self.current_game(), &self.cross) {
+            (Some(game), _) => {
+                // In the free row above the board, until the first guess
+                if let Some(opener) = &self.suggested_opener
+                    && game.guesses().is_empty()
+                {
+                    let suggestion = Paragraph::new(
+                        self.strings()
+                            .text(&TextKey::TryOpener(opener.to_string().to_uppercase())),
+                    )
+                    .style(Style::default().fg(self.theme.not_in_word))
+                    .alignment(ratatui::layout::Alignment::Center);
+                    frame.render_widget(suggestion, Rect { height: 1, ..area });
+                }
+                let area = if self.solver_panel {
+                    self.render_solver_panel(frame, area)
+                } else {
+                    area
+                };
+                let board = BoardWidget::new(game, self.input.as_str(), &self.theme)
+                    .cursor(Some(self.input.cursor()))
+                    .shake(shake)
+                    .tile_style(self.settings.tile_style)
+                    .feedback_style(self.settings.feedback_style);
+                frame.render_widget(board, area);
+            }
+            (None, Some(cross)) => {
+                let board =
+                    CrossBoardWidget::new(cross, self.input.as_str(), &self.theme).shake(shake);
+                frame.render_widget(board, area);
+            }
+            (None, None) => {
+                if let Some(multi) = &self.multi {
+                    let board =
+                        MultiBoardWidget::new(multi, self.input.as_str(), &self.theme).shake(shake);
+                    frame.render_widget(board, area);
+                }
+            }
+        }
+    }
+
+    /// Draw the solver panel on the right of `area`, returning the space
+    /// left for the board: centered if there is room for it, otherwise
+    /// next to the panel
+    fn render_solver_panel(&self, frame: &mut Frame, area: Rect) -> Rect {
+        let centered = area.width >= 2 * SOLVER_PANEL_WIDTH + BOARD_WIDTH;
+        let [board, panel] = if centered {
+            let [_, board, panel] = Layout::horizontal([
+                Constraint::Length(SOLVER_PANEL_WIDTH),
+                Constraint::Min(0),
+                Constraint::Length(SOLVER_PANEL_WIDTH),
+            ])
+            .areas(area);
+            [board, panel]
+        } else {
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(SOLVER_PANEL_WIDTH)])
+                .areas(area)
+        };
+        let panel = Rect {
+            height: panel.height.min(SOLVER_SUGGESTIONS as u16 + 2),
+            ..panel
+        };
+        let widget = SolverPanelWidget::new(&self.suggestions, &self.theme)
+            .pending(self.pending_suggestions.is_some());
+        frame.render_widget(widget, panel);
+        board
+    }
+
+    fn render_stats(&self, frame: &mut Frame, area: Rect) {
+        let strings = self.strings();
+        let stats = &self.statistics;
+        let curiosity = stats.curiosity();
+        let letters = curiosity
+            .most_used_letters(5)
+            .iter()
+            .map(|(letter, count)| format!("{} ({})", letter.to_uppercase(), count))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let opener = curiosity.favorite_opener().map_or("-".to_string(), |(word, count)| {
+            format!("{} ({}x)", word.to_string().to_uppercase(), count)
+        });
+        let fastest = curiosity.fastest_solve().map_or("-".to_string(), clock);
+        let lines = vec![
+            Line::styled(
+                "STATISTICS",
+                Style::default()
+                    .fg(self.theme.text)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Line::raw(strings.text(&TextKey::StatsSummary {
+                played: stats.games_played(),
+                win_percentage: stats.win_percentage(),
+                streak: stats.current_streak(),
+                best_streak: stats.max_streak(),
+            })),
+            Line::raw(strings.text(&TextKey::MostUsedLetters(letters))),
+            Line::raw(strings.text(&TextKey::FavoriteOpener(opener))),
+            Line::raw(strings.text(&TextKey::GreensPerGuess(
+                curiosity.average_greens_per_guess(),
+            ))),
+            Line::raw(strings.text(&TextKey::FastestSolve(fastest))),
+        ];
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.text))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(paragraph, area);
+    }
+
+    /// A list of entries with one of them highlighted, e.g. a menu
+    fn render_list(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        title: &str,
+        items: impl Iterator<Item = String>,
+        selected: usize,
+    ) {
+        let mut lines = vec![
+            Line::styled(
+                title.to_string(),
+                Style::default()
+                    .fg(self.theme.text)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Line::raw(""),
+        ];
+        for (index, item) in items.enumerate() {
+            lines.push(if index == selected {
+                Line::styled(
+                    format!("> {item} <"),
+                    Style::default()
+                        .fg(self.theme.correct)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Line::raw(item)
+            });
+        }
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.text))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Tell which colors of theme.toml the text is hard to read on, at the
+    /// bottom of the settings
+    fn render_contrast_warnings(&self, frame: &mut Frame, area: Rect) {
+        if self.contrast_warnings.is_empty() {
+            return;
+        }
+        let strings = self.strings();
+        let mut lines: Vec<Line> = self
+            .contrast_warnings
+            .iter()
+            .map(|warning| Line::raw(strings.text(&TextKey::HardToRead(warning.to_string()))))
+            .collect();
+        lines.push(Line::raw(strings.text(&TextKey::ContrastFix {
+            readable_text: self.settings.readable_text,
+        })));
+        let height = (lines.len() as u16).min(area.height);
+        let area = Rect {
+            y: area.bottom() - height,
+            height,
+            ..area
+        };
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.wrong_position))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_whats_new(&self, frame: &mut Frame, area: Rect, releases: &[&Release]) {
+        let mut lines = Vec::new();
+        for release in releases {
+            lines.push(Line::styled(
+                format!("WHAT'S NEW IN {}", release.version),
+                Style::default()
+                    .fg(self.theme.correct)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            lines.extend(release.highlights.iter().map(|highlight| Line::raw(format!("• {highlight}"))));
+            lines.push(Line::raw(""));
+        }
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.text))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_history(&self, frame: &mut Frame, area: Rect, selected: usize) {
+        let [header, list] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+        let strings = self.strings();
+        let summary = strings.text(&TextKey::HistorySummary {
+            games: self.past_games.len(),
+            session: self.past_games.len() - self.games_before_session,
+        });
+        let lines = vec![
+            Line::styled(
+                "HISTORY",
+                Style::default()
+                    .fg(self.theme.text)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Line::raw(summary),
+        ];
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.text))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(paragraph, header);
+
+        let items: Vec<String> = self
+            .past_games
+            .iter()
+            .rev()
+            .map(|record| history_line(strings, record))
+            .collect();
+        frame.render_widget(ScrollListWidget::new(&items, selected, &self.theme), list);
+    }
+
+    fn render_past_game(&self, frame: &mut Frame, area: Rect, index: usize) {
+        let Some(record) = self.past_game(index) else {
+            return;
+        };
+        let strings = self.strings();
+        let [header, board] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+        let lines = vec![
+            Line::styled(
+                strings.text(&TextKey::PastGameTitle {
+                    game: index + 1,
+                    games: self.past_games.len(),
+                }),
+                Style::default()
+                    .fg(self.theme.text)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Line::raw(history_line(strings, record)),
+        ];
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.text))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(paragraph, header);
+
+        match record.replay() {
+            Some(game) => frame.render_widget(
+                BoardWidget::new(&game, "", &self.theme)
+                    .tile_style(self.settings.tile_style)
+                    .feedback_style(self.settings.feedback_style),
+                board,
+            ),
+            None => frame.render_widget(
+                Paragraph::new(strings.text(&TextKey::BoardNotShown))
+                    .alignment(ratatui::layout::Alignment::Center),
+                board,
+            ),
+        }
+    }
+
+    fn render_about(&self, frame: &mut Frame, area: Rect) {
+        let strings = self.strings();
+        let word_list = self.word_pool.metadata().map(|metadata| {
+            (
+                metadata.words.map_or("?".to_string(), |words| words.to_string()),
+                metadata.built.clone().unwrap_or("?".to_string()),
+                metadata.recipe.clone().unwrap_or("?".to_string()),
+            )
+        });
+        let lines = vec![
+            Line::styled(
+                "ABOUT",
+                Style::default()
+                    .fg(self.theme.text)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Line::raw(""),
+            Line::raw(format!("Wordle {}", changelog::CURRENT_VERSION)),
+            Line::raw(strings.text(&TextKey::Dictionary {
+                language: self.language,
+                words: self.word_pool.len(),
+            })),
+            Line::raw(strings.text(&TextKey::WordList(word_list))),
+            Line::raw(""),
+            Line::styled(
+                strings.text(&TextKey::BugReportHint),
+                Style::default().fg(self.theme.not_in_word),
+            ),
+        ];
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.text))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_analysis(&self, frame: &mut Frame, area: Rect) {
+        let strings = self.strings();
+        let secret = self
+            .current_game()
+            .and_then(Game::secret)
+            .map_or(String::new(), |secret| secret.to_string().to_uppercase());
+        let mut lines = vec![
+            Line::styled(
+                strings.text(&TextKey::AnalysisTitle),
+                Style::default()
+                    .fg(self.theme.text)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Line::raw(strings.message(&MessageKey::SecretsWere(vec![secret]))),
+            Line::raw(""),
+        ];
+        if self.pending_analysis.is_some() {
+            lines.push(Line::styled(
+                strings.text(&TextKey::AnalysisPending),
+                Style::default().fg(self.theme.not_in_word),
+            ));
+        } else {
+            lines.push(Line::styled(
+                strings.text(&TextKey::AnalysisHeader),
+                Style::default().fg(self.theme.not_in_word),
+            ));
+            lines.extend(self.analysis.iter().enumerate().map(|(index, step)| {
+                let (best, best_bits) = step.best.as_ref().map_or(
+                    ("-".to_string(), "-".to_string()),
+                    |(word, bits)| (word.to_string().to_uppercase(), format!("{bits:.2}")),
+                );
+                Line::raw(format!(
+                    "{:>2}  {}  {:>10}  {:>4.2}   {:<13}  {:>4}",
+                    index + 1,
+                    step.guess.to_string().to_uppercase(),
+                    step.candidates_before,
+                    step.bits,
+                    best,
+                    best_bits,
+                ))
+            }));
+        }
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.text))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_letter_bank(&self, frame: &mut Frame, area: Rect) {
+        if let Some(bank) = self.current_game().and_then(Game::letter_bank) {
+            let widget = LetterBankWidget::new(bank, &self.theme);
+            frame.render_widget(widget, area);
+        }
+    }
+
+    /// The message under the board once a game is over
+    fn message(&self) -> Option<Message> {
+        let (severity, mut parts) = match self.playable().state() {
+            GameState::Won { guesses_used } => {
+                let mut parts = vec![MessageKey::Won { guesses_used }];
+                if let Some(score) = self.current_game().and_then(Game::score) {
+                    parts.push(MessageKey::Score(score.total()));
+                }
+                (Severity::Success, parts)
+            }
+            state @ (GameState::Lost | GameState::Forfeited) => {
+                let (severity, headline) = if state == GameState::Forfeited {
+                    (Severity::Warning, MessageKey::GaveUp)
+                } else {
+                    (Severity::Error, MessageKey::GameOver)
+                };
+                let secrets = MessageKey::SecretsWere(
+                    self.playable()
+                        .secrets()
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|w| self.word_pool.display_form(w).to_uppercase())
+                        .collect(),
+                );
+                let parts = if let Some(sequence) = &self.sequence {
+                    vec![
+                        headline,
+                        secrets,
+                        MessageKey::SequenceOver(sequence.words_solved()),
+                    ]
+                } else if let Some(survival) = &self.survival {
+                    vec![MessageKey::RunOver(survival.words_solved()), secrets]
+                } else {
+                    vec![headline, secrets]
+                };
+                (severity, parts)
+            }
+            // Still playing
+            _ => return None,
+        };
+        parts.push(self.game_over_prompt());
+        let message = Message::new(severity, parts);
+        Some(match &self.definition {
+            Some(definition) => message.with_detail(MessageKey::Similar {
+                synonyms: definition.synonyms.iter().take(MAX_SYNONYMS).cloned().collect(),
+                url: definition.url.clone(),
+            }),
+            None => message,
+        })
+    }
+
+    /// What to do next once a game is over
+    fn game_over_prompt(&self) -> MessageKey {
+        match &self.pack {
+            Some(pack) if pack.progress().is_complete() => MessageKey::PackComplete {
+                solved: pack.progress().solved(),
+                total: pack.pack().len(),
+            },
+            Some(_) => MessageKey::PlayAgain {
+                next_puzzle: true,
+                can_copy: true,
+            },
+            None => MessageKey::PlayAgain {
+                next_puzzle: false,
+                can_copy: self.current_game().is_some(),
+            },
+        }
+    }
+
+    fn render_keyboard(&self, frame: &mut Frame, area: Rect) {
+        // Only single-word puzzles have one set of words still possible
+        let letter_counts = self
+            .current_game()
+            .filter(|_| self.settings.assist)
+            .map(|game| {
+                Candidates::from_guesses(&self.word_pool, game.guesses())
+                    .letter_counts()
+                    .into_iter()
+                    .map(|(letter, count)| (letter.char(), count))
+                    .collect::<HashMap<_, _>>()
+            });
+        let keyboard =
+            KeyboardWidget::new(&self.keyboard_state, self.settings.keyboard_layout, &self.theme)
+                .sorted_by(letter_counts.as_ref())
+                .feedback_style(self.settings.feedback_style);
+        frame.render_widget(keyboard, area);
+    }
+
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let key = if self.demo.is_some() {
+            Some(TextKey::DemoMode)
+        } else if self.pack.is_some() {
+            Some(TextKey::PackMode)
+        } else if self.survival.is_some() {
+            Some(TextKey::SurvivalMode)
+        } else if self.sequence.is_some() {
+            Some(TextKey::SequenceMode)
+        } else if self.cross.is_some() {
+            Some(TextKey::CrossMode)
+        } else if let Some(multi) = &self.multi {
+            Some(TextKey::BoardsMode {
+                boards: multi.board_count(),
+            })
+        } else if self.practicing {
+            Some(TextKey::PracticeFilterMode)
+        } else {
+            self.daily_number.map(TextKey::DailyMode)
+        };
+        let mode = match key {
+            Some(key) => self.strings().text(&key),
+            None => self.mode.label().to_string(),
+        };
+        let (hard_mode, hints_left) = match self.current_game() {
+            Some(game) => (game.config().hard_mode, game.hints_left()),
+            None => (self.config.hard_mode, None),
+        };
+        let widget = StatusBarWidget::new(mode, &self.theme)
+            .hard_mode(hard_mode)
+            .streak(self.statistics.current_streak())
+            .hints_left(hints_left);
+        frame.render_widget(widget, area);
     }
 
     fn render_help(&self, frame: &mut Frame, area: Rect) {
-        let help = Paragraph::new("Type letters to guess | Backspace to delete | Enter to submit | Esc to quit")
+        let strings = self.strings();
+        let hint_keys = match self.current_game() {
+            Some(game) => strings.hint_keys(game.hints_left()),
+            None => String::new(),
+        };
+        let text = match self.screen() {
+            Screen::Playing if self.demo.is_some() => strings.demo_help().to_string(),
+            screen => strings.help(screen, &hint_keys),
+        };
+        let help = Paragraph::new(text)
             .style(Style::default().fg(self.theme.not_in_word))
             .alignment(ratatui::layout::Alignment::Center);
         frame.render_widget(help, area);
     }
 }
+
+/// A finished game in the history list, e.g. "HELLO  Won in 3 guesses  1:05"
+fn history_line(strings: Strings, record: &GameRecord) -> String {
+    let result = strings.text(&TextKey::GameResult(record.state.clone()));
+    let time = record
+        .seconds
+        .map_or("-".to_string(), |seconds| clock(Duration::from_secs(seconds)));
+    format!("{}  {result}  {time}", record.secret.to_uppercase())
+}
+
+/// The result of a worker thread once it is ready. A worker that panicked
+/// delivers nothing, e.g. leaves the solver panel empty.
+fn receive<T: Default>(pending: &mut Option<Receiver<T>>) -> Option<T> {
+    let result = match pending.as_ref()?.try_recv() {
+        Ok(result) => result,
+        Err(TryRecvError::Empty) => return None,
+        Err(TryRecvError::Disconnected) => T::default(),
+    };
+    *pending = None;
+    Some(result)
+}
+
+/// A playing time as minutes and seconds, e.g. "1:05"
+fn clock(time: Duration) -> String {
+    format!("{}:{:02}", time.as_secs() / 60, time.as_secs() % 60)
+}
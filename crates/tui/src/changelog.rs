@@ -0,0 +1,81 @@
+//! Embedded changelog, shown as a "what's new" screen after upgrading.
+
+/// Version of the running application
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Notable changes of one release
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Release {
+    pub version: &'static str,
+    /// One line per feature, short enough to fit the screen
+    pub highlights: &'static [&'static str],
+}
+
+/// All releases, newest first
+pub const CHANGELOG: &[Release] = &[Release {
+    version: "0.1.0",
+    highlights: &[
+        "Survival, sequence and cross variants (Ctrl+E, Ctrl+S, Ctrl+X)",
+        "Statistics page with your favorite opener (Ctrl+T)",
+        "Ban openers and letters per profile with `wordle ban`",
+        "Keep a journal of finished games with --journal",
+    ],
+}];
+
+/// Releases newer than `last_run`, up to the running version, newest first.
+/// Empty if `last_run` can't be parsed.
+pub fn releases_since(last_run: &str) -> Vec<&'static Release> {
+    let (Some(last_run), Some(current)) = (parse_version(last_run), parse_version(CURRENT_VERSION))
+    else {
+        return Vec::new();
+    };
+    CHANGELOG
+        .iter()
+        .filter(|release| {
+            parse_version(release.version)
+                .is_some_and(|version| version > last_run && version <= current)
+        })
+        .collect()
+}
+
+/// Parse "major.minor.patch", ignoring pre-release and build suffixes
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let core = version.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|part| part.parse().ok());
+    let version = (parts.next()??, parts.next()??, parts.next()??);
+    parts.next().is_none().then_some(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("1.2.3"), Some((1, 2, 3)));
+        assert_eq!(parse_version("0.10.0-beta.1"), Some((0, 10, 0)));
+        assert_eq!(parse_version("1.2"), None);
+        assert_eq!(parse_version("1.2.3.4"), None);
+        assert_eq!(parse_version("latest"), None);
+    }
+
+    #[test]
+    fn test_changelog_is_sorted_and_current() {
+        let versions: Vec<_> = CHANGELOG
+            .iter()
+            .map(|release| parse_version(release.version).unwrap())
+            .collect();
+        assert!(versions.windows(2).all(|pair| pair[0] > pair[1]));
+        assert_eq!(CHANGELOG[0].version, CURRENT_VERSION);
+    }
+
+    #[test]
+    fn test_releases_since() {
+        assert_eq!(releases_since(CURRENT_VERSION), Vec::<&Release>::new());
+        assert_eq!(
+            releases_since("0.0.1"),
+            CHANGELOG.iter().collect::<Vec<_>>()
+        );
+        assert!(releases_since("garbage").is_empty());
+    }
+}
@@ -0,0 +1,29 @@
+//! Copying to the system clipboard with the OSC 52 escape sequence, which
+//! most terminals support, also over SSH.
+
+use std::io::{self, Write};
+
+use crate::base64;
+
+/// Ask the terminal to put `text` into the clipboard. Terminals without
+/// OSC 52 support ignore this, so success doesn't guarantee a copy.
+pub fn copy(text: &str) -> io::Result<()> {
+    let mut out = io::stdout();
+    out.write_all(osc52(text).as_bytes())?;
+    out.flush()
+}
+
+/// The escape sequence setting the clipboard to `text`
+fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64::encode(text.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52() {
+        assert_eq!(osc52("hi"), "\x1b]52;c;aGk=\x07");
+    }
+}
@@ -0,0 +1,141 @@
+//! A bot playing on its own, for demos and to watch the solver at work.
+//! The app asks it for a move whenever one is due, and the bot picks its
+//! guesses on a worker thread so the screen keeps up meanwhile.
+
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use wordle_game::solver::guesses_within_budget;
+use wordle_game::{
+    Candidates, EntropyStrategy, GuessFeedback, RandomStrategy, Strategy, Word, WordPool,
+};
+
+use crate::simulate::SimulationStrategy;
+
+/// Most feedbacks the bot computes to pick a guess, so it isn't slower
+/// than its delay on large pools
+const DEMO_BUDGET: usize = 2_000_000;
+
+/// The bot and when it moves next
+pub struct Demo {
+    strategy: SimulationStrategy,
+    /// Pause between the bot's moves
+    delay: Duration,
+    /// When the bot makes its next move
+    next_move: Instant,
+    /// Delivers the guess being picked on a worker thread
+    pending: Option<Receiver<Option<Word>>>,
+}
+
+impl Demo {
+    pub fn new(strategy: SimulationStrategy, delay: Duration, now: Instant) -> Self {
+        Self {
+            strategy,
+            delay,
+            next_move: now + delay,
+            pending: None,
+        }
+    }
+
+    /// When the bot makes its next move
+    pub fn next_move(&self) -> Instant {
+        self.next_move
+    }
+
+    /// Whether a move is due at `now`, and the bot isn't busy picking one
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.pending.is_none() && now >= self.next_move
+    }
+
+    /// Make the next move a delay after `now`
+    pub fn wait(&mut self, now: Instant) {
+        self.next_move = now + self.delay;
+    }
+
+    /// Whether the bot is picking a guess
+    pub fn is_thinking(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Start picking a guess for the puzzle with `guesses` so far
+    pub fn think(&mut self, word_pool: &WordPool, guesses: &[GuessFeedback]) {
+        let word_pool = word_pool.clone();
+        let guesses = guesses.to_vec();
+        let strategy = self.strategy;
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            sender.send(pick(strategy, &word_pool, &guesses)).ok();
+        });
+        self.pending = Some(receiver);
+    }
+
+    /// The guess the bot picked once it is ready. None inside means the
+    /// bot has no idea.
+    pub fn receive(&mut self) -> Option<Option<Word>> {
+        let guess = match self.pending.as_ref()?.try_recv() {
+            Ok(guess) => guess,
+            Err(TryRecvError::Empty) => return None,
+            // The worker panicked
+            Err(TryRecvError::Disconnected) => None,
+        };
+        self.pending = None;
+        Some(guess)
+    }
+}
+
+fn pick(
+    strategy: SimulationStrategy,
+    word_pool: &WordPool,
+    guesses: &[GuessFeedback],
+) -> Option<Word> {
+    let candidates = Candidates::from_guesses(word_pool, guesses);
+    match strategy {
+        SimulationStrategy::Entropy => {
+            // Nothing left to learn
+            if candidates.len() <= 2 {
+                return candidates.words().first().cloned();
+            }
+            let options =
+                guesses_within_budget(word_pool, &candidates, guesses.is_empty(), DEMO_BUDGET);
+            EntropyStrategy
+                .ranked_among(&options, &candidates, 1)
+                .into_iter()
+                .next()
+                .map(|(word, _)| word)
+        }
+        SimulationStrategy::Random => RandomStrategy.next_guess(word_pool, &candidates),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_picks_guesses() {
+        let pool = WordPool::from_strings(["hatch", "latch", "match", "crane"].map(String::from));
+        let now = Instant::now();
+        let mut demo = Demo::new(SimulationStrategy::Entropy, Duration::from_secs(1), now);
+        assert!(!demo.is_due(now));
+        assert!(demo.is_due(now + Duration::from_secs(1)));
+
+        let secret = Word::parse("latch").unwrap();
+        let guesses = [GuessFeedback::evaluate(
+            &Word::parse("hatch").unwrap(),
+            &secret,
+        )];
+        demo.think(&pool, &guesses);
+        assert!(demo.is_thinking());
+        let guess = loop {
+            if let Some(guess) = demo.receive() {
+                break guess;
+            }
+            thread::sleep(Duration::from_millis(1));
+        };
+        assert!(!demo.is_thinking());
+        // Only "latch" and "match" are left
+        let guess = guess.unwrap().to_string();
+        assert!(guess == "latch" || guess == "match", "{guess}");
+    }
+}
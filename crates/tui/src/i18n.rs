@@ -0,0 +1,797 @@
+//! Text of the interface in the languages the word lists come in. Words,
+//! menus and settings are named the same in all of them; what changes is
+//! how the game talks to the player: messages, toasts, results and help.
+
+use wordle_game::{DictionaryCheck, GameState, GuessError, GuessWarning, Language};
+
+use crate::message::MessageKey;
+use crate::screen::Screen;
+use crate::toast::ToastKey;
+
+/// A piece of text on one of the pages, put into words in the language of
+/// the interface by [`Strings::text`]. Page titles and menus are named the
+/// same in all languages.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextKey {
+    /// The question of the quit dialog
+    ConfirmQuit,
+    /// Progress through a pack, under the title
+    PackProgress {
+        seed: String,
+        puzzle: usize,
+        puzzles: usize,
+        solved: usize,
+    },
+    /// The shared budget of a survival run, under the title
+    SurvivalProgress {
+        budget: usize,
+        solved: usize,
+        best: usize,
+    },
+    /// Progress on a multi-board game, under the title
+    BoardsProgress {
+        solved: usize,
+        boards: usize,
+        guesses_left: usize,
+    },
+    /// Words solved in a sequence, under the title
+    SequenceProgress {
+        solved: usize,
+    },
+    /// An opener to start with, above the empty board
+    TryOpener(String),
+    /// The counts of the statistics page
+    StatsSummary {
+        played: usize,
+        win_percentage: f64,
+        streak: usize,
+        best_streak: usize,
+    },
+    /// Letters with their counts, empty if there are none yet
+    MostUsedLetters(String),
+    /// The opener with its count, "-" if there is none yet
+    FavoriteOpener(String),
+    GreensPerGuess(f64),
+    /// The time of the fastest solve, "-" if there is none yet
+    FastestSolve(String),
+    /// A color of theme.toml the text is hard to read on
+    HardToRead(String),
+    /// What to do about hard to read colors, or what was done already
+    ContrastFix {
+        readable_text: bool,
+    },
+    /// The count of games on the history page
+    HistorySummary {
+        games: usize,
+        session: usize,
+    },
+    /// Title of a past game with its position in the history
+    PastGameTitle {
+        game: usize,
+        games: usize,
+    },
+    /// A past game whose board can't be replayed
+    BoardNotShown,
+    /// How a game ended, e.g. in the history
+    GameResult(GameState),
+    /// The dictionary of the About page
+    Dictionary {
+        language: Language,
+        words: usize,
+    },
+    /// Where the word list of the About page comes from. None if it isn't
+    /// a built-in list; otherwise its word count, build date and recipe.
+    WordList(Option<(String, String, String)>),
+    /// Below the details of the About page
+    BugReportHint,
+    /// Modes in the status bar, besides the game modes of the menu
+    DemoMode,
+    PackMode,
+    SurvivalMode,
+    SequenceMode,
+    CrossMode,
+    BoardsMode {
+        boards: usize,
+    },
+    PracticeFilterMode,
+    DailyMode(u64),
+    /// Title of the analysis page
+    AnalysisTitle,
+    /// While the analysis is still being computed
+    AnalysisPending,
+    /// Column headings of the analysis, aligned with its rows
+    AnalysisHeader,
+}
+
+/// The interface text in one language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Strings {
+    language: Language,
+}
+
+impl Strings {
+    pub fn new(language: Language) -> Self {
+        Self { language }
+    }
+
+    /// Why a guess was rejected
+    pub fn guess_error(&self, err: &GuessError) -> String {
+        if self.language == Language::English {
+            return err.to_string();
+        }
+        match err {
+            GuessError::TooShort { .. } => "Zu wenige Buchstaben".to_string(),
+            GuessError::TooLong { .. } => "Zu viele Buchstaben".to_string(),
+            GuessError::NonAlphabetic { char } => format!("'{char}' ist kein Buchstabe"),
+            GuessError::NotInWordList => "Nicht im Wörterbuch".to_string(),
+            GuessError::NotInLetterBank { letter } => format!(
+                "Nicht genug {} in der Buchstabenbank übrig",
+                letter.to_uppercase()
+            ),
+            GuessError::GameOver => "Das Spiel ist vorbei".to_string(),
+            GuessError::AlreadySolved => "Wort schon gelöst".to_string(),
+            GuessError::AlreadyGuessed => "Schon geraten".to_string(),
+            GuessError::BannedLetter { letter } => {
+                format!("{} ist nicht erlaubt", letter.to_uppercase())
+            }
+            GuessError::BannedOpener => "Als erster Versuch nicht erlaubt".to_string(),
+            GuessError::HardModeViolation {
+                letter,
+                position: Some(position),
+            } => format!(
+                "{} muss an Position {} stehen",
+                letter.to_uppercase(),
+                position + 1
+            ),
+            GuessError::HardModeViolation {
+                letter,
+                position: None,
+            } => format!("Der Versuch muss {} enthalten", letter.to_uppercase()),
+            GuessError::TooFast { .. } => "Zu schnell, bitte kurz warten".to_string(),
+            // Errors added later are shown untranslated
+            _ => err.to_string(),
+        }
+    }
+
+    /// A problem with a guess that was accepted anyway
+    pub fn guess_warning(&self, warning: &GuessWarning) -> String {
+        if self.language == Language::English {
+            return warning.to_string();
+        }
+        match warning {
+            GuessWarning::NotInWordList => "Nicht im Wörterbuch, aber akzeptiert".to_string(),
+            GuessWarning::BrokenRule(err) => format!("{}, aber akzeptiert", self.guess_error(err)),
+            _ => warning.to_string(),
+        }
+    }
+
+    /// A piece of a message in words
+    pub fn message(&self, key: &MessageKey) -> String {
+        match key {
+            MessageKey::Won { guesses_used } => self.won(*guesses_used),
+            MessageKey::Score(total) => self.score(*total),
+            MessageKey::GaveUp => self.gave_up().to_string(),
+            MessageKey::GameOver => self.game_over().to_string(),
+            MessageKey::SecretsWere(secrets) => self.secrets_were(secrets),
+            MessageKey::SequenceOver(words_solved) => self.sequence_over(*words_solved),
+            MessageKey::RunOver(words_solved) => self.run_over(*words_solved),
+            MessageKey::PackComplete { solved, total } => self.pack_complete(*solved, *total),
+            MessageKey::PlayAgain {
+                next_puzzle,
+                can_copy,
+            } => self.play_again(*next_puzzle, *can_copy).to_string(),
+            MessageKey::Similar { synonyms, url } => self.similar(synonyms, url.as_deref()),
+        }
+    }
+
+    /// The text of a toast
+    pub fn toast(&self, key: &ToastKey) -> String {
+        match self.language {
+            Language::English => self.english_toast(key),
+            Language::German => self.german_toast(key),
+        }
+    }
+
+    fn english_toast(&self, key: &ToastKey) -> String {
+        let on_off = |on: bool| if on { "on" } else { "off" };
+        match key {
+            ToastKey::GuessError(err) => self.guess_error(err),
+            ToastKey::GuessWarning(warning) => self.guess_warning(warning),
+            ToastKey::ThemeFileIgnored(err) => format!("Ignoring theme.toml: {err}"),
+            ToastKey::Theme(name) => format!("Theme: {name}"),
+            ToastKey::NoWordsMatchFilter => "No words match the filter".to_string(),
+            ToastKey::SettingsApplyNextGame => "Settings apply from the next game".to_string(),
+            ToastKey::NoHintsInVariant => "This variant has no hints".to_string(),
+            ToastKey::HintLetter { position, letter } => {
+                format!("Letter {} is {}", position + 1, letter.to_uppercase())
+            }
+            ToastKey::NoHintsLeft => "No hints left".to_string(),
+            ToastKey::AllLettersKnown => "Every letter is known already".to_string(),
+            ToastKey::NothingToCopy => "This variant has no result to copy".to_string(),
+            ToastKey::Copied => "Copied the result to the clipboard".to_string(),
+            ToastKey::CopyFailed(err) => format!("Could not copy the result: {err}"),
+            ToastKey::SurvivalSolved { bonus } => {
+                format!("Solved! +{bonus} guess, on to the next word")
+            }
+            ToastKey::ClassicUntilTomorrow => {
+                "Next games are classic until tomorrow's word".to_string()
+            }
+            ToastKey::Solver(true) => "Solver on, games played with it don't count".to_string(),
+            ToastKey::Solver(false) => "Solver off from the next game".to_string(),
+            ToastKey::LetterBank(on) => format!("Letter bank {} from the next game", on_off(*on)),
+            ToastKey::Cross(on) => format!("Cross variant {} from the next game", on_off(*on)),
+            ToastKey::Survival(on) => format!("Survival mode {} from the next game", on_off(*on)),
+            ToastKey::Sequence(on) => format!("Sequence mode {} from the next game", on_off(*on)),
+            ToastKey::DictionaryCheck(DictionaryCheck::WarnOnly) => {
+                "Words missing from the word list allowed with a warning from the next game"
+                    .to_string()
+            }
+            ToastKey::DictionaryCheck(DictionaryCheck::Off) => {
+                "Any word allowed from the next game".to_string()
+            }
+            ToastKey::DictionaryCheck(DictionaryCheck::Strict) => {
+                "Only words from the word list allowed from the next game".to_string()
+            }
+            ToastKey::WordListFailed { language, err } => {
+                format!("Could not load the {language} word list: {err}")
+            }
+            ToastKey::PackProgressNotSaved(err) => format!("Could not save pack progress: {err}"),
+            ToastKey::StatisticsNotSaved(err) => format!("Could not save the statistics: {err}"),
+            ToastKey::JournalNotWritten(err) => format!("Could not write to the journal: {err}"),
+            ToastKey::GameNotSaved(err) => format!("Could not save the game: {err}"),
+            ToastKey::SyncFailed(err) => format!("Could not sync the game history: {err}"),
+            ToastKey::HistoryUnreadable { path, err } => {
+                format!("Not saving games, {path} is unreadable: {err}")
+            }
+            ToastKey::StatisticsUnreadable(err) => format!("Could not read the statistics: {err}"),
+            ToastKey::StatisticsStartedOver(err) => {
+                format!("Statistics were unreadable, starting over: {err}")
+            }
+            ToastKey::StatisticsMovedAside { err, path } => {
+                format!("Statistics were unreadable ({err}), moved them to {path}")
+            }
+        }
+    }
+
+    fn german_toast(&self, key: &ToastKey) -> String {
+        let on_off = |on: bool| if on { "an" } else { "aus" };
+        match key {
+            ToastKey::GuessError(err) => self.guess_error(err),
+            ToastKey::GuessWarning(warning) => self.guess_warning(warning),
+            ToastKey::ThemeFileIgnored(err) => format!("theme.toml wird ignoriert: {err}"),
+            ToastKey::Theme(name) => format!("Farben: {name}"),
+            ToastKey::NoWordsMatchFilter => "Kein Wort passt zum Filter".to_string(),
+            ToastKey::SettingsApplyNextGame => {
+                "Einstellungen gelten ab dem nächsten Spiel".to_string()
+            }
+            ToastKey::NoHintsInVariant => "Diese Variante hat keine Tipps".to_string(),
+            ToastKey::HintLetter { position, letter } => {
+                format!("Buchstabe {} ist {}", position + 1, letter.to_uppercase())
+            }
+            ToastKey::NoHintsLeft => "Keine Tipps mehr übrig".to_string(),
+            ToastKey::AllLettersKnown => "Alle Buchstaben sind schon bekannt".to_string(),
+            ToastKey::NothingToCopy => "Diese Variante hat kein Ergebnis zum Kopieren".to_string(),
+            ToastKey::Copied => "Ergebnis in die Zwischenablage kopiert".to_string(),
+            ToastKey::CopyFailed(err) => format!("Ergebnis nicht kopiert: {err}"),
+            ToastKey::SurvivalSolved { bonus } => {
+                format!("Gelöst! +{bonus} Versuch, weiter zum nächsten Wort")
+            }
+            ToastKey::ClassicUntilTomorrow => {
+                "Bis zum Wort von morgen geht es klassisch weiter".to_string()
+            }
+            ToastKey::Solver(true) => "Löser an, Spiele mit ihm zählen nicht".to_string(),
+            ToastKey::Solver(false) => "Löser aus ab dem nächsten Spiel".to_string(),
+            ToastKey::LetterBank(on) => {
+                format!("Buchstabenbank {} ab dem nächsten Spiel", on_off(*on))
+            }
+            ToastKey::Cross(on) => format!("Kreuz-Variante {} ab dem nächsten Spiel", on_off(*on)),
+            ToastKey::Survival(on) => {
+                format!("Überlebensmodus {} ab dem nächsten Spiel", on_off(*on))
+            }
+            ToastKey::Sequence(on) => format!("Serienmodus {} ab dem nächsten Spiel", on_off(*on)),
+            ToastKey::DictionaryCheck(DictionaryCheck::WarnOnly) => {
+                "Wörter außerhalb des Wörterbuchs ab dem nächsten Spiel mit Warnung erlaubt"
+                    .to_string()
+            }
+            ToastKey::DictionaryCheck(DictionaryCheck::Off) => {
+                "Jedes Wort erlaubt ab dem nächsten Spiel".to_string()
+            }
+            ToastKey::DictionaryCheck(DictionaryCheck::Strict) => {
+                "Nur Wörter aus dem Wörterbuch erlaubt ab dem nächsten Spiel".to_string()
+            }
+            ToastKey::WordListFailed { language, err } => {
+                format!("Wortliste {language} nicht geladen: {err}")
+            }
+            ToastKey::PackProgressNotSaved(err) => {
+                format!("Fortschritt des Pakets nicht gespeichert: {err}")
+            }
+            ToastKey::StatisticsNotSaved(err) => format!("Statistik nicht gespeichert: {err}"),
+            ToastKey::JournalNotWritten(err) => format!("Tagebuch nicht geschrieben: {err}"),
+            ToastKey::GameNotSaved(err) => format!("Spiel nicht gespeichert: {err}"),
+            ToastKey::SyncFailed(err) => format!("Spielverlauf nicht abgeglichen: {err}"),
+            ToastKey::HistoryUnreadable { path, err } => {
+                format!("Spiele werden nicht gespeichert, {path} ist unlesbar: {err}")
+            }
+            ToastKey::StatisticsUnreadable(err) => format!("Statistik nicht gelesen: {err}"),
+            ToastKey::StatisticsStartedOver(err) => {
+                format!("Statistik war unlesbar, sie beginnt von vorn: {err}")
+            }
+            ToastKey::StatisticsMovedAside { err, path } => {
+                format!("Statistik war unlesbar ({err}), nach {path} verschoben")
+            }
+        }
+    }
+
+    /// The text of a page
+    pub fn text(&self, key: &TextKey) -> String {
+        match self.language {
+            Language::English => self.english_text(key),
+            Language::German => self.german_text(key),
+        }
+    }
+
+    fn english_text(&self, key: &TextKey) -> String {
+        match key {
+            TextKey::ConfirmQuit => "Quit this game? (y/n)".to_string(),
+            TextKey::PackProgress {
+                seed,
+                puzzle,
+                puzzles,
+                solved,
+            } => format!("PACK {seed}  Puzzle {puzzle}/{puzzles}  Solved: {solved}"),
+            TextKey::SurvivalProgress {
+                budget,
+                solved,
+                best,
+            } => format!("SURVIVAL  Budget: {budget}  Solved: {solved}  Best: {best}"),
+            TextKey::BoardsProgress {
+                solved,
+                boards,
+                guesses_left,
+            } => format!("Solved: {solved}/{boards}  Guesses left: {guesses_left}"),
+            TextKey::SequenceProgress { solved } => format!("SEQUENCE  Solved: {solved}"),
+            TextKey::TryOpener(word) => format!("Try {word}"),
+            TextKey::StatsSummary {
+                played,
+                win_percentage,
+                streak,
+                best_streak,
+            } => format!(
+                "Played: {played}  Won: {win_percentage:.0}%  Streak: {streak}  \
+                 Best streak: {best_streak}"
+            ),
+            TextKey::MostUsedLetters(letters) => format!(
+                "Most used letters: {}",
+                if letters.is_empty() { "-" } else { letters }
+            ),
+            TextKey::FavoriteOpener(opener) => format!("Favorite opener: {opener}"),
+            TextKey::GreensPerGuess(greens) => format!("Greens per guess: {greens:.2}"),
+            TextKey::FastestSolve(time) => format!("Fastest solve: {time}"),
+            TextKey::HardToRead(color) => format!("Hard to read: {color}"),
+            TextKey::ContrastFix {
+                readable_text: true,
+            } => "Text switched to black or white".to_string(),
+            TextKey::ContrastFix {
+                readable_text: false,
+            } => "Turn on readable text to fix".to_string(),
+            TextKey::HistorySummary { games: 0, .. } => "No finished games yet".to_string(),
+            TextKey::HistorySummary { games, session } => {
+                format!("{games} games, {session} this session, newest first")
+            }
+            TextKey::PastGameTitle { game, games } => format!("GAME {game} OF {games}"),
+            TextKey::BoardNotShown => "The board can't be shown".to_string(),
+            TextKey::GameResult(state) => state.to_string(),
+            TextKey::Dictionary { language, words } => {
+                format!("{language} dictionary: {words} playable words")
+            }
+            TextKey::WordList(Some((words, built, recipe))) => {
+                format!("Word list: {words} words, built {built}, recipe {recipe}")
+            }
+            TextKey::WordList(None) => "Word list: not from a built-in word list".to_string(),
+            TextKey::BugReportHint => "Please include these lines in bug reports".to_string(),
+            TextKey::DemoMode => "Demo".to_string(),
+            TextKey::PackMode => "Pack".to_string(),
+            TextKey::SurvivalMode => "Survival".to_string(),
+            TextKey::SequenceMode => "Sequence".to_string(),
+            TextKey::CrossMode => "Cross".to_string(),
+            TextKey::BoardsMode { boards } => format!("{boards} boards"),
+            TextKey::PracticeFilterMode => "Practice filter".to_string(),
+            TextKey::DailyMode(number) => format!("Daily #{number}"),
+            TextKey::AnalysisTitle => "ANALYSIS".to_string(),
+            TextKey::AnalysisPending => "Looking back at your guesses...".to_string(),
+            TextKey::AnalysisHeader => {
+                "   Guess  Words left  Bits   Solver's pick  Bits".to_string()
+            }
+        }
+    }
+
+    fn german_text(&self, key: &TextKey) -> String {
+        match key {
+            TextKey::ConfirmQuit => "Spiel beenden? (j/n)".to_string(),
+            TextKey::PackProgress {
+                seed,
+                puzzle,
+                puzzles,
+                solved,
+            } => format!("PAKET {seed}  Rätsel {puzzle}/{puzzles}  Gelöst: {solved}"),
+            TextKey::SurvivalProgress {
+                budget,
+                solved,
+                best,
+            } => format!("ÜBERLEBEN  Budget: {budget}  Gelöst: {solved}  Rekord: {best}"),
+            TextKey::BoardsProgress {
+                solved,
+                boards,
+                guesses_left,
+            } => format!("Gelöst: {solved}/{boards}  Versuche übrig: {guesses_left}"),
+            TextKey::SequenceProgress { solved } => format!("SERIE  Gelöst: {solved}"),
+            TextKey::TryOpener(word) => format!("Versuch es mit {word}"),
+            TextKey::StatsSummary {
+                played,
+                win_percentage,
+                streak,
+                best_streak,
+            } => format!(
+                "Gespielt: {played}  Gewonnen: {win_percentage:.0}%  Serie: {streak}  \
+                 Beste Serie: {best_streak}"
+            ),
+            TextKey::MostUsedLetters(letters) => format!(
+                "Häufigste Buchstaben: {}",
+                if letters.is_empty() { "-" } else { letters }
+            ),
+            TextKey::FavoriteOpener(opener) => format!("Liebster Anfang: {opener}"),
+            TextKey::GreensPerGuess(greens) => format!("Grüne pro Versuch: {greens:.2}"),
+            TextKey::FastestSolve(time) => format!("Schnellste Lösung: {time}"),
+            TextKey::HardToRead(color) => format!("Schwer lesbar: {color}"),
+            TextKey::ContrastFix {
+                readable_text: true,
+            } => "Text auf Schwarz oder Weiß umgestellt".to_string(),
+            TextKey::ContrastFix {
+                readable_text: false,
+            } => "Lesbaren Text einschalten hilft".to_string(),
+            TextKey::HistorySummary { games: 0, .. } => "Noch keine beendeten Spiele".to_string(),
+            TextKey::HistorySummary { games, session } => {
+                format!("{games} Spiele, {session} in dieser Sitzung, neueste zuerst")
+            }
+            TextKey::PastGameTitle { game, games } => format!("SPIEL {game} VON {games}"),
+            TextKey::BoardNotShown => "Das Spielfeld kann nicht gezeigt werden".to_string(),
+            TextKey::GameResult(state) => match state {
+                GameState::Playing => "Läuft".to_string(),
+                GameState::Won { guesses_used: 1 } => "Gewonnen mit 1 Versuch".to_string(),
+                GameState::Won { guesses_used } => {
+                    format!("Gewonnen mit {guesses_used} Versuchen")
+                }
+                GameState::Lost => "Verloren".to_string(),
+                GameState::Forfeited => "Aufgegeben".to_string(),
+                // States added later are shown untranslated
+                _ => state.to_string(),
+            },
+            TextKey::Dictionary { language, words } => {
+                format!("Wörterbuch ({language}): {words} spielbare Wörter")
+            }
+            TextKey::WordList(Some((words, built, recipe))) => {
+                format!("Wortliste: {words} Wörter, erstellt {built}, Rezept {recipe}")
+            }
+            TextKey::WordList(None) => "Wortliste: keine eingebaute Liste".to_string(),
+            TextKey::BugReportHint => "Bitte diese Zeilen in Fehlerberichte kopieren".to_string(),
+            TextKey::DemoMode => "Vorführung".to_string(),
+            TextKey::PackMode => "Paket".to_string(),
+            TextKey::SurvivalMode => "Überleben".to_string(),
+            TextKey::SequenceMode => "Serie".to_string(),
+            TextKey::CrossMode => "Kreuz".to_string(),
+            TextKey::BoardsMode { boards } => format!("{boards} Spielfelder"),
+            TextKey::PracticeFilterMode => "Übungsfilter".to_string(),
+            TextKey::DailyMode(number) => format!("Tagesrätsel #{number}"),
+            TextKey::AnalysisTitle => "ANALYSE".to_string(),
+            TextKey::AnalysisPending => "Deine Versuche werden nachgerechnet...".to_string(),
+            TextKey::AnalysisHeader => {
+                "   Wort        Übrig  Bits   Löser-Tipp     Bits".to_string()
+            }
+        }
+    }
+
+    /// Headline of a won game, e.g. "You won in 3 guesses!"
+    fn won(&self, guesses_used: usize) -> String {
+        let plural = guesses_used != 1;
+        match self.language {
+            Language::English => format!(
+                "You won in {guesses_used} guess{}!",
+                if plural { "es" } else { "" }
+            ),
+            Language::German => format!(
+                "Gewonnen mit {guesses_used} Versuch{}!",
+                if plural { "en" } else { "" }
+            ),
+        }
+    }
+
+    /// Points of a scored game, following the headline
+    fn score(&self, total: u32) -> String {
+        match self.language {
+            Language::English => format!("Score: {total}."),
+            Language::German => format!("Punkte: {total}."),
+        }
+    }
+
+    /// Headline of a game the player gave up on
+    fn gave_up(&self) -> &'static str {
+        match self.language {
+            Language::English => "You gave up!",
+            Language::German => "Aufgegeben!",
+        }
+    }
+
+    /// Headline of a lost game
+    fn game_over(&self) -> &'static str {
+        match self.language {
+            Language::English => "Game over!",
+            Language::German => "Verloren!",
+        }
+    }
+
+    /// The secret words of a finished game, e.g. "The word was HELLO."
+    fn secrets_were(&self, secrets: &[String]) -> String {
+        match (self.language, secrets) {
+            (Language::English, [secret]) => format!("The word was {secret}."),
+            (Language::English, _) => format!("The words were {}.", secrets.join(" and ")),
+            (Language::German, [secret]) => format!("Das Wort war {secret}."),
+            (Language::German, _) => format!("Die Wörter waren {}.", secrets.join(" und ")),
+        }
+    }
+
+    /// How far a sequence got before it ended
+    fn sequence_over(&self, words_solved: usize) -> String {
+        match self.language {
+            Language::English => format!("Sequence over after {}.", self.words(words_solved)),
+            Language::German => format!("Serie vorbei nach {}.", self.words(words_solved)),
+        }
+    }
+
+    /// How far a survival run got before it ended
+    fn run_over(&self, words_solved: usize) -> String {
+        match self.language {
+            Language::English => format!("Run over after {}!", self.words(words_solved)),
+            Language::German => format!("Lauf vorbei nach {}!", self.words(words_solved)),
+        }
+    }
+
+    /// E.g. "1 word" or "3 words"
+    fn words(&self, count: usize) -> String {
+        match (self.language, count) {
+            (Language::English, 1) => "1 word".to_string(),
+            (Language::English, _) => format!("{count} words"),
+            (Language::German, 1) => "1 Wort".to_string(),
+            (Language::German, _) => format!("{count} Wörtern"),
+        }
+    }
+
+    /// What to do once the last puzzle of a pack is over
+    fn pack_complete(&self, solved: usize, total: usize) -> String {
+        match self.language {
+            Language::English => {
+                format!("Pack complete, {solved} of {total} solved! Press Esc to quit.")
+            }
+            Language::German => {
+                format!("Paket geschafft, {solved} von {total} gelöst! Esc zum Beenden.")
+            }
+        }
+    }
+
+    /// What to do once a game is over. `next_puzzle` if there is another
+    /// puzzle of a pack, `can_copy` if the result can be shared.
+    fn play_again(&self, next_puzzle: bool, can_copy: bool) -> &'static str {
+        match (self.language, next_puzzle, can_copy) {
+            (Language::English, true, _) => {
+                "Press Enter for the next puzzle, C to copy the result."
+            }
+            (Language::English, false, true) => "Press Enter to play again, C to copy the result.",
+            (Language::English, false, false) => "Press Enter to play again.",
+            (Language::German, true, _) => "Enter für das nächste Rätsel, C kopiert das Ergebnis.",
+            (Language::German, false, true) => "Enter für ein neues Spiel, C kopiert das Ergebnis.",
+            (Language::German, false, false) => "Enter für ein neues Spiel.",
+        }
+    }
+
+    /// Words similar to the secret and where to read more, empty if
+    /// neither is known
+    fn similar(&self, synonyms: &[String], url: Option<&str>) -> String {
+        let label = match self.language {
+            Language::English => "Similar",
+            Language::German => "Ähnlich",
+        };
+        let synonyms = synonyms.join(", ");
+        match (synonyms.is_empty(), url) {
+            (false, Some(url)) => format!("{label}: {synonyms} · {url}"),
+            (false, None) => format!("{label}: {synonyms}"),
+            (true, Some(url)) => url.to_string(),
+            (true, None) => String::new(),
+        }
+    }
+
+    /// Keys for hints and the solver while playing, with the number of
+    /// hints left if they are limited
+    pub fn hint_keys(&self, hints_left: Option<usize>) -> String {
+        match (self.language, hints_left) {
+            (Language::English, Some(left)) => format!(" | F2 hint ({left} left) | F3 solver"),
+            (Language::English, None) => " | F2 hint | F3 solver".to_string(),
+            (Language::German, Some(left)) => format!(" | F2 Tipp (noch {left}) | F3 Löser"),
+            (Language::German, None) => " | F2 Tipp | F3 Löser".to_string(),
+        }
+    }
+
+    /// Title of the terminal window, with the guess being typed and the
+    /// maximum while a game is on
+    pub fn terminal_title(&self, guess: Option<(usize, usize)>) -> String {
+        match (self.language, guess) {
+            (_, None) => "Wordle".to_string(),
+            (Language::English, Some((guess, max))) => format!("Wordle – guess {guess}/{max}"),
+            (Language::German, Some((guess, max))) => format!("Wordle – Versuch {guess}/{max}"),
+        }
+    }
+
+    /// The keys while a bot plays
+    pub fn demo_help(&self) -> &'static str {
+        match self.language {
+            Language::English => "A bot is playing | Esc to quit",
+            Language::German => "Ein Bot spielt | Esc zum Beenden",
+        }
+    }
+
+    /// The keys of a screen, shown at the bottom. `hint_keys` is added to
+    /// the keys of the board.
+    pub fn help(&self, screen: &Screen, hint_keys: &str) -> String {
+        match self.language {
+            Language::English => match screen {
+                Screen::Playing => format!(
+                    "Type letters to guess | Backspace to delete | Left/Right to move | \
+                     Ctrl+U to clear | Enter to submit{hint_keys} | Ctrl+O menu | \
+                     Ctrl+B letter bank | Ctrl+D dictionary | Ctrl+X cross | Ctrl+E survival | \
+                     Ctrl+S sequence | Ctrl+R reveal | Ctrl+T stats | F4 theme | Esc to quit"
+                ),
+                Screen::Stats => "Ctrl+T or Esc to go back".to_string(),
+                Screen::About => "Esc to go back".to_string(),
+                Screen::Analysis => "Enter or Esc to go back to the board".to_string(),
+                Screen::PracticeFilter(_) => "Up/Down to choose | Left/Right or a letter to \
+                                              change | Backspace for any letter | Enter on Play \
+                                              to start | Esc to go back"
+                    .to_string(),
+                Screen::History(_) => {
+                    "Up/Down to choose | Enter to show the board | Esc to go back".to_string()
+                }
+                Screen::PastGame(_) => "Left/Right for other games | Esc to go back".to_string(),
+                Screen::WhatsNew(_) => "Enter to continue | N to never show this again".to_string(),
+                Screen::Menu(_) => {
+                    "Up/Down to choose | Enter to select | Esc to go back".to_string()
+                }
+                Screen::Settings(_) => {
+                    "Up/Down to choose | Left/Right to change | Esc to go back".to_string()
+                }
+                Screen::ModeSelect(_) => {
+                    "Up/Down to choose | Enter to play | Esc to keep the current mode".to_string()
+                }
+                Screen::ConfirmQuit => "Y to quit | N or Esc to keep playing".to_string(),
+            },
+            Language::German => match screen {
+                Screen::Playing => format!(
+                    "Buchstaben tippen zum Raten | Rücktaste zum Löschen | Links/Rechts \
+                     bewegen | Ctrl+U leeren | Enter zum Abschicken{hint_keys} | Ctrl+O Menü | \
+                     Ctrl+B Buchstabenbank | Ctrl+D Wörterbuch | Ctrl+X Kreuz | \
+                     Ctrl+E Überleben | Ctrl+S Serie | Ctrl+R aufdecken | Ctrl+T Statistik | \
+                     F4 Farben | Esc zum Beenden"
+                ),
+                Screen::Stats => "Ctrl+T oder Esc zurück".to_string(),
+                Screen::About => "Esc zurück".to_string(),
+                Screen::Analysis => "Enter oder Esc zurück zum Spielfeld".to_string(),
+                Screen::PracticeFilter(_) => "Hoch/Runter wählen | Links/Rechts oder Buchstabe \
+                                              ändert | Rücktaste für jeden Buchstaben | Enter \
+                                              auf Play startet | Esc zurück"
+                    .to_string(),
+                Screen::History(_) => {
+                    "Hoch/Runter wählen | Enter zeigt das Spielfeld | Esc zurück".to_string()
+                }
+                Screen::PastGame(_) => "Links/Rechts für andere Spiele | Esc zurück".to_string(),
+                Screen::WhatsNew(_) => "Enter weiter | N nie wieder zeigen".to_string(),
+                Screen::Menu(_) => "Hoch/Runter wählen | Enter auswählen | Esc zurück".to_string(),
+                Screen::Settings(_) => {
+                    "Hoch/Runter wählen | Links/Rechts ändern | Esc zurück".to_string()
+                }
+                Screen::ModeSelect(_) => {
+                    "Hoch/Runter wählen | Enter spielen | Esc behält den Modus".to_string()
+                }
+                Screen::ConfirmQuit => "J oder Y beenden | N oder Esc weiterspielen".to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordle_game::Letter;
+
+    #[test]
+    fn test_english_errors_are_the_game_messages() {
+        let strings = Strings::new(Language::English);
+        let err = GuessError::NotInWordList;
+        assert_eq!(strings.guess_error(&err), err.to_string());
+        assert_eq!(strings.won(1), "You won in 1 guess!");
+        assert_eq!(
+            strings.secrets_were(&["HELLO".to_string(), "WORLD".to_string()]),
+            "The words were HELLO and WORLD."
+        );
+    }
+
+    #[test]
+    fn test_german() {
+        let strings = Strings::new(Language::German);
+        assert_eq!(
+            strings.guess_error(&GuessError::NotInWordList),
+            "Nicht im Wörterbuch"
+        );
+        let broken = GuessError::BannedLetter {
+            letter: Letter::new('q').unwrap(),
+        };
+        assert_eq!(
+            strings.guess_warning(&GuessWarning::BrokenRule(broken)),
+            "Q ist nicht erlaubt, aber akzeptiert"
+        );
+        assert_eq!(strings.won(1), "Gewonnen mit 1 Versuch!");
+        assert_eq!(strings.won(4), "Gewonnen mit 4 Versuchen!");
+        assert_eq!(strings.run_over(2), "Lauf vorbei nach 2 Wörtern!");
+        assert_eq!(
+            strings.text(&TextKey::GameResult(GameState::Lost)),
+            "Verloren"
+        );
+        assert_eq!(
+            strings.toast(&ToastKey::HintLetter {
+                position: 0,
+                letter: 'q'
+            }),
+            "Buchstabe 1 ist Q"
+        );
+    }
+
+    #[test]
+    fn test_english_game_results_are_the_game_states() {
+        let strings = Strings::new(Language::English);
+        let state = GameState::Won { guesses_used: 3 };
+        assert_eq!(
+            strings.text(&TextKey::GameResult(state.clone())),
+            state.to_string()
+        );
+        assert_eq!(
+            strings.text(&TextKey::HistorySummary {
+                games: 0,
+                session: 0
+            }),
+            "No finished games yet"
+        );
+    }
+
+    #[test]
+    fn test_analysis_headers_line_up() {
+        // The rows of the analysis are formatted the same in every language
+        let english = Strings::new(Language::English).text(&TextKey::AnalysisHeader);
+        for language in Language::ALL {
+            let header = Strings::new(language).text(&TextKey::AnalysisHeader);
+            assert_eq!(header.chars().count(), english.chars().count(), "{header}");
+        }
+    }
+
+    #[test]
+    fn test_toasts_of_guesses_are_the_guess_errors() {
+        let err = GuessError::TooShort { got: 3 };
+        for language in Language::ALL {
+            let strings = Strings::new(language);
+            assert_eq!(
+                strings.toast(&ToastKey::GuessError(err.clone())),
+                strings.guess_error(&err)
+            );
+        }
+        let strings = Strings::new(Language::English);
+        assert_eq!(
+            strings.toast(&ToastKey::Cross(true)),
+            "Cross variant on from the next game"
+        );
+    }
+}
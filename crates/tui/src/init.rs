@@ -0,0 +1,171 @@
+//! First-time setup, asking for the settings that matter before the first
+//! game instead of having players edit the settings file.
+
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use wordle_game::Language;
+
+use crate::profile::Settings;
+use crate::theme::ThemeName;
+
+/// Ask for language, hard mode, theme and where finished games are kept,
+/// starting from `settings`. An empty answer (or the end of the input)
+/// keeps the current value; invalid answers are asked again.
+/// `default_history` is where games are kept unless the player picks a
+/// file.
+pub fn configure(
+    mut settings: Settings,
+    default_history: Option<&Path>,
+    mut input: impl BufRead,
+    mut out: impl Write,
+) -> io::Result<Settings> {
+    let languages: Vec<String> = Language::ALL
+        .iter()
+        .map(|language| format!("{} ({})", language.name(), language.code()))
+        .collect();
+    writeln!(out, "Languages: {}", languages.join(", "))?;
+    settings.language = ask(
+        &mut input,
+        &mut out,
+        "Language",
+        settings.language.name(),
+        |answer| answer.parse().ok(),
+    )?
+    .unwrap_or(settings.language);
+
+    let current = if settings.hard_mode { "yes" } else { "no" };
+    settings.hard_mode = ask(
+        &mut input,
+        &mut out,
+        "Hard mode, where revealed hints must be used (yes/no)",
+        current,
+        parse_yes_no,
+    )?
+    .unwrap_or(settings.hard_mode);
+
+    for (number, theme) in ThemeName::ALL.iter().enumerate() {
+        writeln!(out, "  {}. {}", number + 1, theme.name())?;
+    }
+    settings.theme = ask(
+        &mut input,
+        &mut out,
+        "Theme",
+        settings.theme.name(),
+        parse_theme,
+    )?
+    .unwrap_or(settings.theme);
+
+    let current = settings
+        .history_path
+        .as_deref()
+        .or(default_history)
+        .map(|path| path.display().to_string())
+        .unwrap_or_default();
+    if let Some(path) = ask(
+        &mut input,
+        &mut out,
+        "File to keep statistics in",
+        &current,
+        |answer| Some(PathBuf::from(answer)),
+    )? {
+        // Don't pin the default, so it follows the data directory
+        settings.history_path = (Some(path.as_path()) != default_history).then_some(path);
+    }
+    Ok(settings)
+}
+
+/// Ask a question until `parse` accepts the answer. None if the player
+/// keeps the current value.
+fn ask<T>(
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+    question: &str,
+    current: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> io::Result<Option<T>> {
+    loop {
+        write!(out, "{question} [{current}]: ")?;
+        out.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(None);
+        }
+        let answer = line.trim();
+        if answer.is_empty() {
+            return Ok(None);
+        }
+        match parse(answer) {
+            Some(value) => return Ok(Some(value)),
+            None => writeln!(out, "Sorry, '{answer}' is not one of the choices.")?,
+        }
+    }
+}
+
+fn parse_yes_no(answer: &str) -> Option<bool> {
+    match answer.to_lowercase().as_str() {
+        "y" | "yes" | "on" => Some(true),
+        "n" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// A theme by its number in the list or its name
+fn parse_theme(answer: &str) -> Option<ThemeName> {
+    if let Ok(number) = answer.parse::<usize>() {
+        return number
+            .checked_sub(1)
+            .and_then(|index| ThemeName::ALL.get(index).copied());
+    }
+    ThemeName::ALL
+        .into_iter()
+        .find(|theme| theme.name().eq_ignore_ascii_case(answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(input: &str) -> (Settings, String) {
+        let mut out = Vec::new();
+        let settings = configure(
+            Settings::default(),
+            Some(Path::new("/data/history.json")),
+            input.as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        (settings, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_answers_change_settings() {
+        let (settings, _) = run("en\ny\nhigh contrast\n/sync/games.json\n");
+        assert_eq!(settings.language, Language::English);
+        assert!(settings.hard_mode);
+        assert_eq!(settings.theme, ThemeName::HighContrast);
+        assert_eq!(
+            settings.history_path,
+            Some(PathBuf::from("/sync/games.json"))
+        );
+    }
+
+    #[test]
+    fn test_empty_answers_keep_settings() {
+        let (settings, out) = run("\n\n\n\n");
+        assert_eq!(settings, Settings::default());
+        assert!(out.contains("File to keep statistics in [/data/history.json]: "));
+        // The input may also end early
+        assert_eq!(run("").0, Settings::default());
+    }
+
+    #[test]
+    fn test_invalid_answers_are_asked_again() {
+        let (settings, out) = run("klingon\nde\nmaybe\nno\n7\n2\n/data/history.json\n");
+        assert_eq!(settings.language, Language::German);
+        assert_eq!(settings.theme, ThemeName::Light);
+        assert_eq!(settings.history_path, None);
+        assert_eq!(out.matches("is not one of the choices").count(), 3);
+    }
+}
@@ -4,6 +4,8 @@ use wordle_game::WORD_LENGTH;
 #[derive(Debug, Default, Clone)]
 pub struct InputState {
     buffer: String,
+    /// Position letters are typed at, in letters from the start
+    cursor: usize,
 }
 
 impl InputState {
@@ -11,24 +13,65 @@ impl InputState {
     pub fn new() -> Self {
         Self {
             buffer: String::new(),
+            cursor: 0,
         }
     }
 
-    /// Add a character to the input (if not full)
+    /// Add a character at the cursor (if not full)
     pub fn push(&mut self, c: char) {
-        if self.buffer.chars().count() < WORD_LENGTH && c.is_alphabetic() {
-            self.buffer.push(c.to_lowercase().next().unwrap_or(c));
+        if self.len() < WORD_LENGTH && c.is_alphabetic() {
+            let index = self.byte_index(self.cursor);
+            self.buffer
+                .insert(index, c.to_lowercase().next().unwrap_or(c));
+            self.cursor += 1;
         }
     }
 
-    /// Remove the last character
+    /// Remove the character before the cursor
     pub fn pop(&mut self) {
-        self.buffer.pop();
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.buffer.remove(self.byte_index(self.cursor));
+        }
+    }
+
+    /// Remove the character at the cursor
+    pub fn delete(&mut self) {
+        if self.cursor < self.len() {
+            self.buffer.remove(self.byte_index(self.cursor));
+        }
+    }
+
+    /// Remove everything before the cursor, like Ctrl+W in a shell
+    pub fn delete_before_cursor(&mut self) {
+        self.buffer.drain(..self.byte_index(self.cursor));
+        self.cursor = 0;
+    }
+
+    /// Move the cursor one letter to the left
+    pub fn left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Move the cursor one letter to the right
+    pub fn right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.len());
+    }
+
+    /// Move the cursor to the first letter
+    pub fn home(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Move the cursor behind the last letter
+    pub fn end(&mut self) {
+        self.cursor = self.len();
     }
 
     /// Clear the input
     pub fn clear(&mut self) {
         self.buffer.clear();
+        self.cursor = 0;
     }
 
     /// Get the current input as a string
@@ -36,8 +79,72 @@ impl InputState {
         &self.buffer
     }
 
+    /// Position letters are typed at, in letters from the start
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
     /// Check if the input is complete (WORD_LENGTH letters)
     pub fn is_complete(&self) -> bool {
-        self.buffer.chars().count() == WORD_LENGTH
+        self.len() == WORD_LENGTH
+    }
+
+    fn len(&self) -> usize {
+        self.buffer.chars().count()
+    }
+
+    /// Byte offset in the buffer of the letter at `position`
+    fn byte_index(&self, position: usize) -> usize {
+        self.buffer
+            .char_indices()
+            .nth(position)
+            .map_or(self.buffer.len(), |(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(text: &str) -> InputState {
+        let mut input = InputState::new();
+        text.chars().for_each(|c| input.push(c));
+        input
+    }
+
+    #[test]
+    fn test_insert_and_delete_at_cursor() {
+        let mut input = typed("hllo");
+        input.left();
+        input.left();
+        input.left();
+        input.push('E');
+        assert_eq!(input.as_str(), "hello");
+        assert_eq!(input.cursor(), 2);
+
+        // Full, so nothing is inserted
+        input.push('x');
+        assert_eq!(input.as_str(), "hello");
+
+        input.pop();
+        assert_eq!(input.as_str(), "hllo");
+        input.delete();
+        assert_eq!(input.as_str(), "hlo");
+        input.end();
+        input.right();
+        assert_eq!(input.cursor(), 3);
+    }
+
+    #[test]
+    fn test_delete_before_cursor() {
+        let mut input = typed("größe");
+        input.left();
+        input.left();
+        input.delete_before_cursor();
+        assert_eq!(input.as_str(), "ße");
+        assert_eq!(input.cursor(), 0);
+        input.home();
+        input.pop();
+        assert_eq!(input.as_str(), "ße");
     }
 }
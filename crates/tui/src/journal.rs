@@ -0,0 +1,81 @@
+//! Plaintext record of finished games, for players who like to keep one.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use wordle_game::{GameState, GameSummary, civil};
+
+/// Appends the share text of every finished game, with the date and the
+/// word, to a journal file
+#[derive(Debug, Clone)]
+pub struct Journal {
+    path: PathBuf,
+}
+
+impl Journal {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Append a finished game, e.g. one board of a Quordle. Games still in
+    /// progress are ignored.
+    pub fn record(&self, game: &GameSummary) -> io::Result<()> {
+        if game.outcome == GameState::Playing {
+            return Ok(());
+        }
+        if let Some(dir) = self.path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", entry(game, &today()))
+    }
+}
+
+/// Journal entry of a finished game, followed by an empty line
+fn entry(game: &GameSummary, date: &str) -> String {
+    let word = game.secret.to_string().to_uppercase();
+    format!("{date} {word}\n{}\n", game.share_text())
+}
+
+/// Today's (UTC) date as `YYYY-MM-DD`
+fn today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    civil::format_day((seconds / (24 * 60 * 60)) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordle_game::{Game, Word, WordPool};
+
+    #[test]
+    fn test_record_appends() {
+        let pool = WordPool::from_strings(["hello", "world"].map(String::from));
+        let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+        game.guess("hello").unwrap();
+        let game = game.summary();
+        assert_eq!(
+            entry(&game, "2026-10-17"),
+            "2026-10-17 HELLO\nWordle 1/6\n🟩🟩🟩🟩🟩\n"
+        );
+
+        let path = std::env::temp_dir().join(format!("journal_test_{}.txt", std::process::id()));
+        let journal = Journal::new(&path);
+        journal.record(&game).unwrap();
+        journal.record(&game).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        assert_eq!(text.matches("Wordle 1/6").count(), 2);
+        assert!(text.ends_with("🟩🟩🟩🟩🟩\n\n"));
+        fs::remove_file(path).ok();
+    }
+}
@@ -1,67 +1,525 @@
+mod animation;
 mod app;
+mod base64;
+mod changelog;
+mod clipboard;
+mod demo;
+mod i18n;
+mod init;
 mod input;
+mod journal;
+mod message;
+pub mod output;
+mod pack;
+mod plain;
+mod practice;
+pub mod profile;
+mod screen;
+mod simulate;
+mod sync;
+pub mod testing;
 mod theme;
+mod toast;
 mod widgets;
 
-use std::io::{self, stdout, Stdout};
-use std::time::Duration;
+use std::io::{self, stdout, Stdout, Write};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
 use crossterm::{
-    event,
+    cursor::Show,
+    event::{self, Event},
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    style::Print,
+    terminal::{
+        disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen, SetTitle,
+    },
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use wordle_game::load_german_wordlist;
+use wordle_game::{
+    decode_challenge_code, load_wordlist, load_wordlist_cached, load_wordlist_file, BanList,
+    Game, GameConfig, GameRecord, History, Language, Statistics, WordPool,
+};
 
 use app::App;
+use i18n::Strings;
+use journal::Journal;
+use output::{OutputFormat, PackStatus};
+use pack::PackSession;
+use screen::GameMode;
+use theme::{ColorDepth, Theme, ThemeOverrides};
+use toast::{Severity, ToastKey};
+
+pub use simulate::SimulationStrategy;
+pub use theme::ThemeName;
 
 type Tui = Terminal<CrosstermBackend<Stdout>>;
 
+/// Options shared by all ways to run the application
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Language of the words to play with, instead of the one in the
+    /// profile's settings
+    pub language: Option<Language>,
+    /// Format of what is printed
+    pub output: OutputFormat,
+    /// Player profile whose settings are used
+    pub profile: String,
+    /// File to append the share text of every finished game to
+    pub journal: Option<PathBuf>,
+    /// Color scheme for this run, instead of the one in the profile's
+    /// settings
+    pub theme: Option<ThemeName>,
+    /// Where to sync the game history with other devices, see
+    /// [`sync::backend`]
+    pub sync: Option<String>,
+    /// The player's own word list to play with, instead of the built-in one
+    pub wordlist: Option<PathBuf>,
+    /// Play hard mode, whatever the settings say
+    pub hard: bool,
+    /// Draw the secrets in the same order as every run with this seed
+    pub seed: Option<u64>,
+}
+
 /// Run the Wordle TUI application
-pub fn run() -> io::Result<()> {
+pub fn run(options: &Options) -> io::Result<()> {
+    let settings = profile::load_settings(&options.profile)?;
+    let language = options.language.unwrap_or(settings.language);
+
+    // Load wordlist
+    let word_pool = options_words(options, language)?;
+
+    // Create app
+    let bans = profile::load_ban_list(&options.profile)?;
+    let mut app = App::new(word_pool, language, &bans, settings);
+    if options.hard {
+        app.start_mode(GameMode::Hard);
+    }
+    if let Some(seed) = options.seed {
+        app.use_seed(seed);
+    }
+    // The command line chose already
+    if !options.hard && options.seed.is_none() {
+        app.choose_mode();
+    }
+
+    run_with_terminal(app, options)
+}
+
+/// Watch a bot play with `strategy`, making a move every `delay`. Its
+/// games don't count in the statistics.
+pub fn run_demo(
+    strategy: SimulationStrategy,
+    delay: Duration,
+    options: &Options,
+) -> io::Result<()> {
+    let settings = profile::load_settings(&options.profile)?;
+    let language = options.language.unwrap_or(settings.language);
+    let word_pool = options_words(options, language)?;
+
+    let mut app = App::new(word_pool, language, &BanList::new(), settings);
+    app.start_demo(strategy, delay);
+    run_with_terminal(app, options)
+}
+
+/// Run the Wordle TUI application on a practice pack generated from `seed`
+pub fn run_pack(seed: &str, count: usize, options: &Options) -> io::Result<()> {
+    let settings = profile::load_settings(&options.profile)?;
+    let language = options.language.unwrap_or(settings.language);
+
+    // Load wordlist
+    let word_pool = load_words(language)?;
+
+    let session = PackSession::open(&word_pool, seed, count);
+    let progress = session.progress();
+    if progress.is_complete() {
+        let text = format!(
+            "Pack '{}' is already complete: {} of {} solved.",
+            seed,
+            progress.solved(),
+            progress.results.len()
+        );
+        options.output.print(text, &PackStatus::from(progress));
+        return Ok(());
+    }
+
+    let bans = profile::load_ban_list(&options.profile)?;
+    let app = App::with_pack(word_pool, language, session, &bans, settings);
+    run_with_terminal(app, options)
+}
+
+/// Run the Wordle TUI application on a secret encoded in a challenge code
+pub fn run_challenge(code: &str, options: &Options) -> io::Result<()> {
+    let secret = decode_challenge_code(code)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+    let settings = profile::load_settings(&options.profile)?;
+    let language = options.language.unwrap_or(settings.language);
+
     // Load wordlist
-    let word_pool = load_german_wordlist()?;
+    let word_pool = load_words(language)?;
+
+    let bans = profile::load_ban_list(&options.profile)?;
+    let app = App::with_secret(word_pool, language, secret, &bans, settings);
+    run_with_terminal(app, options)
+}
+
+/// Play one game without the full-screen interface, reading guesses line
+/// by line from stdin. `emoji` prints rows as colored squares instead of
+/// letters on tiles.
+pub fn run_plain(emoji: bool, options: &Options) -> io::Result<()> {
+    let settings = profile::load_settings(&options.profile)?;
+    let language = options.language.unwrap_or(settings.language);
+    let word_pool = options_words(options, language)?;
+
+    let mut config = GameConfig {
+        hard_mode: settings.hard_mode || options.hard,
+        ..GameConfig::default()
+    };
+    profile::load_ban_list(&options.profile)?.apply(&mut config);
+    let mut game = match options.seed {
+        Some(seed) => {
+            let mut secrets = word_pool.seeded_sampler(seed);
+            Game::with_selector(word_pool, &mut secrets, config)
+                .expect("WordPool should not be empty")
+        }
+        None => Game::with_config(word_pool, config),
+    };
+    let theme = Theme::named(options.theme.unwrap_or(settings.theme))
+        .with_color_depth(ColorDepth::detect());
+    let rows = if emoji {
+        plain::RowStyle::Emoji
+    } else {
+        plain::RowStyle::Tiles
+    };
+    // Drops the colors for NO_COLOR, or when not writing to a terminal
+    plain::play(&mut game, &theme, rows, io::stdin().lock(), anstream::stdout())?;
+
+    if let Some(path) = &options.journal {
+        Journal::new(path).record(&game.summary())?;
+    }
+    // Saved with the same id in the history and the statistics
+    let record = GameRecord::new(&game, SystemTime::now());
+    if let Some(path) = profile::history_path(&options.profile, &settings)
+        && let Some(record) = &record
+    {
+        History::record_merged(path, record.clone())?;
+    }
+    if let Some(path) = profile::stats_path(&options.profile)
+        && let Some(record) = &record
+    {
+        // Moves unreadable statistics aside, so the game can be saved
+        let (_, warning) = load_statistics(&path);
+        if let Some(warning) = warning {
+            let strings = Strings::new(settings.ui_language.unwrap_or(language));
+            eprintln!("{}", strings.toast(&warning));
+        }
+        let mut statistics = Statistics::new();
+        statistics.record_finished_game(record, &game);
+        statistics.save_merged(path)?;
+    }
+    Ok(())
+}
+
+/// Play `games` games with `strategy` and write them to `out` as JSON
+/// lines. With a `seed`, the secrets come in the same order every time.
+pub fn run_simulate(
+    games: usize,
+    strategy: SimulationStrategy,
+    out: &Path,
+    seed: Option<u64>,
+    options: &Options,
+) -> io::Result<()> {
+    let settings = profile::load_settings(&options.profile)?;
+    let language = options.language.unwrap_or(settings.language);
+    let word_pool = load_words(language)?;
+    let mut secrets = match seed {
+        Some(seed) => word_pool.seeded_sampler(seed),
+        None => word_pool.sampler(),
+    };
+
+    let file = io::BufWriter::new(std::fs::File::create(out)?);
+    let summary = simulate::simulate(
+        &word_pool,
+        &mut secrets,
+        strategy,
+        games,
+        &GameConfig::default(),
+        file,
+    )?;
+    let text = format!(
+        "Played {} games, won {} with {:.2} guesses on average. Saved them to {}",
+        summary.games,
+        summary.won,
+        summary.average_guesses,
+        out.display()
+    );
+    options.output.print(text, &summary);
+    Ok(())
+}
+
+/// Ask the player for their settings step by step and save them, for a
+/// first-time setup
+pub fn run_init(options: &Options) -> io::Result<()> {
+    let settings = profile::load_settings(&options.profile)?;
+    let default_history = profile::default_history_path(&options.profile);
+    let settings = init::configure(
+        settings,
+        default_history.as_deref(),
+        io::stdin().lock(),
+        io::stdout(),
+    )?;
+    profile::save_settings(&options.profile, &settings)?;
+
+    let text = match profile::settings_path(&options.profile) {
+        Some(path) => format!("Saved the settings to {}", path.display()),
+        None => "Saved the settings".to_string(),
+    };
+    options.output.print(text, &settings);
+    Ok(())
+}
+
+/// Play in the terminal, then report the finished games if JSON output is
+/// requested (the text mode has shown them on screen already)
+fn run_with_terminal(mut app: App, options: &Options) -> io::Result<()> {
+    if let Some(path) = &options.journal {
+        app.set_journal(Journal::new(path));
+    }
+    app.set_color_depth(ColorDepth::detect());
+    match ThemeOverrides::load() {
+        Ok(overrides) => app.set_theme_overrides(overrides),
+        Err(err) => app.toast(Severity::Warning, ToastKey::ThemeFileIgnored(err.to_string())),
+    }
+    if let Some(theme) = options.theme {
+        app.use_theme(theme);
+    }
+    if let Some(path) = profile::stats_path(&options.profile) {
+        let (statistics, warning) = load_statistics(&path);
+        if let Some(warning) = warning {
+            app.toast(Severity::Warning, warning);
+        }
+        app.set_statistics(path, statistics);
+    }
+    if let Some(path) = profile::history_path(&options.profile, app.settings()) {
+        if let Some(spec) = &options.sync
+            && let Err(err) = sync_history(spec, &path)
+        {
+            app.toast(Severity::Warning, ToastKey::SyncFailed(err.to_string()));
+        }
+        match History::load(&path) {
+            Ok(history) => app.set_history(path, &history),
+            Err(err) => app.toast(
+                Severity::Warning,
+                ToastKey::HistoryUnreadable {
+                    path: path.display().to_string(),
+                    err: err.to_string(),
+                },
+            ),
+        }
+    }
+
+    // Show what changed since the last run
+    let settings = app.settings();
+    if settings.show_whats_new
+        && let Some(last_run) = &settings.last_run_version
+    {
+        app.show_whats_new(changelog::releases_since(last_run));
+    }
 
     // Setup terminal
     let mut terminal = setup_terminal()?;
 
-    // Create app
-    let mut app = App::new(word_pool);
-
     // Run main loop
     let result = run_app(&mut terminal, &mut app);
 
     // Restore terminal
     restore_terminal(&mut terminal)?;
 
+    // Upload this session's games
+    if let Some(spec) = &options.sync
+        && let Some(path) = profile::history_path(&options.profile, app.settings())
+        && let Err(err) = sync_history(spec, &path)
+    {
+        eprintln!("Could not sync the game history: {err}");
+    }
+
+    if let Err(err) = app.save_statistics() {
+        eprintln!("Could not save the statistics: {err}");
+    }
+
+    // Save changes made in the settings, and remember this run's version
+    let mut settings = app.settings().clone();
+    settings.last_run_version = Some(changelog::CURRENT_VERSION.to_string());
+    profile::save_settings(&options.profile, &settings)?;
+
+    if options.output == OutputFormat::Json {
+        options.output.print("", &app.results());
+    }
+
     result
 }
 
+/// The statistics saved at `path`. A file that can't be read (e.g. cut
+/// short by a crash) is moved aside, so it doesn't keep the player from
+/// playing, and the statistics start over (caught up with the game
+/// history). Returns a warning for the player in that case.
+fn load_statistics(path: &Path) -> (Statistics, Option<ToastKey>) {
+    match Statistics::load(path) {
+        Ok(statistics) => (statistics, None),
+        Err(err) if err.kind() != io::ErrorKind::InvalidData => (
+            Statistics::new(),
+            Some(ToastKey::StatisticsUnreadable(err.to_string())),
+        ),
+        Err(err) => {
+            let mut backup = path.as_os_str().to_owned();
+            backup.push(".corrupt");
+            let err = err.to_string();
+            let warning = match std::fs::rename(path, &backup) {
+                Ok(()) => ToastKey::StatisticsMovedAside {
+                    err,
+                    path: Path::new(&backup).display().to_string(),
+                },
+                Err(_) => ToastKey::StatisticsStartedOver(err),
+            };
+            (Statistics::new(), Some(warning))
+        }
+    }
+}
+
+/// Merge the saved game history with the one of the sync backend
+fn sync_history(spec: &str, path: &Path) -> io::Result<History> {
+    sync::sync_history(sync::backend(spec)?.as_ref(), path)
+}
+
+/// Escape sequences saving and restoring the terminal's title on a stack.
+/// Terminals without one ignore them and keep the last title set.
+const PUSH_TITLE: &str = "\x1b[22;0t";
+const POP_TITLE: &str = "\x1b[23;0t";
+
+/// Whether the terminal is in raw mode on the alternate screen, so it is
+/// put back only once, by whichever of a panic or the normal exit comes first
+static TERMINAL_SET_UP: AtomicBool = AtomicBool::new(false);
+
 fn setup_terminal() -> io::Result<Tui> {
+    install_panic_hook();
     enable_raw_mode()?;
+    TERMINAL_SET_UP.store(true, Ordering::SeqCst);
     let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    // Keep the terminal's title, to put it back on exit
+    execute!(stdout, EnterAlternateScreen, Print(PUSH_TITLE))?;
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
     Ok(terminal)
 }
 
+/// Read terminal events on a thread of their own, so the main loop can
+/// wait for input and timers at once. The thread stops after the first
+/// error, or once the receiver is gone and another event arrives.
+fn spawn_input_reader() -> Receiver<io::Result<Event>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        loop {
+            let event = event::read();
+            let failed = event.is_err();
+            if sender.send(event).is_err() || failed {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
 fn restore_terminal(terminal: &mut Tui) -> io::Result<()> {
+    reset_terminal(terminal.backend_mut())
+}
+
+/// Leave raw mode and the alternate screen, unless that was done already
+fn reset_terminal(out: &mut impl Write) -> io::Result<()> {
+    if !TERMINAL_SET_UP.swap(false, Ordering::SeqCst) {
+        return Ok(());
+    }
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(out, LeaveAlternateScreen, Print(POP_TITLE), Show)?;
     Ok(())
 }
 
+/// Put the terminal back before the message of a panic is printed, so a
+/// crash doesn't leave it unusable, then go on with the panic as before.
+/// Panics of worker threads are left alone: the app carries on without
+/// their result.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let main_thread = thread::current().id();
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().id() == main_thread {
+                reset_terminal(&mut stdout()).ok();
+            }
+            hook(info);
+        }));
+    });
+}
+
+/// Load the words of a language, through a cache in the user's cache
+/// directory so later starts are quicker
+pub(crate) fn load_words(language: Language) -> io::Result<WordPool> {
+    match dirs::cache_dir() {
+        Some(dir) => load_wordlist_cached(language, &dir.join("wordle")),
+        None => load_wordlist(language),
+    }
+}
+
+/// The words to play with: the player's own list if they gave one, or
+/// else the built-in list of the language
+fn options_words(options: &Options, language: Language) -> io::Result<WordPool> {
+    match &options.wordlist {
+        Some(path) => load_wordlist_file(path, language),
+        None => load_words(language),
+    }
+}
+
 fn run_app(terminal: &mut Tui, app: &mut App) -> io::Result<()> {
+    let events = spawn_input_reader();
+    let mut title = String::new();
     loop {
-        terminal.draw(|frame| app.render(frame))?;
+        if app.needs_redraw() {
+            terminal.draw(|frame| app.render(frame))?;
+            app.drawn();
+        }
+        let new_title = app.terminal_title();
+        if new_title != title {
+            execute!(terminal.backend_mut(), SetTitle(&new_title))?;
+            title = new_title;
+        }
+        if app.take_bell() {
+            execute!(terminal.backend_mut(), Print('\x07'))?;
+        }
 
-        // Poll for events with a timeout
-        if event::poll(Duration::from_millis(100))? {
-            let event = event::read()?;
-            app.handle_event(event);
+        // Sleep until input arrives or the app has something to update
+        let now = Instant::now();
+        let received = match app.next_tick(now) {
+            Some(tick) => events.recv_timeout(tick.saturating_duration_since(now)),
+            None => events.recv().map_err(RecvTimeoutError::from),
+        };
+        match received {
+            Ok(event) => app.handle_event(event?),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(io::Error::other("input reader stopped"));
+            }
+        }
+        // Handle keys typed meanwhile before drawing again
+        for event in events.try_iter() {
+            app.handle_event(event?);
         }
+        app.receive_results();
+        app.tick(Instant::now());
 
         if app.should_quit() {
             return Ok(());
@@ -1,5 +1,328 @@
 use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use wordle_game::{
+    BanEnforcement, BanList, Language, Letter, WORD_LENGTH, Word, challenge_code_for,
+};
+use wordle_tui::output::{BanListStatus, ChallengeCode, OutputFormat};
+use wordle_tui::profile::{self, DEFAULT_PROFILE};
+use wordle_tui::{Options, SimulationStrategy, ThemeName};
+
+#[derive(Parser)]
+#[command(name = "wordle", about = "Play Wordle in the terminal")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Format of what is printed, e.g. `json` for scripts
+    #[arg(long, value_enum, global = true, default_value_t)]
+    output: OutputFormat,
+
+    /// Language of the words, by name or code (e.g. `german` or `de`).
+    /// Defaults to the language chosen in the settings.
+    #[arg(long, global = true)]
+    language: Option<Language>,
+
+    /// Append every finished game's result to this file
+    #[arg(long, global = true, value_name = "PATH")]
+    journal: Option<PathBuf>,
+
+    /// Color scheme, e.g. `high-contrast` for color-blind players.
+    /// Defaults to the theme chosen in the settings.
+    #[arg(long, value_enum, global = true)]
+    theme: Option<ThemeName>,
+
+    /// Sync the game history with other devices: `git:<path>` for a Git
+    /// checkout, or the URL of a WebDAV (or other HTTP) directory
+    #[arg(long, global = true, value_name = "BACKEND")]
+    sync: Option<String>,
+
+    /// Player profile, for separate settings per player
+    #[arg(long, global = true, default_value = DEFAULT_PROFILE)]
+    profile: String,
+
+    /// For playing without a command
+    #[command(flatten)]
+    play: PlayArgs,
+}
+
+/// Words and rules of the games the player plays, given before or after
+/// `play`. Other commands can't honour them.
+#[derive(Args, Debug, Clone, PartialEq)]
+struct PlayArgs {
+    /// Play with the words of this file, one per line, instead of the
+    /// built-in list. Compressed files (`.zst`, `.gz`) work too.
+    #[arg(long, value_name = "PATH")]
+    wordlist: Option<PathBuf>,
+
+    /// Length of the words. Only five letters are supported so far.
+    #[arg(long, default_value_t = WORD_LENGTH, value_parser = parse_length)]
+    length: usize,
+
+    /// Play hard mode: revealed hints must be used in later guesses
+    #[arg(long)]
+    hard: bool,
+
+    /// Draw the secrets in the same order as every run with this seed
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+impl PlayArgs {
+    /// Whether any of the arguments was given
+    fn is_given(&self) -> bool {
+        self.wordlist.is_some() || self.length != WORD_LENGTH || self.hard || self.seed.is_some()
+    }
+
+    /// These arguments, completed by the ones of `other`
+    fn or(self, other: PlayArgs) -> PlayArgs {
+        PlayArgs {
+            wordlist: self.wordlist.or(other.wordlist),
+            length: self.length,
+            hard: self.hard || other.hard,
+            seed: self.seed.or(other.seed),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Play a game (the default without a command)
+    Play {
+        /// Play one game line by line instead of full-screen
+        #[arg(long)]
+        no_tui: bool,
+        /// Print guesses as colored squares instead of letters on tiles
+        #[arg(long, requires = "no_tui")]
+        emoji: bool,
+        #[command(flatten)]
+        play: PlayArgs,
+    },
+    /// Choose language, hard mode, theme and where statistics are kept,
+    /// one question at a time
+    Init,
+    /// Play a pack of puzzles generated from a seed. Everyone using the same
+    /// seed gets the same puzzles, and progress is saved between sessions.
+    Pack {
+        /// Name of the pack
+        #[arg(long)]
+        seed: String,
+        /// Number of puzzles in the pack
+        #[arg(long, default_value_t = 10)]
+        count: usize,
+    },
+    /// Play a word picked by a friend, or create a code to send one
+    Challenge {
+        /// Challenge code received from a friend
+        #[arg(required_unless_present = "create", conflicts_with = "create")]
+        code: Option<String>,
+        /// Print the challenge code for a word instead of playing
+        #[arg(long, value_name = "WORD")]
+        create: Option<String>,
+    },
+    /// Play games without a player and save them as JSON lines, one game
+    /// record per line, e.g. to generate datasets
+    Simulate {
+        /// Number of games to play
+        #[arg(long, default_value_t = 100)]
+        games: usize,
+        /// How the guesses are picked
+        #[arg(long, value_enum, default_value_t)]
+        strategy: SimulationStrategy,
+        /// File to write the games to
+        #[arg(long, value_name = "PATH")]
+        out: PathBuf,
+        /// Draw the secrets in the same order as every run with this seed
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Watch a bot play, e.g. for demos or to see how the solver thinks.
+    /// Its games don't count in the statistics.
+    Demo {
+        /// How the bot picks its guesses
+        #[arg(long, value_enum, default_value_t)]
+        strategy: SimulationStrategy,
+        /// Milliseconds between the bot's moves
+        #[arg(long, default_value_t = 1500)]
+        delay_ms: u64,
+        /// Let the bot play with the words of this file, one per line
+        #[arg(long, value_name = "PATH")]
+        wordlist: Option<PathBuf>,
+    },
+    /// Ban opening words or letters for yourself, as a self-imposed
+    /// challenge. Without arguments, shows the current bans.
+    Ban {
+        /// Word you may not use as first guess (can be repeated)
+        #[arg(long = "opener", value_name = "WORD")]
+        openers: Vec<String>,
+        /// Letter you may not use in any guess (can be repeated)
+        #[arg(long = "letter", value_name = "LETTER")]
+        letters: Vec<char>,
+        /// Only warn about banned guesses instead of rejecting them
+        #[arg(long, conflicts_with = "reject")]
+        warn: bool,
+        /// Reject banned guesses (the default)
+        #[arg(long)]
+        reject: bool,
+        /// Remove all bans before adding new ones
+        #[arg(long)]
+        clear: bool,
+    },
+}
 
 fn main() -> io::Result<()> {
-    wordle_tui::run()
+    let cli = Cli::parse();
+    let play = play_args(&cli)?;
+    let options = Options {
+        language: cli.language,
+        output: cli.output,
+        profile: cli.profile,
+        journal: cli.journal,
+        theme: cli.theme,
+        sync: cli.sync,
+        wordlist: play.wordlist,
+        hard: play.hard,
+        seed: play.seed,
+    };
+    // For checking words given on the command line
+    let language = match cli.language {
+        Some(language) => language,
+        None => profile::load_settings(&options.profile)?.language,
+    };
+    match cli.command {
+        None | Some(Command::Play { no_tui: false, .. }) => wordle_tui::run(&options),
+        Some(Command::Play { emoji, .. }) => wordle_tui::run_plain(emoji, &options),
+        Some(Command::Init) => wordle_tui::run_init(&options),
+        Some(Command::Pack { seed, count }) => wordle_tui::run_pack(&seed, count, &options),
+        Some(Command::Simulate {
+            games,
+            strategy,
+            out,
+            seed,
+        }) => wordle_tui::run_simulate(games, strategy, &out, seed, &options),
+        Some(Command::Demo {
+            strategy, delay_ms, ..
+        }) => wordle_tui::run_demo(strategy, Duration::from_millis(delay_ms), &options),
+        Some(Command::Challenge {
+            create: Some(word), ..
+        }) => {
+            let word = Word::parse(&word)
+                .filter(|w| w.letters().all(|l| language.is_in_alphabet(l.char())))
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("'{word}' is not a valid word"),
+                    )
+                })?;
+            let code = challenge_code_for(&word);
+            cli.output
+                .print(&code, &ChallengeCode { code: code.clone() });
+            Ok(())
+        }
+        Some(Command::Challenge { code, .. }) => {
+            wordle_tui::run_challenge(&code.unwrap_or_default(), &options)
+        }
+        Some(Command::Ban {
+            openers,
+            letters,
+            warn,
+            reject,
+            clear,
+        }) => {
+            let mut bans = if clear {
+                BanList::new()
+            } else {
+                profile::load_ban_list(&options.profile)?
+            };
+            for opener in openers {
+                let word = Word::parse(&opener)
+                    .filter(|w| w.letters().all(|l| language.is_in_alphabet(l.char())))
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("'{opener}' is not a valid word"),
+                        )
+                    })?;
+                bans.ban_opener(word);
+            }
+            for letter in letters {
+                let letter = Letter::new(letter)
+                    .filter(|l| language.is_in_alphabet(l.char()))
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("'{letter}' is not a valid letter"),
+                        )
+                    })?;
+                bans.ban_letter(letter);
+            }
+            if warn {
+                bans.set_enforcement(BanEnforcement::Warn);
+            } else if reject {
+                bans.set_enforcement(BanEnforcement::Reject);
+            }
+            profile::save_ban_list(&options.profile, &bans)?;
+            cli.output
+                .print(&bans, &BanListStatus::new(&options.profile, &bans));
+            Ok(())
+        }
+    }
+}
+
+/// The words and rules to play with, given before or after the command.
+/// Commands that can't honour them reject them.
+fn play_args(cli: &Cli) -> io::Result<PlayArgs> {
+    match &cli.command {
+        None => Ok(cli.play.clone()),
+        Some(Command::Play { play, .. }) => Ok(play.clone().or(cli.play.clone())),
+        Some(_) if cli.play.is_given() => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--wordlist, --length, --hard and --seed only apply to playing",
+        )),
+        Some(Command::Demo { wordlist, .. }) => Ok(PlayArgs {
+            wordlist: wordlist.clone(),
+            ..cli.play.clone()
+        }),
+        Some(_) => Ok(cli.play.clone()),
+    }
+}
+
+fn parse_length(value: &str) -> Result<usize, String> {
+    let length: usize = value.parse().map_err(|err| format!("{err}"))?;
+    if length == WORD_LENGTH {
+        Ok(length)
+    } else {
+        Err(format!("only {WORD_LENGTH}-letter words are supported"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_play_args() {
+        let cli = Cli::try_parse_from(["wordle", "--seed", "3", "play", "--wordlist", "words.txt"])
+            .unwrap();
+        let play = play_args(&cli).unwrap();
+        assert_eq!(play.wordlist, Some(PathBuf::from("words.txt")));
+        assert_eq!(play.seed, Some(3));
+        let cli = Cli::try_parse_from(["wordle", "demo", "--wordlist", "words.txt"]).unwrap();
+        assert_eq!(
+            play_args(&cli).unwrap().wordlist,
+            Some(PathBuf::from("words.txt"))
+        );
+
+        // Commands that can't honour them don't take them
+        assert!(Cli::try_parse_from(["wordle", "pack", "--seed", "x", "--wordlist", "w"]).is_err());
+        assert!(Cli::try_parse_from(["wordle", "simulate", "--out", "o", "--hard"]).is_err());
+        assert!(Cli::try_parse_from(["wordle", "play", "--length", "6"]).is_err());
+        let cli = Cli::try_parse_from(["wordle", "--hard", "pack", "--seed", "x"]).unwrap();
+        assert_eq!(
+            play_args(&cli).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
 }
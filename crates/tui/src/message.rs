@@ -0,0 +1,148 @@
+//! The message under the board, telling how a game ended and what to do
+//! next. It is kept as keys rather than text, so it is put into words in
+//! the language of the interface only when it is shown.
+
+use crate::i18n::Strings;
+use crate::toast::Severity;
+
+/// A piece of a message, put into words by [`Strings::message`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageKey {
+    /// The game was won with this many guesses
+    Won {
+        guesses_used: usize,
+    },
+    /// Points of a scored game
+    Score(u32),
+    GaveUp,
+    GameOver,
+    /// The secret words of a finished game, as shown to the player
+    SecretsWere(Vec<String>),
+    /// A sequence ended after this many solved words
+    SequenceOver(usize),
+    /// A survival run ended after this many solved words
+    RunOver(usize),
+    /// The last puzzle of a pack is over
+    PackComplete {
+        solved: usize,
+        total: usize,
+    },
+    /// How to go on once a game is over
+    PlayAgain {
+        next_puzzle: bool,
+        can_copy: bool,
+    },
+    /// Words similar to the secret, and where to read more about it
+    Similar {
+        synonyms: Vec<String>,
+        url: Option<String>,
+    },
+}
+
+/// A message: a line of text whose color tells its severity, and an
+/// optional line with more detail shown in a dimmer color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub severity: Severity,
+    pub parts: Vec<MessageKey>,
+    pub detail: Option<MessageKey>,
+}
+
+impl Message {
+    pub fn new(severity: Severity, parts: Vec<MessageKey>) -> Self {
+        Self {
+            severity,
+            parts,
+            detail: None,
+        }
+    }
+
+    /// Add a line with more detail
+    pub fn with_detail(mut self, detail: MessageKey) -> Self {
+        self.detail = Some(detail);
+        self
+    }
+
+    /// The text of the message, followed by its detail if there is one
+    pub fn text(&self, strings: &Strings) -> (String, Option<String>) {
+        let text = self
+            .parts
+            .iter()
+            .map(|part| strings.message(part))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let detail = self
+            .detail
+            .as_ref()
+            .map(|detail| strings.message(detail))
+            .filter(|detail| !detail.is_empty());
+        (text, detail)
+    }
+}
+
+/// Break text into lines of at most `width` characters at spaces. Words
+/// longer than a line are split.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        let line_len = line.chars().count();
+        if line_len > 0 && line_len + 1 + word.len() <= width {
+            line.push(' ');
+            line.extend(&word);
+            continue;
+        }
+        if line_len > 0 {
+            lines.push(std::mem::take(&mut line));
+        }
+        while word.len() > width {
+            lines.push(word.drain(..width).collect());
+        }
+        line.extend(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordle_game::Language;
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(
+            wrap("You won in 3 guesses!", 12),
+            ["You won in 3", "guesses!"]
+        );
+        assert_eq!(wrap("abcdefgh ij", 3), ["abc", "def", "gh", "ij"]);
+        assert_eq!(wrap("", 10), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_text_in_language() {
+        let message = Message::new(
+            Severity::Error,
+            vec![
+                MessageKey::GameOver,
+                MessageKey::SecretsWere(vec!["HELLO".to_string()]),
+            ],
+        )
+        .with_detail(MessageKey::Similar {
+            synonyms: Vec::new(),
+            url: None,
+        });
+        assert_eq!(
+            message.text(&Strings::new(Language::English)),
+            ("Game over! The word was HELLO.".to_string(), None)
+        );
+        assert_eq!(
+            message.text(&Strings::new(Language::German)).0,
+            "Verloren! Das Wort war HELLO."
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Machine-readable output of the CLI commands.
+//!
+//! With `--output json`, commands print one of the structs below instead of
+//! human-readable text. Their fields are a stable interface for scripts:
+//! fields may be added, but existing ones keep their name and meaning.
+
+use std::fmt::Display;
+use std::sync::Mutex;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use wordle_game::{
+    BanEnforcement, BanList, Game, GameObserver, GameState, GameSummary, PackProgress,
+};
+
+/// Format of what CLI commands print
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// JSON, for scripts and other tools
+    Json,
+}
+
+impl OutputFormat {
+    /// Print a command's result as text or as JSON
+    pub fn print<T: Serialize>(self, text: impl Display, value: &T) {
+        match self {
+            OutputFormat::Text => println!("{text}"),
+            OutputFormat::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(value).expect("output is always serializable")
+            ),
+        }
+    }
+}
+
+/// A finished game
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameResult {
+    pub secret: String,
+    pub guesses: Vec<String>,
+    pub won: bool,
+}
+
+impl From<&GameSummary> for GameResult {
+    fn from(summary: &GameSummary) -> Self {
+        Self {
+            secret: summary.secret.to_string(),
+            guesses: summary
+                .guesses
+                .iter()
+                .map(|guess| guess.word.to_string())
+                .collect(),
+            won: summary.is_won(),
+        }
+    }
+}
+
+/// Games finished in an interactive session
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SessionResults {
+    pub games: Vec<GameResult>,
+}
+
+/// Code for a challenge created with `wordle challenge --create`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChallengeCode {
+    pub code: String,
+}
+
+/// Progress of a practice pack
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PackStatus {
+    pub seed: String,
+    pub count: usize,
+    pub played: usize,
+    pub solved: usize,
+    pub complete: bool,
+}
+
+impl From<&PackProgress> for PackStatus {
+    fn from(progress: &PackProgress) -> Self {
+        Self {
+            seed: progress.seed.clone(),
+            count: progress.results.len(),
+            played: progress.played(),
+            solved: progress.solved(),
+            complete: progress.is_complete(),
+        }
+    }
+}
+
+/// Games played by `wordle simulate`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SimulationSummary {
+    pub games: usize,
+    pub won: usize,
+    /// Average number of guesses of the won games (0 if none was won)
+    pub average_guesses: f64,
+    #[serde(skip)]
+    guesses_to_win: usize,
+}
+
+impl SimulationSummary {
+    /// Count a finished game
+    pub fn record(&mut self, state: &GameState) {
+        self.games += 1;
+        if let GameState::Won { guesses_used } = state {
+            self.won += 1;
+            self.guesses_to_win += guesses_used;
+            self.average_guesses = self.guesses_to_win as f64 / self.won as f64;
+        }
+    }
+}
+
+/// Openers and letters a profile banned for itself
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BanListStatus {
+    pub profile: String,
+    pub openers: Vec<String>,
+    pub letters: Vec<String>,
+    /// `reject` or `warn`
+    pub enforcement: &'static str,
+}
+
+impl BanListStatus {
+    pub fn new(profile: &str, bans: &BanList) -> Self {
+        Self {
+            profile: profile.to_string(),
+            openers: bans.openers().iter().map(|w| w.to_string()).collect(),
+            letters: bans.letters().iter().map(|l| l.to_string()).collect(),
+            enforcement: match bans.enforcement() {
+                BanEnforcement::Reject => "reject",
+                BanEnforcement::Warn => "warn",
+            },
+        }
+    }
+}
+
+/// Observer collecting the results of finished games
+#[derive(Debug, Default)]
+pub struct ResultsRecorder {
+    games: Mutex<Vec<GameResult>>,
+}
+
+impl ResultsRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Results of all games finished so far
+    pub fn results(&self) -> SessionResults {
+        SessionResults {
+            games: self.games.lock().unwrap().clone(),
+        }
+    }
+
+    /// Add a finished game, e.g. one board of a Quordle, whose variant
+    /// doesn't report to observers. Games in progress are ignored.
+    pub fn record(&self, summary: &GameSummary) {
+        if summary.outcome != GameState::Playing {
+            self.games.lock().unwrap().push(GameResult::from(summary));
+        }
+    }
+}
+
+impl GameObserver for ResultsRecorder {
+    fn on_win(&self, game: &Game) {
+        self.record(&game.summary());
+    }
+
+    fn on_lose(&self, game: &Game) {
+        self.record(&game.summary());
+    }
+
+    fn on_forfeit(&self, game: &Game) {
+        self.record(&game.summary());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use wordle_game::{GameConfig, Word, WordPool};
+
+    use super::*;
+
+    #[test]
+    fn test_results_recorder() {
+        let recorder = Arc::new(ResultsRecorder::new());
+        let config = GameConfig {
+            observers: vec![recorder.clone()],
+            ..GameConfig::default()
+        };
+        let pool = WordPool::from_strings(vec!["hello".to_string(), "world".to_string()]);
+        let mut game = Game::with_secret_and_config(pool, Word::parse("hello").unwrap(), config);
+        game.guess("world").unwrap();
+        assert!(recorder.results().games.is_empty());
+        game.guess("hello").unwrap();
+
+        let json = serde_json::to_value(recorder.results()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "games": [{"secret": "hello", "guesses": ["world", "hello"], "won": true}]
+            })
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::io;
+use std::path::PathBuf;
+
+use wordle_game::{Game, GameConfig, GameState, Pack, PackProgress, WordPool};
+
+use crate::profile::file_name;
+
+/// A practice pack being played, with progress saved after every puzzle so
+/// a pack can be continued later
+pub struct PackSession {
+    pack: Pack,
+    progress: PackProgress,
+    /// Index of the puzzle being played
+    index: Option<usize>,
+    /// Where progress is saved (None if there is no data directory)
+    path: Option<PathBuf>,
+}
+
+impl PackSession {
+    /// Generate a pack and pick up saved progress for its seed
+    pub fn open(word_pool: &WordPool, seed: &str, count: usize) -> Self {
+        let pack = Pack::generate(word_pool, seed, count);
+        let path = progress_path(seed);
+        let mut progress = path
+            .as_ref()
+            .and_then(|path| PackProgress::load(path).ok())
+            .filter(|progress| progress.seed == seed)
+            .unwrap_or_else(|| PackProgress::new(&pack));
+        progress.resize(pack.len());
+        Self {
+            pack,
+            progress,
+            index: None,
+            path,
+        }
+    }
+
+    /// Start the next puzzle not played yet
+    pub fn next_game(&mut self, word_pool: &WordPool, config: &GameConfig) -> Option<Game> {
+        let index = self.progress.next_unplayed()?;
+        self.index = Some(index);
+        self.pack.game(word_pool.clone(), index, config.clone())
+    }
+
+    /// Record the outcome of the current puzzle and save the progress
+    pub fn record(&mut self, state: &GameState) -> io::Result<()> {
+        if let Some(index) = self.index {
+            self.progress.record(index, state);
+        }
+        if let Some(path) = &self.path {
+            // Pick up puzzles played in other windows meanwhile
+            self.progress = self.progress.save_merged(path)?;
+            self.progress.resize(self.pack.len());
+        }
+        Ok(())
+    }
+
+    pub fn pack(&self) -> &Pack {
+        &self.pack
+    }
+
+    pub fn progress(&self) -> &PackProgress {
+        &self.progress
+    }
+
+    /// Index of the puzzle being played
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+/// Progress file of a pack, named after its seed
+fn progress_path(seed: &str) -> Option<PathBuf> {
+    let file_name = file_name(seed);
+    dirs::data_dir().map(|dir| {
+        dir.join("wordle")
+            .join("packs")
+            .join(format!("{file_name}.json"))
+    })
+}
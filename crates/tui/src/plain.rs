@@ -0,0 +1,182 @@
+//! Playing without the full-screen interface, one guess per line, e.g. in
+//! terminals that can't show it or to keep the game in the scrollback.
+
+use std::io::{self, BufRead, Write};
+
+use anstyle::{Ansi256Color, AnsiColor, RgbColor, Style};
+use ratatui::style::Color;
+use wordle_game::{Game, GameState, GuessFeedback, LetterFeedback};
+
+use crate::theme::Theme;
+
+/// How guessed rows are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowStyle {
+    /// Letters on tiles in the theme's colors
+    Tiles,
+    /// Colored squares, as in shared results
+    Emoji,
+}
+
+/// Play `game` reading guesses from `input`, until it is over or the input
+/// ends. Colors are written as ANSI sequences, so `out` should strip them
+/// where they aren't wanted (see `anstream`).
+pub fn play(
+    game: &mut Game,
+    theme: &Theme,
+    rows: RowStyle,
+    mut input: impl BufRead,
+    mut out: impl Write,
+) -> io::Result<()> {
+    while game.state() == GameState::Playing {
+        write!(
+            out,
+            "Guess {}/{}: ",
+            game.guesses().len() + 1,
+            game.max_guesses()
+        )?;
+        out.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            game.forfeit();
+            break;
+        }
+        match game.guess(line.trim()) {
+            Ok(feedback) => {
+                writeln!(out, "{}", row(&feedback, theme, rows))?;
+                for warning in feedback.warnings() {
+                    writeln!(out, "{warning}")?;
+                }
+            }
+            Err(err) => writeln!(out, "{err}")?,
+        }
+    }
+
+    let secret = game
+        .revealed_secret()
+        .map(|secret| secret.to_uppercase())
+        .unwrap_or_default();
+    match game.state() {
+        GameState::Won { guesses_used } => writeln!(
+            out,
+            "You won in {guesses_used} guess{}!",
+            if guesses_used == 1 { "" } else { "es" }
+        )?,
+        _ => writeln!(out, "The word was {secret}.")?,
+    }
+    Ok(())
+}
+
+/// A guessed word with its feedback, as one line
+fn row(feedback: &GuessFeedback, theme: &Theme, rows: RowStyle) -> String {
+    match rows {
+        RowStyle::Emoji => feedback
+            .feedback()
+            .iter()
+            .map(LetterFeedback::emoji)
+            .collect(),
+        RowStyle::Tiles => feedback
+            .iter()
+            .map(|(letter, feedback)| {
+                let bg = match feedback {
+                    LetterFeedback::Correct => theme.correct,
+                    LetterFeedback::WrongPosition => theme.wrong_position,
+                    LetterFeedback::NotInWord => theme.not_in_word,
+                };
+                let style = Style::new()
+                    .bold()
+                    .fg_color(ansi_color(theme.text))
+                    .bg_color(ansi_color(bg));
+                let letter = letter.char().to_uppercase();
+                format!("{style} {letter} {style:#}")
+            })
+            .collect(),
+    }
+}
+
+/// The ANSI color closest to a color of the theme (None for the
+/// terminal's default)
+fn ansi_color(color: Color) -> Option<anstyle::Color> {
+    let ansi = |color: AnsiColor| Some(color.into());
+    match color {
+        Color::Reset => None,
+        Color::Black => ansi(AnsiColor::Black),
+        Color::Red => ansi(AnsiColor::Red),
+        Color::Green => ansi(AnsiColor::Green),
+        Color::Yellow => ansi(AnsiColor::Yellow),
+        Color::Blue => ansi(AnsiColor::Blue),
+        Color::Magenta => ansi(AnsiColor::Magenta),
+        Color::Cyan => ansi(AnsiColor::Cyan),
+        Color::Gray => ansi(AnsiColor::White),
+        Color::DarkGray => ansi(AnsiColor::BrightBlack),
+        Color::LightRed => ansi(AnsiColor::BrightRed),
+        Color::LightGreen => ansi(AnsiColor::BrightGreen),
+        Color::LightYellow => ansi(AnsiColor::BrightYellow),
+        Color::LightBlue => ansi(AnsiColor::BrightBlue),
+        Color::LightMagenta => ansi(AnsiColor::BrightMagenta),
+        Color::LightCyan => ansi(AnsiColor::BrightCyan),
+        Color::White => ansi(AnsiColor::BrightWhite),
+        Color::Indexed(index) => Some(Ansi256Color(index).into()),
+        Color::Rgb(r, g, b) => Some(RgbColor(r, g, b).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::ThemeName;
+    use wordle_game::{Word, WordPool};
+
+    fn game() -> Game {
+        let pool = WordPool::from_strings(["hello", "world", "crane"].map(String::from));
+        Game::with_secret(pool, Word::parse("hello").unwrap())
+    }
+
+    fn play_with(input: &str, rows: RowStyle) -> String {
+        let mut out = Vec::new();
+        let theme = Theme::named(ThemeName::Dark);
+        play(&mut game(), &theme, rows, input.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_emoji_rows() {
+        assert_eq!(
+            play_with("world\nzzzzz\nhello\n", RowStyle::Emoji),
+            "Guess 1/6: ⬜🟨⬜🟩⬜\n\
+             Guess 2/6: Not in word list\n\
+             Guess 2/6: 🟩🟩🟩🟩🟩\n\
+             You won in 2 guesses!\n"
+        );
+    }
+
+    #[test]
+    fn test_tiles() {
+        let out = play_with("hello\n", RowStyle::Tiles);
+        // Bold white on the theme's green
+        assert!(out.starts_with("Guess 1/6: \x1b[1m\x1b[97m\x1b[48;2;106;170;100m H \x1b[0m"));
+    }
+
+    #[test]
+    fn test_end_of_input_gives_up() {
+        assert_eq!(
+            play_with("crane\n", RowStyle::Emoji),
+            "Guess 1/6: ⬜⬜⬜⬜🟨\nGuess 2/6: \nThe word was HELLO.\n"
+        );
+    }
+
+    #[test]
+    fn test_reveals_display_form() {
+        let pool = WordPool::from_display_strings(["e-mail", "hello"].map(String::from));
+        let mut game = Game::with_secret(pool, Word::parse("email").unwrap());
+        let theme = Theme::named(ThemeName::Dark);
+        let mut out = Vec::new();
+        play(&mut game, &theme, RowStyle::Emoji, "".as_bytes(), &mut out).unwrap();
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .ends_with("The word was E-MAIL.\n")
+        );
+    }
+}
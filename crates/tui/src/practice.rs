@@ -0,0 +1,67 @@
+//! Practicing on a part of the word list, e.g. only words with 'ß' or
+//! only words starting with 's'. The filter picks the secrets; guesses
+//! can still be any word.
+
+use wordle_game::{Word, WordPool};
+
+/// Which words secrets are drawn from while practicing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PracticeFilter {
+    /// A letter the secret must contain
+    pub contains: Option<char>,
+    /// The first letter of the secret
+    pub starts_with: Option<char>,
+    /// Only secrets without a letter used twice
+    pub no_repeats: bool,
+}
+
+impl PracticeFilter {
+    /// Whether `word` may be a secret
+    pub fn matches(&self, word: &Word) -> bool {
+        let letters: Vec<char> = word.letters().map(|letter| letter.char()).collect();
+        let contains = self.contains.is_none_or(|ch| letters.contains(&ch));
+        let starts_with = self
+            .starts_with
+            .is_none_or(|ch| letters.first() == Some(&ch));
+        let no_repeats = !self.no_repeats
+            || letters
+                .iter()
+                .enumerate()
+                .all(|(index, ch)| !letters[..index].contains(ch));
+        contains && starts_with && no_repeats
+    }
+
+    /// The words of `word_pool` that may be secrets
+    pub fn apply(&self, word_pool: &WordPool) -> WordPool {
+        word_pool.filtered(|word| self.matches(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let pool = WordPool::from_strings(["straß", "spiel", "hallo", "sonne"].map(String::from));
+        let words = |filter: PracticeFilter| -> Vec<String> {
+            filter.apply(&pool).iter().map(Word::to_string).collect()
+        };
+        assert_eq!(words(PracticeFilter::default()).len(), 4);
+        let filter = PracticeFilter {
+            starts_with: Some('s'),
+            ..PracticeFilter::default()
+        };
+        assert_eq!(words(filter), ["straß", "spiel", "sonne"]);
+        let filter = PracticeFilter {
+            no_repeats: true,
+            ..filter
+        };
+        assert_eq!(words(filter), ["straß", "spiel"]);
+        let filter = PracticeFilter {
+            contains: Some('ß'),
+            ..filter
+        };
+        assert_eq!(words(filter), ["straß"]);
+    }
+}
@@ -0,0 +1,172 @@
+//! Data kept per player profile, so several people can share a computer.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use wordle_game::{BanList, Language};
+
+use crate::animation::Alert;
+use crate::theme::ThemeName;
+use crate::widgets::{FeedbackStyle, KeyboardLayout, TileStyle};
+
+/// Profile used when none is given
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Load the ban list of a profile (empty if none was saved)
+pub fn load_ban_list(profile: &str) -> io::Result<BanList> {
+    match ban_list_path(profile) {
+        Some(path) if path.exists() => BanList::load(path),
+        _ => Ok(BanList::new()),
+    }
+}
+
+/// Save the ban list of a profile
+pub fn save_ban_list(profile: &str, bans: &BanList) -> io::Result<()> {
+    let path = ban_list_path(profile)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    bans.save(path)
+}
+
+/// Settings of a profile, as changed on the settings screen
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: ThemeName,
+    /// Revealed hints must be used in later guesses
+    pub hard_mode: bool,
+    /// Language of the words, unless given on the command line
+    pub language: Language,
+    /// Language of messages and help. None for the language of the words.
+    pub ui_language: Option<Language>,
+    pub keyboard_layout: KeyboardLayout,
+    /// How the tiles of the board are drawn
+    pub tile_style: TileStyle,
+    /// Version of the application the profile was last used with, to tell
+    /// what changed since. None before the first run.
+    pub last_run_version: Option<String>,
+    /// Show what's new after upgrading
+    pub show_whats_new: bool,
+    /// Propose a first guess the player hasn't used recently
+    pub suggest_opener: bool,
+    /// Help from the solver: the keyboard is sorted by how many of the
+    /// words still possible have each letter
+    pub assist: bool,
+    /// Switch the text of a custom theme to black or white if it is hard
+    /// to read on the theme's colors
+    pub readable_text: bool,
+    /// Don't animate anything, such as the shake of a rejected guess
+    pub reduced_motion: bool,
+    /// Whether the feedback on letters is told by colors, symbols or both
+    pub feedback_style: FeedbackStyle,
+    /// How the player is alerted when a guess is rejected
+    pub invalid_guess_alert: Alert,
+    /// File the finished games (and so the statistics) are kept in,
+    /// instead of the profile's directory
+    pub history_path: Option<PathBuf>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: ThemeName::default(),
+            hard_mode: false,
+            language: Language::default(),
+            ui_language: None,
+            keyboard_layout: KeyboardLayout::default(),
+            tile_style: TileStyle::default(),
+            last_run_version: None,
+            show_whats_new: true,
+            suggest_opener: false,
+            assist: false,
+            readable_text: false,
+            reduced_motion: false,
+            feedback_style: FeedbackStyle::default(),
+            invalid_guess_alert: Alert::default(),
+            history_path: None,
+        }
+    }
+}
+
+/// Load the settings of a profile (defaults if none were saved)
+pub fn load_settings(profile: &str) -> io::Result<Settings> {
+    match settings_path(profile) {
+        Some(path) if path.exists() => {
+            let json = fs::read_to_string(path)?;
+            serde_json::from_str(&json)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+        }
+        _ => Ok(Settings::default()),
+    }
+}
+
+/// Save the settings of a profile
+pub fn save_settings(profile: &str, settings: &Settings) -> io::Result<()> {
+    let path = settings_path(profile)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(settings)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    fs::write(path, json)
+}
+
+/// Where a profile's finished games are saved: the file chosen in the
+/// settings, else in the profile's directory (None if there is no data
+/// directory)
+pub fn history_path(profile: &str, settings: &Settings) -> Option<PathBuf> {
+    settings
+        .history_path
+        .clone()
+        .or_else(|| default_history_path(profile))
+}
+
+/// Where a profile's finished games are saved unless the settings say
+/// otherwise
+pub fn default_history_path(profile: &str) -> Option<PathBuf> {
+    profile_dir(profile).map(|dir| dir.join("history.json"))
+}
+
+/// Where a profile's statistics are saved (None if there is no data
+/// directory)
+pub fn stats_path(profile: &str) -> Option<PathBuf> {
+    profile_dir(profile).map(|dir| dir.join("stats.json"))
+}
+
+/// Where a profile's settings are saved (None if there is no data
+/// directory)
+pub fn settings_path(profile: &str) -> Option<PathBuf> {
+    profile_dir(profile).map(|dir| dir.join("settings.json"))
+}
+
+fn ban_list_path(profile: &str) -> Option<PathBuf> {
+    profile_dir(profile).map(|dir| dir.join("bans.json"))
+}
+
+/// Directory of a profile's data (None if there is no data directory)
+fn profile_dir(profile: &str) -> Option<PathBuf> {
+    let profile = if profile.is_empty() {
+        DEFAULT_PROFILE
+    } else {
+        profile
+    };
+    dirs::data_dir().map(|dir| dir.join("wordle").join("profiles").join(file_name(profile)))
+}
+
+/// Turn a user-chosen name into a safe file name
+pub(crate) fn file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
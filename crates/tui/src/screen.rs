@@ -0,0 +1,438 @@
+//! The screens of the app. They form a stack: the game is always at the
+//! bottom, menus and pages are pushed on top of it and popped when closed.
+
+use wordle_game::{Language, WORD_LENGTH};
+
+use crate::animation::Alert;
+use crate::changelog::Release;
+use crate::practice::PracticeFilter;
+use crate::profile::Settings;
+use crate::theme::ThemeName;
+use crate::widgets::{FeedbackStyle, KeyboardLayout, TileStyle};
+
+/// A screen of the app
+#[derive(Debug, Clone, PartialEq)]
+pub enum Screen {
+    /// The board of the current game
+    Playing,
+    /// Statistics page, shown instead of the board
+    Stats,
+    /// Releases the player hasn't seen yet
+    WhatsNew(Vec<&'static Release>),
+    /// The menu, leading to the other screens
+    Menu(Selection<MenuItem>),
+    /// The settings screen
+    Settings(Selection<SettingItem>),
+    /// Choice of how the next games are played, shown at startup
+    ModeSelect(Selection<GameMode>),
+    /// Asks before quitting a game in progress
+    ConfirmQuit,
+    /// Version of the game and of its dictionary, for bug reports
+    About,
+    /// Finished games, newest first, with the selected one
+    History(usize),
+    /// The board of a finished game, by its position in the history list
+    PastGame(usize),
+    /// The guesses of a lost game next to the ones the solver would have
+    /// made, shown right after losing
+    Analysis,
+    /// Which words to practice on
+    PracticeFilter(Selection<FilterItem>),
+}
+
+impl Screen {
+    /// Whether the screen is drawn over the one below it instead of
+    /// replacing it, like a dialog
+    pub fn is_overlay(&self) -> bool {
+        matches!(self, Screen::ConfirmQuit)
+    }
+}
+
+/// The item highlighted in a list of `T`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selection<T: 'static> {
+    items: &'static [T],
+    index: usize,
+}
+
+impl<T: Copy + 'static> Selection<T> {
+    /// Select the first of `items`, which must not be empty
+    pub fn new(items: &'static [T]) -> Self {
+        Self { items, index: 0 }
+    }
+
+    /// The highlighted item
+    pub fn selected(&self) -> T {
+        self.items[self.index]
+    }
+
+    /// Index of the highlighted item
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// All items, in display order
+    pub fn items(&self) -> &'static [T] {
+        self.items
+    }
+
+    /// Highlight the previous item, wrapping around
+    pub fn up(&mut self) {
+        self.index = (self.index + self.items.len() - 1) % self.items.len();
+    }
+
+    /// Highlight the next item, wrapping around
+    pub fn down(&mut self) {
+        self.index = (self.index + 1) % self.items.len();
+    }
+}
+
+/// Entries of the menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuItem {
+    Resume,
+    Settings,
+    Statistics,
+    GameMode,
+    PracticeFilter,
+    History,
+    About,
+    Quit,
+}
+
+impl MenuItem {
+    pub const ALL: [MenuItem; 8] = [
+        MenuItem::Resume,
+        MenuItem::Settings,
+        MenuItem::Statistics,
+        MenuItem::GameMode,
+        MenuItem::PracticeFilter,
+        MenuItem::History,
+        MenuItem::About,
+        MenuItem::Quit,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MenuItem::Resume => "Resume",
+            MenuItem::Settings => "Settings",
+            MenuItem::Statistics => "Statistics",
+            MenuItem::GameMode => "Game mode",
+            MenuItem::PracticeFilter => "Practice filter",
+            MenuItem::History => "History",
+            MenuItem::About => "About",
+            MenuItem::Quit => "Quit",
+        }
+    }
+}
+
+/// How secrets are picked and games are played
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    /// A random word, without repeats within a session
+    Classic,
+    /// The same word for everyone today
+    Daily,
+    /// Classic, with hard mode enforced
+    Hard,
+    /// Failed words come back until they are solved
+    Practice,
+    /// Words with many look-alikes
+    Adversarial,
+    /// Four boards solved with the same guesses
+    Quordle,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 6] = [
+        GameMode::Classic,
+        GameMode::Daily,
+        GameMode::Hard,
+        GameMode::Practice,
+        GameMode::Adversarial,
+        GameMode::Quordle,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "Classic",
+            GameMode::Daily => "Daily",
+            GameMode::Hard => "Hard",
+            GameMode::Practice => "Practice",
+            GameMode::Adversarial => "Adversarial",
+            GameMode::Quordle => "Quordle",
+        }
+    }
+
+    /// One line explaining the mode
+    pub fn description(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "a new random word every game",
+            GameMode::Daily => "today's word, the same for everyone",
+            GameMode::Hard => "revealed hints must be used",
+            GameMode::Practice => "missed words come back later",
+            GameMode::Adversarial => "words with many look-alikes",
+            GameMode::Quordle => "four words at once, every guess counts for all",
+        }
+    }
+}
+
+/// Rows of the settings screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingItem {
+    Theme,
+    HardMode,
+    Language,
+    UiLanguage,
+    WordLength,
+    KeyboardLayout,
+    TileStyle,
+    SuggestOpener,
+    Assist,
+    ReadableText,
+    ReducedMotion,
+    FeedbackStyle,
+    InvalidGuessAlert,
+}
+
+impl SettingItem {
+    pub const ALL: [SettingItem; 13] = [
+        SettingItem::Theme,
+        SettingItem::HardMode,
+        SettingItem::Language,
+        SettingItem::UiLanguage,
+        SettingItem::WordLength,
+        SettingItem::KeyboardLayout,
+        SettingItem::TileStyle,
+        SettingItem::SuggestOpener,
+        SettingItem::Assist,
+        SettingItem::ReadableText,
+        SettingItem::ReducedMotion,
+        SettingItem::FeedbackStyle,
+        SettingItem::InvalidGuessAlert,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingItem::Theme => "Theme",
+            SettingItem::HardMode => "Hard mode",
+            SettingItem::Language => "Language",
+            SettingItem::UiLanguage => "Messages",
+            SettingItem::WordLength => "Word length",
+            SettingItem::KeyboardLayout => "Keyboard",
+            SettingItem::TileStyle => "Tiles",
+            SettingItem::SuggestOpener => "Suggest opener",
+            SettingItem::Assist => "Assist (sorted keyboard)",
+            SettingItem::ReadableText => "Readable text",
+            SettingItem::ReducedMotion => "Reduced motion",
+            SettingItem::FeedbackStyle => "Feedback",
+            SettingItem::InvalidGuessAlert => "Alert on invalid guess",
+        }
+    }
+
+    /// The current value, for display
+    pub fn value(&self, settings: &Settings) -> String {
+        match self {
+            SettingItem::Theme => settings.theme.name().to_string(),
+            SettingItem::HardMode => if settings.hard_mode { "On" } else { "Off" }.to_string(),
+            SettingItem::Language => settings.language.name().to_string(),
+            SettingItem::UiLanguage => settings
+                .ui_language
+                .map_or("Same as words", |language| language.name())
+                .to_string(),
+            SettingItem::WordLength => format!("{WORD_LENGTH} (fixed)"),
+            SettingItem::KeyboardLayout => settings.keyboard_layout.name().to_string(),
+            SettingItem::TileStyle => settings.tile_style.name().to_string(),
+            SettingItem::SuggestOpener => {
+                if settings.suggest_opener { "On" } else { "Off" }.to_string()
+            }
+            SettingItem::Assist => if settings.assist { "On" } else { "Off" }.to_string(),
+            SettingItem::ReadableText => {
+                if settings.readable_text { "On" } else { "Off" }.to_string()
+            }
+            SettingItem::ReducedMotion => {
+                if settings.reduced_motion { "On" } else { "Off" }.to_string()
+            }
+            SettingItem::FeedbackStyle => settings.feedback_style.name().to_string(),
+            SettingItem::InvalidGuessAlert => settings.invalid_guess_alert.name().to_string(),
+        }
+    }
+
+    /// Switch to the next (or previous) value. Returns false if the setting
+    /// can't be changed.
+    pub fn change(&self, settings: &mut Settings, forward: bool) -> bool {
+        match self {
+            SettingItem::Theme => settings.theme = cycle(&ThemeName::ALL, settings.theme, forward),
+            SettingItem::HardMode => settings.hard_mode = !settings.hard_mode,
+            SettingItem::Language => {
+                settings.language = cycle(&Language::ALL, settings.language, forward)
+            }
+            SettingItem::UiLanguage => {
+                let choices: Vec<Option<Language>> =
+                    [None].into_iter().chain(Language::ALL.map(Some)).collect();
+                settings.ui_language = cycle(&choices, settings.ui_language, forward)
+            }
+            // Words have a fixed number of letters throughout the game
+            SettingItem::WordLength => return false,
+            SettingItem::KeyboardLayout => {
+                settings.keyboard_layout =
+                    cycle(&KeyboardLayout::ALL, settings.keyboard_layout, forward)
+            }
+            SettingItem::TileStyle => {
+                settings.tile_style = cycle(&TileStyle::ALL, settings.tile_style, forward)
+            }
+            SettingItem::SuggestOpener => settings.suggest_opener = !settings.suggest_opener,
+            SettingItem::Assist => settings.assist = !settings.assist,
+            SettingItem::ReadableText => settings.readable_text = !settings.readable_text,
+            SettingItem::ReducedMotion => settings.reduced_motion = !settings.reduced_motion,
+            SettingItem::FeedbackStyle => {
+                settings.feedback_style =
+                    cycle(&FeedbackStyle::ALL, settings.feedback_style, forward)
+            }
+            SettingItem::InvalidGuessAlert => {
+                settings.invalid_guess_alert =
+                    cycle(&Alert::ALL, settings.invalid_guess_alert, forward)
+            }
+        }
+        true
+    }
+}
+
+/// Rows of the practice filter screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterItem {
+    Contains,
+    StartsWith,
+    NoRepeats,
+    Play,
+}
+
+impl FilterItem {
+    pub const ALL: [FilterItem; 4] = [
+        FilterItem::Contains,
+        FilterItem::StartsWith,
+        FilterItem::NoRepeats,
+        FilterItem::Play,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FilterItem::Contains => "Must contain",
+            FilterItem::StartsWith => "Starts with",
+            FilterItem::NoRepeats => "No repeated letters",
+            FilterItem::Play => "Play",
+        }
+    }
+
+    /// The current value, for display. `matching` is the number of words
+    /// the filter leaves.
+    pub fn value(&self, filter: &PracticeFilter, matching: usize) -> String {
+        // Uppercase, except for 'ß', whose uppercase form is "SS"
+        let letter = |letter: Option<char>| match letter {
+            None => "any letter".to_string(),
+            Some('ß') => "ß".to_string(),
+            Some(ch) => ch.to_uppercase().to_string(),
+        };
+        match self {
+            FilterItem::Contains => letter(filter.contains),
+            FilterItem::StartsWith => letter(filter.starts_with),
+            FilterItem::NoRepeats => if filter.no_repeats { "On" } else { "Off" }.to_string(),
+            FilterItem::Play if matching == 1 => "1 word".to_string(),
+            FilterItem::Play => format!("{matching} words"),
+        }
+    }
+
+    /// Switch to the next (or previous) value, going through the letters
+    /// of `language`. Returns false if the row has no value.
+    pub fn change(&self, filter: &mut PracticeFilter, language: Language, forward: bool) -> bool {
+        let letters: Vec<Option<char>> = [None]
+            .into_iter()
+            .chain(language.alphabet().chars().map(Some))
+            .collect();
+        match self {
+            FilterItem::Contains => filter.contains = cycle(&letters, filter.contains, forward),
+            FilterItem::StartsWith => {
+                filter.starts_with = cycle(&letters, filter.starts_with, forward)
+            }
+            FilterItem::NoRepeats => filter.no_repeats = !filter.no_repeats,
+            FilterItem::Play => return false,
+        }
+        true
+    }
+
+    /// Set the letter of the row, None for any letter. Returns false if
+    /// the row has no letter.
+    pub fn set_letter(&self, filter: &mut PracticeFilter, letter: Option<char>) -> bool {
+        match self {
+            FilterItem::Contains => filter.contains = letter,
+            FilterItem::StartsWith => filter.starts_with = letter,
+            FilterItem::NoRepeats | FilterItem::Play => return false,
+        }
+        true
+    }
+}
+
+/// The value after (or before) `current` in `values`, wrapping around
+fn cycle<T: Copy + PartialEq>(values: &[T], current: T, forward: bool) -> T {
+    let index = values.iter().position(|&v| v == current).unwrap_or(0);
+    let next = if forward {
+        index + 1
+    } else {
+        index + values.len() - 1
+    };
+    values[next % values.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_wraps() {
+        let mut selection = Selection::new(&MenuItem::ALL);
+        assert_eq!(selection.selected(), MenuItem::Resume);
+        selection.up();
+        assert_eq!(selection.selected(), MenuItem::Quit);
+        selection.down();
+        selection.down();
+        assert_eq!(selection.selected(), MenuItem::Settings);
+    }
+
+    #[test]
+    fn test_change_settings() {
+        let mut settings = Settings::default();
+        assert!(SettingItem::Language.change(&mut settings, true));
+        assert_eq!(settings.language, Language::English);
+        assert!(SettingItem::Language.change(&mut settings, true));
+        assert_eq!(settings.language, Language::German);
+
+        assert_eq!(SettingItem::UiLanguage.value(&settings), "Same as words");
+        assert!(SettingItem::UiLanguage.change(&mut settings, false));
+        assert_eq!(settings.ui_language, Some(Language::English));
+
+        assert!(SettingItem::Theme.change(&mut settings, false));
+        assert_eq!(SettingItem::Theme.value(&settings), "High contrast");
+        assert!(SettingItem::HardMode.change(&mut settings, false));
+        assert!(settings.hard_mode);
+        assert!(SettingItem::SuggestOpener.change(&mut settings, true));
+        assert_eq!(SettingItem::SuggestOpener.value(&settings), "On");
+
+        let before = settings.clone();
+        assert!(!SettingItem::WordLength.change(&mut settings, true));
+        assert_eq!(settings, before);
+    }
+
+    #[test]
+    fn test_change_filter() {
+        let mut filter = PracticeFilter::default();
+        assert!(FilterItem::Contains.change(&mut filter, Language::German, false));
+        assert_eq!(filter.contains, Some('ß'));
+        assert_eq!(FilterItem::Contains.value(&filter, 0), "ß");
+        assert!(FilterItem::StartsWith.change(&mut filter, Language::German, true));
+        assert_eq!(FilterItem::StartsWith.value(&filter, 0), "A");
+        assert!(FilterItem::StartsWith.set_letter(&mut filter, None));
+        assert_eq!(FilterItem::StartsWith.value(&filter, 0), "any letter");
+        assert!(!FilterItem::Play.change(&mut filter, Language::German, true));
+        assert_eq!(FilterItem::Play.value(&filter, 12), "12 words");
+    }
+}
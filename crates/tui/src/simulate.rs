@@ -0,0 +1,116 @@
+//! Playing many games without a player, to generate datasets of games or
+//! to feed the statistics and analyses with lots of them.
+
+use std::io::{self, Write};
+use std::time::SystemTime;
+
+use clap::ValueEnum;
+use wordle_game::solver::play;
+use wordle_game::{
+    Candidates, EntropyStrategy, Game, GameConfig, GameRecord, RandomStrategy, Strategy, WordPool,
+    WordSampler,
+};
+
+use crate::output::SimulationSummary;
+
+/// How the simulated player guesses
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum SimulationStrategy {
+    /// The guess telling the most about the secret
+    #[default]
+    Entropy,
+    /// A random word that can still be the secret
+    Random,
+}
+
+/// Play `games` games against secrets drawn from `secrets`, writing each
+/// one as a line of JSON (a `GameRecord`) to `out`
+pub fn simulate(
+    word_pool: &WordPool,
+    secrets: &mut WordSampler,
+    strategy: SimulationStrategy,
+    games: usize,
+    config: &GameConfig,
+    mut out: impl Write,
+) -> io::Result<SimulationSummary> {
+    // The entropy strategy always opens with the same word, and finding it
+    // takes longest, so it is only searched once
+    let (strategy, opener): (&dyn Strategy, _) = match strategy {
+        SimulationStrategy::Entropy => (
+            &EntropyStrategy,
+            EntropyStrategy.next_guess(word_pool, &Candidates::new(word_pool)),
+        ),
+        SimulationStrategy::Random => (&RandomStrategy, None),
+    };
+
+    let mut summary = SimulationSummary::default();
+    for _ in 0..games {
+        let mut game = Game::with_selector(word_pool.clone(), secrets, config.clone())
+            .map_err(io::Error::other)?;
+        if let Some(opener) = &opener {
+            game.guess_word(opener).map_err(io::Error::other)?;
+        }
+        play(&mut game, word_pool, strategy).map_err(io::Error::other)?;
+        // The strategy ran out of ideas
+        game.forfeit();
+
+        let record = GameRecord::new(&game, SystemTime::now()).expect("the game is over");
+        serde_json::to_writer(&mut out, &record).map_err(io::Error::other)?;
+        writeln!(out)?;
+        summary.record(&game.state());
+    }
+    out.flush()?;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordle_game::History;
+
+    fn pool() -> WordPool {
+        WordPool::from_strings(
+            ["hatch", "latch", "match", "patch", "crane", "hello"].map(String::from),
+        )
+    }
+
+    #[test]
+    fn test_writes_one_record_per_game() {
+        let pool = pool();
+        let mut out = Vec::new();
+        let summary = simulate(
+            &pool,
+            &mut pool.seeded_sampler(4058),
+            SimulationStrategy::Entropy,
+            8,
+            &GameConfig::default(),
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(summary.games, 8);
+        assert_eq!(summary.won, 8);
+
+        let mut history = History::new();
+        for line in String::from_utf8(out).unwrap().lines() {
+            history.add(serde_json::from_str(line).unwrap());
+        }
+        assert_eq!(history.games.len(), 8);
+        assert_eq!(history.statistics().games_won(), 8);
+    }
+
+    #[test]
+    fn test_random_strategy_finishes_games() {
+        let pool = pool();
+        let summary = simulate(
+            &pool,
+            &mut pool.sampler(),
+            SimulationStrategy::Random,
+            5,
+            &GameConfig::default(),
+            io::sink(),
+        )
+        .unwrap();
+        assert_eq!(summary.games, 5);
+        assert!(summary.average_guesses > 0.0);
+    }
+}
@@ -0,0 +1,360 @@
+//! Syncing the game history between devices through storage the player
+//! provides, so streaks follow them from one computer to another.
+//!
+//! Games are merged by their id, so syncing never loses a game, no matter
+//! in which order devices sync. Only the history is synced: the counts and
+//! streaks of the statistics are rebuilt from it on start (see
+//! [`Statistics::catch_up`](wordle_game::Statistics::catch_up)), while
+//! the details about guesses (letter usage, favorite opener, ...) stay
+//! with each device. They are sums over games without the games' ids, so
+//! merging them from another device would count games twice.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use wordle_game::History;
+
+#[cfg(feature = "http-sync")]
+use crate::base64;
+
+/// Name of the history file on the backend
+const HISTORY_FILE: &str = "history.json";
+
+/// Storage for files shared between devices
+pub trait SyncBackend {
+    /// The current content of a file, None if there is none yet
+    fn pull(&self, name: &str) -> io::Result<Option<Vec<u8>>>;
+    /// Replace the content of a file
+    fn push(&self, name: &str, content: &[u8]) -> io::Result<()>;
+}
+
+/// Backend for a `--sync` argument: `git:<path>` for a Git checkout, or an
+/// `http://`/`https://` URL of a directory on a WebDAV or other server
+/// accepting GET and PUT (with the `http-sync` feature)
+pub fn backend(spec: &str) -> io::Result<Box<dyn SyncBackend>> {
+    if let Some(path) = spec.strip_prefix("git:") {
+        return Ok(Box::new(GitBackend::new(path)));
+    }
+    if spec.starts_with("http://") || spec.starts_with("https://") {
+        return http_backend(spec);
+    }
+    Err(io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("unknown sync backend '{spec}', expected git:<path> or an http(s) URL"),
+    ))
+}
+
+#[cfg(feature = "http-sync")]
+fn http_backend(url: &str) -> io::Result<Box<dyn SyncBackend>> {
+    Ok(Box::new(HttpBackend::from_env(url)))
+}
+
+#[cfg(not(feature = "http-sync"))]
+fn http_backend(_url: &str) -> io::Result<Box<dyn SyncBackend>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "syncing over HTTP needs the http-sync feature",
+    ))
+}
+
+/// Merge the history saved at `path` with the one on the backend, and
+/// save the result in both places. Returns the merged history.
+pub fn sync_history(backend: &dyn SyncBackend, path: &Path) -> io::Result<History> {
+    let remote = match backend.pull(HISTORY_FILE)? {
+        Some(content) => History::from_json(&String::from_utf8_lossy(&content))?,
+        None => History::new(),
+    };
+    let merged = remote.save_merged(path)?;
+    let json = serde_json::to_string_pretty(&merged)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    backend.push(HISTORY_FILE, json.as_bytes())?;
+    Ok(merged)
+}
+
+/// Files in a Git checkout, pulled from and pushed to its upstream branch
+/// if it has one. Commits that weren't pushed yet (e.g. because the last
+/// push failed) are rebased onto the upstream branch and pushed along,
+/// taking their own side of a conflict; pushing fails if that doesn't work
+/// out, e.g. because the checkout has uncommitted changes.
+#[derive(Debug, Clone)]
+pub struct GitBackend {
+    repo: PathBuf,
+}
+
+impl GitBackend {
+    pub fn new(repo: impl Into<PathBuf>) -> Self {
+        Self { repo: repo.into() }
+    }
+
+    /// Run git in the checkout, returning its output
+    fn git(&self, args: &[&str]) -> io::Result<Vec<u8>> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(&self.repo)
+            .args(args)
+            .output()?;
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(output.stdout)
+    }
+
+    fn has_upstream(&self) -> bool {
+        self.git(&["rev-parse", "--verify", "--quiet", "@{upstream}"])
+            .is_ok()
+    }
+}
+
+impl SyncBackend for GitBackend {
+    fn pull(&self, name: &str) -> io::Result<Option<Vec<u8>>> {
+        if self.has_upstream() {
+            self.git(&["fetch", "--quiet"])?;
+            // Not in the upstream branch yet
+            return Ok(self.git(&["show", &format!("@{{upstream}}:{name}")]).ok());
+        }
+        match fs::read(self.repo.join(name)) {
+            Ok(content) => Ok(Some(content)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn push(&self, name: &str, content: &[u8]) -> io::Result<()> {
+        let upstream = self.has_upstream();
+        if upstream {
+            // Build on the latest upstream commit, so pushing doesn't
+            // conflict. The content was merged with what was pulled, so
+            // it wins over older local commits.
+            self.git(&["fetch", "--quiet"])?;
+            if let Err(err) = self.git(&["rebase", "--quiet", "-X", "theirs", "@{upstream}"]) {
+                self.git(&["rebase", "--abort"]).ok();
+                return Err(err);
+            }
+        }
+        fs::write(self.repo.join(name), content)?;
+        self.git(&["add", "--", name])?;
+        // Nothing to commit if no device played since the last sync
+        if self.git(&["diff", "--cached", "--quiet"]).is_err() {
+            self.git(&["commit", "--quiet", "-m", &format!("Update {name}")])?;
+        }
+        if upstream {
+            self.git(&["push", "--quiet"])?;
+        }
+        Ok(())
+    }
+}
+
+/// Files in a directory on a web server, e.g. WebDAV, read with GET and
+/// written with PUT. Uses basic authentication if `WORDLE_SYNC_USER` and
+/// `WORDLE_SYNC_PASSWORD` are set.
+#[cfg(feature = "http-sync")]
+#[derive(Debug, Clone)]
+pub struct HttpBackend {
+    url: String,
+    credentials: Option<(String, String)>,
+}
+
+#[cfg(feature = "http-sync")]
+impl HttpBackend {
+    pub fn new(url: impl Into<String>, credentials: Option<(String, String)>) -> Self {
+        Self {
+            url: url.into(),
+            credentials,
+        }
+    }
+
+    /// Backend for `url` with the credentials from the environment
+    pub fn from_env(url: &str) -> Self {
+        let credentials = std::env::var("WORDLE_SYNC_USER")
+            .ok()
+            .zip(std::env::var("WORDLE_SYNC_PASSWORD").ok());
+        Self::new(url, credentials)
+    }
+
+    fn request(&self, method: &str, name: &str) -> ureq::Request {
+        let url = format!("{}/{name}", self.url.trim_end_matches('/'));
+        let request = ureq::request(method, &url);
+        match &self.credentials {
+            Some((user, password)) => {
+                let token = base64::encode(format!("{user}:{password}").as_bytes());
+                request.set("Authorization", &format!("Basic {token}"))
+            }
+            None => request,
+        }
+    }
+}
+
+#[cfg(feature = "http-sync")]
+impl SyncBackend for HttpBackend {
+    fn pull(&self, name: &str) -> io::Result<Option<Vec<u8>>> {
+        match self.request("GET", name).call() {
+            Ok(response) => {
+                let mut content = Vec::new();
+                io::Read::read_to_end(&mut response.into_reader(), &mut content)?;
+                Ok(Some(content))
+            }
+            Err(ureq::Error::Status(404, _)) => Ok(None),
+            Err(err) => Err(io::Error::other(err)),
+        }
+    }
+
+    fn push(&self, name: &str, content: &[u8]) -> io::Result<()> {
+        self.request("PUT", name)
+            .send_bytes(content)
+            .map(|_| ())
+            .map_err(io::Error::other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::time::{Duration, UNIX_EPOCH};
+    use wordle_game::{Game, GameRecord, Word, WordPool};
+
+    /// Backend keeping the files in memory
+    #[derive(Default)]
+    struct MemoryBackend {
+        files: RefCell<HashMap<String, Vec<u8>>>,
+    }
+
+    impl SyncBackend for MemoryBackend {
+        fn pull(&self, name: &str) -> io::Result<Option<Vec<u8>>> {
+            Ok(self.files.borrow().get(name).cloned())
+        }
+
+        fn push(&self, name: &str, content: &[u8]) -> io::Result<()> {
+            self.files
+                .borrow_mut()
+                .insert(name.to_string(), content.to_vec());
+            Ok(())
+        }
+    }
+
+    fn won_game(finished_at: u64) -> GameRecord {
+        let pool = WordPool::from_strings(["hello"].map(String::from));
+        let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+        game.guess("hello").unwrap();
+        GameRecord::new(&game, UNIX_EPOCH + Duration::from_secs(finished_at)).unwrap()
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("wordle_sync_{}_{name}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_devices_end_up_with_all_games() {
+        let dir = temp_dir("memory");
+        let laptop = dir.join("laptop.json");
+        let server = dir.join("server.json");
+        let backend = MemoryBackend::default();
+
+        History::record_merged(&laptop, won_game(10)).unwrap();
+        History::record_merged(&server, won_game(20)).unwrap();
+        sync_history(&backend, &laptop).unwrap();
+        let merged = sync_history(&backend, &server).unwrap();
+        assert_eq!(merged.games.len(), 2);
+        assert_eq!(merged.statistics().current_streak(), 2);
+
+        // Syncing again changes nothing
+        assert_eq!(sync_history(&backend, &laptop).unwrap(), merged);
+        assert_eq!(History::load(&server).unwrap(), merged);
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_backend_spec() {
+        assert!(backend("git:/tmp/sync").is_ok());
+        assert_eq!(
+            backend("ftp://example.com").err().unwrap().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_git_backend() {
+        if Command::new("git").arg("--version").output().is_err() {
+            return;
+        }
+        let dir = temp_dir("git");
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .current_dir(&dir)
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {args:?}");
+        };
+        git(&["init", "--quiet", "--bare", "remote.git"]);
+        git(&["clone", "--quiet", "remote.git", "laptop"]);
+        let laptop = dir.join("laptop");
+        let laptop = laptop.to_str().unwrap();
+        git(&["-C", laptop, "config", "user.name", "Wordle"]);
+        git(&["-C", laptop, "config", "user.email", "wordle@example.com"]);
+        git(&[
+            "-C",
+            laptop,
+            "commit",
+            "--quiet",
+            "--allow-empty",
+            "-m",
+            "Init",
+        ]);
+        git(&["-C", laptop, "push", "--quiet", "-u", "origin", "HEAD"]);
+        git(&["clone", "--quiet", "remote.git", "server"]);
+        let server = dir.join("server");
+        let server_path = server.to_str().unwrap();
+        git(&["-C", server_path, "config", "user.name", "Wordle"]);
+        git(&[
+            "-C",
+            server_path,
+            "config",
+            "user.email",
+            "wordle@example.com",
+        ]);
+
+        let laptop = GitBackend::new(dir.join("laptop"));
+        let server = GitBackend::new(dir.join("server"));
+        assert_eq!(server.pull("history.json").unwrap(), None);
+        laptop.push("history.json", b"first").unwrap();
+        assert_eq!(
+            server.pull("history.json").unwrap(),
+            Some(b"first".to_vec())
+        );
+        server.push("history.json", b"second").unwrap();
+        assert_eq!(
+            laptop.pull("history.json").unwrap(),
+            Some(b"second".to_vec())
+        );
+        // Pushing without pulling first doesn't conflict
+        laptop.push("history.json", b"third").unwrap();
+        assert_eq!(
+            server.pull("history.json").unwrap(),
+            Some(b"third".to_vec())
+        );
+        // Commits that weren't pushed yet are kept and pushed along
+        fs::write(dir.join("server/notes.txt"), "mine").unwrap();
+        git(&["-C", server_path, "add", "notes.txt"]);
+        git(&["-C", server_path, "commit", "--quiet", "-m", "Notes"]);
+        laptop.push("history.json", b"fourth").unwrap();
+        server.push("history.json", b"fifth").unwrap();
+        assert_eq!(laptop.pull("notes.txt").unwrap(), Some(b"mine".to_vec()));
+        assert_eq!(
+            laptop.pull("history.json").unwrap(),
+            Some(b"fifth".to_vec())
+        );
+        fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,353 @@
+//! Drive the application without a terminal, for tests of whole screens:
+//! send it keys, let time pass and look at what it draws into a test
+//! buffer. Nothing is saved, since the harness sets up no profile.
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use ratatui::{Terminal, backend::TestBackend, buffer::Buffer};
+use wordle_game::{BanList, Language, Word, WordPool};
+
+use crate::app::App;
+use crate::profile::Settings;
+use crate::simulate::SimulationStrategy;
+
+pub use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+/// Terminal size harnesses start with
+const DEFAULT_SIZE: (u16, u16) = (80, 30);
+
+/// The application running in a test terminal
+pub struct Harness {
+    app: App,
+    terminal: Terminal<TestBackend>,
+    /// Time as the app sees it, moved on by [`Harness::advance`]
+    now: Instant,
+}
+
+impl Harness {
+    /// An app whose first game has the given secret, with default settings
+    pub fn new(word_pool: WordPool, language: Language, secret: Word) -> Self {
+        Self::with_settings(word_pool, language, secret, Settings::default())
+    }
+
+    /// An app whose first game has the given secret
+    pub fn with_settings(
+        word_pool: WordPool,
+        language: Language,
+        secret: Word,
+        settings: Settings,
+    ) -> Self {
+        let app = App::with_secret(word_pool, language, secret, &BanList::new(), settings);
+        let (width, height) = DEFAULT_SIZE;
+        let terminal =
+            Terminal::new(TestBackend::new(width, height)).expect("test terminal can't fail");
+        Self {
+            app,
+            terminal,
+            now: Instant::now(),
+        }
+    }
+
+    /// Handle an input event, as if it came from the terminal
+    pub fn send(&mut self, event: Event) -> &mut Self {
+        self.app.handle_event(event);
+        self.app.tick(self.now);
+        self
+    }
+
+    /// Press a key without modifiers
+    pub fn press(&mut self, code: KeyCode) -> &mut Self {
+        self.send(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+    }
+
+    /// Press a key while holding Ctrl
+    pub fn ctrl(&mut self, ch: char) -> &mut Self {
+        self.send(Event::Key(KeyEvent::new(
+            KeyCode::Char(ch),
+            KeyModifiers::CONTROL,
+        )))
+    }
+
+    /// Type the characters of `text` one key at a time
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for ch in text.chars() {
+            self.press(KeyCode::Char(ch));
+        }
+        self
+    }
+
+    /// Type a word and submit it
+    pub fn guess(&mut self, word: &str) -> &mut Self {
+        self.type_text(word).press(KeyCode::Enter)
+    }
+
+    /// Let time pass, e.g. for an animation to end or a toast to go away
+    pub fn advance(&mut self, duration: Duration) -> &mut Self {
+        self.now += duration;
+        self.app.tick(self.now);
+        self
+    }
+
+    /// Let a bot play, making a move every `delay` as the harness's time
+    /// advances
+    pub fn start_demo(&mut self, strategy: SimulationStrategy, delay: Duration) -> &mut Self {
+        self.app.start_demo(strategy, delay);
+        self
+    }
+
+    /// Change the size of the terminal, as when its window is resized
+    pub fn resize(&mut self, width: u16, height: u16) -> &mut Self {
+        self.terminal.backend_mut().resize(width, height);
+        self.send(Event::Resize(width, height))
+    }
+
+    /// Draw the app and return what is on screen
+    pub fn render(&mut self) -> io::Result<&Buffer> {
+        self.app.receive_results();
+        self.terminal.draw(|frame| self.app.render(frame))?;
+        self.app.drawn();
+        Ok(self.terminal.backend().buffer())
+    }
+
+    /// Draw the app and return the text on screen, one line per row with
+    /// trailing spaces removed
+    pub fn screen_text(&mut self) -> io::Result<String> {
+        let buffer = self.render()?;
+        let width = usize::from(buffer.area.width);
+        let lines: Vec<String> = buffer
+            .content
+            .chunks(width.max(1))
+            .map(|row| {
+                let line: String = row.iter().map(|cell| cell.symbol()).collect();
+                line.trim_end().to_string()
+            })
+            .collect();
+        Ok(lines.join("\n"))
+    }
+
+    /// Whether an animation is running
+    pub fn is_animating(&self) -> bool {
+        self.app.is_animating()
+    }
+
+    /// Title the app gives the terminal window
+    pub fn terminal_title(&self) -> String {
+        self.app.terminal_title()
+    }
+
+    /// Whether the app rang the terminal's bell since the last call
+    pub fn take_bell(&mut self) -> bool {
+        self.app.take_bell()
+    }
+
+    /// Whether the app asked to quit
+    pub fn has_quit(&self) -> bool {
+        self.app.should_quit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Modifier;
+
+    use super::*;
+    use crate::animation::Alert;
+    use crate::screen::GameMode;
+
+    fn harness() -> Harness {
+        let pool = WordPool::from_strings(["hello", "world", "crane"].map(String::from));
+        Harness::new(pool, Language::English, Word::parse("hello").unwrap())
+    }
+
+    #[test]
+    fn test_play_a_game() {
+        let mut harness = harness();
+        harness.guess("crane");
+        let text = harness.screen_text().unwrap();
+        assert!(text.contains(" C   R   A   N   E"), "{text}");
+
+        harness.guess("hello");
+        let text = harness.screen_text().unwrap();
+        assert!(text.contains("You won in 2 guesses!"), "{text}");
+    }
+
+    #[test]
+    fn test_analysis_after_losing() {
+        let words = [
+            "hello", "world", "crane", "slate", "pious", "dumpy", "fight",
+        ];
+        let pool = WordPool::from_strings(words.map(String::from));
+        let mut harness = Harness::new(pool, Language::English, Word::parse("hello").unwrap());
+        for word in &words[1..] {
+            harness.guess(word);
+        }
+        let text = loop {
+            let text = harness.screen_text().unwrap();
+            if !text.contains("Looking back") {
+                break text;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        };
+        assert!(text.contains("The word was HELLO"), "{text}");
+        assert!(text.contains(" 1  WORLD           7"), "{text}");
+
+        harness.press(KeyCode::Esc);
+        assert!(!harness.screen_text().unwrap().contains("ANALYSIS"));
+    }
+
+    #[test]
+    fn test_demo_plays_on_its_own() {
+        let mut harness = harness();
+        harness.start_demo(SimulationStrategy::Entropy, Duration::from_secs(1));
+        assert!(harness.screen_text().unwrap().contains("A bot is playing"));
+        let mut text = String::new();
+        for _ in 0..200 {
+            harness.advance(Duration::from_secs(1));
+            text = harness.screen_text().unwrap();
+            if text.contains("You won") {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(2));
+        }
+        assert!(text.contains("You won"), "{text}");
+
+        harness.press(KeyCode::Esc);
+        assert!(harness.has_quit());
+    }
+
+    #[test]
+    fn test_practice_filter() {
+        let mut harness = harness();
+        harness.ctrl('o');
+        for _ in 0..4 {
+            harness.press(KeyCode::Down);
+        }
+        harness.press(KeyCode::Enter).type_text("w");
+        let text = harness.screen_text().unwrap();
+        assert!(text.contains("Must contain: W"), "{text}");
+        assert!(text.contains("Play: 1 word"), "{text}");
+
+        for _ in 0..3 {
+            harness.press(KeyCode::Down);
+        }
+        harness.press(KeyCode::Enter).guess("world");
+        let text = harness.screen_text().unwrap();
+        assert!(text.contains("You won in 1 guess"), "{text}");
+        assert!(text.contains("Practice filter"), "{text}");
+    }
+
+    #[test]
+    fn test_rejected_guess_and_quit() {
+        let mut harness = harness();
+        harness.guess("zzzzz");
+        assert!(harness.screen_text().unwrap().contains("Not in word list"));
+        harness.advance(Duration::from_secs(10));
+        assert!(!harness.screen_text().unwrap().contains("Not in word list"));
+
+        harness.resize(40, 20).press(KeyCode::Esc);
+        assert_eq!(harness.render().unwrap().area.width, 40);
+        harness.press(KeyCode::Char('y'));
+        assert!(harness.has_quit());
+    }
+
+    #[test]
+    fn test_quordle_boards_are_recorded() {
+        let words = ["hello", "world", "crane", "slate"];
+        let pool = WordPool::from_strings(words.map(String::from));
+        let mut harness = Harness::new(pool, Language::English, Word::parse("hello").unwrap());
+        harness.app.start_mode(GameMode::Quordle);
+        let text = harness.screen_text().unwrap();
+        assert!(text.contains("4 boards"), "{text}");
+        harness.ctrl('r');
+
+        let results = harness.app.results().games;
+        assert_eq!(results.len(), 4);
+        assert!(
+            results
+                .iter()
+                .all(|game| !game.won && game.guesses.is_empty())
+        );
+        harness.ctrl('t');
+        let text = harness.screen_text().unwrap();
+        assert!(text.contains("Played: 4  Won: 0%"), "{text}");
+    }
+
+    #[test]
+    fn test_german_pages() {
+        let pool = WordPool::from_strings(["hello", "world", "crane"].map(String::from));
+        let mut harness = Harness::new(pool, Language::German, Word::parse("hello").unwrap());
+        harness.guess("world").ctrl('t');
+        let text = harness.screen_text().unwrap();
+        assert!(text.contains("Gespielt: 0"), "{text}");
+
+        harness.press(KeyCode::Esc).press(KeyCode::Esc);
+        let text = harness.screen_text().unwrap();
+        assert!(text.contains("Spiel beenden? (j/n)"), "{text}");
+    }
+
+    #[test]
+    fn test_reduced_motion() {
+        let mut harness = harness();
+        harness.guess("zzzzz");
+        assert!(harness.is_animating());
+
+        let pool = WordPool::from_strings(["hello", "world", "crane"].map(String::from));
+        let settings = Settings {
+            reduced_motion: true,
+            ..Settings::default()
+        };
+        let secret = Word::parse("hello").unwrap();
+        let mut harness = Harness::with_settings(pool, Language::English, secret, settings);
+        harness.guess("zzzzz");
+        assert!(!harness.is_animating());
+        assert!(harness.screen_text().unwrap().contains("Not in word list"));
+    }
+
+    fn harness_alerting(alert: Alert) -> Harness {
+        let pool = WordPool::from_strings(["hello", "world", "crane"].map(String::from));
+        let settings = Settings {
+            invalid_guess_alert: alert,
+            ..Settings::default()
+        };
+        let secret = Word::parse("hello").unwrap();
+        Harness::with_settings(pool, Language::English, secret, settings)
+    }
+
+    #[test]
+    fn test_terminal_title() {
+        let mut harness = harness();
+        assert_eq!(harness.terminal_title(), "Wordle – guess 1/6");
+        harness.guess("world");
+        assert_eq!(harness.terminal_title(), "Wordle – guess 2/6");
+        harness.guess("hello");
+        assert_eq!(harness.terminal_title(), "Wordle");
+
+        let pool = WordPool::from_strings(["hello", "world"].map(String::from));
+        let mut harness = Harness::new(pool, Language::German, Word::parse("hello").unwrap());
+        harness.guess("world");
+        assert_eq!(harness.terminal_title(), "Wordle – Versuch 2/6");
+    }
+
+    #[test]
+    fn test_alert_on_invalid_guess() {
+        let mut harness = harness();
+        harness.guess("zzzzz");
+        assert!(!harness.take_bell());
+
+        let mut harness = harness_alerting(Alert::Bell);
+        harness.guess("zzzzz");
+        assert!(harness.take_bell());
+        assert!(!harness.take_bell());
+
+        let mut harness = harness_alerting(Alert::Flash);
+        harness.type_text("zz").press(KeyCode::Enter);
+        assert!(!harness.take_bell());
+        let buffer = harness.render().unwrap();
+        assert!(buffer[(0, 0)].modifier.contains(Modifier::REVERSED));
+        harness.advance(Duration::from_secs(1));
+        assert!(!harness.is_animating());
+        let buffer = harness.render().unwrap();
+        assert!(!buffer[(0, 0)].modifier.contains(Modifier::REVERSED));
+    }
+}
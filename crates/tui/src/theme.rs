@@ -1,4 +1,38 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use clap::ValueEnum;
 use ratatui::style::Color;
+use serde::{Deserialize, Deserializer, Serialize, de};
+
+/// The built-in color schemes, as chosen in the settings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    /// Orange and blue instead of green and yellow, which look alike to
+    /// players with red-green color blindness
+    HighContrast,
+}
+
+impl ThemeName {
+    /// All built-in themes, in the order the settings cycle through them
+    pub const ALL: [ThemeName; 3] = [ThemeName::Dark, ThemeName::Light, ThemeName::HighContrast];
+
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ThemeName::Dark => "Dark",
+            ThemeName::Light => "Light",
+            ThemeName::HighContrast => "High contrast",
+        }
+    }
+}
 
 /// Wordle color scheme
 #[derive(Debug, Clone)]
@@ -17,6 +51,8 @@ pub struct Theme {
     pub background: Color,
     /// Border color
     pub border: Color,
+    /// Flash of a rejected guess
+    pub invalid: Color,
 }
 
 impl Default for Theme {
@@ -29,6 +65,560 @@ impl Default for Theme {
             text: Color::White,
             background: Color::Rgb(18, 18, 19),       // Near black #121213
             border: Color::Rgb(58, 58, 60),           // Same as empty
+            invalid: Color::Rgb(160, 60, 60),         // Muted red
+        }
+    }
+}
+
+impl Theme {
+    /// The colors of a built-in theme
+    pub fn named(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::default(),
+            ThemeName::Light => Self {
+                empty: Color::Rgb(211, 214, 218), // Light gray #d3d6da
+                text: Color::Black,
+                background: Color::White,
+                border: Color::Rgb(211, 214, 218), // Same as empty
+                ..Self::default()
+            },
+            ThemeName::HighContrast => Self {
+                correct: Color::Rgb(245, 121, 58),        // Orange #f5793a
+                wrong_position: Color::Rgb(133, 192, 249), // Blue #85c0f9
+                ..Self::default()
+            },
+        }
+    }
+
+    /// These colors with the ones set in `overrides` replaced, or why the
+    /// result would be unreadable
+    pub fn with_overrides(&self, overrides: &ThemeOverrides) -> Result<Self, &'static str> {
+        let pick = |color: Option<ThemeColor>, fallback: Color| color.map_or(fallback, |c| c.0);
+        let theme = Self {
+            correct: pick(overrides.correct, self.correct),
+            wrong_position: pick(overrides.wrong_position, self.wrong_position),
+            not_in_word: pick(overrides.not_in_word, self.not_in_word),
+            empty: pick(overrides.empty, self.empty),
+            text: pick(overrides.text, self.text),
+            background: pick(overrides.background, self.background),
+            border: pick(overrides.border, self.border),
+            invalid: pick(overrides.invalid, self.invalid),
+        };
+        if theme.text == theme.background {
+            return Err("text and background have the same color");
+        }
+        if theme.correct == theme.wrong_position
+            || theme.correct == theme.not_in_word
+            || theme.wrong_position == theme.not_in_word
+        {
+            return Err("feedback colors must all be different");
+        }
+        Ok(theme)
+    }
+}
+
+/// How many colors the terminal can show
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// Any RGB color
+    #[default]
+    TrueColor,
+    /// The 256 colors of xterm
+    Indexed256,
+    /// Only the 16 colors of the terminal's own palette
+    Ansi16,
+}
+
+impl ColorDepth {
+    /// What the terminal the game runs in supports, going by the
+    /// environment variables terminals set
+    pub fn detect() -> Self {
+        Self::from_env(
+            env::var("COLORTERM").ok().as_deref(),
+            env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    /// The depth that `$COLORTERM` and `$TERM` advertise. Terminals without
+    /// `$TERM` aren't Unix terminals, and those (like Windows Terminal)
+    /// show RGB colors.
+    pub fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        match (colorterm, term) {
+            (Some("truecolor" | "24bit"), _) | (_, None) => ColorDepth::TrueColor,
+            (_, Some(term)) if term.ends_with("-direct") => ColorDepth::TrueColor,
+            (_, Some(term)) if term.contains("256color") => ColorDepth::Indexed256,
+            _ => ColorDepth::Ansi16,
+        }
+    }
+
+    /// Whether the terminal shows `color` as it is
+    fn supports(&self, color: Color) -> bool {
+        match (self, color) {
+            (ColorDepth::TrueColor, _) => true,
+            (_, Color::Rgb(..)) => false,
+            (ColorDepth::Indexed256, _) => true,
+            (ColorDepth::Ansi16, Color::Indexed(index)) => index < 16,
+            (ColorDepth::Ansi16, _) => true,
+        }
+    }
+
+    /// The color the terminal can show that is closest to `color`, other
+    /// than those in `taken`
+    fn nearest(&self, color: Color, taken: &[Color]) -> Color {
+        if self.supports(color) && !taken.contains(&color) {
+            return color;
+        }
+        let Some(target) = rgb(color) else {
+            return color;
+        };
+        let (candidates, distance): (Vec<Color>, fn(_, _) -> f64) = match self {
+            ColorDepth::TrueColor => return color,
+            // Leave out the first 16, which terminals let users change
+            ColorDepth::Indexed256 => ((16..=255).map(Color::Indexed).collect(), rgb_distance),
+            ColorDepth::Ansi16 => (NAMED.to_vec(), hue_distance),
+        };
+        candidates
+            .into_iter()
+            .filter(|candidate| !taken.contains(candidate))
+            .filter_map(|candidate| Some((candidate, distance(target, rgb(candidate)?))))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(color, |(candidate, _)| candidate)
+    }
+}
+
+/// Squared distance of two colors in RGB space, which is dense enough with
+/// 256 colors to find a close match for everything
+fn rgb_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let square = |a: u8, b: u8| (f64::from(a) - f64::from(b)).powi(2);
+    square(a.0, b.0) + square(a.1, b.1) + square(a.2, b.2)
+}
+
+/// Distance of two colors for the 16 colors of the palette, which have
+/// few muted shades: the muted Wordle green is closer to gray than to any
+/// of them in RGB space. Colors go to the one of the nearest hue instead,
+/// and grays to grays, then by lightness.
+fn hue_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    /// Below this (of 255), colors count as gray
+    const MIN_CHROMA: u8 = 32;
+
+    let lightness =
+        |(r, g, b): (u8, u8, u8)| (f64::from(r.max(g).max(b)) + f64::from(r.min(g).min(b))) / 2.0;
+    let lightness_distance = (lightness(a) - lightness(b)).powi(2);
+    match (hue(a, MIN_CHROMA), hue(b, MIN_CHROMA)) {
+        (None, None) => lightness_distance,
+        (Some(a), Some(b)) => {
+            let angle = (a - b).abs().min(360.0 - (a - b).abs());
+            // A hue 30 degrees off weighs more than any lightness
+            100.0 * angle.powi(2) + lightness_distance
+        }
+        // Gray for a color (or the other way round) only if nothing else
+        // is left, since no hue is that far off
+        _ => 1e7 + lightness_distance,
+    }
+}
+
+/// Hue of a color in degrees, None for grays
+fn hue((r, g, b): (u8, u8, u8), min_chroma: u8) -> Option<f64> {
+    let max = r.max(g).max(b);
+    let chroma = max - r.min(g).min(b);
+    if chroma < min_chroma {
+        return None;
+    }
+    let sector =
+        |a: u8, b: u8, offset: f64| (f64::from(a) - f64::from(b)) / f64::from(chroma) + offset;
+    let sector = if max == r {
+        sector(g, b, 0.0)
+    } else if max == g {
+        sector(b, r, 2.0)
+    } else {
+        sector(r, g, 4.0)
+    };
+    Some((60.0 * sector).rem_euclid(360.0))
+}
+
+impl Theme {
+    /// These colors as close as the terminal can show them. The feedback
+    /// colors and empty tiles stay different from each other, even if that
+    /// means a color further off.
+    pub fn with_color_depth(&self, depth: ColorDepth) -> Self {
+        let mut taken = Vec::new();
+        let mut tile = |color| {
+            let reduced = depth.nearest(color, &taken);
+            taken.push(reduced);
+            reduced
+        };
+        let correct = tile(self.correct);
+        let wrong_position = tile(self.wrong_position);
+        let not_in_word = tile(self.not_in_word);
+        let empty = tile(self.empty);
+        Self {
+            correct,
+            wrong_position,
+            not_in_word,
+            empty,
+            text: depth.nearest(self.text, &[]),
+            background: depth.nearest(self.background, &[]),
+            border: depth.nearest(self.border, &[]),
+            invalid: depth.nearest(self.invalid, &[]),
         }
     }
 }
+
+/// Contrast ratio WCAG asks for between text and its background
+pub const MIN_CONTRAST: f64 = 4.5;
+
+/// Text of a custom theme that is hard to read on one of its colors
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastWarning {
+    /// What the text is drawn on, e.g. "correct"
+    pub background: &'static str,
+    /// From 1 (none) to 21 (black on white)
+    pub ratio: f64,
+}
+
+impl fmt::Display for ContrastWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "text on {}: {:.1}:1", self.background, self.ratio)
+    }
+}
+
+impl Theme {
+    /// The colors text is drawn on, with their names in theme files
+    fn text_backgrounds(&self) -> [(&'static str, Color); 6] {
+        [
+            ("correct", self.correct),
+            ("wrong_position", self.wrong_position),
+            ("not_in_word", self.not_in_word),
+            ("empty", self.empty),
+            ("invalid", self.invalid),
+            ("background", self.background),
+        ]
+    }
+
+    /// Colors the text has too little contrast on, of those `overrides`
+    /// changes (the built-in themes keep the colors players know from
+    /// Wordle). Colors of the terminal's own palette are assumed to look
+    /// like xterm's.
+    pub fn contrast_warnings(&self, overrides: &ThemeOverrides) -> Vec<ContrastWarning> {
+        let text_changed = overrides.text.is_some();
+        let changed = [
+            overrides.correct,
+            overrides.wrong_position,
+            overrides.not_in_word,
+            overrides.empty,
+            overrides.invalid,
+            overrides.background,
+        ];
+        self.text_backgrounds()
+            .into_iter()
+            .zip(changed)
+            .filter(|(_, changed)| text_changed || changed.is_some())
+            .filter_map(|((background, color), _)| {
+                let ratio = contrast_ratio(self.text, color)?;
+                (ratio < MIN_CONTRAST).then_some(ContrastWarning { background, ratio })
+            })
+            .collect()
+    }
+
+    /// These colors with black or white text, whichever is easiest to read
+    /// on the color it is hardest to read on. Keeps the text color if
+    /// neither is better.
+    pub fn with_readable_text(&self) -> Self {
+        let worst = |text: Color| {
+            self.text_backgrounds()
+                .into_iter()
+                .filter_map(|(_, color)| contrast_ratio(text, color))
+                .fold(f64::INFINITY, f64::min)
+        };
+        let text = [self.text, Color::White, Color::Black]
+            .into_iter()
+            .max_by(|a, b| worst(*a).total_cmp(&worst(*b)))
+            .unwrap_or(self.text);
+        Self {
+            text,
+            ..self.clone()
+        }
+    }
+}
+
+/// WCAG contrast ratio of two colors, None if one of them is the
+/// terminal's default, which can be anything
+fn contrast_ratio(a: Color, b: Color) -> Option<f64> {
+    let (a, b) = (relative_luminance(a)?, relative_luminance(b)?);
+    Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+}
+
+/// WCAG relative luminance, from 0 (black) to 1 (white)
+fn relative_luminance(color: Color) -> Option<f64> {
+    let (r, g, b) = rgb(color)?;
+    let linear = |channel: u8| {
+        let c = f64::from(channel) / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+}
+
+/// The 16 colors of the terminal's palette, as xterm shows them
+const PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The colors of `PALETTE` by name
+const NAMED: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// Red, green and blue of a color, None for the terminal's default
+fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some((r, g, b)),
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+        Color::Indexed(index) => index,
+    };
+    Some(match index {
+        0..=15 => PALETTE[usize::from(index)],
+        // 6x6x6 color cube
+        16..=231 => {
+            let level = |n: u8| if n == 0 { 0 } else { 55 + 40 * n };
+            let n = index - 16;
+            (level(n / 36), level(n / 6 % 6), level(n % 6))
+        }
+        // Grayscale ramp
+        _ => {
+            let gray = 8 + 10 * (index - 232);
+            (gray, gray, gray)
+        }
+    })
+}
+
+/// A color in a theme file: a name like `"red"` or `"darkgray"`, or hex
+/// like `"#6aaa64"`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemeColor(pub Color);
+
+impl<'de> Deserialize<'de> for ThemeColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map(ThemeColor).map_err(|_| {
+            de::Error::custom(format!(
+                "invalid color {name:?}, expected a name like \"red\" or hex like \"#6aaa64\""
+            ))
+        })
+    }
+}
+
+/// The player's own colors, read from `theme.toml` in the config directory.
+/// Colors that are left out keep the ones of the built-in theme in use, so
+/// a file can change just a few of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeOverrides {
+    pub correct: Option<ThemeColor>,
+    pub wrong_position: Option<ThemeColor>,
+    pub not_in_word: Option<ThemeColor>,
+    pub empty: Option<ThemeColor>,
+    pub text: Option<ThemeColor>,
+    pub background: Option<ThemeColor>,
+    pub border: Option<ThemeColor>,
+    pub invalid: Option<ThemeColor>,
+}
+
+impl ThemeOverrides {
+    /// Parse a theme file
+    pub fn parse(toml: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml)
+    }
+
+    /// Load the player's theme file. No overrides if there is none.
+    pub fn load() -> io::Result<Self> {
+        match user_theme_path() {
+            Some(path) if path.exists() => {
+                let toml = fs::read_to_string(&path)?;
+                Self::parse(&toml).map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: {}", path.display(), err.message()),
+                    )
+                })
+            }
+            _ => Ok(Self::default()),
+        }
+    }
+}
+
+/// Where the player's theme file is (None if there is no config directory)
+fn user_theme_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("wordle").join("theme.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_names_and_hex() {
+        let overrides = ThemeOverrides::parse(
+            r##"
+                correct = "#00ff00"
+                background = "black"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(overrides.correct, Some(ThemeColor(Color::Rgb(0, 255, 0))));
+        assert_eq!(overrides.background, Some(ThemeColor(Color::Black)));
+        assert_eq!(overrides.text, None);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_files() {
+        let err = ThemeOverrides::parse(r#"correct = "greenish""#).unwrap_err();
+        assert!(err.message().contains("invalid color \"greenish\""));
+        assert!(ThemeOverrides::parse(r#"corect = "green""#).is_err());
+    }
+
+    #[test]
+    fn test_overrides_keep_missing_colors() {
+        let overrides = ThemeOverrides::parse(r#"correct = "blue""#).unwrap();
+        let light = Theme::named(ThemeName::Light);
+        let theme = light.with_overrides(&overrides).unwrap();
+        assert_eq!(theme.correct, Color::Blue);
+        assert_eq!(theme.background, light.background);
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let ratio = |a, b| contrast_ratio(a, b).unwrap();
+        assert!((ratio(Color::Black, Color::White) - 21.0).abs() < 0.01);
+        assert_eq!(ratio(Color::Red, Color::Red), 1.0);
+        // The same gray in the palette and as RGB
+        assert_eq!(ratio(Color::Indexed(244), Color::Rgb(128, 128, 128)), 1.0);
+        assert_eq!(contrast_ratio(Color::Reset, Color::White), None);
+    }
+
+    #[test]
+    fn test_contrast_warnings_for_changed_colors() {
+        let overrides = ThemeOverrides::parse(r#"correct = "yellow""#).unwrap();
+        let theme = Theme::default().with_overrides(&overrides).unwrap();
+        // White on the palette's yellow, the built-in colors aren't checked
+        let warnings = theme.contrast_warnings(&overrides);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].background, "correct");
+        assert_eq!(warnings[0].to_string(), "text on correct: 1.7:1");
+        assert!(theme.contrast_warnings(&ThemeOverrides::default()).is_empty());
+    }
+
+    #[test]
+    fn test_readable_text() {
+        let light = Theme {
+            text: Color::White,
+            ..Theme::named(ThemeName::Light)
+        };
+        assert_eq!(light.with_readable_text().text, Color::Black);
+        // White is still best on the dark theme's gray tiles
+        assert_eq!(Theme::default().with_readable_text().text, Color::White);
+    }
+
+    #[test]
+    fn test_detect_color_depth() {
+        let depth = ColorDepth::from_env;
+        assert_eq!(depth(Some("truecolor"), Some("xterm-256color")), ColorDepth::TrueColor);
+        assert_eq!(depth(None, Some("xterm-256color")), ColorDepth::Indexed256);
+        assert_eq!(depth(None, Some("xterm-direct")), ColorDepth::TrueColor);
+        assert_eq!(depth(None, Some("linux")), ColorDepth::Ansi16);
+        assert_eq!(depth(None, None), ColorDepth::TrueColor);
+    }
+
+    #[test]
+    fn test_nearest_colors() {
+        let theme = Theme::named(ThemeName::Dark).with_color_depth(ColorDepth::Indexed256);
+        // #6aaa64 is closest to #5faf5f of the color cube
+        assert_eq!(theme.correct, Color::Indexed(71));
+        assert_eq!(theme.text, Color::White);
+
+        let theme = Theme::named(ThemeName::Light).with_color_depth(ColorDepth::Ansi16);
+        assert_eq!(theme.background, Color::White);
+        assert_eq!(theme.not_in_word, Color::DarkGray);
+        // Green rather than the gray it is closest to in RGB
+        assert_eq!(theme.correct, Color::LightGreen);
+        assert_eq!(theme.wrong_position, Color::LightYellow);
+    }
+
+    #[test]
+    fn test_tiles_stay_distinguishable() {
+        for name in ThemeName::ALL {
+            for depth in [ColorDepth::Indexed256, ColorDepth::Ansi16] {
+                let theme = Theme::named(name).with_color_depth(depth);
+                let tiles = [theme.correct, theme.wrong_position, theme.not_in_word];
+                let mut tiles = tiles.map(|color| format!("{color:?}")).to_vec();
+                tiles.push(format!("{:?}", theme.empty));
+                tiles.sort();
+                assert!(tiles.windows(2).all(|pair| pair[0] != pair[1]), "{name:?} {depth:?}");
+                assert!(depth.supports(theme.correct));
+            }
+        }
+    }
+
+    #[test]
+    fn test_overrides_must_stay_readable() {
+        let overrides = ThemeOverrides::parse(r#"text = "white""#).unwrap();
+        let unreadable = Theme {
+            background: Color::White,
+            ..Theme::default()
+        };
+        assert!(unreadable.with_overrides(&overrides).is_err());
+
+        // The default green
+        let overrides = ThemeOverrides::parse(r##"wrong_position = "#6aaa64""##).unwrap();
+        assert!(Theme::default().with_overrides(&overrides).is_err());
+    }
+}
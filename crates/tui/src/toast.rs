@@ -0,0 +1,216 @@
+//! Short notifications floating over the screen, dismissed after a while.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use wordle_game::{DictionaryCheck, GuessError, GuessWarning};
+
+/// Most toasts shown at once; the oldest is dropped when another arrives
+const MAX_TOASTS: usize = 3;
+
+/// How important a toast is, which decides its color and how long it stays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    /// Something went well, like a won game
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// How long a toast stays on screen
+    fn duration(self) -> Duration {
+        match self {
+            Severity::Info | Severity::Success => Duration::from_secs(3),
+            Severity::Warning => Duration::from_secs(4),
+            // Errors usually need to be read in full
+            Severity::Error => Duration::from_secs(8),
+        }
+    }
+}
+
+/// What a toast tells the player, put into words in the language of the
+/// interface by [`Strings::toast`](crate::i18n::Strings::toast). Details
+/// of errors are shown as the system gives them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToastKey {
+    /// A guess was rejected
+    GuessError(GuessError),
+    /// A guess was accepted despite a problem
+    GuessWarning(GuessWarning),
+    /// The theme file can't be used, for this reason
+    ThemeFileIgnored(String),
+    /// The theme switched to, by name
+    Theme(&'static str),
+    NoWordsMatchFilter,
+    SettingsApplyNextGame,
+    NoHintsInVariant,
+    /// A hint revealed the letter at a position (counted from 0)
+    HintLetter {
+        position: usize,
+        letter: char,
+    },
+    NoHintsLeft,
+    AllLettersKnown,
+    NothingToCopy,
+    Copied,
+    CopyFailed(String),
+    /// A word of a survival run was solved, earning bonus guesses
+    SurvivalSolved {
+        bonus: usize,
+    },
+    /// The daily word was played, so the next games are classic
+    ClassicUntilTomorrow,
+    /// The solver was turned on or off
+    Solver(bool),
+    /// These variants were turned on or off for the next game
+    LetterBank(bool),
+    Cross(bool),
+    Survival(bool),
+    Sequence(bool),
+    /// Which guesses missing from the word list are allowed from the next
+    /// game
+    DictionaryCheck(DictionaryCheck),
+    /// The word list of a language, by name, can't be loaded
+    WordListFailed {
+        language: &'static str,
+        err: String,
+    },
+    PackProgressNotSaved(String),
+    StatisticsNotSaved(String),
+    JournalNotWritten(String),
+    GameNotSaved(String),
+    SyncFailed(String),
+    /// The history at a path can't be read, so games aren't saved
+    HistoryUnreadable {
+        path: String,
+        err: String,
+    },
+    StatisticsUnreadable(String),
+    /// Unreadable statistics couldn't be moved aside, they start over
+    StatisticsStartedOver(String),
+    /// Unreadable statistics were moved to a path, to start over
+    StatisticsMovedAside {
+        err: String,
+        path: String,
+    },
+}
+
+/// A notification shown for a while
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toast {
+    pub text: String,
+    pub severity: Severity,
+    shown: Instant,
+}
+
+impl Toast {
+    fn is_expired(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.shown) >= self.severity.duration()
+    }
+}
+
+/// The toasts currently shown, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct Toasts {
+    toasts: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show a toast. Repeating the newest toast restarts its timer instead
+    /// of stacking a copy.
+    pub fn push(&mut self, severity: Severity, text: impl Into<String>, now: Instant) {
+        let text = text.into();
+        if let Some(newest) = self.toasts.back_mut()
+            && newest.text == text
+            && newest.severity == severity
+        {
+            newest.shown = now;
+            return;
+        }
+        if self.toasts.len() == MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+        self.toasts.push_back(Toast {
+            text,
+            severity,
+            shown: now,
+        });
+    }
+
+    /// Dismiss the toasts whose time is up. Returns whether any was
+    /// dismissed.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let shown = self.toasts.len();
+        self.toasts.retain(|toast| !toast.is_expired(now));
+        self.toasts.len() != shown
+    }
+
+    /// When the next toast is dismissed, None if there are none
+    pub fn next_expiry(&self) -> Option<Instant> {
+        self.toasts
+            .iter()
+            .map(|toast| toast.shown + toast.severity.duration())
+            .min()
+    }
+
+    /// Oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(toasts: &Toasts) -> Vec<&str> {
+        toasts.iter().map(|toast| toast.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_toasts_expire_by_severity() {
+        let start = Instant::now();
+        let mut toasts = Toasts::new();
+        toasts.push(Severity::Info, "saved", start);
+        toasts.push(Severity::Error, "failed", start);
+
+        assert!(!toasts.tick(start + Duration::from_secs(2)));
+        assert_eq!(texts(&toasts), ["saved", "failed"]);
+        assert_eq!(toasts.next_expiry(), Some(start + Duration::from_secs(3)));
+        assert!(toasts.tick(start + Duration::from_secs(3)));
+        assert_eq!(texts(&toasts), ["failed"]);
+        assert!(toasts.tick(start + Duration::from_secs(8)));
+        assert_eq!(texts(&toasts), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_toasts_stack_up_to_a_limit() {
+        let now = Instant::now();
+        let mut toasts = Toasts::new();
+        for text in ["a", "b", "c", "d"] {
+            toasts.push(Severity::Info, text, now);
+        }
+        assert_eq!(texts(&toasts), ["b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_repeated_toast_restarts_timer() {
+        let start = Instant::now();
+        let mut toasts = Toasts::new();
+        toasts.push(Severity::Warning, "Not enough letters", start);
+        toasts.push(
+            Severity::Warning,
+            "Not enough letters",
+            start + Duration::from_secs(3),
+        );
+        assert_eq!(texts(&toasts), ["Not enough letters"]);
+        toasts.tick(start + Duration::from_secs(5));
+        assert_eq!(texts(&toasts), ["Not enough letters"]);
+    }
+}
@@ -2,17 +2,79 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Modifier, Style},
-    widgets::Widget,
+    widgets::{Block, Widget},
 };
-use wordle_game::{Game, LetterFeedback, MAX_GUESSES, WORD_LENGTH};
+use serde::{Deserialize, Serialize};
+use wordle_game::{Game, GameState, Hint, LetterFeedback, MAX_GUESSES, WORD_LENGTH};
 
+use super::FeedbackStyle;
 use crate::theme::Theme;
 
+/// How the tiles of the board are drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TileStyle {
+    /// One row of text per guess, with colored cells
+    #[default]
+    Compact,
+    /// Boxes of 5x3 characters with the letter in the middle, like the web
+    /// game. Boards that don't fit are drawn compact.
+    Bordered,
+}
+
+impl TileStyle {
+    /// All styles, in the order the settings cycle through them
+    pub const ALL: [TileStyle; 2] = [TileStyle::Compact, TileStyle::Bordered];
+
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            TileStyle::Compact => "Compact",
+            TileStyle::Bordered => "Bordered",
+        }
+    }
+
+    /// Width and height of a tile
+    fn tile_size(&self) -> (u16, u16) {
+        match self {
+            TileStyle::Compact => (3, 1),
+            TileStyle::Bordered => (5, 3),
+        }
+    }
+
+    /// Width and height of a board with tiles in this style
+    pub fn board_size(&self) -> (u16, u16) {
+        let (width, height) = self.tile_size();
+        let spacing = 1;
+        (
+            WORD_LENGTH as u16 * (width + spacing) - spacing,
+            MAX_GUESSES as u16 * height,
+        )
+    }
+}
+
+/// What a tile shows
+enum Tile {
+    /// A letter of a guess, colored by its feedback
+    Guessed(char, LetterFeedback),
+    /// A typed letter (or the one a hint revealed) of the input row
+    Input(Option<char>),
+    /// A tile of a row not reached yet
+    Empty,
+}
+
 /// Widget for rendering the Wordle game board
 pub struct BoardWidget<'a> {
     game: &'a Game,
     current_input: &'a str,
     theme: &'a Theme,
+    /// Horizontal offset of the input row while it shakes after a
+    /// rejected guess
+    shake: Option<i16>,
+    tile_style: TileStyle,
+    /// Column of the input row letters are typed at, None to show none
+    cursor: Option<usize>,
+    feedback_style: FeedbackStyle,
 }
 
 impl<'a> BoardWidget<'a> {
@@ -21,77 +83,214 @@ impl<'a> BoardWidget<'a> {
             game,
             current_input,
             theme,
+            shake: None,
+            tile_style: TileStyle::Compact,
+            cursor: None,
+            feedback_style: FeedbackStyle::Colors,
+        }
+    }
+
+    /// Shake and flash the input row, shifted by the given offset
+    pub fn shake(mut self, shake: Option<i16>) -> Self {
+        self.shake = shake;
+        self
+    }
+
+    /// Mark the tile of the input row at `cursor`
+    pub fn cursor(mut self, cursor: Option<usize>) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Draw the tiles in `style` where the board fits
+    pub fn tile_style(mut self, style: TileStyle) -> Self {
+        self.tile_style = style;
+        self
+    }
+
+    /// Tell the feedback of guessed letters by colors, symbols or both
+    pub fn feedback_style(mut self, style: FeedbackStyle) -> Self {
+        self.feedback_style = style;
+        self
+    }
+
+    /// The letter a hint revealed at a position
+    fn hinted_letter(&self, position: usize) -> Option<char> {
+        self.game.hints().iter().find_map(|hint| match hint {
+            Hint::Letter {
+                position: p,
+                letter,
+            } if *p == position => Some(letter.char()),
+            _ => None,
+        })
+    }
+}
+
+impl BoardWidget<'_> {
+    /// What the tile of a column shows in a row of the game, which is
+    /// either a guess, the input row or a row not reached yet
+    fn tile(&self, row: usize, col: usize, input: &[char]) -> Tile {
+        let guesses = self.game.guesses();
+        if let Some(feedback) = guesses.get(row) {
+            Tile::Guessed(feedback.word().letter(col).char(), feedback.feedback()[col])
+        } else if row == guesses.len() {
+            Tile::Input(input.get(col).copied())
+        } else {
+            Tile::Empty
+        }
+    }
+
+    /// Style of a tile's letter and background
+    fn cell_style(&self, tile: &Tile, col: usize) -> Style {
+        match tile {
+            Tile::Guessed(_, feedback) => Style::default()
+                .fg(self.theme.text)
+                .bg(self.feedback_style.color(*feedback, self.theme))
+                .add_modifier(Modifier::BOLD),
+            Tile::Input(letter) => {
+                let bg = match self.shake {
+                    Some(_) => self.theme.invalid,
+                    None => self.theme.empty,
+                };
+                let mut style = Style::default()
+                    .fg(self.theme.text)
+                    .bg(bg)
+                    .add_modifier(Modifier::BOLD);
+                if self.cursor == Some(col) {
+                    style = style.add_modifier(Modifier::UNDERLINED);
+                }
+                // Show revealed letters where nothing is typed yet
+                if letter.is_none() && self.hinted_letter(col).is_some() {
+                    style
+                        .fg(self.theme.correct)
+                        .add_modifier(Modifier::UNDERLINED)
+                } else {
+                    style
+                }
+            }
+            Tile::Empty => Style::default().fg(self.theme.border).bg(self.theme.empty),
         }
     }
 
-    fn feedback_to_bg_color(&self, feedback: LetterFeedback) -> ratatui::style::Color {
-        match feedback {
-            LetterFeedback::Correct => self.theme.correct,
-            LetterFeedback::WrongPosition => self.theme.wrong_position,
-            LetterFeedback::NotInWord => self.theme.not_in_word,
+    /// The letter shown on a tile
+    fn letter(&self, tile: &Tile, col: usize) -> Option<char> {
+        match tile {
+            Tile::Guessed(letter, _) => Some(*letter),
+            Tile::Input(letter) => letter.or_else(|| self.hinted_letter(col)),
+            Tile::Empty => None,
+        }
+    }
+
+    /// The symbol shown next to the letter of a tile, if any
+    fn symbol(&self, tile: &Tile) -> Option<char> {
+        match tile {
+            Tile::Guessed(_, feedback) => self.feedback_style.symbol(*feedback),
+            _ => None,
+        }
+    }
+
+    /// Draw a tile as a box with the letter in the middle. Guessed letters
+    /// fill the box with their color, the others only color its border.
+    fn draw_bordered(&self, tile: &Tile, col: usize, area: Rect, buf: &mut Buffer) {
+        let style = self.cell_style(tile, col);
+        let letter_style = match tile {
+            Tile::Guessed(..) => {
+                buf.set_style(area, style);
+                style
+            }
+            _ => {
+                let border = match (tile, self.shake) {
+                    (Tile::Input(_), Some(_)) => self.theme.invalid,
+                    (Tile::Input(_), None) if self.cursor == Some(col) => self.theme.text,
+                    (Tile::Input(Some(_)), None) => self.theme.not_in_word,
+                    _ => self.theme.border,
+                };
+                Block::bordered()
+                    .border_style(Style::default().fg(border))
+                    .render(area, buf);
+                Style { bg: None, ..style }
+            }
+        };
+        let (x, y) = (area.x + area.width / 2, area.y + area.height / 2);
+        if let Some(ch) = self.letter(tile, col) {
+            buf[(x, y)]
+                .set_char(super::tile_char(ch))
+                .set_style(letter_style);
+        }
+        if let Some(symbol) = self.symbol(tile) {
+            buf[(x + 1, y)].set_char(symbol).set_style(letter_style);
         }
     }
 }
 
 impl Widget for BoardWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        // Each cell is 3 chars wide, 1 char tall, with 1 char spacing
-        let cell_width = 3;
+        // Bordered tiles only where the whole board fits
+        let (board_width, board_height) = self.tile_style.board_size();
+        let style = if board_width <= area.width && board_height <= area.height {
+            self.tile_style
+        } else {
+            TileStyle::Compact
+        };
+        let (cell_width, cell_height) = style.tile_size();
         let cell_spacing = 1;
-        let total_width = WORD_LENGTH as u16 * (cell_width + cell_spacing) - cell_spacing;
-        let total_height = MAX_GUESSES as u16;
+        let (total_width, total_height) = style.board_size();
 
         // Center the board in the area
         let start_x = area.x + (area.width.saturating_sub(total_width)) / 2;
         let start_y = area.y + (area.height.saturating_sub(total_height)) / 2;
 
         let guesses = self.game.guesses();
+        let input: Vec<char> = self.current_input.chars().collect();
+
+        // Games with a larger budget (e.g. survival mode) scroll so the
+        // most recent guesses and the input row stay visible
+        let visible = match self.game.state() {
+            GameState::Playing => guesses.len() + 1,
+            _ => guesses.len(),
+        };
+        let first_row = visible.saturating_sub(MAX_GUESSES);
 
-        for row in 0..MAX_GUESSES {
+        for display_row in 0..MAX_GUESSES {
+            let row = first_row + display_row;
             for col in 0..WORD_LENGTH {
-                let x = start_x + col as u16 * (cell_width + cell_spacing);
-                let y = start_y + row as u16;
+                let mut x = start_x + col as u16 * (cell_width + cell_spacing);
+                let y = start_y + display_row as u16 * cell_height;
+                if row == guesses.len()
+                    && let Some(offset) = self.shake
+                {
+                    x = x.saturating_add_signed(offset);
+                }
 
-                if x + cell_width > area.x + area.width || y >= area.y + area.height {
+                if x < area.x
+                    || x + cell_width > area.x + area.width
+                    || y + cell_height > area.y + area.height
+                {
                     continue;
                 }
 
-                let (letter, style) = if row < guesses.len() {
-                    // Completed guess row
-                    let feedback = &guesses[row];
-                    let letter = feedback.word().letter(col).char();
-                    let fb = feedback.feedback()[col];
-                    let bg = self.feedback_to_bg_color(fb);
-                    let style = Style::default()
-                        .fg(self.theme.text)
-                        .bg(bg)
-                        .add_modifier(Modifier::BOLD);
-                    (Some(letter), style)
-                } else if row == guesses.len() {
-                    // Current input row
-                    let input_chars: Vec<char> = self.current_input.chars().collect();
-                    let letter = input_chars.get(col).copied();
-                    let style = Style::default()
-                        .fg(self.theme.text)
-                        .bg(self.theme.empty)
-                        .add_modifier(Modifier::BOLD);
-                    (letter, style)
-                } else {
-                    // Empty row
-                    let style = Style::default().fg(self.theme.border).bg(self.theme.empty);
-                    (None, style)
-                };
+                let tile = self.tile(row, col, &input);
+                if style == TileStyle::Bordered {
+                    let tile_area = Rect::new(x, y, cell_width, cell_height);
+                    self.draw_bordered(&tile, col, tile_area, buf);
+                    continue;
+                }
 
                 // Draw the cell background
+                let cell_style = self.cell_style(&tile, col);
                 for i in 0..cell_width {
-                    buf[(x + i, y)].set_style(style);
+                    buf[(x + i, y)].set_style(cell_style);
                 }
 
-                // Draw the letter (centered in the cell)
-                if let Some(ch) = letter {
+                // Draw the letter (centered in the cell), and its symbol
+                // right of it
+                if let Some(ch) = self.letter(&tile, col) {
                     buf[(x + 1, y)]
-                        .set_char(ch.to_uppercase().next().unwrap_or(ch))
-                        .set_style(style);
+                        .set_char(super::tile_char(ch))
+                        .set_style(cell_style);
+                }
+                if let Some(symbol) = self.symbol(&tile) {
+                    buf[(x + 2, y)].set_char(symbol).set_style(cell_style);
                 }
             }
         }
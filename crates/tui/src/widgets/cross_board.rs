@@ -0,0 +1,187 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    widgets::Widget,
+};
+use wordle_game::{CrossGame, CrossTarget, GuessFeedback, Letter, LetterFeedback, WORD_LENGTH};
+
+use crate::theme::Theme;
+
+/// Each cell is 3 chars wide, 1 char tall, with 1 char spacing
+const CELL_WIDTH: u16 = 3;
+const CELL_SPACING: u16 = 1;
+/// Gap between the cross and the guess log
+const LOG_GAP: u16 = 4;
+
+/// Widget for rendering a cross game: the two words as a cross of known
+/// letters, next to a log of the most recent guesses
+pub struct CrossBoardWidget<'a> {
+    game: &'a CrossGame,
+    current_input: &'a str,
+    theme: &'a Theme,
+    /// Horizontal offset of the input row while it shakes after a
+    /// rejected guess
+    shake: Option<i16>,
+}
+
+impl<'a> CrossBoardWidget<'a> {
+    pub fn new(game: &'a CrossGame, current_input: &'a str, theme: &'a Theme) -> Self {
+        Self {
+            game,
+            current_input,
+            theme,
+            shake: None,
+        }
+    }
+
+    /// Shake and flash the input row, shifted by the given offset
+    pub fn shake(mut self, shake: Option<i16>) -> Self {
+        self.shake = shake;
+        self
+    }
+
+    fn feedback_to_bg_color(&self, feedback: LetterFeedback) -> ratatui::style::Color {
+        match feedback {
+            LetterFeedback::Correct => self.theme.correct,
+            LetterFeedback::WrongPosition => self.theme.wrong_position,
+            LetterFeedback::NotInWord => self.theme.not_in_word,
+        }
+    }
+
+    fn draw_cell(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        x: u16,
+        y: u16,
+        letter: Option<char>,
+        style: Style,
+    ) {
+        if x < area.x || x + CELL_WIDTH > area.x + area.width || y >= area.y + area.height {
+            return;
+        }
+        for i in 0..CELL_WIDTH {
+            buf[(x + i, y)].set_style(style);
+        }
+        if let Some(ch) = letter {
+            buf[(x + 1, y)]
+                .set_char(super::tile_char(ch))
+                .set_style(style);
+        }
+    }
+
+    fn render_cross(&self, buf: &mut Buffer, area: Rect, start_x: u16, start_y: u16) {
+        let (across_index, down_index) = self.game.intersection();
+        let known_style = Style::default()
+            .fg(self.theme.text)
+            .bg(self.theme.correct)
+            .add_modifier(Modifier::BOLD);
+        let unknown_style = Style::default().fg(self.theme.border).bg(self.theme.empty);
+        let cell = |letter: Option<Letter>| match letter {
+            Some(l) => (Some(l.char()), known_style),
+            None => (None, unknown_style),
+        };
+
+        // The across word runs through the row of the shared letter
+        for (col, letter) in self
+            .game
+            .known_letters(CrossTarget::Across)
+            .into_iter()
+            .enumerate()
+        {
+            let (ch, style) = cell(letter);
+            let x = start_x + col as u16 * (CELL_WIDTH + CELL_SPACING);
+            self.draw_cell(buf, area, x, start_y + down_index as u16, ch, style);
+        }
+
+        // The down word runs through the column of the shared letter
+        let x = start_x + across_index as u16 * (CELL_WIDTH + CELL_SPACING);
+        for (row, letter) in self
+            .game
+            .known_letters(CrossTarget::Down)
+            .into_iter()
+            .enumerate()
+        {
+            let (ch, style) = cell(letter);
+            self.draw_cell(buf, area, x, start_y + row as u16, ch, style);
+        }
+    }
+
+    fn render_log(&self, buf: &mut Buffer, area: Rect, start_x: u16, start_y: u16, rows: usize) {
+        let marker_style = Style::default().fg(self.theme.text);
+        let marker = |target: CrossTarget| match target {
+            CrossTarget::Across => '→',
+            CrossTarget::Down => '↓',
+        };
+
+        // Keep the last row for the current input
+        let guesses = self.game.guesses();
+        let shown = &guesses[guesses.len().saturating_sub(rows.saturating_sub(1))..];
+
+        let draw_row =
+            |buf: &mut Buffer, y: u16, target: CrossTarget, feedback: Option<&GuessFeedback>| {
+                if y >= area.y + area.height || start_x >= area.x + area.width {
+                    return;
+                }
+                buf[(start_x, y)]
+                    .set_char(marker(target))
+                    .set_style(marker_style);
+                for col in 0..WORD_LENGTH {
+                    let x = start_x + 2 + col as u16 * (CELL_WIDTH + CELL_SPACING);
+                    let (letter, style) = match feedback {
+                        Some(feedback) => (
+                            Some(feedback.word().letter(col).char()),
+                            Style::default()
+                                .fg(self.theme.text)
+                                .bg(self.feedback_to_bg_color(feedback.feedback()[col]))
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        None => (
+                            self.current_input.chars().nth(col),
+                            Style::default()
+                                .fg(self.theme.text)
+                                .bg(match self.shake {
+                                    Some(_) => self.theme.invalid,
+                                    None => self.theme.empty,
+                                })
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    };
+                    let x = match (feedback, self.shake) {
+                        (None, Some(offset)) => x.saturating_add_signed(offset),
+                        _ => x,
+                    };
+                    self.draw_cell(buf, area, x, y, letter, style);
+                }
+            };
+
+        for (i, (target, feedback)) in shown.iter().enumerate() {
+            draw_row(buf, start_y + i as u16, *target, Some(feedback));
+        }
+        if let Some(target) = self.game.active_target() {
+            draw_row(buf, start_y + shown.len() as u16, target, None);
+        }
+    }
+}
+
+impl Widget for CrossBoardWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let word_width = WORD_LENGTH as u16 * (CELL_WIDTH + CELL_SPACING) - CELL_SPACING;
+        // The log has a 2 char target marker in front of each row
+        let total_width = word_width + LOG_GAP + 2 + word_width;
+        let cross_height = WORD_LENGTH as u16;
+
+        let start_x = area.x + (area.width.saturating_sub(total_width)) / 2;
+        let start_y = area.y + (area.height.saturating_sub(cross_height)) / 2;
+
+        self.render_cross(buf, area, start_x, start_y);
+        self.render_log(
+            buf,
+            area,
+            start_x + word_width + LOG_GAP,
+            area.y,
+            area.height as usize,
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Clear, Padding, Paragraph, Widget},
+};
+
+use crate::theme::Theme;
+
+/// Widget for a modal question, in a box centered over whatever is below
+pub struct DialogWidget<'a> {
+    text: &'a str,
+    theme: &'a Theme,
+}
+
+impl<'a> DialogWidget<'a> {
+    pub fn new(text: &'a str, theme: &'a Theme) -> Self {
+        Self { text, theme }
+    }
+}
+
+impl Widget for DialogWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = (self.text.chars().count() as u16 + 6).min(area.width);
+        let height = 3.min(area.height);
+        let rect = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        Clear.render(rect, buf);
+        Paragraph::new(self.text)
+            .alignment(Alignment::Center)
+            .style(
+                Style::default()
+                    .fg(self.theme.text)
+                    .bg(self.theme.background)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::bordered()
+                    .border_style(Style::default().fg(self.theme.wrong_position))
+                    .padding(Padding::horizontal(1)),
+            )
+            .render(rect, buf);
+    }
+}
@@ -4,9 +4,11 @@ use ratatui::{
     style::{Modifier, Style},
     widgets::Widget,
 };
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use wordle_game::{GuessFeedback, LetterFeedback};
+use wordle_game::{GameState, GuessFeedback, LetterFeedback, MultiGame, Playable};
 
+use super::FeedbackStyle;
 use crate::theme::Theme;
 
 /// Tracks the best feedback state for each letter
@@ -41,6 +43,28 @@ impl KeyboardState {
         }
     }
 
+    /// Combined states of the boards of a multi-board game that aren't
+    /// solved yet (all boards once the game is over): a letter is only
+    /// marked as not in the word if it is in none of them
+    pub fn combined(game: &MultiGame) -> Self {
+        let playing = game.state() == GameState::Playing;
+        let mut state = Self::new();
+        for board in 0..game.board_count() {
+            if playing && game.is_solved(board) {
+                continue;
+            }
+            for feedback in game.board_guesses(board) {
+                state.update(feedback);
+            }
+        }
+        state
+    }
+
+    /// Mark a letter revealed by a hint as being in the word
+    pub fn reveal(&mut self, letter: char) {
+        self.letter_states.insert(letter, LetterFeedback::Correct);
+    }
+
     /// Get the state of a letter
     pub fn get(&self, letter: char) -> Option<LetterFeedback> {
         self.letter_states.get(&letter.to_lowercase().next().unwrap_or(letter)).copied()
@@ -52,26 +76,106 @@ impl KeyboardState {
     }
 }
 
+/// Arrangement of the keys on the virtual keyboard
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyboardLayout {
+    /// German, with keys for umlauts and ß
+    #[default]
+    Qwertz,
+    /// English
+    Qwerty,
+    /// French
+    Azerty,
+    /// Dvorak (simplified), with the punctuation keys left out
+    Dvorak,
+}
+
+impl KeyboardLayout {
+    /// All layouts, in the order the settings cycle through them
+    pub const ALL: [KeyboardLayout; 4] = [
+        KeyboardLayout::Qwertz,
+        KeyboardLayout::Qwerty,
+        KeyboardLayout::Azerty,
+        KeyboardLayout::Dvorak,
+    ];
+
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyboardLayout::Qwertz => "QWERTZ",
+            KeyboardLayout::Qwerty => "QWERTY",
+            KeyboardLayout::Azerty => "AZERTY",
+            KeyboardLayout::Dvorak => "Dvorak",
+        }
+    }
+
+    /// Letters of each row, top to bottom
+    pub fn rows(&self) -> [&'static str; 3] {
+        match self {
+            KeyboardLayout::Qwertz => ["qwertzuiopüß", "asdfghjklöä", "yxcvbnm"],
+            KeyboardLayout::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            KeyboardLayout::Dvorak => ["pyfgcrl", "aoeuidhtns", "qjkxbmwvz"],
+        }
+    }
+}
+
 /// Widget for rendering the virtual keyboard
 pub struct KeyboardWidget<'a> {
     state: &'a KeyboardState,
+    layout: KeyboardLayout,
     theme: &'a Theme,
+    /// In how many of the words still possible each letter occurs, to
+    /// sort the keys by
+    letter_counts: Option<&'a HashMap<char, usize>>,
+    feedback_style: FeedbackStyle,
 }
 
 impl<'a> KeyboardWidget<'a> {
-    pub fn new(state: &'a KeyboardState, theme: &'a Theme) -> Self {
-        Self { state, theme }
+    pub fn new(state: &'a KeyboardState, layout: KeyboardLayout, theme: &'a Theme) -> Self {
+        Self {
+            state,
+            layout,
+            theme,
+            letter_counts: None,
+            feedback_style: FeedbackStyle::Colors,
+        }
+    }
+
+    /// Tell the feedback of used letters by colors, symbols or both
+    pub fn feedback_style(mut self, style: FeedbackStyle) -> Self {
+        self.feedback_style = style;
+        self
+    }
+
+    /// Sort the keys by how many of the words still possible have them,
+    /// most useful first, instead of following the layout. The rows keep
+    /// the layout's lengths.
+    pub fn sorted_by(mut self, letter_counts: Option<&'a HashMap<char, usize>>) -> Self {
+        self.letter_counts = letter_counts;
+        self
+    }
+
+    /// Letters of each row, top to bottom
+    fn rows(&self) -> Vec<Vec<char>> {
+        let rows = self.layout.rows();
+        let Some(counts) = self.letter_counts else {
+            return rows.iter().map(|row| row.chars().collect()).collect();
+        };
+        let mut letters: Vec<char> = rows.concat().chars().collect();
+        // Stable, so letters with the same count stay in layout order
+        letters.sort_by_key(|ch| std::cmp::Reverse(counts.get(ch).copied().unwrap_or(0)));
+        let mut letters = letters.into_iter();
+        rows.iter()
+            .map(|row| letters.by_ref().take(row.chars().count()).collect())
+            .collect()
     }
 }
 
 impl Widget for KeyboardWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        // QWERTZ keyboard layout (German)
-        let rows = [
-            "qwertzuiop",
-            "asdfghjkl",
-            "yxcvbnm",
-        ];
+        let rows = self.rows();
 
         let key_width = 3;
         let key_spacing = 1;
@@ -87,19 +191,17 @@ impl Widget for KeyboardWidget<'_> {
                 continue;
             }
 
-            for (col_idx, ch) in row.chars().enumerate() {
+            for (col_idx, &ch) in row.iter().enumerate() {
                 let x = row_x + col_idx as u16 * (key_width + key_spacing);
 
                 if x + key_width > area.x + area.width {
                     continue;
                 }
 
-                let bg_color = match self.state.get(ch) {
-                    Some(LetterFeedback::Correct) => self.theme.correct,
-                    Some(LetterFeedback::WrongPosition) => self.theme.wrong_position,
-                    Some(LetterFeedback::NotInWord) => self.theme.not_in_word,
-                    None => self.theme.empty,
-                };
+                let feedback = self.state.get(ch);
+                let bg_color = feedback.map_or(self.theme.empty, |feedback| {
+                    self.feedback_style.color(feedback, self.theme)
+                });
 
                 let style = Style::default()
                     .fg(self.theme.text)
@@ -111,10 +213,14 @@ impl Widget for KeyboardWidget<'_> {
                     buf[(x + i, y)].set_style(style);
                 }
 
-                // Draw letter (centered)
+                // Draw letter (centered), and its symbol right of it
                 buf[(x + 1, y)]
-                    .set_char(ch.to_uppercase().next().unwrap_or(ch))
+                    .set_char(super::tile_char(ch))
                     .set_style(style);
+                let symbol = feedback.and_then(|feedback| self.feedback_style.symbol(feedback));
+                if let Some(symbol) = symbol {
+                    buf[(x + 2, y)].set_char(symbol).set_style(style);
+                }
             }
         }
     }
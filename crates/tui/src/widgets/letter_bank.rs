@@ -0,0 +1,59 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    widgets::Widget,
+};
+use wordle_game::LetterBank;
+
+use crate::theme::Theme;
+
+/// Widget for rendering the remaining letters of a letter bank
+pub struct LetterBankWidget<'a> {
+    bank: &'a LetterBank,
+    theme: &'a Theme,
+}
+
+impl<'a> LetterBankWidget<'a> {
+    pub fn new(bank: &'a LetterBank, theme: &'a Theme) -> Self {
+        Self { bank, theme }
+    }
+}
+
+impl Widget for LetterBankWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Each entry is rendered as "A2" followed by a space
+        let entry_width = 3;
+        let entries: Vec<_> = self.bank.iter().collect();
+        let total_width = (entries.len() as u16 * entry_width).saturating_sub(1);
+
+        let start_x = area.x + (area.width.saturating_sub(total_width)) / 2;
+        let y = area.y;
+
+        if area.height == 0 {
+            return;
+        }
+
+        for (i, (letter, count)) in entries.into_iter().enumerate() {
+            let x = start_x + i as u16 * entry_width;
+            if x + entry_width - 1 > area.x + area.width {
+                break;
+            }
+
+            let style = if count == 0 {
+                Style::default().fg(self.theme.empty)
+            } else {
+                Style::default()
+                    .fg(self.theme.text)
+                    .add_modifier(Modifier::BOLD)
+            };
+
+            let ch = letter.char();
+            buf[(x, y)].set_char(super::tile_char(ch)).set_style(style);
+            let count_char = char::from_digit(count.min(9) as u32, 10).unwrap_or('9');
+            buf[(x + 1, y)]
+                .set_char(count_char)
+                .set_style(style.remove_modifier(Modifier::BOLD));
+        }
+    }
+}
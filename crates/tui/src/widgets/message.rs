@@ -0,0 +1,57 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Paragraph, Widget},
+};
+
+use crate::i18n::Strings;
+use crate::message::{Message, wrap};
+use crate::theme::Theme;
+
+/// Widget for the message under the board, colored by its severity and
+/// wrapped to the width of the terminal
+pub struct MessageWidget<'a> {
+    message: &'a Message,
+    strings: Strings,
+    theme: &'a Theme,
+}
+
+impl<'a> MessageWidget<'a> {
+    pub fn new(message: &'a Message, strings: Strings, theme: &'a Theme) -> Self {
+        Self {
+            message,
+            strings,
+            theme,
+        }
+    }
+
+    /// Rows needed to show the whole message in `width` columns
+    pub fn height(&self, width: u16) -> u16 {
+        self.lines(width).len() as u16
+    }
+
+    fn lines(&self, width: u16) -> Vec<Line<'static>> {
+        let (text, detail) = self.message.text(&self.strings);
+        let text_style =
+            Style::default().fg(super::severity_color(self.message.severity, self.theme));
+        let detail_style = Style::default().fg(self.theme.not_in_word);
+        let text = wrap(&text, width.into())
+            .into_iter()
+            .map(|line| Line::styled(line, text_style));
+        let detail = detail
+            .iter()
+            .flat_map(|detail| wrap(detail, width.into()))
+            .map(|line| Line::styled(line, detail_style));
+        text.chain(detail).collect()
+    }
+}
+
+impl Widget for MessageWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Paragraph::new(self.lines(area.width))
+            .alignment(Alignment::Center)
+            .render(area, buf);
+    }
+}
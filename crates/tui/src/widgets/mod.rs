@@ -1,5 +1,103 @@
 mod board;
+mod cross_board;
+mod dialog;
 mod keyboard;
+mod letter_bank;
+mod message;
+mod multi_board;
+mod scroll_list;
+#[cfg(test)]
+mod snapshot_tests;
+mod solver_panel;
+mod status_bar;
+mod toast;
 
-pub use board::BoardWidget;
-pub use keyboard::{KeyboardState, KeyboardWidget};
+pub use board::{BoardWidget, TileStyle};
+pub use cross_board::CrossBoardWidget;
+pub use dialog::DialogWidget;
+pub use keyboard::{KeyboardLayout, KeyboardState, KeyboardWidget};
+pub use letter_bank::LetterBankWidget;
+pub use message::MessageWidget;
+pub use multi_board::MultiBoardWidget;
+pub use scroll_list::ScrollListWidget;
+pub use solver_panel::{SOLVER_PANEL_WIDTH, SolverPanelWidget};
+pub use status_bar::StatusBarWidget;
+pub use toast::ToastWidget;
+
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use wordle_game::LetterFeedback;
+
+use crate::theme::Theme;
+use crate::toast::Severity;
+
+/// How tiles and keys tell the feedback of their letter
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackStyle {
+    /// By their color
+    #[default]
+    Colors,
+    /// By their color and a symbol next to the letter, for players who
+    /// can't tell the colors apart
+    ColorsAndSymbols,
+    /// Only by a symbol, for monochrome terminals
+    Symbols,
+}
+
+impl FeedbackStyle {
+    /// All styles, in the order the settings cycle through them
+    pub const ALL: [FeedbackStyle; 3] = [
+        FeedbackStyle::Colors,
+        FeedbackStyle::ColorsAndSymbols,
+        FeedbackStyle::Symbols,
+    ];
+
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            FeedbackStyle::Colors => "Colors",
+            FeedbackStyle::ColorsAndSymbols => "Colors and symbols",
+            FeedbackStyle::Symbols => "Symbols only",
+        }
+    }
+
+    /// The background of a tile or key with `feedback`
+    fn color(&self, feedback: LetterFeedback, theme: &Theme) -> Color {
+        match (self, feedback) {
+            (FeedbackStyle::Symbols, _) => theme.empty,
+            (_, LetterFeedback::Correct) => theme.correct,
+            (_, LetterFeedback::WrongPosition) => theme.wrong_position,
+            (_, LetterFeedback::NotInWord) => theme.not_in_word,
+        }
+    }
+
+    /// The symbol shown next to a letter with `feedback`, if any
+    fn symbol(&self, feedback: LetterFeedback) -> Option<char> {
+        match (self, feedback) {
+            (FeedbackStyle::Colors, _) => None,
+            (_, LetterFeedback::Correct) => Some('✓'),
+            (_, LetterFeedback::WrongPosition) => Some('~'),
+            (_, LetterFeedback::NotInWord) => Some('✗'),
+        }
+    }
+}
+
+/// Color telling the severity of a toast or message
+fn severity_color(severity: Severity, theme: &Theme) -> Color {
+    match severity {
+        Severity::Info => theme.text,
+        Severity::Success => theme.correct,
+        Severity::Warning => theme.wrong_position,
+        Severity::Error => theme.invalid,
+    }
+}
+
+/// A letter as shown on a tile or key: uppercase, except for 'ß', whose
+/// uppercase form "SS" doesn't fit on one cell
+fn tile_char(ch: char) -> char {
+    match ch {
+        'ß' => ch,
+        _ => ch.to_uppercase().next().unwrap_or(ch),
+    }
+}
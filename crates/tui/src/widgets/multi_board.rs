@@ -0,0 +1,170 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    widgets::Widget,
+};
+use wordle_game::{GameState, LetterFeedback, MultiGame, Playable, WORD_LENGTH};
+
+use crate::theme::Theme;
+
+/// Each cell is 3 chars wide, 1 char tall, with 1 char spacing
+const CELL_WIDTH: u16 = 3;
+const CELL_SPACING: u16 = 1;
+const BOARD_WIDTH: u16 = WORD_LENGTH as u16 * (CELL_WIDTH + CELL_SPACING) - CELL_SPACING;
+/// Space between boards, horizontally
+const BOARD_GAP: u16 = 3;
+/// Space between rows of boards
+const ROW_GAP: u16 = 1;
+
+/// Widget for rendering a game with several boards (Dordle, Quordle): the
+/// boards side by side if the area is wide enough, otherwise in a grid.
+/// Every unsolved board shows the input, since a guess is played on all
+/// of them.
+pub struct MultiBoardWidget<'a> {
+    game: &'a MultiGame,
+    current_input: &'a str,
+    theme: &'a Theme,
+    /// Horizontal offset of the input rows while they shake after a
+    /// rejected guess
+    shake: Option<i16>,
+}
+
+impl<'a> MultiBoardWidget<'a> {
+    pub fn new(game: &'a MultiGame, current_input: &'a str, theme: &'a Theme) -> Self {
+        Self {
+            game,
+            current_input,
+            theme,
+            shake: None,
+        }
+    }
+
+    /// Shake and flash the input rows, shifted by the given offset
+    pub fn shake(mut self, shake: Option<i16>) -> Self {
+        self.shake = shake;
+        self
+    }
+
+    /// Number of boards per row of the grid for an area `width` wide: all
+    /// of them if they fit, otherwise two, or one on narrow terminals
+    pub fn columns(boards: usize, width: u16) -> usize {
+        let fits = |columns: u16| columns * BOARD_WIDTH + (columns - 1) * BOARD_GAP <= width;
+        if fits(boards as u16) {
+            boards.max(1)
+        } else if boards > 1 && fits(2) {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Height the boards of `game` need to show every row when laid out
+    /// in an area `width` wide
+    pub fn height(game: &MultiGame, width: u16) -> u16 {
+        let rows = game
+            .board_count()
+            .div_ceil(Self::columns(game.board_count(), width)) as u16;
+        rows * game.max_guesses() as u16 + rows.saturating_sub(1) * ROW_GAP
+    }
+
+    fn feedback_to_bg_color(&self, feedback: LetterFeedback) -> ratatui::style::Color {
+        match feedback {
+            LetterFeedback::Correct => self.theme.correct,
+            LetterFeedback::WrongPosition => self.theme.wrong_position,
+            LetterFeedback::NotInWord => self.theme.not_in_word,
+        }
+    }
+
+    /// Draw one board with its top left corner at (x, y), showing `height`
+    /// rows. Boards taller than that scroll, so the most recent guesses and
+    /// the input row stay visible.
+    fn render_board(
+        &self,
+        board: usize,
+        x: u16,
+        y: u16,
+        height: u16,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let guesses = self.game.board_guesses(board);
+        let playing = self.game.state() == GameState::Playing && !self.game.is_solved(board);
+        let visible = guesses.len() + usize::from(playing);
+        let first_row = visible.saturating_sub(height as usize);
+
+        for display_row in 0..height.min(self.game.max_guesses() as u16) {
+            let row = first_row + display_row as usize;
+            for col in 0..WORD_LENGTH {
+                let mut cell_x = x + col as u16 * (CELL_WIDTH + CELL_SPACING);
+                let cell_y = y + display_row;
+                let input_row = playing && row == guesses.len();
+                if input_row && let Some(offset) = self.shake {
+                    cell_x = cell_x.saturating_add_signed(offset);
+                }
+
+                let (letter, style) = if let Some(feedback) = guesses.get(row) {
+                    let style = Style::default()
+                        .fg(self.theme.text)
+                        .bg(self.feedback_to_bg_color(feedback.feedback()[col]))
+                        .add_modifier(Modifier::BOLD);
+                    (Some(feedback.word().letter(col).char()), style)
+                } else if input_row {
+                    let bg = match self.shake {
+                        Some(_) => self.theme.invalid,
+                        None => self.theme.empty,
+                    };
+                    let style = Style::default()
+                        .fg(self.theme.text)
+                        .bg(bg)
+                        .add_modifier(Modifier::BOLD);
+                    (self.current_input.chars().nth(col), style)
+                } else {
+                    let style = Style::default().fg(self.theme.border).bg(self.theme.empty);
+                    (None, style)
+                };
+                draw_cell(buf, area, cell_x, cell_y, letter, style);
+            }
+        }
+    }
+}
+
+fn draw_cell(buf: &mut Buffer, area: Rect, x: u16, y: u16, letter: Option<char>, style: Style) {
+    if x < area.x || x + CELL_WIDTH > area.x + area.width || y >= area.y + area.height {
+        return;
+    }
+    for i in 0..CELL_WIDTH {
+        buf[(x + i, y)].set_style(style);
+    }
+    if let Some(ch) = letter {
+        buf[(x + 1, y)]
+            .set_char(super::tile_char(ch))
+            .set_style(style);
+    }
+}
+
+impl Widget for MultiBoardWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let boards = self.game.board_count();
+        let columns = Self::columns(boards, area.width);
+        let rows = boards.div_ceil(columns) as u16;
+
+        // Share the height between the rows of boards, scrolling the
+        // boards if it isn't enough for all guesses
+        let max_height = self.game.max_guesses() as u16;
+        let board_height =
+            (area.height.saturating_sub((rows - 1) * ROW_GAP) / rows).min(max_height);
+        let total_width = columns as u16 * BOARD_WIDTH + (columns as u16 - 1) * BOARD_GAP;
+        let total_height = rows * board_height + (rows - 1) * ROW_GAP;
+
+        // Center the grid in the area
+        let start_x = area.x + area.width.saturating_sub(total_width) / 2;
+        let start_y = area.y + area.height.saturating_sub(total_height) / 2;
+
+        for board in 0..boards {
+            let x = start_x + (board % columns) as u16 * (BOARD_WIDTH + BOARD_GAP);
+            let y = start_y + (board / columns) as u16 * (board_height + ROW_GAP);
+            self.render_board(board, x, y, board_height, area, buf);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget, Widget},
+};
+
+use crate::theme::Theme;
+
+/// Widget for a list that may be longer than its area: it shows the part
+/// around the selected item, with a scrollbar on the right when items are
+/// hidden
+pub struct ScrollListWidget<'a> {
+    items: &'a [String],
+    selected: usize,
+    theme: &'a Theme,
+}
+
+impl<'a> ScrollListWidget<'a> {
+    pub fn new(items: &'a [String], selected: usize, theme: &'a Theme) -> Self {
+        Self {
+            items,
+            selected,
+            theme,
+        }
+    }
+
+    /// Index of the first item shown in `height` rows, keeping the selected
+    /// item in the middle where possible
+    pub fn first_visible(&self, height: usize) -> usize {
+        let last_start = self.items.len().saturating_sub(height);
+        self.selected.saturating_sub(height / 2).min(last_start)
+    }
+}
+
+impl Widget for ScrollListWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = usize::from(area.height);
+        let first = self.first_visible(height);
+        let lines: Vec<Line> = self
+            .items
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(height)
+            .map(|(index, item)| {
+                if index == self.selected {
+                    Line::styled(
+                        format!("> {item} <"),
+                        Style::default()
+                            .fg(self.theme.correct)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Line::raw(item.as_str())
+                }
+            })
+            .collect();
+        Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.text))
+            .alignment(Alignment::Center)
+            .render(area, buf);
+
+        if self.items.len() > height {
+            let mut state = ScrollbarState::new(self.items.len() - height + 1).position(first);
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .style(Style::default().fg(self.theme.not_in_word))
+                .render(area, buf, &mut state);
+        }
+    }
+}
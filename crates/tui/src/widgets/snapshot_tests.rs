@@ -0,0 +1,295 @@
+//! Snapshot tests rendering the widgets into a test terminal, so layout
+//! changes can't slip in unnoticed.
+//!
+//! The expected buffers (text and styles) are checked in under
+//! `snapshots/`. After an intended change, review the new ones with
+//! `cargo insta review`.
+
+use std::collections::HashMap;
+
+use insta::assert_debug_snapshot;
+use ratatui::{Terminal, backend::TestBackend, buffer::Buffer, widgets::Widget};
+use wordle_game::{Game, Language, MultiGame, Playable, Word, WordPool};
+
+use super::{
+    BoardWidget, DialogWidget, FeedbackStyle, KeyboardLayout, KeyboardState, KeyboardWidget,
+    MessageWidget, MultiBoardWidget, SOLVER_PANEL_WIDTH, ScrollListWidget, SolverPanelWidget,
+    StatusBarWidget, TileStyle,
+};
+use crate::i18n::Strings;
+use crate::message::{Message, MessageKey};
+use crate::theme::{Theme, ThemeName};
+use crate::toast::Severity;
+
+fn render(widget: impl Widget, width: u16, height: u16) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|frame| frame.render_widget(widget, frame.area()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn game(guesses: &[&str]) -> Game {
+    let words = [
+        "hello", "world", "crane", "slate", "pious", "tried", "ghost", "lemon",
+    ];
+    let pool = WordPool::from_strings(words.iter().map(|w| w.to_string()));
+    let mut game = Game::with_secret(pool, Word::parse("hello").unwrap());
+    for guess in guesses {
+        game.guess(guess).unwrap();
+    }
+    game
+}
+
+fn keyboard_state(game: &Game) -> KeyboardState {
+    let mut state = KeyboardState::new();
+    for feedback in game.guesses() {
+        state.update(feedback);
+    }
+    state
+}
+
+fn assert_board_snapshot(name: &str, game: &Game, input: &str, theme: ThemeName) {
+    let theme = Theme::named(theme);
+    assert_debug_snapshot!(name, &render(BoardWidget::new(game, input, &theme), 21, 6));
+}
+
+fn assert_keyboard_snapshot(name: &str, game: &Game, theme: ThemeName) {
+    assert_layout_snapshot(name, game, KeyboardLayout::Qwertz, theme);
+}
+
+fn assert_layout_snapshot(name: &str, game: &Game, layout: KeyboardLayout, theme: ThemeName) {
+    let theme = Theme::named(theme);
+    let state = keyboard_state(game);
+    let widget = KeyboardWidget::new(&state, layout, &theme);
+    assert_debug_snapshot!(name, &render(widget, 47, 3));
+}
+
+#[test]
+fn test_empty_board() {
+    assert_board_snapshot("board_empty", &game(&[]), "", ThemeName::Dark);
+    assert_keyboard_snapshot("keyboard_empty", &game(&[]), ThemeName::Dark);
+}
+
+#[test]
+fn test_mid_game() {
+    let game = game(&["crane", "world"]);
+    assert_board_snapshot("board_mid_game", &game, "he", ThemeName::Dark);
+    assert_keyboard_snapshot("keyboard_mid_game", &game, ThemeName::Dark);
+}
+
+#[test]
+fn test_won() {
+    let game = game(&["crane", "lemon", "hello"]);
+    assert_board_snapshot("board_won", &game, "", ThemeName::Dark);
+    assert_keyboard_snapshot("keyboard_won", &game, ThemeName::Dark);
+}
+
+#[test]
+fn test_lost() {
+    let game = game(&["world", "crane", "slate", "pious", "tried", "ghost"]);
+    assert_board_snapshot("board_lost", &game, "", ThemeName::Dark);
+    assert_keyboard_snapshot("keyboard_lost", &game, ThemeName::Dark);
+}
+
+#[test]
+fn test_high_contrast() {
+    let game = game(&["crane", "lemon"]);
+    assert_board_snapshot("board_high_contrast", &game, "", ThemeName::HighContrast);
+    assert_keyboard_snapshot("keyboard_high_contrast", &game, ThemeName::HighContrast);
+}
+
+#[test]
+fn test_keyboard_layouts() {
+    let game = game(&["crane"]);
+    assert_layout_snapshot(
+        "keyboard_azerty",
+        &game,
+        KeyboardLayout::Azerty,
+        ThemeName::Dark,
+    );
+    assert_layout_snapshot(
+        "keyboard_dvorak",
+        &game,
+        KeyboardLayout::Dvorak,
+        ThemeName::Dark,
+    );
+}
+
+#[test]
+fn test_keyboard_sorted_by_letter_counts() {
+    let game = game(&["crane"]);
+    let theme = Theme::named(ThemeName::Dark);
+    let state = keyboard_state(&game);
+    // Ties stay in layout order, letters no word has come last
+    let counts = HashMap::from([('o', 4), ('l', 2), ('h', 2), ('s', 2), ('e', 2)]);
+    let widget =
+        KeyboardWidget::new(&state, KeyboardLayout::Qwerty, &theme).sorted_by(Some(&counts));
+    assert_debug_snapshot!("keyboard_sorted", &render(widget, 47, 3));
+}
+
+#[test]
+fn test_solver_panel() {
+    let theme = Theme::named(ThemeName::Dark);
+    let suggestions = [
+        (Word::parse("slate").unwrap(), 2.75),
+        (Word::parse("crane").unwrap(), 2.5),
+    ];
+    let widget = SolverPanelWidget::new(&suggestions, &theme);
+    assert_debug_snapshot!("solver_panel", &render(widget, SOLVER_PANEL_WIDTH, 7));
+}
+
+#[test]
+fn test_solver_panel_pending() {
+    let theme = Theme::named(ThemeName::Dark);
+    let widget = SolverPanelWidget::new(&[], &theme).pending(true);
+    assert_debug_snapshot!(
+        "solver_panel_pending",
+        &render(widget, SOLVER_PANEL_WIDTH, 7)
+    );
+}
+
+#[test]
+fn test_scroll_list_keeps_selection_in_view() {
+    let theme = Theme::named(ThemeName::Dark);
+    let items: Vec<String> = (1..=10).map(|n| format!("Game {n}")).collect();
+    let widget = ScrollListWidget::new(&items, 8, &theme);
+    assert_eq!(widget.first_visible(4), 6);
+    assert_debug_snapshot!("scroll_list", &render(widget, 20, 4));
+    // Short lists don't scroll
+    assert_eq!(
+        ScrollListWidget::new(&items[..3], 2, &theme).first_visible(4),
+        0
+    );
+}
+
+#[test]
+fn test_dialog_is_centered() {
+    let theme = Theme::named(ThemeName::Dark);
+    let widget = DialogWidget::new("Quit this game? (y/n)", &theme);
+    assert_debug_snapshot!("dialog", &render(widget, 41, 7));
+}
+
+#[test]
+fn test_hinted_letter() {
+    // No letter of "crane" is in place, so the hint reveals the first one
+    let mut game = game(&["crane"]);
+    game.hint().unwrap();
+    assert_board_snapshot("board_hinted", &game, "", ThemeName::Dark);
+}
+
+#[test]
+fn test_shaking_input_row() {
+    let game = game(&["crane"]);
+    let theme = Theme::named(ThemeName::Dark);
+    let widget = BoardWidget::new(&game, "hel", &theme).shake(Some(2));
+    assert_debug_snapshot!("board_shaking", &render(widget, 21, 6));
+}
+
+#[test]
+fn test_bordered_tiles() {
+    let game = game(&["crane", "world"]);
+    let theme = Theme::named(ThemeName::Dark);
+    let widget = BoardWidget::new(&game, "he", &theme).tile_style(TileStyle::Bordered);
+    assert_debug_snapshot!("board_bordered", &render(widget, 29, 18));
+}
+
+#[test]
+fn test_bordered_tiles_fall_back_to_compact() {
+    let game = game(&["crane", "world"]);
+    let theme = Theme::named(ThemeName::Dark);
+    let bordered = BoardWidget::new(&game, "he", &theme).tile_style(TileStyle::Bordered);
+    let compact = BoardWidget::new(&game, "he", &theme);
+    assert_eq!(render(bordered, 21, 6), render(compact, 21, 6));
+}
+
+#[test]
+fn test_feedback_symbols() {
+    let game = game(&["crane", "lemon"]);
+    let theme = Theme::named(ThemeName::Dark);
+    let board = BoardWidget::new(&game, "", &theme).feedback_style(FeedbackStyle::Symbols);
+    assert_debug_snapshot!("board_symbols", &render(board, 21, 6));
+    let bordered = BoardWidget::new(&game, "", &theme)
+        .tile_style(TileStyle::Bordered)
+        .feedback_style(FeedbackStyle::ColorsAndSymbols);
+    assert_debug_snapshot!("board_bordered_symbols", &render(bordered, 29, 18));
+    let state = keyboard_state(&game);
+    let keyboard = KeyboardWidget::new(&state, KeyboardLayout::Qwerty, &theme)
+        .feedback_style(FeedbackStyle::ColorsAndSymbols);
+    assert_debug_snapshot!("keyboard_symbols", &render(keyboard, 47, 3));
+}
+
+#[test]
+fn test_message_wraps_on_narrow_terminals() {
+    let theme = Theme::named(ThemeName::Dark);
+    let message = Message::new(
+        Severity::Success,
+        vec![
+            MessageKey::Won { guesses_used: 3 },
+            MessageKey::PlayAgain {
+                next_puzzle: false,
+                can_copy: true,
+            },
+        ],
+    )
+    .with_detail(MessageKey::Similar {
+        synonyms: vec!["hi".to_string(), "hey".to_string()],
+        url: None,
+    });
+    let widget = MessageWidget::new(&message, Strings::new(Language::English), &theme);
+    assert_eq!(widget.height(30), 4);
+    assert_debug_snapshot!("message_wrapped", &render(widget, 30, 4));
+}
+
+#[test]
+fn test_cursor_in_input_row() {
+    let game = game(&["crane"]);
+    let theme = Theme::named(ThemeName::Dark);
+    let widget = BoardWidget::new(&game, "hlo", &theme).cursor(Some(1));
+    assert_debug_snapshot!("board_cursor", &render(widget, 21, 6));
+}
+
+#[test]
+fn test_status_bar() {
+    let theme = Theme::named(ThemeName::Dark);
+    let widget = StatusBarWidget::new("Daily #123".to_string(), &theme)
+        .hard_mode(true)
+        .streak(4)
+        .hints_left(Some(2));
+    assert_debug_snapshot!("status_bar", &render(widget, 50, 1));
+}
+
+fn quordle(guesses: &[&str]) -> MultiGame {
+    let words = ["hello", "world", "crane", "slate", "pious", "tried"];
+    let pool = WordPool::from_strings(words.iter().map(|w| w.to_string()));
+    let secrets = ["hello", "world", "crane", "slate"].map(|w| Word::parse(w).unwrap());
+    let mut game = MultiGame::with_secrets(pool, secrets.to_vec(), 9);
+    for guess in guesses {
+        game.guess(guess).unwrap();
+    }
+    game
+}
+
+#[test]
+fn test_quordle_layouts() {
+    let game = quordle(&["pious", "crane"]);
+    let theme = Theme::named(ThemeName::Dark);
+    // All boards in a row on wide terminals, two per row otherwise
+    let widget = MultiBoardWidget::new(&game, "wor", &theme);
+    assert_debug_snapshot!("quordle_wide", &render(widget, 85, 9));
+    let widget = MultiBoardWidget::new(&game, "wor", &theme);
+    assert_debug_snapshot!("quordle_grid", &render(widget, 41, 19));
+    assert_eq!(MultiBoardWidget::height(&game, 85), 9);
+    assert_eq!(MultiBoardWidget::height(&game, 41), 19);
+    assert_eq!(MultiBoardWidget::height(&game, 30), 39);
+}
+
+#[test]
+fn test_quordle_keyboard() {
+    // 'c', 'r', 'a', 'n' and 'e' are only grey if no unsolved board has them
+    let game = quordle(&["crane", "tried"]);
+    let theme = Theme::named(ThemeName::Dark);
+    let state = KeyboardState::combined(&game);
+    let widget = KeyboardWidget::new(&state, KeyboardLayout::Qwertz, &theme);
+    assert_debug_snapshot!("keyboard_quordle", &render(widget, 47, 3));
+}
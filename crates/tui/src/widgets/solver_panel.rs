@@ -0,0 +1,79 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Clear, Padding, Paragraph, Widget},
+};
+use wordle_game::Word;
+
+use crate::theme::Theme;
+
+/// Width the panel needs for five letters and their scores
+pub const SOLVER_PANEL_WIDTH: u16 = 24;
+
+/// Widget for the solver's suggestions: the best next guesses with the
+/// information they are expected to give, in a box marked as not counting
+/// for the statistics
+pub struct SolverPanelWidget<'a> {
+    suggestions: &'a [(Word, f64)],
+    theme: &'a Theme,
+    pending: bool,
+}
+
+impl<'a> SolverPanelWidget<'a> {
+    pub fn new(suggestions: &'a [(Word, f64)], theme: &'a Theme) -> Self {
+        Self {
+            suggestions,
+            theme,
+            pending: false,
+        }
+    }
+
+    /// Tell that the suggestions are still being computed
+    pub fn pending(mut self, pending: bool) -> Self {
+        self.pending = pending;
+        self
+    }
+}
+
+impl Widget for SolverPanelWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines: Vec<Line> = if self.pending {
+            vec![Line::raw("Thinking...")]
+        } else if self.suggestions.is_empty() {
+            vec![Line::raw("No suggestions")]
+        } else {
+            self.suggestions
+                .iter()
+                .enumerate()
+                .map(|(rank, (word, bits))| {
+                    Line::raw(format!(
+                        "{}. {}  {bits:.2} bits",
+                        rank + 1,
+                        word.to_string().to_uppercase()
+                    ))
+                })
+                .collect()
+        };
+        let block = Block::bordered()
+            .title(Line::styled(
+                " SOLVER ",
+                Style::default()
+                    .fg(self.theme.wrong_position)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(" not in stats ")
+            .border_style(Style::default().fg(self.theme.wrong_position))
+            .padding(Padding::horizontal(1));
+        Clear.render(area, buf);
+        Paragraph::new(lines)
+            .style(
+                Style::default()
+                    .fg(self.theme.text)
+                    .bg(self.theme.background),
+            )
+            .block(block)
+            .render(area, buf);
+    }
+}
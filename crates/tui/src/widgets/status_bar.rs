@@ -0,0 +1,73 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use crate::theme::Theme;
+
+/// Widget for the bottom row: the mode being played, whether hard mode is
+/// on, the streak and the hints left
+pub struct StatusBarWidget<'a> {
+    mode: String,
+    hard_mode: bool,
+    streak: usize,
+    hints_left: Option<usize>,
+    theme: &'a Theme,
+}
+
+impl<'a> StatusBarWidget<'a> {
+    pub fn new(mode: String, theme: &'a Theme) -> Self {
+        Self {
+            mode,
+            hard_mode: false,
+            streak: 0,
+            hints_left: None,
+            theme,
+        }
+    }
+
+    pub fn hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    pub fn streak(mut self, streak: usize) -> Self {
+        self.streak = streak;
+        self
+    }
+
+    /// Hints the game still gives, None if it has no hints
+    pub fn hints_left(mut self, hints_left: Option<usize>) -> Self {
+        self.hints_left = hints_left;
+        self
+    }
+}
+
+impl Widget for StatusBarWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let plain = Style::default().fg(self.theme.not_in_word);
+        let mut spans = vec![Span::styled(
+            format!(" {} ", self.mode),
+            Style::default()
+                .fg(self.theme.text)
+                .add_modifier(Modifier::BOLD),
+        )];
+        if self.hard_mode {
+            spans.push(Span::styled(
+                " HARD ",
+                Style::default()
+                    .fg(self.theme.background)
+                    .bg(self.theme.wrong_position)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        spans.push(Span::styled(format!("  Streak: {}", self.streak), plain));
+        if let Some(hints) = self.hints_left {
+            spans.push(Span::styled(format!("  Hints: {hints}"), plain));
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+}
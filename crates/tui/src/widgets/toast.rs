@@ -0,0 +1,53 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Clear, Padding, Paragraph, Widget},
+};
+
+use crate::theme::Theme;
+use crate::toast::Toasts;
+
+/// Widget for the toasts, stacked in the top right corner with the newest
+/// at the bottom
+pub struct ToastWidget<'a> {
+    toasts: &'a Toasts,
+    theme: &'a Theme,
+}
+
+impl<'a> ToastWidget<'a> {
+    pub fn new(toasts: &'a Toasts, theme: &'a Theme) -> Self {
+        Self { toasts, theme }
+    }
+}
+
+impl Widget for ToastWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = 3;
+        let mut y = area.y + 1;
+        for toast in self.toasts.iter() {
+            if y + height > area.y + area.height {
+                break;
+            }
+            let width = (toast.text.chars().count() as u16 + 4).min(area.width.saturating_sub(2));
+            let x = area.x + area.width.saturating_sub(width + 1);
+            let rect = Rect::new(x, y, width, height);
+
+            let color = super::severity_color(toast.severity, self.theme);
+            Clear.render(rect, buf);
+            Paragraph::new(toast.text.as_str())
+                .style(
+                    Style::default()
+                        .fg(self.theme.text)
+                        .bg(self.theme.background),
+                )
+                .block(
+                    Block::bordered()
+                        .border_style(Style::default().fg(color))
+                        .padding(Padding::horizontal(1)),
+                )
+                .render(rect, buf);
+            y += height;
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::io::{self, BufRead, BufWriter, Write};
+
+use clap::Args;
+use wordle_wordlists_processing::recipe::{Stage, apply_stages};
+use wordle_wordlists_processing::stream::{from_sorted_reader, from_txt};
+
+/// Filters and transforms for `wordle-wordlists filter`, applied in the
+/// order they are listed here
+#[derive(Debug, Clone, Default, Args)]
+pub struct FilterArgs {
+    /// Fix words with invisible characters, non-breaking spaces or Cyrillic
+    /// lookalike letters, and drop those that can't be fixed
+    #[arg(long)]
+    pub sanitize: bool,
+    /// Keep only words with this many characters
+    #[arg(long)]
+    pub length: Option<usize>,
+    /// Drop words with non-alphabetic characters (with a warning on stderr)
+    #[arg(long)]
+    pub alphabetic: bool,
+    /// Keep only words consisting of these letters (case-insensitive)
+    #[arg(long, value_name = "LETTERS")]
+    pub alphabet: Option<String>,
+    /// Convert words to lowercase
+    #[arg(long)]
+    pub lowercase: bool,
+    /// Remove duplicates (case-insensitive)
+    #[arg(long)]
+    pub dedup: bool,
+    /// Input is not sorted in case-fold order: read and sort all of it
+    /// before writing anything. Without this, words are processed one at a
+    /// time, which requires sorted input (e.g. from `LC_ALL=C sort` for
+    /// lowercase words).
+    #[arg(long)]
+    pub unsorted: bool,
+}
+
+/// Run the filter from `input` to `output`
+pub fn run<R>(args: &FilterArgs, input: R, output: impl Write) -> io::Result<()>
+where
+    R: BufRead + 'static,
+{
+    let stream = if args.unsorted {
+        from_txt(input)?.boxed()
+    } else {
+        from_sorted_reader(input).boxed()
+    };
+    apply_stages(&stages(args), stream).write_to_writer(BufWriter::new(output))
+}
+
+/// The recipe stages equivalent to the command line flags
+fn stages(args: &FilterArgs) -> Vec<Stage> {
+    let mut stages = Vec::new();
+    if args.sanitize {
+        stages.push(Stage::Sanitize);
+    }
+    if let Some(length) = args.length {
+        stages.push(Stage::Length { length });
+    }
+    if args.alphabetic {
+        stages.push(Stage::Alphabetic);
+    }
+    if let Some(letters) = &args.alphabet {
+        stages.push(Stage::Alphabet {
+            letters: letters.clone(),
+        });
+    }
+    if args.lowercase {
+        stages.push(Stage::Lowercase);
+    }
+    if args.dedup {
+        stages.push(Stage::Dedup);
+    }
+    stages
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn filter(args: &FilterArgs, input: &'static str) -> String {
+        let mut output = Vec::new();
+        run(args, Cursor::new(input), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_no_filters_passes_through() {
+        let args = FilterArgs::default();
+        assert_eq!(filter(&args, "apple\nbanana\n"), "apple\nbanana\n");
+    }
+
+    #[test]
+    fn test_all_filters() {
+        let args = FilterArgs {
+            length: Some(5),
+            alphabetic: true,
+            lowercase: true,
+            dedup: true,
+            ..FilterArgs::default()
+        };
+        let input = "apple\nApple\nb4nan\nberry\ncherry\n";
+        assert_eq!(filter(&args, input), "apple\nberry\n");
+    }
+
+    #[test]
+    fn test_alphabet() {
+        let args = FilterArgs {
+            alphabet: Some("ABELPRY".to_string()),
+            ..FilterArgs::default()
+        };
+        assert_eq!(filter(&args, "apple\nBerry\ncherry\n"), "apple\nBerry\n");
+    }
+
+    #[test]
+    fn test_unsorted_input() {
+        let args = FilterArgs {
+            unsorted: true,
+            dedup: true,
+            ..FilterArgs::default()
+        };
+        assert_eq!(filter(&args, "cherry\napple\ncherry\n"), "apple\ncherry\n");
+    }
+}
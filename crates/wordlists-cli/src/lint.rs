@@ -0,0 +1,243 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use clap::Args;
+use serde::Serialize;
+use wordle_wordlists_data::{BUILTINS, BuiltinLists, blocklist};
+use wordle_wordlists_processing::recipe::Builtins;
+
+use crate::output::{LintReport, OutputFormat};
+
+/// Arguments for `wordle-wordlists lint`
+#[derive(Debug, Clone, Args)]
+pub struct LintArgs {
+    /// Word file to check, one word per line
+    pub file: PathBuf,
+    /// Number of letters words must have
+    #[arg(long, default_value_t = 5)]
+    pub length: usize,
+    /// Letters words may consist of (case-insensitive)
+    #[arg(
+        long,
+        value_name = "LETTERS",
+        default_value = "abcdefghijklmnopqrstuvwxyzäöüß"
+    )]
+    pub alphabet: String,
+    /// Don't compare against the built-in word lists, which takes a moment
+    #[arg(long)]
+    pub skip_builtins: bool,
+}
+
+/// Something wrong with one line of the checked file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// 1-based line number
+    pub line: usize,
+    pub word: String,
+    pub problem: Problem,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Problem {
+    /// The word doesn't have the required number of letters
+    Length { got: usize, expected: usize },
+    /// The word contains a character outside the alphabet
+    Alphabet { char: char },
+    /// The same word (ignoring case) is on an earlier line
+    Duplicate { first_line: usize },
+    /// The word was removed from a built-in list on purpose
+    Blocked,
+    /// A built-in word list has the word already
+    InBuiltin { name: &'static str },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: '{}' ", self.line, self.word)?;
+        match &self.problem {
+            Problem::Length { got, expected } => {
+                write!(f, "has {got} letters, but words must have {expected}")
+            }
+            Problem::Alphabet { char } => write!(
+                f,
+                "contains {char:?} (U+{:04X}), which is not in the alphabet",
+                u32::from(*char)
+            ),
+            Problem::Duplicate { first_line } => {
+                write!(f, "repeats line {first_line}, remove one of them")
+            }
+            Problem::Blocked => write!(f, "was removed from the built-in lists on purpose"),
+            Problem::InBuiltin { name } => write!(f, "is already in {name}, no need to add it"),
+        }
+    }
+}
+
+/// Check the file and print a diagnostic per problem. Fails if there are any.
+pub fn run(args: &LintArgs, format: OutputFormat) -> io::Result<()> {
+    let text = fs::read_to_string(&args.file)?;
+    let existing = if args.skip_builtins {
+        Vec::new()
+    } else {
+        builtin_words()?
+    };
+    let blocked: HashSet<String> = blocklist().into_iter().map(str::to_string).collect();
+
+    let diagnostics = lint(&text, args, &blocked, &existing);
+    match format {
+        OutputFormat::Text => {
+            for diagnostic in &diagnostics {
+                println!("{}: {diagnostic}", args.file.display());
+            }
+        }
+        OutputFormat::Json => format.print_json(&LintReport {
+            file: args.file.display().to_string(),
+            problems: diagnostics.clone(),
+        }),
+    }
+    if diagnostics.is_empty() {
+        eprintln!("{}: no problems found", args.file.display());
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "{}: {} problem{} found",
+            args.file.display(),
+            diagnostics.len(),
+            if diagnostics.len() == 1 { "" } else { "s" }
+        )))
+    }
+}
+
+/// The lowercase words of each built-in list
+fn builtin_words() -> io::Result<Vec<(&'static str, HashSet<String>)>> {
+    BUILTINS
+        .iter()
+        .map(|&name| {
+            let words = BuiltinLists
+                .load(name)?
+                .map(|word| word.map(|word| word.0.to_lowercase()))
+                .collect::<io::Result<_>>()?;
+            Ok((name, words))
+        })
+        .collect()
+}
+
+/// Check every non-empty line of `text`, in line order
+fn lint(
+    text: &str,
+    args: &LintArgs,
+    blocked: &HashSet<String>,
+    existing: &[(&'static str, HashSet<String>)],
+) -> Vec<Diagnostic> {
+    let alphabet = args.alphabet.to_lowercase();
+    let mut first_lines: HashMap<String, usize> = HashMap::new();
+    let mut diagnostics = Vec::new();
+
+    for (index, line) in text.trim_start_matches('\u{FEFF}').lines().enumerate() {
+        let word = line.trim();
+        if word.is_empty() {
+            continue;
+        }
+        let line = index + 1;
+        let lowercase = word.to_lowercase();
+        let mut report = |problem| {
+            diagnostics.push(Diagnostic {
+                line,
+                word: word.to_string(),
+                problem,
+            })
+        };
+
+        let got = word.chars().count();
+        if got != args.length {
+            report(Problem::Length {
+                got,
+                expected: args.length,
+            });
+        }
+        if let Some(char) = lowercase.chars().find(|&c| !alphabet.contains(c)) {
+            report(Problem::Alphabet { char });
+        }
+        if let Some(&first_line) = first_lines.get(&lowercase) {
+            report(Problem::Duplicate { first_line });
+        } else {
+            first_lines.insert(lowercase.clone(), line);
+        }
+        if blocked.contains(&lowercase) {
+            report(Problem::Blocked);
+        }
+        if let Some((name, _)) = existing
+            .iter()
+            .find(|(_, words)| words.contains(&lowercase))
+        {
+            report(Problem::InBuiltin { name });
+        }
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args() -> LintArgs {
+        LintArgs {
+            file: PathBuf::from("words.txt"),
+            length: 5,
+            alphabet: "abcdefghijklmnopqrstuvwxyzäöüß".to_string(),
+            skip_builtins: true,
+        }
+    }
+
+    fn problems(text: &str) -> Vec<(usize, Problem)> {
+        let blocked = HashSet::from(["œuvre".to_string()]);
+        let existing = vec![("de/test", HashSet::from(["apfel".to_string()]))];
+        lint(text, &args(), &blocked, &existing)
+            .into_iter()
+            .map(|diagnostic| (diagnostic.line, diagnostic.problem))
+            .collect()
+    }
+
+    #[test]
+    fn test_clean_file() {
+        assert_eq!(problems("\u{FEFF}Birne\r\n\nTraum\n"), vec![]);
+    }
+
+    #[test]
+    fn test_problems() {
+        let text = "kirsche\nb4nan\nTraum\ntraum\nŒuvre\nApfel\n";
+        assert_eq!(
+            problems(text),
+            vec![
+                (
+                    1,
+                    Problem::Length {
+                        got: 7,
+                        expected: 5
+                    }
+                ),
+                (2, Problem::Alphabet { char: '4' }),
+                (4, Problem::Duplicate { first_line: 3 }),
+                (5, Problem::Alphabet { char: 'œ' }),
+                (5, Problem::Blocked),
+                (6, Problem::InBuiltin { name: "de/test" }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_message() {
+        let diagnostic = Diagnostic {
+            line: 4,
+            word: "traum".to_string(),
+            problem: Problem::Duplicate { first_line: 3 },
+        };
+        assert_eq!(
+            diagnostic.to_string(),
+            "line 4: 'traum' repeats line 3, remove one of them"
+        );
+    }
+}
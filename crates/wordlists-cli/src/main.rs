@@ -0,0 +1,45 @@
+mod filter;
+mod lint;
+mod output;
+mod run;
+
+use std::io;
+
+use clap::{Parser, Subcommand};
+
+use filter::FilterArgs;
+use lint::LintArgs;
+use output::OutputFormat;
+use run::RunArgs;
+
+#[derive(Parser)]
+#[command(name = "wordle-wordlists", about = "Process word lists for wordle")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Format of what `run` and `lint` print, e.g. `json` for scripts
+    #[arg(long, value_enum, global = true, default_value_t)]
+    output: OutputFormat,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Read words from stdin, one per line, and write the words passing the
+    /// filters to stdout
+    Filter(FilterArgs),
+    /// Build the word lists described by a recipe file
+    Run(RunArgs),
+    /// Check a proposed word file against the game's constraints and the
+    /// built-in word lists before contributing it
+    Lint(LintArgs),
+}
+
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Filter(args) => filter::run(&args, io::stdin().lock(), io::stdout().lock()),
+        Command::Run(args) => run::run(&args, cli.output),
+        Command::Lint(args) => lint::run(&args, cli.output),
+    }
+}
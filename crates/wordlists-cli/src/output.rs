@@ -0,0 +1,171 @@
+//! Machine-readable output of the commands.
+//!
+//! With `--output json`, `run` and `lint` print one of the structs below
+//! instead of human-readable text. Their fields are a stable interface for
+//! scripts: fields may be added, but existing ones keep their name and
+//! meaning.
+
+use std::path::Path;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use wordle_wordlists_processing::recipe::{TraceEntry, TraceOutcome};
+use wordle_wordlists_processing::stream::Checksum;
+
+use crate::lint::Diagnostic;
+
+/// Format of what the commands print
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// JSON, for scripts and other tools
+    Json,
+}
+
+impl OutputFormat {
+    /// Print a command's result as JSON. In text mode, commands report as
+    /// they go instead.
+    pub fn print_json<T: Serialize>(self, value: &T) {
+        if self == OutputFormat::Json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(value).expect("output is always serializable")
+            );
+        }
+    }
+}
+
+/// An output written by `run`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuiltOutput {
+    pub path: String,
+    pub words: usize,
+    /// SHA-256 of the uncompressed words, in hex
+    pub sha256: String,
+    /// Whether it was built twice with the same result
+    pub reproducible: bool,
+}
+
+impl BuiltOutput {
+    pub fn new(path: &Path, checksum: &Checksum, reproducible: bool) -> Self {
+        Self {
+            path: path.display().to_string(),
+            words: checksum.words(),
+            sha256: checksum.to_string(),
+            reproducible,
+        }
+    }
+}
+
+/// The outputs written by `run`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RunReport {
+    pub outputs: Vec<BuiltOutput>,
+}
+
+/// A source line traced by `run --trace-word`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TracedLine {
+    pub source: String,
+    /// 1-based line number in the source
+    pub line: usize,
+    /// The word as read from the source
+    pub raw: String,
+    /// The word it ended up as, if it passed all stages
+    pub kept_as: Option<String>,
+    /// The stage that dropped it, otherwise
+    pub dropped_by: Option<String>,
+}
+
+impl From<&TraceEntry> for TracedLine {
+    fn from(entry: &TraceEntry) -> Self {
+        let (kept_as, dropped_by) = match &entry.outcome {
+            TraceOutcome::Kept(word) => (Some(word.to_string()), None),
+            TraceOutcome::Dropped(stage) => (None, Some(stage.to_string())),
+        };
+        Self {
+            source: entry.provenance.source.clone(),
+            line: entry.provenance.line,
+            raw: entry.raw.to_string(),
+            kept_as,
+            dropped_by,
+        }
+    }
+}
+
+/// Where a word of one output comes from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WordTrace {
+    pub word: String,
+    pub output: String,
+    /// Empty if the word is not in any source
+    pub lines: Vec<TracedLine>,
+}
+
+/// Result of `run --trace-word`, one trace per output of the recipe
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TraceReport {
+    pub traces: Vec<WordTrace>,
+}
+
+/// Result of `lint`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LintReport {
+    pub file: String,
+    pub problems: Vec<Diagnostic>,
+}
+
+#[cfg(test)]
+mod tests {
+    use wordle_wordlists_processing::Word;
+    use wordle_wordlists_processing::provenance::Provenance;
+    use wordle_wordlists_processing::recipe::Stage;
+
+    use super::*;
+    use crate::lint::Problem;
+
+    #[test]
+    fn test_lint_report() {
+        let report = LintReport {
+            file: "words.txt".to_string(),
+            problems: vec![Diagnostic {
+                line: 2,
+                word: "b4nan".to_string(),
+                problem: Problem::Alphabet { char: '4' },
+            }],
+        };
+        assert_eq!(
+            serde_json::to_value(report).unwrap(),
+            serde_json::json!({
+                "file": "words.txt",
+                "problems": [
+                    {"line": 2, "word": "b4nan", "problem": {"kind": "alphabet", "char": "4"}}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_traced_line() {
+        let entry = TraceEntry {
+            provenance: Provenance {
+                source: "extra.txt".to_string(),
+                line: 3,
+            },
+            raw: Word("Äpfel".to_string()),
+            outcome: TraceOutcome::Dropped(Stage::Length { length: 6 }),
+        };
+        assert_eq!(
+            serde_json::to_value(TracedLine::from(&entry)).unwrap(),
+            serde_json::json!({
+                "source": "extra.txt",
+                "line": 3,
+                "raw": "Äpfel",
+                "kept_as": null,
+                "dropped_by": "length 6"
+            })
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use wordle_wordlists_data::BuiltinLists;
+use wordle_wordlists_processing::recipe::{Output, Recipe};
+use wordle_wordlists_processing::stream::{Checksum, Codec, from_sorted_compressed_file};
+
+use crate::output::{BuiltOutput, OutputFormat, RunReport, TraceReport, TracedLine, WordTrace};
+
+/// Arguments for `wordle-wordlists run`
+#[derive(Debug, Clone, Args)]
+pub struct RunArgs {
+    /// Recipe file (TOML) describing sources, stages and outputs. File
+    /// sources are relative to the directory of the recipe.
+    pub recipe: PathBuf,
+    /// Directory to write the outputs to (defaults to the directory of the recipe)
+    #[arg(long)]
+    pub out_dir: Option<PathBuf>,
+    /// Build every output twice and fail if the results differ. The
+    /// `.meta.toml` written next to each output records the build date,
+    /// so set `SOURCE_DATE_EPOCH` to make it reproducible as well.
+    #[arg(long)]
+    pub verify_reproducible: bool,
+    /// Instead of building, show every source line that ends up as this
+    /// word (or is this word but gets dropped), and which stage dropped it
+    #[arg(long, value_name = "WORD", conflicts_with = "verify_reproducible")]
+    pub trace_word: Option<String>,
+    /// Fail if words of other lengths make up more than
+    /// `--max-other-fraction` of an output, e.g. because a length stage
+    /// was dropped from the recipe
+    #[arg(long, value_name = "LENGTH")]
+    pub expect_length: Option<usize>,
+    /// Fraction of words (0 to 1) that may have another length than
+    /// `--expect-length`
+    #[arg(
+        long,
+        value_name = "FRACTION",
+        default_value_t = 0.0,
+        value_parser = parse_fraction,
+        requires = "expect_length"
+    )]
+    pub max_other_fraction: f64,
+}
+
+/// Build all outputs of the recipe
+pub fn run(args: &RunArgs, format: OutputFormat) -> io::Result<()> {
+    let recipe = Recipe::from_file(&args.recipe)?;
+    let input_dir = args.recipe.parent().unwrap_or(Path::new("."));
+    let output_dir = args.out_dir.as_deref().unwrap_or(input_dir);
+
+    if let Some(word) = &args.trace_word {
+        let traces = recipe
+            .outputs
+            .iter()
+            .map(|output| trace(output, word, input_dir, format))
+            .collect::<io::Result<_>>()?;
+        format.print_json(&TraceReport { traces });
+        return Ok(());
+    }
+
+    if args.verify_reproducible && std::env::var_os("SOURCE_DATE_EPOCH").is_none() {
+        eprintln!("Warning: SOURCE_DATE_EPOCH is not set, so the metadata records today");
+    }
+    let mut report = RunReport {
+        outputs: Vec::new(),
+    };
+    for output in &recipe.outputs {
+        let path = output_dir.join(&output.path);
+        let checksum = if args.verify_reproducible {
+            let checksum = build_reproducible(output, input_dir, &path)?;
+            eprintln!(
+                "Wrote {} (reproducible, SHA-256 {checksum})",
+                path.display()
+            );
+            checksum
+        } else {
+            let checksum = output.write(input_dir, output_dir, &BuiltinLists)?;
+            eprintln!("Wrote {}", path.display());
+            checksum
+        };
+        if let Some(length) = args.expect_length {
+            let histogram = length_histogram(&path, output.codec()?)?;
+            check_lengths(&histogram, length, args.max_other_fraction)
+                .map_err(|err| io::Error::other(format!("{}: {err}", path.display())))?;
+        }
+        report
+            .outputs
+            .push(BuiltOutput::new(&path, &checksum, args.verify_reproducible));
+    }
+    format.print_json(&report);
+    Ok(())
+}
+
+fn parse_fraction(s: &str) -> Result<f64, String> {
+    let fraction: f64 = s.parse().map_err(|err| format!("{err}"))?;
+    if (0.0..=1.0).contains(&fraction) {
+        Ok(fraction)
+    } else {
+        Err(format!("{fraction} is not between 0 and 1"))
+    }
+}
+
+/// Number of words of each length (in characters) in a written output
+fn length_histogram(path: &Path, codec: &dyn Codec) -> io::Result<BTreeMap<usize, usize>> {
+    let mut histogram = BTreeMap::new();
+    for word in from_sorted_compressed_file(path, codec)? {
+        *histogram.entry(word?.0.chars().count()).or_insert(0) += 1;
+    }
+    Ok(histogram)
+}
+
+/// Check that at most `max_other_fraction` of the words are not `length`
+/// characters long
+fn check_lengths(
+    histogram: &BTreeMap<usize, usize>,
+    length: usize,
+    max_other_fraction: f64,
+) -> Result<(), String> {
+    let total: usize = histogram.values().sum();
+    let others: usize = histogram
+        .iter()
+        .filter(|&(&l, _)| l != length)
+        .map(|(_, count)| count)
+        .sum();
+    if others == 0 || others as f64 <= max_other_fraction * total as f64 {
+        return Ok(());
+    }
+    let counts: Vec<String> = histogram
+        .iter()
+        .filter(|&(&l, _)| l != length)
+        .map(|(l, count)| format!("{count} with {l} letters"))
+        .collect();
+    Err(format!(
+        "{others} of {total} words are not {length} letters long ({})",
+        counts.join(", ")
+    ))
+}
+
+/// Find out where `word` in the output comes from, printing it in text mode
+fn trace(
+    output: &Output,
+    word: &str,
+    input_dir: &Path,
+    format: OutputFormat,
+) -> io::Result<WordTrace> {
+    let entries = output.trace(word, input_dir, &BuiltinLists)?;
+    if format == OutputFormat::Text {
+        if entries.is_empty() {
+            println!("{word} in {}: not in any source", output.path.display());
+        } else {
+            println!("{word} in {}:", output.path.display());
+            for entry in &entries {
+                println!("  {entry}");
+            }
+        }
+    }
+    Ok(WordTrace {
+        word: word.to_string(),
+        output: output.path.display().to_string(),
+        lines: entries.iter().map(TracedLine::from).collect(),
+    })
+}
+
+/// Build the output twice, compare the results and write it to `path` if
+/// they match. Returns the checksum of its words.
+fn build_reproducible(output: &Output, input_dir: &Path, path: &Path) -> io::Result<Checksum> {
+    let (first, checksum) = output.to_bytes_checksummed(input_dir, &BuiltinLists)?;
+    let second = output.to_bytes(input_dir, &BuiltinLists)?;
+    if first != second {
+        return Err(io::Error::other(format!(
+            "{} is not reproducible: builds differ from byte {}",
+            path.display(),
+            first_difference(&first, &second)
+        )));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, first)?;
+    output.write_sidecars(path, &checksum)?;
+    Ok(checksum)
+}
+
+/// Offset of the first byte where `a` and `b` differ
+fn first_difference(a: &[u8], b: &[u8]) -> usize {
+    a.iter()
+        .zip(b)
+        .position(|(x, y)| x != y)
+        .unwrap_or(a.len().min(b.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_lengths() {
+        let histogram = BTreeMap::from([(5, 98), (4, 1), (6, 1)]);
+        assert_eq!(check_lengths(&histogram, 5, 0.02), Ok(()));
+        assert_eq!(
+            check_lengths(&histogram, 5, 0.0),
+            Err(
+                "2 of 100 words are not 5 letters long (1 with 4 letters, 1 with 6 letters)"
+                    .to_string()
+            )
+        );
+        assert_eq!(check_lengths(&BTreeMap::from([(5, 3)]), 5, 0.0), Ok(()));
+        assert_eq!(check_lengths(&BTreeMap::new(), 5, 0.0), Ok(()));
+    }
+
+    #[test]
+    fn test_first_difference() {
+        assert_eq!(first_difference(b"abc", b"abd"), 2);
+        assert_eq!(first_difference(b"abc", b"abcd"), 3);
+        assert_eq!(first_difference(b"", b"a"), 0);
+    }
+
+    #[test]
+    fn test_parse_fraction() {
+        assert_eq!(parse_fraction("0.5"), Ok(0.5));
+        assert!(parse_fraction("1.5").is_err());
+        assert!(parse_fraction("half").is_err());
+    }
+}
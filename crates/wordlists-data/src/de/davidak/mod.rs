@@ -1,11 +1,13 @@
-use std::{collections::HashSet, io::Cursor};
+use std::{collections::HashSet, io::{BufRead, BufReader, Cursor}};
 
 use common_macros::hash_set;
-use wordle_wordlists_processing::{Word, stream::{WordStream, from_txt_zstd}};
+use wordle_wordlists_processing::{Word, provenance::{Record, records_from_txt_zstd}, stream::{Codec, TxtOptions, WordStream, Zstd, from_txt}};
 
+const NAME: &str = "de/davidak";
 const DATA: &[u8] = include_bytes!("davidak.txt.zst");
 
-fn remove_words() -> HashSet<&'static str> {
+/// Words dropped from this source, e.g. foreign words that can't be typed
+pub fn remove_words() -> HashSet<&'static str> {
     hash_set! {
         "œuvre",
         "ōsaka",
@@ -16,6 +18,19 @@ fn remove_words() -> HashSet<&'static str> {
 }
 
 pub fn load() -> Result<WordStream<impl Iterator<Item = std::io::Result<Word>> + 'static>, std::io::Error> {
-    Ok(from_txt_zstd(Cursor::new(DATA))?
+    load_from(BufReader::new(Zstd.decoder(Box::new(Cursor::new(DATA)))?))
+}
+
+/// Reads words in the format of this source from uncompressed text, e.g. a
+/// sample of it for tests
+pub fn load_from(reader: impl BufRead) -> Result<WordStream<impl Iterator<Item = std::io::Result<Word>> + 'static>, std::io::Error> {
+    Ok(from_txt(reader)?
         .filter(|w| !remove_words().contains(w.to_lowercase().as_str())))
 }
+
+/// The words with the line each was read from, in source order
+pub fn records() -> std::io::Result<Vec<Record>> {
+    let mut records = records_from_txt_zstd(Cursor::new(DATA), NAME, TxtOptions::default())?;
+    records.retain(|r| !remove_words().contains(r.word.0.to_lowercase().as_str()));
+    Ok(records)
+}
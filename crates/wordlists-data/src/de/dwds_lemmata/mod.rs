@@ -1,18 +1,32 @@
-use std::{collections::HashSet, io::Cursor};
+use std::{collections::HashSet, io::{BufReader, Cursor, Read}};
 
 use common_macros::hash_set;
-use wordle_wordlists_processing::{Word, stream::{WordStream, from_csv_zstd}};
+use wordle_wordlists_processing::{Word, provenance::{Record, records_from_csv_zstd}, stream::{Codec, WordStream, Zstd, from_csv}};
 
+const NAME: &str = "de/dwds_lemmata";
 const DATA: &[u8] = include_bytes!("dwds_lemmata_2026-01-01.csv.zst");
 
-fn remove_words() -> HashSet<&'static str> {
+/// Words dropped from this source, e.g. foreign words that can't be typed
+pub fn remove_words() -> HashSet<&'static str> {
     hash_set! {
         "œuvre",
     }
 }
 
 pub fn load() -> Result<WordStream<impl Iterator<Item = std::io::Result<Word>> + 'static>, std::io::Error> {
-    Ok(from_csv_zstd(Cursor::new(DATA))?
+    load_from(BufReader::new(Zstd.decoder(Box::new(Cursor::new(DATA)))?))
+}
+
+/// Reads words in the format of this source from uncompressed CSV, e.g. a
+/// sample of it for tests
+pub fn load_from(reader: impl Read) -> Result<WordStream<impl Iterator<Item = std::io::Result<Word>> + 'static>, std::io::Error> {
+    Ok(from_csv(reader)?
         .filter(|w| !remove_words().contains(w.to_lowercase().as_str())))
 }
 
+/// The words with the line each was read from, in source order
+pub fn records() -> std::io::Result<Vec<Record>> {
+    let mut records = records_from_csv_zstd(Cursor::new(DATA), NAME)?;
+    records.retain(|r| !remove_words().contains(r.word.0.to_lowercase().as_str()));
+    Ok(records)
+}
@@ -1,2 +1,3 @@
 pub mod davidak;
 pub mod dwds_lemmata;
+pub mod openthesaurus;
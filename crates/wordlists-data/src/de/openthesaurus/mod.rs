@@ -0,0 +1,87 @@
+use std::io::{self, BufRead, BufReader, Cursor};
+
+use wordle_wordlists_processing::stream::{Codec, Zstd};
+
+const DATA: &[u8] = include_bytes!("openthesaurus.txt.zst");
+
+/// Words with the same meaning as `word` (case-insensitive), from all of
+/// its meanings, in source order
+pub fn synonyms(word: &str) -> io::Result<Vec<String>> {
+    synonyms_from(
+        BufReader::new(Zstd.decoder(Box::new(Cursor::new(DATA)))?),
+        word,
+    )
+}
+
+/// Looks up synonyms in text in the format of this source: one meaning per
+/// line, with its words separated by `;` and annotated in parentheses
+pub fn synonyms_from(reader: impl BufRead, word: &str) -> io::Result<Vec<String>> {
+    let word = word.to_lowercase();
+    let mut synonyms: Vec<String> = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.starts_with('#') {
+            continue;
+        }
+        let terms: Vec<String> = line.split(';').map(strip_annotations).collect();
+        if !terms.iter().any(|term| term.to_lowercase() == word) {
+            continue;
+        }
+        for term in terms {
+            if term.to_lowercase() != word && !term.is_empty() && !synonyms.contains(&term) {
+                synonyms.push(term);
+            }
+        }
+    }
+    Ok(synonyms)
+}
+
+/// A term without remarks like `(ugs.)`
+fn strip_annotations(term: &str) -> String {
+    let mut stripped = String::new();
+    let mut depth = 0usize;
+    for c in term.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if depth == 0 => stripped.push(c),
+            _ => {}
+        }
+    }
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "# OpenThesaurus\n\
+        Fission;Kernfission;Atomspaltung;Kernspaltung (fachspr., technisch)\n\
+        Haus;Gebäude;Bude (ugs.)\n\
+        (sich) verstecken;untertauchen\n\
+        Haus;Dynastie;Geschlecht\n";
+
+    #[test]
+    fn test_synonyms_from() {
+        let synonyms = synonyms_from(SAMPLE.as_bytes(), "haus").unwrap();
+        assert_eq!(synonyms, vec!["Gebäude", "Bude", "Dynastie", "Geschlecht"]);
+        assert_eq!(
+            synonyms_from(SAMPLE.as_bytes(), "Verstecken").unwrap(),
+            vec!["untertauchen"]
+        );
+        assert!(
+            synonyms_from(SAMPLE.as_bytes(), "Katze")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_embedded_data() {
+        assert!(
+            synonyms("Kernspaltung")
+                .unwrap()
+                .contains(&"Fission".to_string())
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! Short explanations of words, e.g. to show with the answer of a game.
+//!
+//! There is no offline dictionary with definitions yet, so an explanation
+//! consists of synonyms (where a thesaurus is available) and the address
+//! of an online dictionary entry.
+
+use std::io;
+
+/// What is known about the meaning of a word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Definition {
+    /// Words with the same meaning, most relevant first
+    pub synonyms: Vec<String>,
+    /// Online dictionary entry of the word
+    pub url: Option<String>,
+}
+
+/// Explain `word` of the language with the ISO 639-1 code `language`
+pub fn lookup(language: &str, word: &str) -> io::Result<Definition> {
+    match language {
+        "de" => Ok(Definition {
+            synonyms: crate::de::openthesaurus::synonyms(word)?,
+            url: Some(format!("https://www.dwds.de/wb/{word}")),
+        }),
+        "en" => Ok(Definition {
+            synonyms: Vec::new(),
+            url: Some(format!("https://en.wiktionary.org/wiki/{word}")),
+        }),
+        _ => Ok(Definition {
+            synonyms: Vec::new(),
+            url: None,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let definition = lookup("de", "kernspaltung").unwrap();
+        assert!(definition.synonyms.contains(&"Atomspaltung".to_string()));
+        assert_eq!(
+            definition.url.as_deref(),
+            Some("https://www.dwds.de/wb/kernspaltung")
+        );
+        assert!(lookup("en", "crane").unwrap().synonyms.is_empty());
+        assert_eq!(lookup("fr", "chien").unwrap().url, None);
+    }
+}
@@ -1 +1,52 @@
+use std::collections::HashSet;
+use std::io;
+
+use wordle_wordlists_processing::provenance::Record;
+use wordle_wordlists_processing::recipe::Builtins;
+use wordle_wordlists_processing::stream::BoxedWordStream;
+
 pub mod de;
+pub mod definitions;
+
+/// Names of the built-in word lists, as used in recipes
+pub const BUILTINS: &[&str] = &["de/davidak", "de/dwds_lemmata"];
+
+/// The built-in word lists, for running recipes
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BuiltinLists;
+
+impl Builtins for BuiltinLists {
+    fn load(&self, name: &str) -> io::Result<BoxedWordStream> {
+        match name {
+            "de/davidak" => Ok(de::davidak::load()?.boxed()),
+            "de/dwds_lemmata" => Ok(de::dwds_lemmata::load()?.boxed()),
+            _ => Err(unknown(name)),
+        }
+    }
+
+    fn records(&self, name: &str) -> io::Result<Vec<Record>> {
+        match name {
+            "de/davidak" => de::davidak::records(),
+            "de/dwds_lemmata" => de::dwds_lemmata::records(),
+            _ => Err(unknown(name)),
+        }
+    }
+}
+
+/// Words that were removed from a built-in list on purpose, and so
+/// shouldn't be contributed again
+pub fn blocklist() -> HashSet<&'static str> {
+    let mut words = de::davidak::remove_words();
+    words.extend(de::dwds_lemmata::remove_words());
+    words
+}
+
+fn unknown(name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!(
+            "Unknown built-in word list '{name}', expected one of: {}",
+            BUILTINS.join(", ")
+        ),
+    )
+}
@@ -0,0 +1,104 @@
+//! Runs the recipe of the word lists embedded into the game on small
+//! samples of the built-in sources (`fixtures/`) and compares the results
+//! with the expected lists in `golden/`. Changes to stages or to the
+//! ordering that would alter the shipped lists show up as a diff here.
+//!
+//! After an intended change, run the test with `UPDATE_GOLDEN=1` to rewrite
+//! the golden files, and review the diff.
+
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+use wordle_wordlists_data::de;
+use wordle_wordlists_processing::provenance::Record;
+use wordle_wordlists_processing::recipe::{Builtins, Output, Recipe};
+use wordle_wordlists_processing::stream::BoxedWordStream;
+
+fn test_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+/// The built-in word lists, read from the samples in `fixtures/`
+struct FixtureLists;
+
+impl FixtureLists {
+    fn open(name: &str) -> io::Result<BufReader<File>> {
+        Ok(BufReader::new(File::open(
+            test_dir().join("fixtures").join(name),
+        )?))
+    }
+}
+
+impl Builtins for FixtureLists {
+    fn load(&self, name: &str) -> io::Result<BoxedWordStream> {
+        match name {
+            "de/davidak" => Ok(de::davidak::load_from(Self::open("de/davidak.txt")?)?.boxed()),
+            "de/dwds_lemmata" => {
+                Ok(de::dwds_lemmata::load_from(Self::open("de/dwds_lemmata.csv")?)?.boxed())
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No fixture for built-in word list '{name}'"),
+            )),
+        }
+    }
+
+    fn records(&self, name: &str) -> io::Result<Vec<Record>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("Fixtures don't keep records of '{name}'"),
+        ))
+    }
+}
+
+/// Where the expected words of an output are, uncompressed
+fn golden_path(output: &Output) -> io::Result<PathBuf> {
+    let codec_extension = output.codec()?.extension();
+    let name = if output.path.extension().and_then(|e| e.to_str()) == codec_extension {
+        output.path.with_extension("")
+    } else {
+        output.path.clone()
+    };
+    Ok(test_dir().join("golden").join(name))
+}
+
+fn assert_golden(path: &Path, actual: &str) {
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(path, actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(path).unwrap_or_else(|err| {
+        panic!(
+            "Missing golden file {}: {err}. Run with UPDATE_GOLDEN=1 to create it.",
+            path.display()
+        )
+    });
+    assert!(
+        expected == actual,
+        "{} changed. Run with UPDATE_GOLDEN=1 to accept.\nExpected:\n{expected}\nActual:\n{actual}",
+        path.display()
+    );
+}
+
+#[test]
+fn test_game_recipe_matches_golden_outputs() {
+    let recipe_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../game");
+    let recipe = Recipe::from_file(recipe_dir.join("wordlists.toml")).unwrap();
+    assert!(!recipe.outputs.is_empty());
+
+    for output in &recipe.outputs {
+        let mut words = Vec::new();
+        output
+            .build(&recipe_dir, &FixtureLists)
+            .unwrap()
+            .write_to_writer(&mut words)
+            .unwrap();
+        let golden = golden_path(output).unwrap();
+        assert_golden(&golden, &String::from_utf8(words).unwrap());
+
+        let mut metadata = golden.into_os_string();
+        metadata.push(".meta.toml");
+        assert_golden(Path::new(&metadata), &output.metadata().to_toml());
+    }
+}
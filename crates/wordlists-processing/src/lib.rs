@@ -1,6 +1,12 @@
+pub mod civil;
 pub mod ordering;
+pub mod prelude;
+pub mod provenance;
+pub mod recipe;
+pub mod sharp_s;
 mod word;
 mod word_set;
+pub mod wordpack;
 
 pub use word::Word;
 pub use word_set::WordSet;
@@ -3,34 +3,54 @@
 //! Provides a deterministic ordering where:
 //! - Primary sort key: lowercase form of characters
 //! - Secondary sort key: original case (lowercase < uppercase)
+//! - Final tiebreaker: Unicode code point, so only identical strings compare
+//!   equal and sorting never depends on the input order
 //!
 //! This ensures `"apple" < "Apple" < "APPLE" < "banana"`.
+//!
+//! Most words in the lists are plain ASCII, so runs of ASCII bytes are
+//! compared without going through Unicode case mapping.
 
 use std::cmp::Ordering;
 
 /// Compare two chars using case-fold ordering.
 ///
 /// Compares the full lowercase sequence first (handles multi-char expansions
-/// like 'İ' → "i\u{307}"), then uses case as tiebreaker.
+/// like 'İ' → "i\u{307}"), then uses case as tiebreaker, then the code point
+/// (e.g. for the Kelvin sign 'K', which lowercases to 'k' just like 'K').
 ///
 /// Result: `'a' < 'A' < 'b' < 'B'`
 fn char_cmp(a: char, b: char) -> Ordering {
     match a.to_lowercase().cmp(b.to_lowercase()) {
         Ordering::Equal => {
             // Tiebreaker: lowercase < uppercase (false < true)
-            a.is_uppercase().cmp(&b.is_uppercase())
+            a.is_uppercase()
+                .cmp(&b.is_uppercase())
+                .then_with(|| a.cmp(&b))
         }
         other => other,
     }
 }
 
+/// Compare two ASCII bytes like [`char_cmp`] compares their chars
+fn ascii_cmp(a: u8, b: u8) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    match a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase()) {
+        // Case variants of the same letter: lowercase < uppercase
+        Ordering::Equal => a.is_ascii_uppercase().cmp(&b.is_ascii_uppercase()),
+        other => other,
+    }
+}
+
 /// Compare two strings using case-fold ordering (char by char).
 ///
 /// # Examples
 ///
 /// ```
 /// use std::cmp::Ordering;
-/// # use wordle::wordlist::ordering::case_fold_cmp;
+/// # use wordle_wordlists_processing::ordering::case_fold_cmp;
 ///
 /// assert_eq!(case_fold_cmp("apple", "Apple"), Ordering::Less);
 /// assert_eq!(case_fold_cmp("Apple", "APPLE"), Ordering::Less);
@@ -38,6 +58,22 @@ fn char_cmp(a: char, b: char) -> Ordering {
 /// assert_eq!(case_fold_cmp("ärger", "Ärger"), Ordering::Less);
 /// ```
 pub fn case_fold_cmp(a: &str, b: &str) -> Ordering {
+    let (a_bytes, b_bytes) = (a.as_bytes(), b.as_bytes());
+    for (index, (&ab, &bb)) in a_bytes.iter().zip(b_bytes).enumerate() {
+        if !ab.is_ascii() || !bb.is_ascii() {
+            // Everything before was ASCII, so `index` is a char boundary
+            return unicode_case_fold_cmp(&a[index..], &b[index..]);
+        }
+        let cmp = ascii_cmp(ab, bb);
+        if cmp != Ordering::Equal {
+            return cmp;
+        }
+    }
+    a_bytes.len().cmp(&b_bytes.len())
+}
+
+/// [`case_fold_cmp`] without the ASCII fast path
+fn unicode_case_fold_cmp(a: &str, b: &str) -> Ordering {
     let mut a_chars = a.chars();
     let mut b_chars = b.chars();
 
@@ -121,6 +157,80 @@ mod tests {
         assert_eq!(char_cmp('a', 'a'), Ordering::Equal);
     }
 
+    #[test]
+    fn test_total_order() {
+        // The Kelvin sign and 'K' only differ by code point
+        assert_eq!(char_cmp('K', '\u{212A}'), Ordering::Less);
+        assert_eq!(char_cmp('\u{212A}', 'K'), Ordering::Greater);
+        assert_eq!(case_fold_cmp("Kilo", "\u{212A}ilo"), Ordering::Less);
+    }
+
+    /// Chars that case mapping treats specially, next to plain ASCII
+    const TRICKY_CHARS: &[char] = &[
+        'ä', 'Ä', 'ß', 'ẞ', 'İ', 'ı', '\u{212A}', '\u{307}', 'é', 'É', 'ǅ', 'Σ', 'ς', 'σ', '\u{80}',
+    ];
+
+    fn all_chars() -> Vec<char> {
+        (0..=0x7Fu8)
+            .map(char::from)
+            .chain(TRICKY_CHARS.iter().copied())
+            .collect()
+    }
+
+    #[test]
+    fn test_fast_path_matches_unicode_for_all_char_pairs() {
+        let chars = all_chars();
+        for &a in &chars {
+            for &b in &chars {
+                let (a, b) = (a.to_string(), b.to_string());
+                assert_eq!(
+                    case_fold_cmp(&a, &b),
+                    unicode_case_fold_cmp(&a, &b),
+                    "{a:?} vs {b:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fast_path_matches_unicode_for_random_strings() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let chars = all_chars();
+        let letters: Vec<char> = "aAbBzZ"
+            .chars()
+            .chain(TRICKY_CHARS.iter().copied())
+            .collect();
+        let mut rng = StdRng::seed_from_u64(4048);
+        let random_string = |rng: &mut StdRng| -> String {
+            let len = rng.gen_range(0..8);
+            (0..len)
+                .map(|_| {
+                    // Mostly a few letters, so strings often share prefixes
+                    let pool = if rng.gen_bool(0.8) { &letters } else { &chars };
+                    pool[rng.gen_range(0..pool.len())]
+                })
+                .collect()
+        };
+        for _ in 0..100_000 {
+            let a = random_string(&mut rng);
+            let b = if rng.gen_bool(0.5) {
+                // Same prefix, different tail
+                let keep = rng.gen_range(0..=a.chars().count());
+                let cut = a.char_indices().nth(keep).map_or(a.len(), |(i, _)| i);
+                format!("{}{}", &a[..cut], random_string(&mut rng))
+            } else {
+                random_string(&mut rng)
+            };
+            assert_eq!(
+                case_fold_cmp(&a, &b),
+                unicode_case_fold_cmp(&a, &b),
+                "{a:?} vs {b:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_multi_char_lowercase() {
         // 'İ' (U+0130, Turkish capital I with dot) lowercases to "i\u{0307}" (2 chars)
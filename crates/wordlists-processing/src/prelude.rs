@@ -0,0 +1,18 @@
+//! What most word list pipelines need, for a glob import:
+//!
+//! ```no_run
+//! use wordle_wordlists_processing::prelude::*;
+//!
+//! from_sorted_zst_file("words.zst")?
+//!     .filter(|w| w.len() == 5)
+//!     .write_to_file("five.txt")?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+pub use crate::recipe::Recipe;
+pub use crate::sharp_s::SharpSPolicy;
+pub use crate::stream::{
+    BoxedWordStream, Codec, WordStream, codec_for_path, from_sorted_file, from_sorted_zst_file,
+    from_txt, from_txt_zstd,
+};
+pub use crate::{Word, WordSet};
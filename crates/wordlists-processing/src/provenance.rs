@@ -0,0 +1,203 @@
+//! Where words come from, for debugging word list builds.
+//!
+//! Word streams are sorted and therefore lose the position of each word in
+//! its source. The functions here read sources as [`Record`]s instead,
+//! keeping the line every word was read from, so that a word in a built
+//! list can be traced back to the lines that produced it (see
+//! [`Output::trace`](crate::recipe::Output::trace)).
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+
+use crate::Word;
+use crate::stream::{Codec, TextLines, TxtOptions, Zstd};
+
+/// The line a word was read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    /// Name of the source, e.g. a file name or built-in word list
+    pub source: String,
+    /// Line number in the source, starting at 1
+    pub line: usize,
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.source, self.line)
+    }
+}
+
+/// A word as read from its source, with its provenance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub word: Word,
+    pub provenance: Provenance,
+}
+
+/// Reads plain text words with their line numbers, in source order.
+///
+/// Lines are read according to `options` and lines without a word skipped,
+/// like [`from_txt_with_options`](crate::stream::from_txt_with_options).
+///
+/// # Errors
+///
+/// Returns an error if reading fails, or if the text is not valid UTF-8 and
+/// `options` don't allow lossy decoding.
+pub fn records_from_txt<R: BufRead>(
+    reader: R,
+    source: &str,
+    options: TxtOptions,
+) -> io::Result<Vec<Record>> {
+    let mut records = Vec::new();
+    for (index, line) in TextLines::new(reader, source, options).enumerate() {
+        let line = line?;
+        if let Some(word) = options.word(&line) {
+            records.push(record(word, source, index + 1));
+        }
+    }
+    Ok(records)
+}
+
+/// Reads zstd-compressed plain text words with their line numbers.
+///
+/// # Errors
+///
+/// Returns an error if reading fails or the stream is not valid zstd.
+pub fn records_from_txt_zstd<R: Read>(
+    reader: R,
+    source: &str,
+    options: TxtOptions,
+) -> io::Result<Vec<Record>> {
+    records_from_txt_compressed(reader, source, options, &Zstd)
+}
+
+/// Reads plain text words compressed with `codec` with their line numbers.
+///
+/// # Errors
+///
+/// Returns an error if reading fails or the stream is not valid for the codec.
+pub fn records_from_txt_compressed<R: Read>(
+    reader: R,
+    source: &str,
+    options: TxtOptions,
+    codec: &dyn Codec,
+) -> io::Result<Vec<Record>> {
+    records_from_txt(
+        BufReader::new(codec.decoder(Box::new(reader))?),
+        source,
+        options,
+    )
+}
+
+/// Reads the first column of a CSV with the line numbers of the rows,
+/// like [`from_csv`](crate::stream::from_csv).
+///
+/// # Errors
+///
+/// Returns an error if reading fails or CSV parsing encounters invalid data.
+pub fn records_from_csv<R: Read>(reader: R, source: &str) -> io::Result<Vec<Record>> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(reader);
+
+    let mut records = Vec::new();
+    for result in csv_reader.records() {
+        let row = result.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let line = row.position().map_or(0, |p| p.line() as usize);
+        if let Some(first_field) = row.get(0) {
+            let trimmed = first_field.trim();
+            if !trimmed.is_empty() {
+                records.push(record(trimmed, source, line));
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// Reads the first column of a zstd-compressed CSV with line numbers.
+///
+/// # Errors
+///
+/// Returns an error if reading fails, the stream is not valid zstd,
+/// or CSV parsing encounters invalid data.
+pub fn records_from_csv_zstd<R: Read>(reader: R, source: &str) -> io::Result<Vec<Record>> {
+    records_from_csv_compressed(reader, source, &Zstd)
+}
+
+/// Reads the first column of a CSV compressed with `codec` with line numbers.
+///
+/// # Errors
+///
+/// Returns an error if reading fails, the stream is not valid for the codec,
+/// or CSV parsing encounters invalid data.
+pub fn records_from_csv_compressed<R: Read>(
+    reader: R,
+    source: &str,
+    codec: &dyn Codec,
+) -> io::Result<Vec<Record>> {
+    records_from_csv(BufReader::new(codec.decoder(Box::new(reader))?), source)
+}
+
+fn record(word: &str, source: &str, line: usize) -> Record {
+    Record {
+        word: Word(word.to_string()),
+        provenance: Provenance {
+            source: source.to_string(),
+            line,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn lines(records: &[Record]) -> Vec<(String, usize)> {
+        records
+            .iter()
+            .map(|r| (r.word.0.clone(), r.provenance.line))
+            .collect()
+    }
+
+    #[test]
+    fn test_txt_keeps_source_order_and_lines() {
+        let records = records_from_txt(
+            Cursor::new("cherry\n\n  apple \n"),
+            "words.txt",
+            TxtOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            lines(&records),
+            vec![("cherry".to_string(), 1), ("apple".to_string(), 3)]
+        );
+        assert_eq!(records[1].provenance.to_string(), "words.txt:3");
+    }
+
+    #[test]
+    fn test_csv_lines() {
+        let data = "apple,1\n\"multi\nline\",2\nbanana,3\n";
+        let records = records_from_csv(Cursor::new(data), "words.csv").unwrap();
+        assert_eq!(
+            lines(&records),
+            vec![
+                ("apple".to_string(), 1),
+                ("multi\nline".to_string(), 2),
+                ("banana".to_string(), 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_txt_zstd() {
+        let data = zstd::encode_all(Cursor::new(b"apple\nbanana\n".as_slice()), 0).unwrap();
+        let records =
+            records_from_txt_zstd(Cursor::new(data), "words.txt.zst", TxtOptions::default())
+                .unwrap();
+        assert_eq!(
+            lines(&records),
+            vec![("apple".to_string(), 1), ("banana".to_string(), 2)]
+        );
+    }
+}
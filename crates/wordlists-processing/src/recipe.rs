@@ -0,0 +1,1040 @@
+//! Declarative word list builds.
+//!
+//! A recipe describes a word list build as data: which sources to read,
+//! which stages to apply to each of them, and where to write the merged
+//! result. Recipes are written in TOML:
+//!
+//! ```toml
+//! [[outputs]]
+//! path = "de.txt.zst"
+//! stages = [{ op = "dedup" }]
+//!
+//! [[outputs.sources]]
+//! builtin = "de/davidak"
+//! stages = [
+//!     { op = "length", length = 5 },
+//!     { op = "alphabetic" },
+//!     { op = "lowercase" },
+//!     { op = "dedup" },
+//! ]
+//!
+//! [[outputs.sources]]
+//! file = "extra.txt"
+//! ```
+//!
+//! Sources are either built-in word lists, resolved by name by the caller,
+//! or files. File sources are sorted in memory unless marked with
+//! `sorted = true`, and must be valid UTF-8 unless marked with
+//! `utf8 = "lossy"`. Hand-maintained files can be
+//! marked with `comments = true` to allow `# ...` comment lines and
+//! annotations after words (`wort  # note`). Large sorted files that were
+//! already validated can skip most of the order check with
+//! `sort_policy = { sample = { chunk_size = 4096 } }` (see [`SortPolicy`]).
+//!
+//! Files are compressed according to their extension (`.zst` for zstd,
+//! `.gz` for gzip, see [`codec_for_path`]), unless a source or output
+//! names a codec with `compression = "zstd"`, `"gzip"` or `"none"`.
+//!
+//! Outputs with `checksum = true` also get a `<path>.sha256` file with the
+//! SHA-256 of their uncompressed words, computed while writing them.
+//!
+//! Outputs can set `sharp_s` to pick one spelling for words with 'ß'
+//! (see [`SharpSPolicy`]). The policy is applied to every source before its
+//! stages, since it can change the length of words, and recorded in a
+//! `<path>.meta.toml` file written next to the output. The file also tells
+//! how many words the output has, when it was built (the day of
+//! `SOURCE_DATE_EPOCH` if set, for reproducible builds) and a fingerprint
+//! of the output's recipe, so a build can be identified later.
+//!
+//! To find out why a word is (or is not) in an output, [`Output::trace`]
+//! reports every source line that turns into the word and what the stages
+//! did to it.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Word;
+use crate::civil;
+use crate::provenance::{Provenance, Record, records_from_txt};
+use crate::sharp_s::{ListMetadata, SharpSPolicy};
+use crate::stream::{
+    BoxedWordStream, Checksum, Codec, Duplicates, Sanitized, SortPolicy, Trim, TxtOptions,
+    Utf8Mode, codec_by_name, codec_for_path, from_sorted_reader_with_options,
+    from_txt_with_options, sanitize_word,
+};
+
+/// Built-in word lists that recipes can refer to by name
+pub trait Builtins {
+    /// Loads a built-in word list as a sorted stream
+    fn load(&self, name: &str) -> io::Result<BoxedWordStream>;
+
+    /// Reads a built-in word list with the line of each word, in source order
+    fn records(&self, name: &str) -> io::Result<Vec<Record>>;
+}
+
+/// A word list build: a set of outputs, each merged from several sources
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Recipe {
+    #[serde(default)]
+    pub outputs: Vec<Output>,
+}
+
+/// A word list written by a recipe
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Output {
+    /// Where to write the word list, relative to the output directory
+    pub path: PathBuf,
+    /// Word lists merged into this output
+    pub sources: Vec<Source>,
+    /// Stages applied to the merged sources
+    #[serde(default)]
+    pub stages: Vec<Stage>,
+    /// How to spell words with 'ß', applied to each source before its stages
+    #[serde(default)]
+    pub sharp_s: SharpSPolicy,
+    /// Name of the codec to compress the file with, instead of the one
+    /// for its extension
+    #[serde(default)]
+    pub compression: Option<String>,
+    /// Also write the SHA-256 of the uncompressed words to `<path>.sha256`
+    #[serde(default)]
+    pub checksum: bool,
+}
+
+/// A word list read by a recipe
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "SourceSpec", into = "SourceSpec")]
+pub struct Source {
+    pub input: Input,
+    /// The file is already sorted in case-fold order and can be streamed
+    #[serde(default)]
+    pub sorted: bool,
+    /// How to treat bytes in the file that are not valid UTF-8
+    #[serde(default)]
+    pub utf8: Utf8Mode,
+    /// Whether a sorted file may repeat a word
+    #[serde(default)]
+    pub duplicates: Duplicates,
+    /// How thoroughly the order of a sorted file is checked
+    #[serde(default)]
+    pub sort_policy: SortPolicy,
+    /// What to trim from each line of the file
+    #[serde(default)]
+    pub trim: Trim,
+    /// Whether the file has `#` comments and annotations
+    #[serde(default)]
+    pub comments: bool,
+    /// Name of the codec the file is compressed with, instead of the one
+    /// for its extension
+    #[serde(default)]
+    pub compression: Option<String>,
+    /// Stages applied to this source before merging
+    #[serde(default)]
+    pub stages: Vec<Stage>,
+}
+
+/// Where a source reads its words from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// A built-in word list, by name (e.g. `de/davidak`)
+    Builtin(String),
+    /// A file with one word per line, relative to the input directory
+    File(PathBuf),
+}
+
+/// A source as written in a recipe, with `builtin` and `file` as separate
+/// keys so that unknown keys can be rejected (which `#[serde(flatten)]`
+/// doesn't support)
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SourceSpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    builtin: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<PathBuf>,
+    #[serde(default)]
+    sorted: bool,
+    #[serde(default)]
+    utf8: Utf8Mode,
+    #[serde(default)]
+    duplicates: Duplicates,
+    #[serde(default)]
+    sort_policy: SortPolicy,
+    #[serde(default)]
+    trim: Trim,
+    #[serde(default)]
+    comments: bool,
+    #[serde(default)]
+    compression: Option<String>,
+    #[serde(default)]
+    stages: Vec<Stage>,
+}
+
+impl TryFrom<SourceSpec> for Source {
+    type Error = String;
+
+    fn try_from(spec: SourceSpec) -> Result<Self, String> {
+        let input = match (spec.builtin, spec.file) {
+            (Some(name), None) => Input::Builtin(name),
+            (None, Some(path)) => Input::File(path),
+            (Some(_), Some(_)) => return Err("source has both `builtin` and `file`".to_string()),
+            (None, None) => return Err("source needs `builtin` or `file`".to_string()),
+        };
+        Ok(Self {
+            input,
+            sorted: spec.sorted,
+            utf8: spec.utf8,
+            duplicates: spec.duplicates,
+            sort_policy: spec.sort_policy,
+            trim: spec.trim,
+            comments: spec.comments,
+            compression: spec.compression,
+            stages: spec.stages,
+        })
+    }
+}
+
+impl From<Source> for SourceSpec {
+    fn from(source: Source) -> Self {
+        let (builtin, file) = match source.input {
+            Input::Builtin(name) => (Some(name), None),
+            Input::File(path) => (None, Some(path)),
+        };
+        Self {
+            builtin,
+            file,
+            sorted: source.sorted,
+            utf8: source.utf8,
+            duplicates: source.duplicates,
+            sort_policy: source.sort_policy,
+            trim: source.trim,
+            comments: source.comments,
+            compression: source.compression,
+            stages: source.stages,
+        }
+    }
+}
+
+/// A processing step applied to a stream of words
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Stage {
+    /// Keep only words with this many characters
+    Length { length: usize },
+    /// Drop words with non-alphabetic characters (with a warning on stderr)
+    Alphabetic,
+    /// Keep only words consisting of these letters (case-insensitive)
+    Alphabet { letters: String },
+    /// Convert words to lowercase
+    Lowercase,
+    /// Remove duplicates (case-insensitive)
+    Dedup,
+    /// Fix words with invisible characters, non-breaking spaces or Cyrillic
+    /// lookalike letters, and drop those that can't be fixed (with a
+    /// warning on stderr)
+    Sanitize,
+}
+
+/// What happened to a source line in a build, see [`Output::trace`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub provenance: Provenance,
+    /// The word as read from the source
+    pub raw: Word,
+    pub outcome: TraceOutcome,
+}
+
+/// Whether a source line made it through the stages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceOutcome {
+    /// Passed all stages, ending up as this word (duplicates of it may
+    /// still be merged by `dedup`)
+    Kept(Word),
+    /// Dropped by this stage
+    Dropped(Stage),
+}
+
+impl Recipe {
+    /// Parses a recipe from TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if the recipe is malformed.
+    pub fn parse(toml: &str) -> io::Result<Self> {
+        toml::from_str(toml).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Reads and parses a recipe file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or the recipe is malformed.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Builds all outputs of the recipe.
+    ///
+    /// File sources are read relative to `input_dir`, outputs are written
+    /// relative to `output_dir`, and built-in sources are loaded from `builtins`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source cannot be read or an output cannot be written.
+    pub fn run(
+        &self,
+        input_dir: &Path,
+        output_dir: &Path,
+        builtins: &dyn Builtins,
+    ) -> io::Result<()> {
+        for output in &self.outputs {
+            output.write(input_dir, output_dir, builtins)?;
+        }
+        Ok(())
+    }
+}
+
+impl Output {
+    /// Merges the sources and applies the stages, without writing anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output has no sources or a source cannot be read.
+    pub fn build(&self, input_dir: &Path, builtins: &dyn Builtins) -> io::Result<BoxedWordStream> {
+        let mut sources = self.sources.iter();
+        let first = sources.next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Output '{}' has no sources", self.path.display()),
+            )
+        })?;
+
+        let mut stream = self.load_source(first, input_dir, builtins)?;
+        for source in sources {
+            stream = stream.merge(self.load_source(source, input_dir, builtins)?);
+        }
+        Ok(apply_stages(&self.stages, stream))
+    }
+
+    /// Reads a source, spells it according to `sharp_s`, and applies its stages
+    fn load_source(
+        &self,
+        source: &Source,
+        input_dir: &Path,
+        builtins: &dyn Builtins,
+    ) -> io::Result<BoxedWordStream> {
+        let stream = self
+            .sharp_s
+            .apply_to_stream(source.read(input_dir, builtins)?);
+        Ok(apply_stages(&source.stages, stream))
+    }
+
+    /// The metadata written next to the output, as far as it follows from
+    /// the recipe
+    pub fn metadata(&self) -> ListMetadata {
+        // The output section written back as TOML, with every setting spelled
+        // out, so equivalent recipes get the same fingerprint
+        let toml = toml::to_string(self).expect("recipe outputs serialize to TOML");
+        let recipe = Checksum::of(toml.as_bytes()).to_string();
+        ListMetadata {
+            sharp_s: self.sharp_s,
+            recipe: Some(recipe[..16].to_string()),
+            ..ListMetadata::default()
+        }
+    }
+
+    /// Builds the output and writes it to `output_dir`, along with its
+    /// metadata. Returns the checksum of its words.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source cannot be read or the output cannot be written.
+    pub fn write(
+        &self,
+        input_dir: &Path,
+        output_dir: &Path,
+        builtins: &dyn Builtins,
+    ) -> io::Result<Checksum> {
+        let stream = self.build(input_dir, builtins)?;
+
+        let path = output_dir.join(&self.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let checksum = stream.write_to_compressed_file_checksummed(&path, self.codec()?)?;
+        self.write_sidecars(&path, &checksum)?;
+        Ok(checksum)
+    }
+
+    /// Writes the files that go next to the output at `path`: its metadata,
+    /// with the number of words and the build date, and its checksum if the
+    /// output asks for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be written.
+    pub fn write_sidecars(&self, path: &Path, checksum: &Checksum) -> io::Result<()> {
+        let metadata = ListMetadata {
+            words: Some(checksum.words()),
+            built: Some(build_date()),
+            ..self.metadata()
+        };
+        metadata.write_for(path)?;
+        if self.checksum {
+            checksum.write_sidecar(path)?;
+        }
+        Ok(())
+    }
+
+    /// Builds the output and returns the bytes `write` would write to the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source cannot be read.
+    pub fn to_bytes(&self, input_dir: &Path, builtins: &dyn Builtins) -> io::Result<Vec<u8>> {
+        Ok(self.to_bytes_checksummed(input_dir, builtins)?.0)
+    }
+
+    /// Like [`to_bytes`](Self::to_bytes), and returns the checksum of the
+    /// uncompressed words.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source cannot be read.
+    pub fn to_bytes_checksummed(
+        &self,
+        input_dir: &Path,
+        builtins: &dyn Builtins,
+    ) -> io::Result<(Vec<u8>, Checksum)> {
+        let stream = self.build(input_dir, builtins)?;
+        let mut bytes = Vec::new();
+        let checksum = stream.write_to_compressed_writer_checksummed(&mut bytes, self.codec()?)?;
+        Ok((bytes, checksum))
+    }
+
+    /// The codec the output is compressed with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `compression` names an unknown codec.
+    pub fn codec(&self) -> io::Result<&'static dyn Codec> {
+        codec(self.compression.as_deref(), &self.path)
+    }
+
+    /// Finds every source line that is, or turns into, `word` (ignoring
+    /// case), and what the source and output stages did to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source cannot be read.
+    pub fn trace(
+        &self,
+        word: &str,
+        input_dir: &Path,
+        builtins: &dyn Builtins,
+    ) -> io::Result<Vec<TraceEntry>> {
+        let word = word.to_lowercase();
+        let mut entries = Vec::new();
+        for source in &self.sources {
+            for record in source.records(input_dir, builtins)? {
+                let spelled = Word(self.sharp_s.apply(&record.word.0));
+                let outcome = trace_stages(source.stages.iter().chain(&self.stages), spelled);
+                let matches = |w: &Word| w.0.to_lowercase() == word;
+                let relevant = matches(&record.word)
+                    || matches!(&outcome, TraceOutcome::Kept(kept) if matches(kept));
+                if relevant {
+                    entries.push(TraceEntry {
+                        provenance: record.provenance,
+                        raw: record.word,
+                        outcome,
+                    });
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+impl Source {
+    /// Reads the source and applies its stages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be read.
+    pub fn load(&self, input_dir: &Path, builtins: &dyn Builtins) -> io::Result<BoxedWordStream> {
+        Ok(apply_stages(&self.stages, self.read(input_dir, builtins)?))
+    }
+
+    /// Reads the source without applying its stages
+    fn read(&self, input_dir: &Path, builtins: &dyn Builtins) -> io::Result<BoxedWordStream> {
+        Ok(match &self.input {
+            Input::Builtin(name) => builtins.load(name)?,
+            Input::File(path) => {
+                let reader = open_file(&input_dir.join(path), self.codec(path)?)?;
+                let name = path.display().to_string();
+                if self.sorted {
+                    from_sorted_reader_with_options(reader, name, self.txt_options()).boxed()
+                } else {
+                    from_txt_with_options(reader, name, self.txt_options())?.boxed()
+                }
+            }
+        })
+    }
+
+    /// Reads the source with the line of each word, in source order,
+    /// without applying the stages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be read.
+    pub fn records(&self, input_dir: &Path, builtins: &dyn Builtins) -> io::Result<Vec<Record>> {
+        match &self.input {
+            Input::Builtin(name) => builtins.records(name),
+            Input::File(path) => {
+                let reader = open_file(&input_dir.join(path), self.codec(path)?)?;
+                records_from_txt(reader, &path.display().to_string(), self.txt_options())
+            }
+        }
+    }
+
+    fn codec(&self, path: &Path) -> io::Result<&'static dyn Codec> {
+        codec(self.compression.as_deref(), path)
+    }
+
+    fn txt_options(&self) -> TxtOptions {
+        TxtOptions {
+            utf8: self.utf8,
+            duplicates: self.duplicates,
+            sort_policy: self.sort_policy,
+            trim: self.trim,
+            comments: self.comments,
+        }
+    }
+}
+
+impl Stage {
+    /// Applies this stage to a stream.
+    pub fn apply(&self, stream: BoxedWordStream) -> BoxedWordStream {
+        match self {
+            Stage::Length { .. } | Stage::Alphabet { .. } => {
+                let stage = self.clone();
+                stream.filter(move |w| stage.keeps(w))
+            }
+            Stage::Alphabetic => stream.filter_non_alphabetic(),
+            Stage::Lowercase => stream.to_lowercase(),
+            Stage::Dedup => stream.dedup(),
+            Stage::Sanitize => stream.sanitize(),
+        }
+    }
+
+    /// Applies this stage to a single word. Returns None if the stage drops it.
+    /// `dedup` keeps every word, since it depends on the words around it.
+    pub fn apply_to_word(&self, word: Word) -> Option<Word> {
+        match self {
+            Stage::Lowercase => Some(Word(word.0.to_lowercase())),
+            Stage::Sanitize => match sanitize_word(&word.0) {
+                Sanitized::Clean => Some(word),
+                Sanitized::Fixed(fixed) => Some(Word(fixed)),
+                Sanitized::Flagged(_) => None,
+            },
+            _ if self.keeps(&word.0) => Some(word),
+            _ => None,
+        }
+    }
+
+    /// Whether a filtering stage keeps the word (transforming stages keep all)
+    fn keeps(&self, word: &str) -> bool {
+        match self {
+            Stage::Length { length } => word.chars().count() == *length,
+            Stage::Alphabetic => word.chars().all(|c| c.is_alphabetic()),
+            Stage::Alphabet { letters } => {
+                let alphabet: Vec<char> = letters.chars().flat_map(char::to_lowercase).collect();
+                word.chars()
+                    .flat_map(char::to_lowercase)
+                    .all(|c| alphabet.contains(&c))
+            }
+            Stage::Lowercase | Stage::Dedup | Stage::Sanitize => true,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Length { length } => write!(f, "length {length}"),
+            Stage::Alphabetic => write!(f, "alphabetic"),
+            Stage::Alphabet { letters } => write!(f, "alphabet \"{letters}\""),
+            Stage::Lowercase => write!(f, "lowercase"),
+            Stage::Dedup => write!(f, "dedup"),
+            Stage::Sanitize => write!(f, "sanitize"),
+        }
+    }
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} \"{}\": ", self.provenance, self.raw)?;
+        match &self.outcome {
+            TraceOutcome::Kept(word) => write!(f, "kept as \"{word}\""),
+            TraceOutcome::Dropped(stage) => write!(f, "dropped by {stage}"),
+        }
+    }
+}
+
+/// Follows a single word through stages
+fn trace_stages<'a>(stages: impl IntoIterator<Item = &'a Stage>, word: Word) -> TraceOutcome {
+    let mut word = word;
+    for stage in stages {
+        match stage.apply_to_word(word) {
+            Some(next) => word = next,
+            None => return TraceOutcome::Dropped(stage.clone()),
+        }
+    }
+    TraceOutcome::Kept(word)
+}
+
+/// Applies stages to a stream, in order.
+pub fn apply_stages(stages: &[Stage], stream: BoxedWordStream) -> BoxedWordStream {
+    stages
+        .iter()
+        .fold(stream, |stream, stage| stage.apply(stream))
+}
+
+/// The codec named by `compression`, or the one for the file extension
+fn codec(compression: Option<&str>, path: &Path) -> io::Result<&'static dyn Codec> {
+    match compression {
+        Some(name) => codec_by_name(name),
+        None => Ok(codec_for_path(path)),
+    }
+}
+
+/// Today (UTC) as `YYYY-MM-DD`, or the day of `SOURCE_DATE_EPOCH` if set
+fn build_date() -> String {
+    let seconds = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs())
+        });
+    civil::format_day((seconds / (24 * 60 * 60)) as i64)
+}
+
+/// Opens a file source, decompressing it with `codec`
+fn open_file(path: &Path, codec: &dyn Codec) -> io::Result<Box<dyn io::BufRead>> {
+    let file = io::BufReader::new(fs::File::open(path)?);
+    Ok(Box::new(io::BufReader::new(codec.decoder(Box::new(file))?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestBuiltins;
+
+    impl TestBuiltins {
+        fn words(name: &str) -> io::Result<&'static [&'static str]> {
+            match name {
+                "fruits" => Ok(&["Apple", "apple", "banana", "kiwi"]),
+                "berries" => Ok(&["apple", "berry", "cherry"]),
+                _ => Err(io::Error::new(io::ErrorKind::NotFound, name.to_string())),
+            }
+        }
+    }
+
+    impl Builtins for TestBuiltins {
+        fn load(&self, name: &str) -> io::Result<BoxedWordStream> {
+            let words: Vec<io::Result<Word>> = Self::words(name)?
+                .iter()
+                .map(|w| Ok(Word(w.to_string())))
+                .collect();
+            Ok(BoxedWordStream::new(words.into_iter()))
+        }
+
+        fn records(&self, name: &str) -> io::Result<Vec<Record>> {
+            records_from_txt(
+                io::Cursor::new(Self::words(name)?.join("\n")),
+                name,
+                TxtOptions::default(),
+            )
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "test_recipe_{}_{}",
+            name,
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    const RECIPE: &str = r#"
+        [[outputs]]
+        path = "out/words.txt"
+        stages = [{ op = "dedup" }]
+
+        [[outputs.sources]]
+        builtin = "fruits"
+        stages = [{ op = "length", length = 5 }, { op = "lowercase" }]
+
+        [[outputs.sources]]
+        builtin = "berries"
+    "#;
+
+    #[test]
+    fn test_parse() {
+        let recipe = Recipe::parse(RECIPE).unwrap();
+        assert_eq!(recipe.outputs.len(), 1);
+        let output = &recipe.outputs[0];
+        assert_eq!(output.path, PathBuf::from("out/words.txt"));
+        assert_eq!(output.stages, vec![Stage::Dedup]);
+        assert_eq!(
+            output.sources[0],
+            Source {
+                input: Input::Builtin("fruits".to_string()),
+                sorted: false,
+                utf8: Utf8Mode::Strict,
+                duplicates: Duplicates::Allow,
+                sort_policy: SortPolicy::Strict,
+                trim: Trim::Whitespace,
+                comments: false,
+                compression: None,
+                stages: vec![Stage::Length { length: 5 }, Stage::Lowercase],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_stage() {
+        let recipe = r#"
+            [[outputs]]
+            path = "words.txt"
+            sources = [{ builtin = "fruits", stages = [{ op = "shuffle" }] }]
+        "#;
+        let err = Recipe::parse(recipe).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_sources() {
+        for source in [
+            r#"{ file = "words.txt", sortd = true }"#,
+            r#"{ file = "words.txt", utf = "lossy" }"#,
+            r#"{ builtin = "fruits", file = "words.txt" }"#,
+            r#"{ sorted = true }"#,
+        ] {
+            let recipe = format!("[[outputs]]\npath = \"words.txt\"\nsources = [{source}]\n");
+            let err = Recipe::parse(&recipe).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData, "{source}");
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let dir = temp_dir("run");
+        Recipe::parse(RECIPE)
+            .unwrap()
+            .run(&dir, &dir, &TestBuiltins)
+            .unwrap();
+
+        let content = fs::read_to_string(dir.join("out/words.txt")).unwrap();
+        assert_eq!(content, "apple\nberry\ncherry\n");
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_file_source() {
+        let dir = temp_dir("file");
+        fs::write(dir.join("words.txt"), "kiwi\nApple\n").unwrap();
+        let recipe = r#"
+            [[outputs]]
+            path = "words.txt.zst"
+            sources = [{ file = "words.txt", stages = [{ op = "alphabet", letters = "aelpkiw" }] }]
+        "#;
+
+        let words: Vec<String> = Recipe::parse(recipe).unwrap().outputs[0]
+            .build(&dir, &TestBuiltins)
+            .unwrap()
+            .map(|w| w.unwrap().0)
+            .collect();
+        assert_eq!(words, vec!["Apple", "kiwi"]);
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_to_bytes_matches_written_file() {
+        let dir = temp_dir("bytes");
+        let recipe = RECIPE.replace("out/words.txt", "words.txt.zst");
+        let output = &Recipe::parse(&recipe).unwrap().outputs[0];
+        output.write(&dir, &dir, &TestBuiltins).unwrap();
+
+        let written = fs::read(dir.join("words.txt.zst")).unwrap();
+        assert_eq!(output.to_bytes(&dir, &TestBuiltins).unwrap(), written);
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_trace() {
+        let output = &Recipe::parse(RECIPE).unwrap().outputs[0];
+        let trace: Vec<String> = output
+            .trace("APPLE", Path::new("."), &TestBuiltins)
+            .unwrap()
+            .iter()
+            .map(TraceEntry::to_string)
+            .collect();
+        assert_eq!(
+            trace,
+            vec![
+                "fruits:1 \"Apple\": kept as \"apple\"",
+                "fruits:2 \"apple\": kept as \"apple\"",
+                "berries:1 \"apple\": kept as \"apple\"",
+            ]
+        );
+
+        let trace = output.trace("kiwi", Path::new("."), &TestBuiltins).unwrap();
+        assert_eq!(
+            trace[0].outcome,
+            TraceOutcome::Dropped(Stage::Length { length: 5 })
+        );
+    }
+
+    #[test]
+    fn test_sanitize_stage() {
+        let dir = temp_dir("sanitize");
+        // A Cyrillic 'а', a zero width space and an untypeable Cyrillic 'д'
+        fs::write(
+            dir.join("words.txt"),
+            "bаum\nbirne\nkir\u{200B}sche\nbaдm\n",
+        )
+        .unwrap();
+        let recipe = r#"
+            [[outputs]]
+            path = "words.txt.zst"
+            sources = [{ file = "words.txt", stages = [{ op = "sanitize" }] }]
+        "#;
+
+        let output = &Recipe::parse(recipe).unwrap().outputs[0];
+        let words: Vec<String> = output
+            .build(&dir, &TestBuiltins)
+            .unwrap()
+            .map(|w| w.unwrap().0)
+            .collect();
+        assert_eq!(words, vec!["baum", "birne", "kirsche"]);
+        assert_eq!(
+            Stage::Sanitize.apply_to_word(Word("baдm".to_string())),
+            None
+        );
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_sharp_s_policy() {
+        let dir = temp_dir("sharp_s");
+        fs::write(dir.join("words.txt"), "fuß\nfüße\ngrüße\nmaße\n").unwrap();
+        let recipe = r#"
+            [[outputs]]
+            path = "out.txt"
+            sharp_s = "normalize_to_ss"
+            sources = [{ file = "words.txt", stages = [{ op = "length", length = 5 }] }]
+        "#;
+
+        let output = &Recipe::parse(recipe).unwrap().outputs[0];
+        output.write(&dir, &dir, &TestBuiltins).unwrap();
+        // Normalized before the length stage: "füße" is kept as "füsse"
+        assert_eq!(
+            fs::read_to_string(dir.join("out.txt")).unwrap(),
+            "füsse\nmasse\n"
+        );
+        let metadata =
+            ListMetadata::parse(&fs::read_to_string(dir.join("out.txt.meta.toml")).unwrap())
+                .unwrap();
+        assert_eq!(metadata.sharp_s, SharpSPolicy::NormalizeToSs);
+        assert_eq!(metadata.words, Some(2));
+        assert_eq!(metadata.recipe, output.metadata().recipe);
+        assert_eq!(metadata.built.unwrap().len(), "2026-10-17".len());
+
+        let trace: Vec<String> = output
+            .trace("masse", &dir, &TestBuiltins)
+            .unwrap()
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        assert_eq!(trace, vec!["words.txt:4 \"maße\": kept as \"masse\""]);
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_commented_file_source() {
+        let dir = temp_dir("comments");
+        fs::write(
+            dir.join("words.txt"),
+            "# Allowlist, curated by hand\nkiwi  # added for #12\n\nApple\n",
+        )
+        .unwrap();
+        let recipe = r#"
+            [[outputs]]
+            path = "out.txt"
+            sources = [{ file = "words.txt", comments = true }]
+        "#;
+
+        let output = &Recipe::parse(recipe).unwrap().outputs[0];
+        let words: Vec<String> = output
+            .build(&dir, &TestBuiltins)
+            .unwrap()
+            .map(|w| w.unwrap().0)
+            .collect();
+        assert_eq!(words, vec!["Apple", "kiwi"]);
+
+        let trace: Vec<String> = output
+            .trace("kiwi", &dir, &TestBuiltins)
+            .unwrap()
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        assert_eq!(trace, vec!["words.txt:2 \"kiwi\": kept as \"kiwi\""]);
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_compression() {
+        let dir = temp_dir("compression");
+        let recipe = r#"
+            [[outputs]]
+            path = "words.txt.gz"
+            sources = [{ builtin = "berries" }]
+
+            [[outputs]]
+            path = "words.bin"
+            compression = "zstd"
+            sources = [{ builtin = "fruits", stages = [{ op = "lowercase" }, { op = "dedup" }] }]
+
+            [[outputs]]
+            path = "merged.txt"
+            sources = [
+                { file = "words.txt.gz" },
+                { file = "words.bin", compression = "zstd", sorted = true, sort_policy = { sample = { chunk_size = 2 } } },
+            ]
+        "#;
+        let recipe = Recipe::parse(recipe).unwrap();
+        recipe.run(&dir, &dir, &TestBuiltins).unwrap();
+
+        let gz = fs::read(dir.join("words.txt.gz")).unwrap();
+        assert_eq!(&gz[..2], b"\x1f\x8b");
+        assert_eq!(
+            fs::read_to_string(dir.join("merged.txt")).unwrap(),
+            "apple\napple\nbanana\nberry\ncherry\nkiwi\n"
+        );
+
+        let unknown = r#"
+            [[outputs]]
+            path = "words.txt"
+            compression = "lzma"
+            sources = [{ builtin = "fruits" }]
+        "#;
+        let err = Recipe::parse(unknown).unwrap().outputs[0]
+            .to_bytes(&dir, &TestBuiltins)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_checksum_sidecar() {
+        let dir = temp_dir("checksum");
+        let recipe = RECIPE.replace("\"out/words.txt\"", "\"words.txt.zst\"\nchecksum = true");
+        let output = &Recipe::parse(&recipe).unwrap().outputs[0];
+        output.write(&dir, &dir, &TestBuiltins).unwrap();
+
+        let expected = Checksum::of(b"apple\nberry\ncherry\n");
+        assert_eq!(
+            fs::read_to_string(dir.join("words.txt.zst.sha256")).unwrap(),
+            format!("{expected}\n")
+        );
+        let (_, checksum) = output.to_bytes_checksummed(&dir, &TestBuiltins).unwrap();
+        assert_eq!(checksum, expected);
+        assert_eq!(checksum.words(), 3);
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_invalid_utf8_file_source() {
+        let dir = temp_dir("utf8");
+        fs::write(dir.join("words.txt"), b"apple\nb\xffrry\ncherry\n").unwrap();
+        let recipe = |utf8: &str| {
+            Recipe::parse(&format!(
+                r#"
+                [[outputs]]
+                path = "words.txt"
+                sources = [{{ file = "words.txt", utf8 = "{utf8}", stages = [{{ op = "alphabetic" }}] }}]
+                "#
+            ))
+            .unwrap()
+        };
+
+        let err = recipe("strict").outputs[0]
+            .to_bytes(&dir, &TestBuiltins)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "words.txt: invalid UTF-8 at byte 7");
+
+        let bytes = recipe("lossy").outputs[0]
+            .to_bytes(&dir, &TestBuiltins)
+            .unwrap();
+        assert_eq!(bytes, b"apple\ncherry\n");
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_output_without_sources() {
+        let output = Output {
+            path: PathBuf::from("words.txt"),
+            sources: Vec::new(),
+            stages: Vec::new(),
+            sharp_s: SharpSPolicy::default(),
+            compression: None,
+            checksum: false,
+        };
+        assert!(output.build(Path::new("."), &TestBuiltins).is_err());
+    }
+
+    #[test]
+    fn test_recipe_fingerprint() {
+        let output = &Recipe::parse(RECIPE).unwrap().outputs[0];
+        let recipe = output.metadata().recipe.unwrap();
+        assert_eq!(recipe.len(), 16);
+        assert_eq!(output.metadata().recipe.unwrap(), recipe);
+
+        let changed = RECIPE.replace("length = 5", "length = 6");
+        let changed = &Recipe::parse(&changed).unwrap().outputs[0];
+        assert_ne!(changed.metadata().recipe.unwrap(), recipe);
+
+        // Spelling out a default doesn't change the recipe
+        let same = RECIPE.replace(
+            "builtin = \"berries\"",
+            "builtin = \"berries\"\nsorted = false\nutf8 = \"strict\"",
+        );
+        let same = &Recipe::parse(&same).unwrap().outputs[0];
+        assert_eq!(same.metadata().recipe.unwrap(), recipe);
+    }
+}
@@ -0,0 +1,158 @@
+//! How German word lists spell words with 'ß'.
+//!
+//! Swiss German writes "ss" where German writes 'ß' ("gross" / "groß"), and
+//! word lists mix both. A [`SharpSPolicy`] picks one spelling for a list
+//! build, and is recorded in the list's [`ListMetadata`] so the game can
+//! normalize guesses the same way.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Word;
+use crate::stream::BoxedWordStream;
+
+/// How to treat 'ß' and "ss" in words
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SharpSPolicy {
+    /// Keep words as they are, "gross" and "groß" are different words
+    #[default]
+    PreserveBoth,
+    /// Spell 'ß' as "ss", so "groß" becomes "gross"
+    NormalizeToSs,
+    /// Spell "ss" as 'ß', so "gross" becomes "groß". This is lossy: words
+    /// that really are spelled with "ss" (e.g. "masse") merge with their
+    /// 'ß' counterparts ("maße").
+    NormalizeToSharpS,
+}
+
+impl SharpSPolicy {
+    /// Spells a word according to the policy. This can change the length of
+    /// the word, so it should happen before filtering by length.
+    pub fn apply(self, word: &str) -> String {
+        match self {
+            SharpSPolicy::PreserveBoth => word.to_string(),
+            SharpSPolicy::NormalizeToSs => word.replace('ß', "ss").replace('ẞ', "SS"),
+            SharpSPolicy::NormalizeToSharpS => word.replace("ss", "ß").replace("SS", "ẞ"),
+        }
+    }
+
+    /// Spells every word of a stream according to the policy. Since this can
+    /// change the sort order, the stream is read completely before yielding
+    /// anything (unless the policy keeps words as they are).
+    pub fn apply_to_stream(self, stream: BoxedWordStream) -> BoxedWordStream {
+        match self {
+            SharpSPolicy::PreserveBoth => stream,
+            _ => stream.remap(move |word| Some(Word(self.apply(&word.0)))),
+        }
+    }
+}
+
+/// Facts about a built word list that consumers need to read it correctly,
+/// written next to it as `<path>.meta.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListMetadata {
+    /// How the list spells words with 'ß'
+    #[serde(default)]
+    pub sharp_s: SharpSPolicy,
+    /// Fingerprint of the recipe output the list was built from, which
+    /// changes whenever its sources or stages do
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipe: Option<String>,
+    /// Number of words in the list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub words: Option<usize>,
+    /// Day the list was built, as `YYYY-MM-DD`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub built: Option<String>,
+}
+
+impl ListMetadata {
+    /// Parses metadata from TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if the metadata is malformed.
+    pub fn parse(toml: &str) -> io::Result<Self> {
+        toml::from_str(toml).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Serializes the metadata to TOML.
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("metadata should always serialize")
+    }
+
+    /// Where the metadata for the word list at `path` is stored
+    pub fn path_for(path: &Path) -> PathBuf {
+        let mut name = path.as_os_str().to_owned();
+        name.push(".meta.toml");
+        PathBuf::from(name)
+    }
+
+    /// Writes the metadata for the word list at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn write_for(&self, path: &Path) -> io::Result<()> {
+        fs::write(Self::path_for(path), self.to_toml())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(SharpSPolicy::PreserveBoth.apply("groß"), "groß");
+        assert_eq!(SharpSPolicy::PreserveBoth.apply("gross"), "gross");
+        assert_eq!(SharpSPolicy::NormalizeToSs.apply("groß"), "gross");
+        assert_eq!(SharpSPolicy::NormalizeToSs.apply("GROẞ"), "GROSS");
+        assert_eq!(SharpSPolicy::NormalizeToSharpS.apply("gross"), "groß");
+        assert_eq!(SharpSPolicy::NormalizeToSharpS.apply("groß"), "groß");
+    }
+
+    #[test]
+    fn test_apply_to_stream_resorts() {
+        let words: Vec<io::Result<Word>> = ["fuss", "fusel", "fuß"]
+            .iter()
+            .map(|w| Ok(Word(w.to_string())))
+            .collect();
+        let stream = SharpSPolicy::NormalizeToSharpS
+            .apply_to_stream(BoxedWordStream::new(words.into_iter()));
+        let words: Vec<String> = stream.map(|w| w.unwrap().0).collect();
+        assert_eq!(words, vec!["fusel", "fuß", "fuß"]);
+    }
+
+    #[test]
+    fn test_metadata_roundtrip() {
+        let metadata = ListMetadata {
+            sharp_s: SharpSPolicy::NormalizeToSs,
+            ..ListMetadata::default()
+        };
+        assert_eq!(metadata.to_toml(), "sharp_s = \"normalize_to_ss\"\n");
+        let built = ListMetadata {
+            recipe: Some("0123456789abcdef".to_string()),
+            words: Some(3),
+            built: Some("2026-10-17".to_string()),
+            ..metadata
+        };
+        assert_eq!(ListMetadata::parse(&built.to_toml()).unwrap(), built);
+        assert_eq!(ListMetadata::parse(&metadata.to_toml()).unwrap(), metadata);
+        assert_eq!(ListMetadata::parse("").unwrap(), ListMetadata::default());
+        assert!(ListMetadata::parse("sharp_s = \"maybe\"").is_err());
+    }
+
+    #[test]
+    fn test_metadata_path() {
+        assert_eq!(
+            ListMetadata::path_for(Path::new("out/de.txt.zst")),
+            Path::new("out/de.txt.zst.meta.toml")
+        );
+    }
+}
@@ -1,13 +1,16 @@
 //! Type-erased word stream for dynamic composition.
 
-use std::io;
+use std::io::{self, Write};
 use std::path::Path;
 
 use crate::Word;
 
+use super::checksum::Checksum;
+use super::codec::Codec;
 use super::sinks;
 use super::transforms::{
-    DedupStream, FilterStream, LowercaseStream, MergeStream, filter_non_alphabetic,
+    DedupStream, FilterStream, LowercaseStream, MergeStream, RemapStream, filter_non_alphabetic,
+    sanitize,
 };
 
 /// A type-erased word stream for dynamic composition.
@@ -19,7 +22,7 @@ use super::transforms::{
 /// # Example
 ///
 /// ```no_run
-/// use wordle::wordlist::stream::from_sorted_zst_file;
+/// use wordle_wordlists_processing::stream::from_sorted_zst_file;
 ///
 /// let inputs = ["a.zst", "b.zst", "c.zst"];
 /// let mut stream = from_sorted_zst_file(inputs[0])?.boxed();
@@ -83,6 +86,25 @@ impl BoxedWordStream {
         BoxedWordStream::new(filter_non_alphabetic(self.inner))
     }
 
+    /// Fixes or drops words with untypeable characters, warning on stderr.
+    pub fn sanitize(self) -> Self {
+        BoxedWordStream::new(sanitize(self.inner))
+    }
+
+    /// Maps every word, dropping those mapped to `None`. Since the mapping
+    /// can change the sort order, the whole stream is read and re-sorted.
+    pub fn remap<F>(self, map: F) -> Self
+    where
+        F: FnMut(Word) -> Option<Word> + 'static,
+    {
+        BoxedWordStream::new(RemapStream::new(self.inner, map))
+    }
+
+    /// Writes all items to a writer, one per line.
+    pub fn write_to_writer(self, writer: impl Write) -> io::Result<()> {
+        sinks::write_to_writer(self.inner, writer)
+    }
+
     /// Writes all items to a file, one per line.
     pub fn write_to_file(self, path: impl AsRef<Path>) -> io::Result<()> {
         sinks::write_to_file(self.inner, path)
@@ -92,6 +114,49 @@ impl BoxedWordStream {
     pub fn write_to_zst_file(self, path: impl AsRef<Path>) -> io::Result<()> {
         sinks::write_to_zst_file(self.inner, path)
     }
+
+    /// Writes all items zstd-compressed to a writer, one per line.
+    pub fn write_to_zst_writer(self, writer: impl Write) -> io::Result<()> {
+        sinks::write_to_zst_writer(self.inner, writer)
+    }
+
+    /// Writes all items to a file compressed with `codec`, one per line.
+    pub fn write_to_compressed_file(
+        self,
+        path: impl AsRef<Path>,
+        codec: &dyn Codec,
+    ) -> io::Result<()> {
+        sinks::write_to_compressed_file(self.inner, path, codec)
+    }
+
+    /// Writes all items compressed with `codec` to a writer, one per line.
+    pub fn write_to_compressed_writer(
+        self,
+        writer: impl Write,
+        codec: &dyn Codec,
+    ) -> io::Result<()> {
+        sinks::write_to_compressed_writer(self.inner, writer, codec)
+    }
+
+    /// Writes all items to a file compressed with `codec`, one per line,
+    /// and returns the checksum of the uncompressed output.
+    pub fn write_to_compressed_file_checksummed(
+        self,
+        path: impl AsRef<Path>,
+        codec: &dyn Codec,
+    ) -> io::Result<Checksum> {
+        sinks::write_to_compressed_file_checksummed(self.inner, path, codec)
+    }
+
+    /// Writes all items compressed with `codec` to a writer, one per line,
+    /// and returns the checksum of the uncompressed output.
+    pub fn write_to_compressed_writer_checksummed(
+        self,
+        writer: impl Write,
+        codec: &dyn Codec,
+    ) -> io::Result<Checksum> {
+        sinks::write_to_compressed_writer_checksummed(self.inner, writer, codec)
+    }
 }
 
 impl Iterator for BoxedWordStream {
@@ -205,7 +270,7 @@ mod tests {
     fn test_error_propagates() {
         let items: Vec<io::Result<Word>> = vec![
             Ok(Word("apple".to_string())),
-            Err(io::Error::new(io::ErrorKind::Other, "test error")),
+            Err(io::Error::other("test error")),
             Ok(Word("banana".to_string())),
         ];
         let stream = BoxedWordStream::new(items.into_iter());
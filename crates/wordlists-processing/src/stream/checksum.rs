@@ -0,0 +1,139 @@
+//! SHA-256 checksums of word lists, computed while writing them.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+/// SHA-256 of the uncompressed contents of a word list, one word per line.
+/// It doesn't depend on the codec, so the same words have the same checksum
+/// whether they are stored as `.txt`, `.txt.zst` or `.txt.gz`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Checksum {
+    digest: [u8; 32],
+    /// Lines of the contents, i.e. words
+    words: usize,
+}
+
+impl Checksum {
+    /// The checksum of `bytes`
+    pub fn of(bytes: &[u8]) -> Self {
+        Self {
+            digest: Sha256::digest(bytes).into(),
+            words: count_lines(bytes),
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Number of words in the list, counted along with the checksum
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    /// Where the checksum of the word list at `path` is stored
+    pub fn sidecar_path(path: &Path) -> PathBuf {
+        let mut name = path.as_os_str().to_owned();
+        name.push(".sha256");
+        PathBuf::from(name)
+    }
+
+    /// Writes the checksum in hex to the sidecar file of the word list at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn write_sidecar(&self, path: &Path) -> io::Result<()> {
+        fs::write(Self::sidecar_path(path), format!("{self}\n"))
+    }
+}
+
+impl fmt::Display for Checksum {
+    /// Lowercase hex, like `sha256sum`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.digest {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A writer that computes the checksum of everything written through it
+pub struct ChecksumWriter<W> {
+    inner: W,
+    hasher: Sha256,
+    words: usize,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+            words: 0,
+        }
+    }
+
+    /// Returns the wrapped writer and the checksum of what was written
+    pub fn finish(self) -> (W, Checksum) {
+        let checksum = Checksum {
+            digest: self.hasher.finalize().into(),
+            words: self.words,
+        };
+        (self.inner, checksum)
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        self.words += count_lines(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+fn count_lines(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&byte| byte == b'\n').count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        // Known SHA-256 of the empty input
+        assert_eq!(
+            Checksum::of(b"").to_string(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_writer_matches_checksum_of_bytes() {
+        let mut writer = ChecksumWriter::new(Vec::new());
+        writer.write_all(b"apple\n").unwrap();
+        writer.write_all(b"banana\n").unwrap();
+        let (bytes, checksum) = writer.finish();
+        assert_eq!(bytes, b"apple\nbanana\n");
+        assert_eq!(checksum, Checksum::of(b"apple\nbanana\n"));
+        assert_eq!(checksum.words(), 2);
+    }
+
+    #[test]
+    fn test_sidecar_path() {
+        assert_eq!(
+            Checksum::sidecar_path(Path::new("out/de.txt.zst")),
+            Path::new("out/de.txt.zst.sha256")
+        );
+    }
+}
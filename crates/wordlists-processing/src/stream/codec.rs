@@ -0,0 +1,229 @@
+//! Compression formats for sources and sinks.
+//!
+//! Every compressed source and sink goes through a [`Codec`], so supporting
+//! a new format is one implementation plus an entry in [`CODECS`]. Codecs
+//! are picked by file extension ([`codec_for_path`]) or by name
+//! ([`codec_by_name`]).
+
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+
+/// Compression level of zstd outputs. All encoder parameters are fixed, so
+/// the same words always compress to the same bytes.
+pub const ZSTD_LEVEL: i32 = 19;
+
+/// A compression format
+pub trait Codec: fmt::Debug + Send + Sync {
+    /// Name of the format, as used in recipes
+    fn name(&self) -> &'static str;
+
+    /// File extension (without the dot) of files in this format, None if
+    /// files aren't recognized by extension
+    fn extension(&self) -> Option<&'static str>;
+
+    /// Wraps a reader to decompress what it reads
+    fn decoder<'a>(&self, reader: Box<dyn Read + 'a>) -> io::Result<Box<dyn Read + 'a>>;
+
+    /// Wraps a writer to compress what is written. The result must be
+    /// finished with [`Encoder::finish`] to write the end of the stream.
+    fn encoder<'a>(&self, writer: Box<dyn Write + 'a>) -> io::Result<Box<dyn Encoder + 'a>>;
+}
+
+/// A compressing writer created by [`Codec::encoder`]
+pub trait Encoder: Write {
+    /// Writes the end of the compressed stream and flushes it
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+/// zstd, with fixed encoder parameters for reproducible outputs
+#[derive(Debug, Clone, Copy)]
+pub struct Zstd;
+
+/// gzip, also reading files of several concatenated gzip members
+#[derive(Debug, Clone, Copy)]
+pub struct Gzip;
+
+/// No compression
+#[derive(Debug, Clone, Copy)]
+pub struct Uncompressed;
+
+/// The codecs recognized by name and file extension
+pub const CODECS: &[&dyn Codec] = &[&Zstd, &Gzip, &Uncompressed];
+
+/// The codec for a file, by its extension. Files with unknown extensions
+/// are not compressed.
+pub fn codec_for_path(path: &Path) -> &'static dyn Codec {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    CODECS
+        .iter()
+        .copied()
+        .find(|codec| extension.is_some() && codec.extension() == extension)
+        .unwrap_or(&Uncompressed)
+}
+
+/// The codec with the given name.
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error naming the known codecs if there is none
+/// with this name.
+pub fn codec_by_name(name: &str) -> io::Result<&'static dyn Codec> {
+    CODECS
+        .iter()
+        .copied()
+        .find(|codec| codec.name() == name)
+        .ok_or_else(|| {
+            let names: Vec<&str> = CODECS.iter().map(|codec| codec.name()).collect();
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Unknown compression '{name}', expected one of: {}",
+                    names.join(", ")
+                ),
+            )
+        })
+}
+
+impl Codec for Zstd {
+    fn name(&self) -> &'static str {
+        "zstd"
+    }
+
+    fn extension(&self) -> Option<&'static str> {
+        Some("zst")
+    }
+
+    fn decoder<'a>(&self, reader: Box<dyn Read + 'a>) -> io::Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(zstd::Decoder::new(reader)?))
+    }
+
+    fn encoder<'a>(&self, writer: Box<dyn Write + 'a>) -> io::Result<Box<dyn Encoder + 'a>> {
+        let mut encoder = zstd::Encoder::new(writer, ZSTD_LEVEL)?;
+        encoder.include_checksum(false)?;
+        encoder.include_contentsize(false)?;
+        encoder.include_dictid(false)?;
+        encoder.long_distance_matching(false)?;
+        Ok(Box::new(encoder))
+    }
+}
+
+impl<W: Write> Encoder for zstd::Encoder<'_, W> {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        (*self).finish()?.flush()
+    }
+}
+
+impl Codec for Gzip {
+    fn name(&self) -> &'static str {
+        "gzip"
+    }
+
+    fn extension(&self) -> Option<&'static str> {
+        Some("gz")
+    }
+
+    fn decoder<'a>(&self, reader: Box<dyn Read + 'a>) -> io::Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(MultiGzDecoder::new(reader)))
+    }
+
+    fn encoder<'a>(&self, writer: Box<dyn Write + 'a>) -> io::Result<Box<dyn Encoder + 'a>> {
+        // The header has no timestamp, so outputs are reproducible
+        Ok(Box::new(GzEncoder::new(
+            writer,
+            flate2::Compression::best(),
+        )))
+    }
+}
+
+impl<W: Write> Encoder for GzEncoder<W> {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        (*self).finish()?.flush()
+    }
+}
+
+impl Codec for Uncompressed {
+    fn name(&self) -> &'static str {
+        "none"
+    }
+
+    fn extension(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn decoder<'a>(&self, reader: Box<dyn Read + 'a>) -> io::Result<Box<dyn Read + 'a>> {
+        Ok(reader)
+    }
+
+    fn encoder<'a>(&self, writer: Box<dyn Write + 'a>) -> io::Result<Box<dyn Encoder + 'a>> {
+        Ok(Box::new(writer))
+    }
+}
+
+impl Encoder for Box<dyn Write + '_> {
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        self.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(codec: &dyn Codec, data: &[u8]) -> Vec<u8> {
+        let mut compressed = Vec::new();
+        let mut encoder = codec.encoder(Box::new(&mut compressed)).unwrap();
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap();
+
+        let mut decompressed = Vec::new();
+        codec
+            .decoder(Box::new(compressed.as_slice()))
+            .unwrap()
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        decompressed
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for codec in CODECS {
+            assert_eq!(roundtrip(*codec, b"apple\nbanana\n"), b"apple\nbanana\n");
+        }
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let compress = |codec: &dyn Codec| {
+            let mut compressed = Vec::new();
+            let mut encoder = codec.encoder(Box::new(&mut compressed)).unwrap();
+            encoder.write_all(b"apple\n").unwrap();
+            encoder.finish().unwrap();
+            compressed
+        };
+        for codec in CODECS {
+            assert_eq!(compress(*codec), compress(*codec));
+        }
+    }
+
+    #[test]
+    fn test_codec_for_path() {
+        assert_eq!(codec_for_path(Path::new("de.txt.zst")).name(), "zstd");
+        assert_eq!(codec_for_path(Path::new("de.txt.gz")).name(), "gzip");
+        assert_eq!(codec_for_path(Path::new("de.txt")).name(), "none");
+        assert_eq!(codec_for_path(Path::new("words")).name(), "none");
+    }
+
+    #[test]
+    fn test_codec_by_name() {
+        assert_eq!(codec_by_name("gzip").unwrap().name(), "gzip");
+        let err = codec_by_name("lzma").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown compression 'lzma', expected one of: zstd, gzip, none"
+        );
+    }
+}
@@ -7,7 +7,7 @@
 //! # Example
 //!
 //! ```no_run
-//! use wordle::wordlist::stream::from_sorted_file;
+//! use wordle_wordlists_processing::stream::from_sorted_file;
 //!
 //! // Load a sorted file, filter to 5-letter words, collect
 //! let words = from_sorted_file("words.txt")?
@@ -15,7 +15,7 @@
 //!     .collect_to_set()?;
 //!
 //! // Load from zstd-compressed sorted file, process, write to compressed file
-//! use wordle::wordlist::stream::from_sorted_zst_file;
+//! use wordle_wordlists_processing::stream::from_sorted_zst_file;
 //!
 //! from_sorted_zst_file("words.zst")?
 //!     .filter(|w| w.len() == 5)
@@ -26,6 +26,10 @@
 //! For loading from streams (e.g., embedded data), use `from_csv`, `from_csv_zstd`,
 //! `from_txt`, or `from_txt_zstd` with a reader like `std::io::Cursor`.
 //!
+//! Compression goes through a [`Codec`]. Besides the zstd shorthands, every
+//! compressed source and sink has a `_compressed` variant taking a codec,
+//! e.g. the one [`codec_for_path`] picks by file extension.
+//!
 //! # Case-Fold Ordering
 //!
 //! Strings are ordered by:
@@ -35,6 +39,8 @@
 //! This means `"apple" < "Apple" < "APPLE" < "banana"`.
 
 mod boxed;
+mod checksum;
+mod codec;
 mod sinks;
 mod sources;
 pub(crate) mod transforms;
@@ -42,21 +48,29 @@ mod word_stream;
 
 pub use super::ordering::case_fold_cmp;
 pub use boxed::BoxedWordStream;
+pub use checksum::{Checksum, ChecksumWriter};
+pub use codec::{
+    CODECS, Codec, Encoder, Gzip, Uncompressed, ZSTD_LEVEL, Zstd, codec_by_name, codec_for_path,
+};
 pub use sources::{
-    SortedLines, UnsortedWords, from_csv, from_csv_zstd, from_sorted_file, from_sorted_reader,
-    from_sorted_zst_file, from_txt, from_txt_zstd,
+    CompressedFileLines, SortedLines, TextLines, Trim, TxtOptions, UnsortedWords, Utf8Mode,
+    from_csv, from_csv_compressed, from_csv_zstd, from_sorted_compressed_file, from_sorted_file,
+    from_sorted_reader, from_sorted_reader_with_options, from_sorted_zst_file, from_txt,
+    from_txt_compressed, from_txt_with_options, from_txt_zstd,
 };
-pub use word_stream::WordStream;
+pub use transforms::{
+    DedupStream, DisplayFormStream, FilterStream, LowercaseStream, MergeStream, RemapStream,
+    Sanitized, play_form, sanitize_word,
+};
+pub use word_stream::{Duplicates, SortPolicy, WordStream};
 
 use std::fs::File;
-use std::io::{self, BufReader};
+use std::io::{self, BufReader, Write};
 use std::iter::Peekable;
 use std::path::Path;
 
-use zstd::Decoder;
-
 use crate::{Word, WordSet};
-use transforms::{DedupStream, FilterStream, LowercaseStream, MergeStream, filter_non_alphabetic};
+use transforms::{filter_non_alphabetic, sanitize, to_play_forms};
 
 /// Type alias for the iterator produced by `WordStream::from_word_set`.
 type WordSetIter =
@@ -79,7 +93,7 @@ impl WordStream<SortedLines<BufReader<File>>> {
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::WordStream;
+    /// use wordle_wordlists_processing::stream::WordStream;
     ///
     /// let stream = WordStream::from_sorted_file("words.txt")?;
     /// for word in stream {
@@ -92,7 +106,7 @@ impl WordStream<SortedLines<BufReader<File>>> {
     }
 }
 
-impl WordStream<SortedLines<BufReader<Decoder<'static, BufReader<File>>>>> {
+impl WordStream<CompressedFileLines> {
     /// Creates a WordStream from a pre-sorted zstd-compressed file.
     ///
     /// Reads lines lazily, decompressing on the fly.
@@ -109,7 +123,7 @@ impl WordStream<SortedLines<BufReader<Decoder<'static, BufReader<File>>>>> {
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::WordStream;
+    /// use wordle_wordlists_processing::stream::WordStream;
     ///
     /// let stream = WordStream::from_sorted_zst_file("words.zst")?;
     /// for word in stream {
@@ -131,7 +145,7 @@ impl WordStream<WordSetIter> {
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::{from_sorted_file, WordStream};
+    /// use wordle_wordlists_processing::stream::{from_sorted_file, WordStream};
     ///
     /// // Load, filter, collect to set, then convert back to stream
     /// let set = from_sorted_file("words.txt")?
@@ -159,7 +173,7 @@ where
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::from_sorted_file;
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
     ///
     /// let five_letter_words = from_sorted_file("words.txt")?
     ///     .filter(|w| w.len() == 5)
@@ -181,7 +195,7 @@ where
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::from_sorted_file;
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
     ///
     /// from_sorted_file("words.txt")?
     ///     .to_lowercase()
@@ -201,7 +215,7 @@ where
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::from_sorted_file;
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
     ///
     /// from_sorted_file("words.txt")?
     ///     .to_lowercase()
@@ -221,7 +235,7 @@ where
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::from_sorted_file;
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
     ///
     /// from_sorted_file("words.txt")?
     ///     .filter_non_alphabetic()
@@ -234,6 +248,76 @@ where
         WordStream::new(filter_non_alphabetic(self.into_inner()))
     }
 
+    /// Fixes or drops words with characters that can't be typed: removes
+    /// zero-width characters, replaces non-breaking spaces, and replaces
+    /// Cyrillic lookalikes in Latin words. Words that can't be fixed are
+    /// dropped. Warns on stderr for each word either way.
+    ///
+    /// The stream stays sorted: fixed words can sort anywhere, so this
+    /// reads the whole input and sorts it before yielding anything.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
+    ///
+    /// from_sorted_file("words.txt")?
+    ///     .sanitize()
+    ///     .write_to_file("typeable_words.txt")?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn sanitize(
+        self,
+    ) -> WordStream<RemapStream<Peekable<I>, impl FnMut(Word) -> Option<Word>>> {
+        WordStream::new(sanitize(self.into_inner()))
+    }
+
+    /// Replaces every word by its play form: the word without hyphens and
+    /// apostrophes, as it is typed in the game (see [`play_form`]). Words
+    /// with other characters that aren't letters are dropped.
+    ///
+    /// The stream stays sorted: play forms can sort elsewhere than the
+    /// words they come from, so this reads the whole input and sorts it
+    /// before yielding anything.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
+    ///
+    /// from_sorted_file("curated.txt")?
+    ///     .to_play_forms()
+    ///     .write_to_file("playable.txt")?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn to_play_forms(
+        self,
+    ) -> WordStream<RemapStream<Peekable<I>, impl FnMut(Word) -> Option<Word>>> {
+        WordStream::new(to_play_forms(self.into_inner()))
+    }
+
+    /// Pairs every word with its play form, as `(play form, display form)`,
+    /// so the game can show the word the way it is written when it is
+    /// revealed. Words without a play form are dropped.
+    ///
+    /// The pairs are in the order of the display forms, so this ends the
+    /// stream.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
+    ///
+    /// for pair in from_sorted_file("curated.txt")?.display_forms() {
+    ///     let (play, display) = pair?;
+    ///     println!("{play} {display}");
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn display_forms(self) -> DisplayFormStream<Peekable<I>> {
+        DisplayFormStream::new(self.into_inner())
+    }
+
     /// Merges this stream with another sorted stream.
     ///
     /// Both streams must be sorted in case-fold order. The resulting stream
@@ -245,7 +329,7 @@ where
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::from_sorted_file;
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
     ///
     /// let merged = from_sorted_file("words1.txt")?
     ///     .merge(from_sorted_file("words2.txt")?)
@@ -268,7 +352,7 @@ where
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::from_sorted_file;
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
     ///
     /// let words = from_sorted_file("words.txt")?
     ///     .filter(|w| w.len() == 5)
@@ -279,6 +363,29 @@ where
         sinks::collect_to_set(self.into_inner())
     }
 
+    /// Writes all items to a writer, one per line.
+    ///
+    /// This is a streaming operation: each item is written as soon as it is
+    /// read, so it can be used in pipelines (e.g. writing to stdout).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails or if any item in the stream is an I/O error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
+    ///
+    /// from_sorted_file("words.txt")?
+    ///     .dedup()
+    ///     .write_to_writer(std::io::stdout().lock())?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write_to_writer(self, writer: impl Write) -> io::Result<()> {
+        sinks::write_to_writer(self.into_inner(), writer)
+    }
+
     /// Writes all items to a file, one per line.
     ///
     /// Uses buffered writing for efficiency. This is a streaming operation
@@ -292,7 +399,7 @@ where
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::from_sorted_file;
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
     ///
     /// from_sorted_file("words.txt")?
     ///     .filter(|w| w.chars().all(|c| c.is_alphabetic()))
@@ -316,7 +423,7 @@ where
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::from_sorted_file;
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
     ///
     /// from_sorted_file("words.txt")?
     ///     .filter(|w| w.chars().all(|c| c.is_alphabetic()))
@@ -326,13 +433,115 @@ where
     pub fn write_to_zst_file(self, path: impl AsRef<Path>) -> io::Result<()> {
         sinks::write_to_zst_file(self.into_inner(), path)
     }
+
+    /// Writes all items zstd-compressed to a writer, one per line.
+    ///
+    /// Uses the same fixed encoder parameters as `write_to_zst_file`, so
+    /// the output is byte-identical for the same words.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails or if any item in the stream is an I/O error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use wordle_wordlists_processing::stream::from_sorted_file;
+    ///
+    /// let mut compressed = Vec::new();
+    /// from_sorted_file("words.txt")?.write_to_zst_writer(&mut compressed)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write_to_zst_writer(self, writer: impl Write) -> io::Result<()> {
+        sinks::write_to_zst_writer(self.into_inner(), writer)
+    }
+
+    /// Writes all items to a file compressed with `codec`, one per line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created, written to,
+    /// or if any item in the stream is an I/O error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use wordle_wordlists_processing::stream::{codec_for_path, from_sorted_file};
+    ///
+    /// let path = Path::new("words.txt.gz");
+    /// from_sorted_file("words.txt")?.write_to_compressed_file(path, codec_for_path(path))?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write_to_compressed_file(
+        self,
+        path: impl AsRef<Path>,
+        codec: &dyn Codec,
+    ) -> io::Result<()> {
+        sinks::write_to_compressed_file(self.into_inner(), path, codec)
+    }
+
+    /// Writes all items compressed with `codec` to a writer, one per line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails or if any item in the stream is an I/O error.
+    pub fn write_to_compressed_writer(
+        self,
+        writer: impl Write,
+        codec: &dyn Codec,
+    ) -> io::Result<()> {
+        sinks::write_to_compressed_writer(self.into_inner(), writer, codec)
+    }
+
+    /// Like [`write_to_compressed_file`](Self::write_to_compressed_file),
+    /// and returns the checksum of the uncompressed output, computed while
+    /// writing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created, written to,
+    /// or if any item in the stream is an I/O error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use wordle_wordlists_processing::stream::{Zstd, from_sorted_file};
+    ///
+    /// let path = Path::new("words.txt.zst");
+    /// let checksum = from_sorted_file("words.txt")?.write_to_compressed_file_checksummed(path, &Zstd)?;
+    /// checksum.write_sidecar(path)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write_to_compressed_file_checksummed(
+        self,
+        path: impl AsRef<Path>,
+        codec: &dyn Codec,
+    ) -> io::Result<Checksum> {
+        sinks::write_to_compressed_file_checksummed(self.into_inner(), path, codec)
+    }
+
+    /// Like [`write_to_compressed_writer`](Self::write_to_compressed_writer),
+    /// and returns the checksum of the uncompressed output.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails or if any item in the stream is an I/O error.
+    pub fn write_to_compressed_writer_checksummed(
+        self,
+        writer: impl Write,
+        codec: &dyn Codec,
+    ) -> io::Result<Checksum> {
+        sinks::write_to_compressed_writer_checksummed(self.into_inner(), writer, codec)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::io::{Read as _, Write};
-    use zstd::Encoder;
+    use zstd::{Decoder, Encoder};
 
     fn create_temp_file(content: &str) -> std::path::PathBuf {
         let path = std::env::temp_dir().join(format!(
@@ -4,8 +4,8 @@ use std::fs::File;
 use std::io::{self, BufWriter, Write};
 use std::path::Path;
 
-use zstd::Encoder;
-
+use super::checksum::{Checksum, ChecksumWriter};
+use super::codec::{Codec, Zstd};
 use crate::{Word, WordSet};
 
 /// Collects an iterator of `io::Result<Word>` into a `WordSet`.
@@ -55,22 +55,101 @@ where
     write_to_writer(iter, BufWriter::new(file))
 }
 
-/// Writes items from an iterator to a zstd-compressed file, one per line.
+/// Writes items from an iterator to a file compressed with `codec`, one per line.
 ///
-/// Uses buffered writing and default compression level for efficiency.
+/// # Errors
+///
+/// Returns an error if the file cannot be created or written to,
+/// or if any item in the iterator is an error.
+pub fn write_to_compressed_file<I>(
+    iter: I,
+    path: impl AsRef<Path>,
+    codec: &dyn Codec,
+) -> io::Result<()>
+where
+    I: Iterator<Item = io::Result<Word>>,
+{
+    write_to_compressed_file_checksummed(iter, path, codec).map(|_| ())
+}
+
+/// Like [`write_to_compressed_file`], and returns the checksum of the
+/// uncompressed output, computed while writing.
 ///
 /// # Errors
 ///
 /// Returns an error if the file cannot be created or written to,
 /// or if any item in the iterator is an error.
-pub fn write_to_zst_file<I>(iter: I, path: impl AsRef<Path>) -> io::Result<()>
+pub fn write_to_compressed_file_checksummed<I>(
+    iter: I,
+    path: impl AsRef<Path>,
+    codec: &dyn Codec,
+) -> io::Result<Checksum>
 where
     I: Iterator<Item = io::Result<Word>>,
 {
     let file = File::create(path)?;
-    let writer = BufWriter::new(file);
-    let encoder = Encoder::new(writer, 19)?.auto_finish();
-    write_to_writer(iter, encoder)
+    write_to_compressed_writer_checksummed(iter, BufWriter::new(file), codec)
+}
+
+/// Writes items from an iterator compressed with `codec` to any writer, one per line.
+///
+/// # Errors
+///
+/// Returns an error if writing fails or if any item in the iterator is an error.
+pub fn write_to_compressed_writer<I, W>(iter: I, writer: W, codec: &dyn Codec) -> io::Result<()>
+where
+    I: Iterator<Item = io::Result<Word>>,
+    W: Write,
+{
+    write_to_compressed_writer_checksummed(iter, writer, codec).map(|_| ())
+}
+
+/// Like [`write_to_compressed_writer`], and returns the checksum of the
+/// uncompressed output, computed while writing.
+///
+/// # Errors
+///
+/// Returns an error if writing fails or if any item in the iterator is an error.
+pub fn write_to_compressed_writer_checksummed<I, W>(
+    iter: I,
+    writer: W,
+    codec: &dyn Codec,
+) -> io::Result<Checksum>
+where
+    I: Iterator<Item = io::Result<Word>>,
+    W: Write,
+{
+    let mut writer = ChecksumWriter::new(codec.encoder(Box::new(writer))?);
+    write_to_writer(iter, &mut writer)?;
+    let (encoder, checksum) = writer.finish();
+    encoder.finish()?;
+    Ok(checksum)
+}
+
+/// Writes items from an iterator to a zstd-compressed file, one per line.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be created or written to,
+/// or if any item in the iterator is an error.
+pub fn write_to_zst_file<I>(iter: I, path: impl AsRef<Path>) -> io::Result<()>
+where
+    I: Iterator<Item = io::Result<Word>>,
+{
+    write_to_compressed_file(iter, path, &Zstd)
+}
+
+/// Writes items from an iterator zstd-compressed to any writer, one per line.
+///
+/// # Errors
+///
+/// Returns an error if writing fails or if any item in the iterator is an error.
+pub fn write_to_zst_writer<I, W>(iter: I, writer: W) -> io::Result<()>
+where
+    I: Iterator<Item = io::Result<Word>>,
+    W: Write,
+{
+    write_to_compressed_writer(iter, writer, &Zstd)
 }
 
 #[cfg(test)]
@@ -109,7 +188,7 @@ mod tests {
     fn test_collect_to_set_error() {
         let items: Vec<io::Result<Word>> = vec![
             Ok(Word("apple".to_string())),
-            Err(io::Error::new(io::ErrorKind::Other, "test error")),
+            Err(io::Error::other("test error")),
         ];
         let result = collect_to_set(items.into_iter());
         assert!(result.is_err());
@@ -163,7 +242,7 @@ mod tests {
 
         let items: Vec<io::Result<Word>> = vec![
             Ok(Word("apple".to_string())),
-            Err(io::Error::new(io::ErrorKind::Other, "test error")),
+            Err(io::Error::other("test error")),
         ];
 
         let result = write_to_file(items.into_iter(), &path);
@@ -194,6 +273,19 @@ mod tests {
         std::fs::remove_file(path).ok();
     }
 
+    #[test]
+    fn test_write_to_zst_writer_is_deterministic() {
+        let compress = || {
+            let mut output = Vec::new();
+            write_to_zst_writer(ok_iter(["apple", "banana", "cherry"]), &mut output).unwrap();
+            output
+        };
+        let output = compress();
+        assert_eq!(output, compress());
+        let content = zstd::decode_all(output.as_slice()).unwrap();
+        assert_eq!(content, b"apple\nbanana\ncherry\n");
+    }
+
     #[test]
     fn test_write_to_zst_file_empty() {
         let path = std::env::temp_dir().join(format!(
@@ -215,4 +307,21 @@ mod tests {
 
         std::fs::remove_file(path).ok();
     }
+
+    #[test]
+    fn test_checksum_of_uncompressed_output() {
+        use crate::stream::{Gzip, Uncompressed};
+
+        let expected = Checksum::of(b"apple\nbanana\n");
+        for codec in [&Zstd as &dyn Codec, &Gzip, &Uncompressed] {
+            let mut output = Vec::new();
+            let checksum = write_to_compressed_writer_checksummed(
+                ok_iter(["apple", "banana"]),
+                &mut output,
+                codec,
+            )
+            .unwrap();
+            assert_eq!(checksum, expected, "{codec:?}");
+        }
+    }
 }
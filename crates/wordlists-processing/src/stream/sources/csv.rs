@@ -2,10 +2,9 @@
 
 use std::io::{self, BufReader, Read};
 
-use zstd::Decoder;
-
 use super::txt::UnsortedWords;
 use crate::Word;
+use crate::stream::codec::{Codec, Zstd};
 use crate::stream::word_stream::WordStream;
 
 /// Creates a WordStream from a CSV reader, using the first column as words.
@@ -21,7 +20,7 @@ use crate::stream::word_stream::WordStream;
 ///
 /// ```no_run
 /// use std::io::Cursor;
-/// use wordle::wordlist::stream::from_csv;
+/// use wordle_wordlists_processing::stream::from_csv;
 ///
 /// let data = b"apple,1\nbanana,2\ncherry,3\n";
 /// let stream = from_csv(Cursor::new(data))?;
@@ -66,9 +65,9 @@ pub fn from_csv<R: Read>(reader: R) -> io::Result<WordStream<UnsortedWords>> {
 ///
 /// ```no_run
 /// use std::io::Cursor;
-/// use wordle::wordlist::stream::from_csv_zstd;
+/// use wordle_wordlists_processing::stream::from_csv_zstd;
 ///
-/// let compressed_data: &[u8] = include_bytes!("some_file.csv.zst");
+/// let compressed_data: &[u8] = &std::fs::read("some_file.csv.zst")?;
 /// let stream = from_csv_zstd(Cursor::new(compressed_data))?;
 /// for word in stream {
 ///     println!("{}", word?);
@@ -76,8 +75,20 @@ pub fn from_csv<R: Read>(reader: R) -> io::Result<WordStream<UnsortedWords>> {
 /// # Ok::<(), std::io::Error>(())
 /// ```
 pub fn from_csv_zstd<R: Read>(reader: R) -> io::Result<WordStream<UnsortedWords>> {
-    let decoder = Decoder::new(reader)?;
-    from_csv(BufReader::new(decoder))
+    from_csv_compressed(reader, &Zstd)
+}
+
+/// Creates a WordStream from a CSV stream compressed with `codec`.
+///
+/// # Errors
+///
+/// Returns an error if reading fails, the stream is not valid for the codec,
+/// or CSV parsing encounters invalid data.
+pub fn from_csv_compressed<R: Read>(
+    reader: R,
+    codec: &dyn Codec,
+) -> io::Result<WordStream<UnsortedWords>> {
+    from_csv(BufReader::new(codec.decoder(Box::new(reader))?))
 }
 
 #[cfg(test)]
@@ -89,6 +100,14 @@ mod tests {
         zstd::encode_all(Cursor::new(data), 0).unwrap()
     }
 
+    #[test]
+    fn test_bom_and_crlf() {
+        let data = b"\xEF\xBB\xBFcherry,1\r\napple,2\r\n";
+        let stream = from_csv(Cursor::new(data)).unwrap();
+        let words: Vec<String> = stream.map(|r| r.unwrap().0).collect();
+        assert_eq!(words, vec!["apple", "cherry"]);
+    }
+
     #[test]
     fn test_basic_csv() {
         let data = b"apple,1,ignored\nbanana,2,data\ncherry,3,here\n";
@@ -0,0 +1,263 @@
+//! Line reading shared by the text sources.
+
+use std::io::{self, BufRead};
+
+use serde::{Deserialize, Serialize};
+
+use crate::stream::{Duplicates, SortPolicy};
+
+/// How text sources treat bytes that are not valid UTF-8
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Utf8Mode {
+    /// Fail with an error naming the source and the byte offset
+    #[default]
+    Strict,
+    /// Replace invalid bytes with U+FFFD. The replacement character is not
+    /// alphabetic, so such words are dropped by `filter_non_alphabetic`.
+    Lossy,
+}
+
+/// What text sources trim from both ends of each line
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trim {
+    /// Trim all Unicode whitespace
+    #[default]
+    Whitespace,
+    /// Trim only ASCII whitespace, keeping e.g. non-breaking spaces for
+    /// the `sanitize` stage to report
+    Ascii,
+    /// Keep lines as they are
+    None,
+}
+
+/// UTF-8 encoding of U+FEFF, which some editors put at the start of files
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Options for reading plain text sources
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TxtOptions {
+    /// How to treat bytes that are not valid UTF-8
+    pub utf8: Utf8Mode,
+    /// Whether a sorted source may repeat a word. Unsorted sources are
+    /// sorted after reading and ignore this.
+    pub duplicates: Duplicates,
+    /// How thoroughly a sorted source's order is checked. Unsorted sources
+    /// ignore this.
+    pub sort_policy: SortPolicy,
+    /// What to trim from each line
+    pub trim: Trim,
+    /// Skip comment lines (`# ...`) and strip annotations after a word
+    /// (`wort  # note`), so curated files can carry notes. An annotation's
+    /// `#` must follow whitespace, so it can't be part of the word.
+    pub comments: bool,
+}
+
+impl TxtOptions {
+    /// The word on a line, without comments and trimmed. None if the line
+    /// has no word.
+    pub fn word<'a>(&self, line: &'a str) -> Option<&'a str> {
+        let line = if self.comments {
+            strip_comment(line)
+        } else {
+            line
+        };
+        let word = match self.trim {
+            Trim::Whitespace => line.trim(),
+            Trim::Ascii => line.trim_ascii(),
+            Trim::None => line,
+        };
+        (!word.is_empty()).then_some(word)
+    }
+}
+
+/// Cuts a line at a `#` that starts the line or follows whitespace
+fn strip_comment(line: &str) -> &str {
+    let mut previous = None;
+    for (index, c) in line.char_indices() {
+        if c == '#' && previous.is_none_or(char::is_whitespace) {
+            return &line[..index];
+        }
+        previous = Some(c);
+    }
+    line
+}
+
+/// Iterator over the lines of a text source, without line endings, decoded
+/// according to [`TxtOptions`].
+///
+/// Unlike `BufRead::lines`, invalid UTF-8 can be decoded lossily, and
+/// errors say where the invalid bytes are. Files saved by Windows editors
+/// are read like any other: both `\n` and `\r\n` end a line, and a UTF-8
+/// byte order mark at the start of the source is skipped.
+pub struct TextLines<R: BufRead> {
+    reader: R,
+    source: String,
+    options: TxtOptions,
+    /// Byte offset of the next line in the source
+    offset: u64,
+    buf: Vec<u8>,
+}
+
+impl<R: BufRead> TextLines<R> {
+    /// Creates a line iterator. `source` names the source in error messages.
+    pub fn new(reader: R, source: impl Into<String>, options: TxtOptions) -> Self {
+        Self {
+            reader,
+            source: source.into(),
+            options,
+            offset: 0,
+            buf: Vec::new(),
+        }
+    }
+
+    fn decode(&self, mut line_offset: u64) -> io::Result<String> {
+        let mut bytes = self.buf.as_slice();
+        if line_offset == 0
+            && let Some(stripped) = bytes.strip_prefix(BOM)
+        {
+            bytes = stripped;
+            line_offset += BOM.len() as u64;
+        }
+        if let Some(stripped) = bytes.strip_suffix(b"\n") {
+            bytes = stripped;
+            if let Some(stripped) = bytes.strip_suffix(b"\r") {
+                bytes = stripped;
+            }
+        }
+        match self.options.utf8 {
+            Utf8Mode::Lossy => Ok(String::from_utf8_lossy(bytes).into_owned()),
+            Utf8Mode::Strict => match std::str::from_utf8(bytes) {
+                Ok(line) => Ok(line.to_string()),
+                Err(err) => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{}: invalid UTF-8 at byte {}",
+                        self.source,
+                        line_offset + err.valid_up_to() as u64
+                    ),
+                )),
+            },
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for TextLines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buf.clear();
+        match self.reader.read_until(b'\n', &mut self.buf) {
+            Ok(0) => None,
+            Ok(read) => {
+                let line_offset = self.offset;
+                self.offset += read as u64;
+                Some(self.decode(line_offset))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn lines(data: &[u8], utf8: Utf8Mode) -> Vec<io::Result<String>> {
+        let options = TxtOptions {
+            utf8,
+            ..TxtOptions::default()
+        };
+        TextLines::new(Cursor::new(data.to_vec()), "words.txt", options).collect()
+    }
+
+    #[test]
+    fn test_lines() {
+        let lines: Vec<String> = lines(b"apple\n\nbanana", Utf8Mode::Strict)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines, vec!["apple", "", "banana"]);
+    }
+
+    #[test]
+    fn test_strict_reports_offset() {
+        let lines = lines(b"apple\nba\xffnana\ncherry\n", Utf8Mode::Strict);
+        assert_eq!(lines[0].as_ref().unwrap(), "apple");
+        let err = lines[1].as_ref().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "words.txt: invalid UTF-8 at byte 8");
+        // Reading continues after the invalid line
+        assert_eq!(lines[2].as_ref().unwrap(), "cherry");
+    }
+
+    #[test]
+    fn test_crlf_and_bom() {
+        let lines: Vec<String> = lines(b"\xEF\xBB\xBFapple\r\nbanana\r\n", Utf8Mode::Strict)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines, vec!["apple", "banana"]);
+    }
+
+    #[test]
+    fn test_bom_only_at_start() {
+        let lines: Vec<String> = lines(b"apple\n\xEF\xBB\xBFbanana\n", Utf8Mode::Strict)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines, vec!["apple", "\u{FEFF}banana"]);
+    }
+
+    #[test]
+    fn test_offset_counts_bom() {
+        let lines = lines(b"\xEF\xBB\xBFba\xffnana\n", Utf8Mode::Strict);
+        let err = lines[0].as_ref().unwrap_err();
+        assert_eq!(err.to_string(), "words.txt: invalid UTF-8 at byte 5");
+    }
+
+    #[test]
+    fn test_word_strips_comments() {
+        let options = TxtOptions {
+            comments: true,
+            ..TxtOptions::default()
+        };
+        assert_eq!(options.word("# curated by hand"), None);
+        assert_eq!(options.word("   # indented comment"), None);
+        assert_eq!(options.word("wort  # note"), Some("wort"));
+        assert_eq!(options.word("wort\t#note"), Some("wort"));
+        assert_eq!(options.word("wo#rt"), Some("wo#rt"));
+        assert_eq!(options.word("  wort  "), Some("wort"));
+
+        let options = TxtOptions::default();
+        assert_eq!(options.word("# not a comment"), Some("# not a comment"));
+        assert_eq!(options.word("  "), None);
+    }
+
+    #[test]
+    fn test_word_trim_modes() {
+        let line = "\u{00A0}wort ";
+        let trimmed = |trim| {
+            TxtOptions {
+                trim,
+                ..TxtOptions::default()
+            }
+            .word(line)
+        };
+        assert_eq!(trimmed(Trim::Whitespace), Some("wort"));
+        assert_eq!(trimmed(Trim::Ascii), Some("\u{00A0}wort"));
+        assert_eq!(trimmed(Trim::None), Some(line));
+    }
+
+    #[test]
+    fn test_lossy_replaces_invalid_bytes() {
+        let lines: Vec<String> = lines(b"ba\xffnana\ncherry\n", Utf8Mode::Lossy)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines, vec!["ba\u{FFFD}nana", "cherry"]);
+    }
+}
@@ -1,9 +1,14 @@
 //! Source iterators for WordStream.
 
 mod csv;
+mod lines;
 mod sorted_file;
 mod txt;
 
-pub use csv::{from_csv, from_csv_zstd};
-pub use sorted_file::{SortedLines, from_sorted_file, from_sorted_reader, from_sorted_zst_file};
-pub use txt::{UnsortedWords, from_txt, from_txt_zstd};
+pub use csv::{from_csv, from_csv_compressed, from_csv_zstd};
+pub use lines::{TextLines, Trim, TxtOptions, Utf8Mode};
+pub use sorted_file::{
+    CompressedFileLines, SortedLines, from_sorted_compressed_file, from_sorted_file,
+    from_sorted_reader, from_sorted_reader_with_options, from_sorted_zst_file,
+};
+pub use txt::{UnsortedWords, from_txt, from_txt_compressed, from_txt_with_options, from_txt_zstd};
@@ -1,26 +1,39 @@
 //! Lazy reading for pre-sorted word sources.
 
 use std::fs::File;
-use std::io::{self, BufRead, BufReader, Lines};
+use std::io::{self, BufRead, BufReader, Read};
 use std::path::Path;
 
-use zstd::Decoder;
-
+use super::lines::{TextLines, TxtOptions};
 use crate::Word;
+use crate::stream::codec::{Codec, Zstd};
 use crate::stream::word_stream::WordStream;
 
-/// Iterator that reads lines from any `BufRead` source, trimming whitespace and skipping empty lines.
+/// Line iterator over a compressed file, as produced by
+/// [`from_sorted_compressed_file`] and [`from_sorted_zst_file`].
+pub type CompressedFileLines = SortedLines<BufReader<Box<dyn Read>>>;
+
+/// Iterator that reads lines from any `BufRead` source, trimming whitespace and skipping empty lines
+/// (and comments, if enabled in the [`TxtOptions`]).
 ///
 /// This is the underlying iterator type for sorted word streams.
 pub struct SortedLines<R: BufRead> {
-    lines: Lines<R>,
+    lines: TextLines<R>,
+    options: TxtOptions,
 }
 
 impl<R: BufRead> SortedLines<R> {
     /// Creates a new `SortedLines` iterator from a buffered reader.
     pub fn new(reader: R) -> Self {
+        Self::with_options(reader, "input", TxtOptions::default())
+    }
+
+    /// Creates a new `SortedLines` iterator with custom text options.
+    /// `source` names the source in error messages.
+    pub fn with_options(reader: R, source: impl Into<String>, options: TxtOptions) -> Self {
         Self {
-            lines: reader.lines(),
+            lines: TextLines::new(reader, source, options),
+            options,
         }
     }
 }
@@ -32,11 +45,9 @@ impl<R: BufRead> Iterator for SortedLines<R> {
         loop {
             match self.lines.next()? {
                 Ok(line) => {
-                    let trimmed = line.trim();
-                    if trimmed.is_empty() {
-                        continue;
+                    if let Some(word) = self.options.word(&line) {
+                        return Some(Ok(Word(word.to_string())));
                     }
-                    return Some(Ok(Word(trimmed.to_string())));
                 }
                 Err(e) => return Some(Err(e)),
             }
@@ -55,6 +66,40 @@ pub fn from_sorted_reader<R: BufRead>(reader: R) -> WordStream<SortedLines<R>> {
     WordStream::new(SortedLines::new(reader))
 }
 
+/// Creates a WordStream from a buffered reader containing pre-sorted words,
+/// with custom text options. `source` names the source in error messages.
+///
+/// # Panics
+///
+/// Panics during iteration if the data is not sorted, or if it repeats a
+/// word and `options.duplicates` rejects that. `options.sort_policy` can
+/// relax the order check for trusted files.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::Cursor;
+/// use wordle_wordlists_processing::stream::{TxtOptions, Utf8Mode, from_sorted_reader_with_options};
+///
+/// let options = TxtOptions { utf8: Utf8Mode::Lossy, ..TxtOptions::default() };
+/// let stream = from_sorted_reader_with_options(Cursor::new(b"apple\nb\xffnana\n"), "words.txt", options);
+/// for word in stream {
+///     println!("{}", word?);
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn from_sorted_reader_with_options<R: BufRead>(
+    reader: R,
+    source: impl Into<String>,
+    options: TxtOptions,
+) -> WordStream<SortedLines<R>> {
+    WordStream::with_options(
+        SortedLines::with_options(reader, source, options),
+        options.duplicates,
+        options.sort_policy,
+    )
+}
+
 /// Creates a WordStream from a pre-sorted file.
 ///
 /// Reads lines lazily without loading the entire file into memory.
@@ -71,7 +116,7 @@ pub fn from_sorted_reader<R: BufRead>(reader: R) -> WordStream<SortedLines<R>> {
 /// # Example
 ///
 /// ```no_run
-/// use wordle::wordlist::stream::from_sorted_file;
+/// use wordle_wordlists_processing::stream::from_sorted_file;
 ///
 /// let stream = from_sorted_file("words.txt")?;
 /// for word in stream {
@@ -82,8 +127,12 @@ pub fn from_sorted_reader<R: BufRead>(reader: R) -> WordStream<SortedLines<R>> {
 pub fn from_sorted_file(
     path: impl AsRef<Path>,
 ) -> io::Result<WordStream<SortedLines<BufReader<File>>>> {
-    let file = File::open(path)?;
-    Ok(from_sorted_reader(BufReader::new(file)))
+    let file = File::open(&path)?;
+    Ok(from_sorted_reader_with_options(
+        BufReader::new(file),
+        path.as_ref().display().to_string(),
+        TxtOptions::default(),
+    ))
 }
 
 /// Creates a WordStream from a pre-sorted zstd-compressed file.
@@ -102,7 +151,7 @@ pub fn from_sorted_file(
 /// # Example
 ///
 /// ```no_run
-/// use wordle::wordlist::stream::from_sorted_zst_file;
+/// use wordle_wordlists_processing::stream::from_sorted_zst_file;
 ///
 /// let stream = from_sorted_zst_file("words.zst")?;
 /// for word in stream {
@@ -110,18 +159,38 @@ pub fn from_sorted_file(
 /// }
 /// # Ok::<(), std::io::Error>(())
 /// ```
-pub fn from_sorted_zst_file(
+pub fn from_sorted_zst_file(path: impl AsRef<Path>) -> io::Result<WordStream<CompressedFileLines>> {
+    from_sorted_compressed_file(path, &Zstd)
+}
+
+/// Creates a WordStream from a pre-sorted file compressed with `codec`,
+/// e.g. the one [`codec_for_path`](crate::stream::codec_for_path) picks.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or is not valid for the codec.
+///
+/// # Panics
+///
+/// Panics during iteration if the file is not sorted.
+pub fn from_sorted_compressed_file(
     path: impl AsRef<Path>,
-) -> io::Result<WordStream<SortedLines<BufReader<Decoder<'static, BufReader<File>>>>>> {
-    let file = File::open(path)?;
-    let decoder = Decoder::new(file)?;
-    Ok(from_sorted_reader(BufReader::new(decoder)))
+    codec: &dyn Codec,
+) -> io::Result<WordStream<CompressedFileLines>> {
+    let file = File::open(&path)?;
+    let decoder = codec.decoder(Box::new(BufReader::new(file)))?;
+    Ok(from_sorted_reader_with_options(
+        BufReader::new(decoder),
+        path.as_ref().display().to_string(),
+        TxtOptions::default(),
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::io::Write;
+    use crate::stream::Duplicates;
+    use std::io::{Cursor, Write};
 
     fn create_temp_file(content: &str) -> std::path::PathBuf {
         let path = std::env::temp_dir().join(format!(
@@ -187,6 +256,32 @@ mod tests {
         // Cleanup won't run due to panic, but that's ok for tests
     }
 
+    #[test]
+    fn test_windows_line_endings_and_bom() {
+        let path = create_temp_file("\u{FEFF}apple\r\nbanana\r\ncherry\r\n");
+        let words: Vec<String> = from_sorted_file(&path)
+            .unwrap()
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(words, vec!["apple", "banana", "cherry"]);
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    #[should_panic(expected = "has duplicates")]
+    fn test_rejected_duplicates_panic() {
+        let options = TxtOptions {
+            duplicates: Duplicates::Reject,
+            ..TxtOptions::default()
+        };
+        let stream = from_sorted_reader_with_options(
+            Cursor::new("apple\nApple\nApple\n"),
+            "words.txt",
+            options,
+        );
+        let _: Vec<_> = stream.collect();
+    }
+
     #[test]
     fn test_file_not_found() {
         let result = from_sorted_file("/nonexistent/path/to/file.txt");
@@ -2,9 +2,9 @@
 
 use std::io::{self, BufRead, BufReader, Read};
 
-use zstd::Decoder;
-
+use super::lines::{TextLines, TxtOptions};
 use crate::Word;
+use crate::stream::codec::{Codec, Zstd};
 use crate::stream::word_stream::WordStream;
 
 /// Iterator over words loaded from an unsorted source and sorted in memory.
@@ -43,7 +43,7 @@ impl Iterator for UnsortedWords {
 ///
 /// ```no_run
 /// use std::io::Cursor;
-/// use wordle::wordlist::stream::from_txt;
+/// use wordle_wordlists_processing::stream::from_txt;
 ///
 /// let data = b"cherry\napple\nbanana\n";
 /// let stream = from_txt(Cursor::new(data))?;
@@ -53,14 +53,42 @@ impl Iterator for UnsortedWords {
 /// # Ok::<(), std::io::Error>(())
 /// ```
 pub fn from_txt<R: BufRead>(reader: R) -> io::Result<WordStream<UnsortedWords>> {
-    // Read all lines, trim, skip empty
+    from_txt_with_options(reader, "input", TxtOptions::default())
+}
+
+/// Creates a WordStream from a buffered reader containing plain text words,
+/// with custom text options. `source` names the source in error messages.
+///
+/// # Errors
+///
+/// Returns an error if reading fails, or (with `Utf8Mode::Strict`) if the
+/// text is not valid UTF-8.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::Cursor;
+/// use wordle_wordlists_processing::stream::{TxtOptions, Utf8Mode, from_txt_with_options};
+///
+/// let options = TxtOptions { utf8: Utf8Mode::Lossy, ..TxtOptions::default() };
+/// let stream = from_txt_with_options(Cursor::new(b"cherry\nb\xffnana\n"), "words.txt", options)?;
+/// for word in stream {
+///     println!("{}", word?);
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn from_txt_with_options<R: BufRead>(
+    reader: R,
+    source: impl Into<String>,
+    options: TxtOptions,
+) -> io::Result<WordStream<UnsortedWords>> {
+    // Read all lines, strip comments, trim, skip empty
     let mut words: Vec<Word> = Vec::new();
 
-    for line_result in reader.lines() {
+    for line_result in TextLines::new(reader, source, options) {
         let line = line_result?;
-        let trimmed = line.trim();
-        if !trimmed.is_empty() {
-            words.push(Word(trimmed.to_string()));
+        if let Some(word) = options.word(&line) {
+            words.push(Word(word.to_string()));
         }
     }
 
@@ -84,9 +112,9 @@ pub fn from_txt<R: BufRead>(reader: R) -> io::Result<WordStream<UnsortedWords>>
 ///
 /// ```no_run
 /// use std::io::Cursor;
-/// use wordle::wordlist::stream::from_txt_zstd;
+/// use wordle_wordlists_processing::stream::from_txt_zstd;
 ///
-/// let compressed_data: &[u8] = include_bytes!("some_file.txt.zst");
+/// let compressed_data: &[u8] = &std::fs::read("some_file.txt.zst")?;
 /// let stream = from_txt_zstd(Cursor::new(compressed_data))?;
 /// for word in stream {
 ///     println!("{}", word?);
@@ -94,8 +122,19 @@ pub fn from_txt<R: BufRead>(reader: R) -> io::Result<WordStream<UnsortedWords>>
 /// # Ok::<(), std::io::Error>(())
 /// ```
 pub fn from_txt_zstd<R: Read>(reader: R) -> io::Result<WordStream<UnsortedWords>> {
-    let decoder = Decoder::new(reader)?;
-    from_txt(BufReader::new(decoder))
+    from_txt_compressed(reader, &Zstd)
+}
+
+/// Creates a WordStream from a plain text stream compressed with `codec`.
+///
+/// # Errors
+///
+/// Returns an error if reading fails or the stream is not valid for the codec.
+pub fn from_txt_compressed<R: Read>(
+    reader: R,
+    codec: &dyn Codec,
+) -> io::Result<WordStream<UnsortedWords>> {
+    from_txt(BufReader::new(codec.decoder(Box::new(reader))?))
 }
 
 #[cfg(test)]
@@ -140,6 +179,14 @@ mod tests {
         assert_eq!(words, vec!["apple", "banana", "cherry"]);
     }
 
+    #[test]
+    fn test_windows_line_endings_and_bom() {
+        let data = b"\xEF\xBB\xBFcherry\r\napple\r\nbanana\r\n";
+        let stream = from_txt(Cursor::new(data)).unwrap();
+        let words: Vec<String> = stream.map(|r| r.unwrap().0).collect();
+        assert_eq!(words, vec!["apple", "banana", "cherry"]);
+    }
+
     #[test]
     fn test_empty() {
         let data = b"";
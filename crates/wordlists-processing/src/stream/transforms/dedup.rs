@@ -104,7 +104,7 @@ mod tests {
     fn test_dedup_preserves_errors() {
         let items: Vec<io::Result<Word>> = vec![
             Ok(Word("apple".to_string())),
-            Err(io::Error::new(io::ErrorKind::Other, "test error")),
+            Err(io::Error::other("test error")),
             Ok(Word("apple".to_string())), // This is still considered a dup of the first apple
             Ok(Word("banana".to_string())), // Different word, not a dup
         ];
@@ -0,0 +1,115 @@
+//! Display form transform for WordStream.
+//!
+//! Curated lists may keep words the way they are written, e.g. "x-ray" or
+//! "o'clock", while the game can only take letters. The play form of such
+//! a word drops the hyphens and apostrophes, and the game shows the
+//! display form again when the word is revealed.
+
+use std::io;
+
+use crate::Word;
+
+use super::RemapStream;
+
+/// Characters that are kept for display but not typed
+const SEPARATORS: &[char] = &[
+    '-',        // hyphen-minus
+    '\u{2010}', // hyphen
+    '\u{2011}', // non-breaking hyphen
+    '\'',       // apostrophe
+    '\u{2019}', // right single quotation mark, as a typographic apostrophe
+];
+
+/// The letters of a display form, without its hyphens and apostrophes.
+/// None if that leaves anything but letters, or nothing at all.
+pub fn play_form(display: &str) -> Option<String> {
+    let play: String = display
+        .chars()
+        .filter(|c| !SEPARATORS.contains(c))
+        .collect();
+    (!play.is_empty() && play.chars().all(char::is_alphabetic)).then_some(play)
+}
+
+/// An iterator of `(play form, display form)` pairs, in the order of the
+/// display forms. Words without a play form are dropped.
+pub struct DisplayFormStream<I> {
+    inner: I,
+}
+
+impl<I> DisplayFormStream<I> {
+    pub fn new(inner: I) -> Self {
+        Self { inner }
+    }
+}
+
+impl<I> Iterator for DisplayFormStream<I>
+where
+    I: Iterator<Item = io::Result<Word>>,
+{
+    type Item = io::Result<(Word, Word)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next()? {
+                Ok(display) => {
+                    if let Some(play) = play_form(&display.0) {
+                        return Some(Ok((Word(play), display)));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Replaces every word by its play form, dropping words without one
+pub fn to_play_forms<I>(iter: I) -> RemapStream<I, impl FnMut(Word) -> Option<Word>>
+where
+    I: Iterator<Item = io::Result<Word>>,
+{
+    RemapStream::new(iter, |word: Word| play_form(&word.0).map(Word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok_iter<I: IntoIterator<Item = &'static str>>(
+        items: I,
+    ) -> impl Iterator<Item = io::Result<Word>> {
+        items.into_iter().map(|s| Ok(Word(s.to_string())))
+    }
+
+    #[test]
+    fn test_play_form() {
+        assert_eq!(play_form("x-ray"), Some("xray".to_string()));
+        assert_eq!(play_form("o’clock"), Some("oclock".to_string()));
+        assert_eq!(play_form("apple"), Some("apple".to_string()));
+        assert_eq!(play_form("r2-d2"), None);
+        assert_eq!(play_form("--"), None);
+    }
+
+    #[test]
+    fn test_pairs() {
+        let pairs: Vec<(String, String)> = DisplayFormStream::new(ok_iter(["a-b", "c d", "ef"]))
+            .map(|r| r.unwrap())
+            .map(|(play, display)| (play.0, display.0))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("ab".to_string(), "a-b".to_string()),
+                ("ef".to_string(), "ef".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_play_forms_stay_sorted() {
+        // "re-do" sorts before "red", "redo" after it
+        let words: Vec<String> = to_play_forms(ok_iter(["re-do", "red"]))
+            .map(|r| r.unwrap().0)
+            .collect();
+        assert_eq!(words, vec!["red", "redo"]);
+    }
+}
@@ -85,7 +85,7 @@ mod tests {
     fn test_filter_preserves_errors() {
         let items: Vec<io::Result<Word>> = vec![
             Ok(Word("apple".to_string())),
-            Err(io::Error::new(io::ErrorKind::Other, "test error")),
+            Err(io::Error::other("test error")),
             Ok(Word("banana".to_string())),
         ];
         let stream = FilterStream::new(items.into_iter(), |_: &str| true);
@@ -74,7 +74,7 @@ mod tests {
     fn test_lowercase_preserves_errors() {
         let items: Vec<io::Result<Word>> = vec![
             Ok(Word("HELLO".to_string())),
-            Err(io::Error::new(io::ErrorKind::Other, "test error")),
+            Err(io::Error::other("test error")),
             Ok(Word("WORLD".to_string())),
         ];
         let stream = LowercaseStream::new(items.into_iter());
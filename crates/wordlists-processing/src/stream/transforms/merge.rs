@@ -130,7 +130,7 @@ mod tests {
     fn test_merge_preserves_errors() {
         let left: Vec<io::Result<Word>> = vec![
             Ok(Word("apple".to_string())),
-            Err(io::Error::new(io::ErrorKind::Other, "left error")),
+            Err(io::Error::other("left error")),
             Ok(Word("cherry".to_string())),
         ];
         let right: Vec<io::Result<Word>> =
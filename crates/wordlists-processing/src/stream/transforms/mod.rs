@@ -1,13 +1,19 @@
 //! Transform iterators for WordStream.
 
 mod dedup;
+mod display_form;
 mod filter;
 mod filter_non_alphabetic;
 mod lowercase;
 mod merge;
+mod remap;
+mod sanitize;
 
 pub use dedup::DedupStream;
+pub use display_form::{DisplayFormStream, play_form, to_play_forms};
 pub use filter::FilterStream;
 pub use filter_non_alphabetic::filter_non_alphabetic;
 pub use lowercase::LowercaseStream;
 pub use merge::MergeStream;
+pub use remap::RemapStream;
+pub use sanitize::{Sanitized, sanitize, sanitize_word};
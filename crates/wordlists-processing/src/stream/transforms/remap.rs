@@ -0,0 +1,101 @@
+//! Transform that changes words in ways that can change their order.
+
+use std::collections::VecDeque;
+use std::io;
+
+use crate::Word;
+use crate::ordering::case_fold_cmp;
+
+/// An iterator that maps every word, dropping those mapped to `None`.
+///
+/// Unlike lowercasing, such a mapping can move a word anywhere in the sort
+/// order (e.g. replacing a Cyrillic 'с', which sorts after all Latin
+/// letters, by a Latin 'c'), so the stream reads all of its input and sorts
+/// it before yielding the first word.
+pub struct RemapStream<I, F> {
+    inner: Option<I>,
+    map: F,
+    /// Mapped words in case-fold order
+    words: VecDeque<Word>,
+}
+
+impl<I, F> RemapStream<I, F> {
+    pub fn new(inner: I, map: F) -> Self {
+        Self {
+            inner: Some(inner),
+            map,
+            words: VecDeque::new(),
+        }
+    }
+}
+
+impl<I, F> Iterator for RemapStream<I, F>
+where
+    I: Iterator<Item = io::Result<Word>>,
+    F: FnMut(Word) -> Option<Word>,
+{
+    type Item = io::Result<Word>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(mut inner) = self.inner.take() {
+            let mut words = Vec::new();
+            for word in inner.by_ref() {
+                match word {
+                    Ok(word) => words.extend((self.map)(word)),
+                    Err(e) => {
+                        // Hand out the error first, and continue reading afterwards
+                        self.words.extend(words);
+                        self.inner = Some(inner);
+                        return Some(Err(e));
+                    }
+                }
+            }
+            self.words.extend(words);
+            self.words
+                .make_contiguous()
+                .sort_by(|a, b| case_fold_cmp(&a.0, &b.0));
+        }
+        self.words.pop_front().map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok_iter<I: IntoIterator<Item = &'static str>>(
+        items: I,
+    ) -> impl Iterator<Item = io::Result<Word>> {
+        items.into_iter().map(|s| Ok(Word(s.to_string())))
+    }
+
+    #[test]
+    fn test_resorts_mapped_words() {
+        let stream = RemapStream::new(ok_iter(["apple", "banana", "zucchini"]), |w: Word| {
+            Some(Word(w.0.replace('z', "c")))
+        });
+        let collected: Vec<String> = stream.map(|r| r.unwrap().0).collect();
+        assert_eq!(collected, vec!["apple", "banana", "cucchini"]);
+    }
+
+    #[test]
+    fn test_drops_unmapped_words() {
+        let stream = RemapStream::new(ok_iter(["apple", "banana", "cherry"]), |w: Word| {
+            (w.0 != "banana").then_some(w)
+        });
+        let collected: Vec<String> = stream.map(|r| r.unwrap().0).collect();
+        assert_eq!(collected, vec!["apple", "cherry"]);
+    }
+
+    #[test]
+    fn test_preserves_errors() {
+        let items: Vec<io::Result<Word>> = vec![
+            Ok(Word("apple".to_string())),
+            Err(io::Error::other("test error")),
+            Ok(Word("banana".to_string())),
+        ];
+        let results: Vec<_> = RemapStream::new(items.into_iter(), Some).collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results.iter().filter(|r| r.is_err()).count(), 1);
+    }
+}
@@ -0,0 +1,220 @@
+//! Sanitization transform for WordStream.
+//!
+//! Source lists sometimes contain characters that look like letters (or
+//! like nothing at all) but can never be typed in the game: zero-width
+//! characters, non-breaking spaces, and Cyrillic letters mixed into Latin
+//! words because they look the same. This transform fixes what it can and
+//! drops the rest, warning on stderr either way.
+
+use std::io;
+
+use crate::Word;
+
+use super::RemapStream;
+
+/// Characters that render as nothing and are removed
+const INVISIBLE: &[char] = &[
+    '\u{00AD}', // soft hyphen
+    '\u{200B}', // zero width space
+    '\u{200C}', // zero width non-joiner
+    '\u{200D}', // zero width joiner
+    '\u{2060}', // word joiner
+    '\u{FEFF}', // zero width no-break space (BOM)
+];
+
+/// Spaces that look like a regular space, and are replaced by one
+const NON_BREAKING_SPACES: &[char] = &[
+    '\u{00A0}', // no-break space
+    '\u{2007}', // figure space
+    '\u{202F}', // narrow no-break space
+];
+
+/// Cyrillic letters and the Latin letters they look like
+const CYRILLIC_HOMOGLYPHS: &[(char, char)] = &[
+    ('а', 'a'),
+    ('в', 'b'),
+    ('е', 'e'),
+    ('і', 'i'),
+    ('ј', 'j'),
+    ('к', 'k'),
+    ('м', 'm'),
+    ('н', 'h'),
+    ('о', 'o'),
+    ('р', 'p'),
+    ('с', 'c'),
+    ('ѕ', 's'),
+    ('т', 't'),
+    ('у', 'y'),
+    ('х', 'x'),
+    ('ԁ', 'd'),
+    ('ԛ', 'q'),
+    ('ԝ', 'w'),
+    ('А', 'A'),
+    ('В', 'B'),
+    ('Е', 'E'),
+    ('І', 'I'),
+    ('Ј', 'J'),
+    ('К', 'K'),
+    ('М', 'M'),
+    ('Н', 'H'),
+    ('О', 'O'),
+    ('Р', 'P'),
+    ('С', 'C'),
+    ('Ѕ', 'S'),
+    ('Т', 'T'),
+    ('У', 'Y'),
+    ('Х', 'X'),
+];
+
+/// Result of checking a word for untypeable characters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sanitized {
+    /// Nothing to fix
+    Clean,
+    /// The word with invisible characters removed, non-breaking spaces
+    /// replaced and Cyrillic lookalikes in a Latin word replaced
+    Fixed(String),
+    /// A Latin word with a Cyrillic letter that has no Latin lookalike,
+    /// so it can't be fixed
+    Flagged(char),
+}
+
+/// Check a word for untypeable characters, fixing them if possible.
+/// Words written entirely in Cyrillic are left alone.
+pub fn sanitize_word(word: &str) -> Sanitized {
+    let mut fixed: String = word
+        .chars()
+        .filter(|c| !INVISIBLE.contains(c))
+        .map(|c| {
+            if NON_BREAKING_SPACES.contains(&c) {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    if fixed.chars().any(is_latin) && fixed.chars().any(is_cyrillic) {
+        let mut replaced = String::with_capacity(fixed.len());
+        for c in fixed.chars() {
+            match CYRILLIC_HOMOGLYPHS
+                .iter()
+                .find(|(cyrillic, _)| *cyrillic == c)
+            {
+                Some(&(_, latin)) => replaced.push(latin),
+                None if is_cyrillic(c) => return Sanitized::Flagged(c),
+                None => replaced.push(c),
+            }
+        }
+        fixed = replaced;
+    }
+
+    if fixed == word {
+        Sanitized::Clean
+    } else {
+        Sanitized::Fixed(fixed)
+    }
+}
+
+fn is_latin(c: char) -> bool {
+    c.is_ascii_alphabetic() || (c.is_alphabetic() && ('\u{00C0}'..='\u{024F}').contains(&c))
+}
+
+fn is_cyrillic(c: char) -> bool {
+    ('\u{0400}'..='\u{052F}').contains(&c)
+}
+
+/// Creates a transform that fixes or drops words with untypeable
+/// characters, see [`sanitize_word`]. Outputs a warning to stderr for each
+/// changed word. Reads all of its input before yielding anything, since a
+/// fixed word can sort anywhere.
+pub fn sanitize<I>(iter: I) -> RemapStream<I, impl FnMut(Word) -> Option<Word>>
+where
+    I: Iterator<Item = io::Result<Word>>,
+{
+    RemapStream::new(iter, |word: Word| match sanitize_word(&word.0) {
+        Sanitized::Clean => Some(word),
+        Sanitized::Fixed(fixed) => {
+            eprintln!("Warning: fixing untypeable characters in word: {word:?} -> {fixed:?}");
+            Some(Word(fixed))
+        }
+        Sanitized::Flagged(c) => {
+            eprintln!("Warning: filtering word with Cyrillic {c:?}: {word:?}");
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok_iter<I: IntoIterator<Item = &'static str>>(
+        items: I,
+    ) -> impl Iterator<Item = io::Result<Word>> {
+        items.into_iter().map(|s| Ok(Word(s.to_string())))
+    }
+
+    #[test]
+    fn test_clean_words() {
+        assert_eq!(sanitize_word("apfel"), Sanitized::Clean);
+        assert_eq!(sanitize_word("Größe"), Sanitized::Clean);
+        // Real Cyrillic words are not homoglyph mixups
+        assert_eq!(sanitize_word("слово"), Sanitized::Clean);
+    }
+
+    #[test]
+    fn test_invisible_characters() {
+        assert_eq!(
+            sanitize_word("ap\u{200B}fel\u{FEFF}"),
+            Sanitized::Fixed("apfel".to_string())
+        );
+        assert_eq!(
+            sanitize_word("Schiff\u{00AD}fahrt"),
+            Sanitized::Fixed("Schifffahrt".to_string())
+        );
+        assert_eq!(
+            sanitize_word("New\u{00A0}York"),
+            Sanitized::Fixed("New York".to_string())
+        );
+    }
+
+    #[test]
+    fn test_homoglyphs() {
+        // Cyrillic 'а' and 'о'
+        assert_eq!(
+            sanitize_word("bаum\u{200B}"),
+            Sanitized::Fixed("baum".to_string())
+        );
+        assert_eq!(sanitize_word("Оbst"), Sanitized::Fixed("Obst".to_string()));
+        // 'д' looks like no Latin letter
+        assert_eq!(sanitize_word("baдm"), Sanitized::Flagged('д'));
+    }
+
+    #[test]
+    fn test_stream_stays_sorted() {
+        // "сarl" starts with a Cyrillic 'с', which sorts after all Latin words
+        let stream = sanitize(ok_iter(["apfel", "bаum", "birne", "zebra", "сarl"]));
+        let collected: Vec<String> = stream.map(|r| r.unwrap().0).collect();
+        assert_eq!(collected, vec!["apfel", "baum", "birne", "carl", "zebra"]);
+    }
+
+    #[test]
+    fn test_stream_drops_flagged_words() {
+        let stream = sanitize(ok_iter(["apfel", "baдm", "birne"]));
+        let collected: Vec<String> = stream.map(|r| r.unwrap().0).collect();
+        assert_eq!(collected, vec!["apfel", "birne"]);
+    }
+
+    #[test]
+    fn test_preserves_errors() {
+        let items: Vec<io::Result<Word>> = vec![
+            Ok(Word("apple".to_string())),
+            Err(io::Error::other("test error")),
+            Ok(Word("banana".to_string())),
+        ];
+        let results: Vec<_> = sanitize(items.into_iter()).collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results.iter().filter(|r| r.is_err()).count(), 1);
+    }
+}
@@ -4,11 +4,102 @@ use std::cmp::Ordering;
 use std::io;
 use std::iter::Peekable;
 
+use serde::{Deserialize, Serialize};
+
 use crate::Word;
 
+/// Whether a sorted stream may contain the same word more than once.
+///
+/// Words are compared in case-fold order, which is a total order: two words
+/// are only equal if they are the same string. Case variants like `"apple"`
+/// and `"Apple"` are different words and never count as duplicates; use
+/// [`dedup`](crate::stream::BoxedWordStream::dedup) to merge them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Duplicates {
+    /// Equal adjacent words are accepted
+    #[default]
+    Allow,
+    /// Every word must be strictly greater than the one before; a repeated
+    /// word panics like unsorted data
+    Reject,
+}
+
+/// How thoroughly a sorted stream checks its order.
+///
+/// [`Strict`](Self::Strict) compares every word with the next one. For very
+/// large inputs that were already validated (e.g. written by an earlier
+/// build), [`Sample`](Self::Sample) only compares the pair at the start of
+/// each chunk and one randomly picked pair within it. Unsorted data usually
+/// still panics, but errors between checked pairs go unnoticed, and
+/// duplicates are only rejected where a pair is checked.
+///
+/// The sample is drawn from a fixed seed, so the same input is always
+/// checked at the same positions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortPolicy {
+    /// Every pair of adjacent words is checked
+    #[default]
+    Strict,
+    /// Two pairs out of every `chunk_size` are checked
+    Sample { chunk_size: usize },
+}
+
+/// Seed of the sampling order, see [`SortPolicy`]
+const SAMPLE_SEED: u64 = 0x5EED_5EED_5EED_5EED;
+
+/// Decides which pairs of a stream to check under a [`SortPolicy`]
+#[derive(Debug, Clone)]
+struct SortChecker {
+    policy: SortPolicy,
+    /// Index of the next pair
+    position: usize,
+    /// Offset within the current chunk of the randomly picked pair
+    sampled: usize,
+    rng: u64,
+}
+
+impl SortChecker {
+    fn new(policy: SortPolicy) -> Self {
+        Self {
+            policy,
+            position: 0,
+            sampled: 0,
+            rng: SAMPLE_SEED,
+        }
+    }
+
+    /// Whether the next pair should be checked
+    fn check_next(&mut self) -> bool {
+        let SortPolicy::Sample { chunk_size } = self.policy else {
+            return true;
+        };
+        let chunk_size = chunk_size.max(1);
+        let offset = self.position % chunk_size;
+        self.position += 1;
+        if offset == 0 {
+            self.sampled = (self.next_random() % chunk_size as u64) as usize;
+            return true;
+        }
+        offset == self.sampled
+    }
+
+    /// splitmix64, which is plenty for picking sample positions
+    fn next_random(&mut self) -> u64 {
+        self.rng = self.rng.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.rng;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
 /// A stream of words, guaranteed to be sorted in case-fold order.
 ///
-/// Panics during iteration if the underlying data is not sorted.
+/// Panics during iteration if the underlying data is not sorted, or if it
+/// contains duplicates and they are rejected (see [`Duplicates`]). With a
+/// sampling [`SortPolicy`], only some of the words are checked.
 /// This ensures that any `WordStream` can be safely used for operations
 /// that require sorted input (like deduplication or writing to sorted files).
 ///
@@ -19,6 +110,8 @@ where
     I: Iterator<Item = io::Result<Word>> + 'static,
 {
     inner: Peekable<I>,
+    duplicates: Duplicates,
+    checker: SortChecker,
 }
 
 impl<I: Iterator> WordStream<I>
@@ -30,11 +123,32 @@ where
     /// The stream will validate sortedness during iteration and panic
     /// if items are not in case-fold order.
     pub(crate) fn new(inner: I) -> Self {
+        Self::with_duplicates(inner, Duplicates::Allow)
+    }
+
+    /// Creates a new WordStream that treats equal adjacent items according
+    /// to `duplicates`.
+    pub(crate) fn with_duplicates(inner: I, duplicates: Duplicates) -> Self {
+        Self::with_options(inner, duplicates, SortPolicy::Strict)
+    }
+
+    /// Creates a new WordStream that treats equal adjacent items according
+    /// to `duplicates` and checks the order according to `sort_policy`.
+    pub(crate) fn with_options(inner: I, duplicates: Duplicates, sort_policy: SortPolicy) -> Self {
         Self {
             inner: inner.peekable(),
+            duplicates,
+            checker: SortChecker::new(sort_policy),
         }
     }
 
+    /// Checks the order of the remaining words according to `sort_policy`
+    /// instead, e.g. to trust a large file that was already validated.
+    pub fn with_sort_policy(mut self, sort_policy: SortPolicy) -> Self {
+        self.checker = SortChecker::new(sort_policy);
+        self
+    }
+
     /// Consumes the stream and returns the underlying peekable iterator.
     pub fn into_inner(self) -> Peekable<I> {
         self.inner
@@ -48,12 +162,13 @@ where
     /// Converts to a type-erased `BoxedWordStream` for dynamic composition.
     ///
     /// This allows merging an arbitrary number of streams in a loop,
-    /// at the cost of dynamic dispatch overhead.
+    /// at the cost of dynamic dispatch overhead. The boxed stream keeps
+    /// validating the order of this stream.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use wordle::wordlist::stream::from_sorted_zst_file;
+    /// use wordle_wordlists_processing::stream::from_sorted_zst_file;
     ///
     /// let inputs = ["a.zst", "b.zst"];
     /// let mut stream = from_sorted_zst_file(inputs[0])?.boxed();
@@ -63,7 +178,7 @@ where
     /// # Ok::<(), std::io::Error>(())
     /// ```
     pub fn boxed(self) -> super::boxed::BoxedWordStream {
-        super::boxed::BoxedWordStream::new(self.inner)
+        super::boxed::BoxedWordStream::new(self)
     }
 }
 
@@ -80,9 +195,17 @@ where
             Ok(w) => {
                 // Validate sortedness by peeking at the next item
                 if let Some(Ok(next)) = self.inner.peek()
-                    && w.cmp(next) == Ordering::Greater
+                    && self.checker.check_next()
                 {
-                    panic!("WordStream is not sorted: {:?} came before {:?}", w, next);
+                    match w.cmp(next) {
+                        Ordering::Greater => {
+                            panic!("WordStream is not sorted: {:?} came before {:?}", w, next)
+                        }
+                        Ordering::Equal if self.duplicates == Duplicates::Reject => {
+                            panic!("WordStream has duplicates: {:?} came twice", w)
+                        }
+                        _ => {}
+                    }
                 }
                 Some(Ok(w))
             }
@@ -131,6 +254,80 @@ mod tests {
         let _: Vec<_> = stream.collect();
     }
 
+    #[test]
+    fn test_duplicates_allowed_by_default() {
+        let stream = WordStream::new(ok_iter(["apple", "apple", "banana"]));
+        let collected: Vec<String> = stream.map(|r| r.unwrap().0).collect();
+        assert_eq!(collected, vec!["apple", "apple", "banana"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "has duplicates")]
+    fn test_rejected_duplicates_panic() {
+        let stream = WordStream::with_duplicates(ok_iter(["apple", "apple"]), Duplicates::Reject);
+        let _: Vec<_> = stream.collect();
+    }
+
+    #[test]
+    fn test_case_variants_are_not_duplicates() {
+        let stream =
+            WordStream::with_duplicates(ok_iter(["apple", "Apple", "APPLE"]), Duplicates::Reject);
+        let collected: Vec<String> = stream.map(|r| r.unwrap().0).collect();
+        assert_eq!(collected, vec!["apple", "Apple", "APPLE"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not sorted")]
+    fn test_boxed_stream_validates() {
+        let stream = WordStream::new(ok_iter(["banana", "apple"])).boxed();
+        let _: Vec<_> = stream.collect();
+    }
+
+    fn sample(chunk_size: usize) -> SortPolicy {
+        SortPolicy::Sample { chunk_size }
+    }
+
+    #[test]
+    fn test_sampling_checks_chunk_boundaries() {
+        // The pair at index 4 ("e", "d") starts the second chunk
+        let words = ["a", "b", "c", "d", "e", "d", "f", "g"];
+        let result = std::panic::catch_unwind(|| {
+            WordStream::with_options(ok_iter(words), Duplicates::Allow, sample(4)).count()
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_sampling_checks_some_pairs_in_each_chunk() {
+        let mut checker = SortChecker::new(sample(100));
+        let checked: Vec<usize> = (0..1000).filter(|_| checker.check_next()).collect();
+        assert!((10..=20).contains(&checked.len()));
+        for chunk in 0..10 {
+            assert!(checked.contains(&(chunk * 100)));
+        }
+    }
+
+    #[test]
+    fn test_sampling_skips_unchecked_pairs() {
+        let mut checker = SortChecker::new(sample(1000));
+        let skipped = (0..1000).position(|_| !checker.check_next()).unwrap();
+        // Put the only unsorted pair where the sample doesn't look
+        let mut words: Vec<String> = (0..=1000).map(|i| format!("w{i:04}")).collect();
+        words.swap(skipped, skipped + 1);
+        let stream = WordStream::with_options(
+            words.into_iter().map(|w| Ok(Word(w))),
+            Duplicates::Allow,
+            sample(1000),
+        );
+        assert_eq!(stream.count(), 1001);
+    }
+
+    #[test]
+    fn test_strict_policy_checks_every_pair() {
+        let mut checker = SortChecker::new(SortPolicy::Strict);
+        assert!((0..100).all(|_| checker.check_next()));
+    }
+
     #[test]
     fn test_empty_stream() {
         let stream: WordStream<_> = WordStream::new(ok_iter([]));
@@ -149,7 +346,7 @@ mod tests {
     fn test_io_error_propagates() {
         let items: Vec<io::Result<Word>> = vec![
             Ok(Word("apple".to_string())),
-            Err(io::Error::new(io::ErrorKind::Other, "test error")),
+            Err(io::Error::other("test error")),
             Ok(Word("banana".to_string())),
         ];
         let stream = WordStream::new(items.into_iter());
@@ -0,0 +1,379 @@
+//! Wordpacks: everything the game needs of a language in one file.
+//!
+//! A wordpack is a zstd-compressed tar archive, so `tar --zstd -tf` can list
+//! it. The first entry is `manifest.toml` with the format version, the
+//! [`ListMetadata`] and the SHA-256 and word count of every other entry;
+//! those are word lists, one word per line:
+//!
+//! - `answers.txt`: the words secrets are picked from
+//! - `guesses.txt`: words accepted as guesses besides the answers
+//! - `display_forms.txt`: how words are written where that isn't just
+//!   their letters, e.g. "x-ray"
+//! - `opening_book.txt`: good first guesses, best first
+//!
+//! Lists without words are left out. Archives are written the same way
+//! every time, so the same words always give the same file.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::sharp_s::ListMetadata;
+use crate::stream::{Checksum, Codec, Zstd};
+
+/// Version of the format, increased when readers of older versions would
+/// misread new packs
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Name of the manifest entry
+const MANIFEST: &str = "manifest.toml";
+/// Size of tar headers and the unit entries are padded to
+const BLOCK: usize = 512;
+
+/// The word lists and metadata of a language
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Wordpack {
+    pub metadata: ListMetadata,
+    /// Words secrets are picked from
+    pub answers: Vec<String>,
+    /// Words accepted as guesses besides the answers
+    pub guesses: Vec<String>,
+    /// Words as they are written, where that isn't just their letters
+    pub display_forms: Vec<String>,
+    /// Good first guesses, best first
+    pub opening_book: Vec<String>,
+}
+
+/// `manifest.toml`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    format: u32,
+    metadata: ListMetadata,
+    /// The other entries by name
+    files: BTreeMap<String, FileEntry>,
+}
+
+/// What the manifest records about an entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileEntry {
+    /// Lowercase hex, as in `.sha256` sidecars
+    sha256: String,
+    words: usize,
+}
+
+impl Wordpack {
+    /// The word lists with their entry names
+    fn lists(&self) -> [(&'static str, &Vec<String>); 4] {
+        [
+            ("answers.txt", &self.answers),
+            ("guesses.txt", &self.guesses),
+            ("display_forms.txt", &self.display_forms),
+            ("opening_book.txt", &self.opening_book),
+        ]
+    }
+
+    /// Writes the pack to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn write(&self, writer: impl Write) -> io::Result<()> {
+        let entries: Vec<(&str, Vec<u8>)> = self
+            .lists()
+            .into_iter()
+            .filter(|(_, words)| !words.is_empty())
+            .map(|(name, words)| {
+                let text: String = words.iter().map(|word| format!("{word}\n")).collect();
+                (name, text.into_bytes())
+            })
+            .collect();
+        let manifest = Manifest {
+            format: FORMAT_VERSION,
+            metadata: self.metadata.clone(),
+            files: entries
+                .iter()
+                .map(|(name, contents)| {
+                    let checksum = Checksum::of(contents);
+                    let entry = FileEntry {
+                        sha256: checksum.to_string(),
+                        words: checksum.words(),
+                    };
+                    (name.to_string(), entry)
+                })
+                .collect(),
+        };
+        let manifest = toml::to_string(&manifest)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let mut encoder = Zstd.encoder(Box::new(writer))?;
+        write_entry(&mut encoder, MANIFEST, manifest.as_bytes())?;
+        for (name, contents) in &entries {
+            write_entry(&mut encoder, name, contents)?;
+        }
+        // The end of a tar archive is two empty blocks
+        encoder.write_all(&[0; 2 * BLOCK])?;
+        encoder.finish()
+    }
+
+    /// Reads a pack written by [`Wordpack::write`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if the pack is damaged: not an
+    /// archive, without a manifest or answers, of a newer format, or with
+    /// entries that don't match their checksums.
+    pub fn read(reader: impl Read) -> io::Result<Self> {
+        let mut archive = Vec::new();
+        Zstd.decoder(Box::new(reader))?.read_to_end(&mut archive)?;
+        let entries = read_entries(&archive)?;
+
+        let (name, manifest) = entries.first().ok_or_else(|| invalid("empty wordpack"))?;
+        if name != MANIFEST {
+            return Err(invalid("wordpack doesn't start with a manifest"));
+        }
+        let manifest =
+            std::str::from_utf8(manifest).map_err(|_| invalid("manifest isn't UTF-8"))?;
+        let manifest: Manifest = toml::from_str(manifest)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        if manifest.format > FORMAT_VERSION {
+            return Err(invalid(&format!(
+                "wordpack format {} is newer than this version supports ({FORMAT_VERSION})",
+                manifest.format
+            )));
+        }
+
+        let mut pack = Wordpack {
+            metadata: manifest.metadata,
+            ..Wordpack::default()
+        };
+        let mut lists: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for (name, contents) in &entries[1..] {
+            let expected = manifest
+                .files
+                .get(name)
+                .ok_or_else(|| invalid(&format!("{name} isn't in the manifest")))?;
+            if Checksum::of(contents).to_string() != expected.sha256 {
+                return Err(invalid(&format!("{name} doesn't match its checksum")));
+            }
+            let text = std::str::from_utf8(contents)
+                .map_err(|_| invalid(&format!("{name} isn't UTF-8")))?;
+            lists.insert(name, text.lines().map(str::to_string).collect());
+        }
+        if let Some(missing) = manifest
+            .files
+            .keys()
+            .find(|name| !lists.contains_key(name.as_str()))
+        {
+            return Err(invalid(&format!("{missing} is missing")));
+        }
+        for (name, list) in [
+            ("answers.txt", &mut pack.answers),
+            ("guesses.txt", &mut pack.guesses),
+            ("display_forms.txt", &mut pack.display_forms),
+            ("opening_book.txt", &mut pack.opening_book),
+        ] {
+            *list = lists.remove(name).unwrap_or_default();
+        }
+        if pack.answers.is_empty() {
+            return Err(invalid("wordpack has no answers"));
+        }
+        Ok(pack)
+    }
+
+    /// Writes the pack to a file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Reads a pack from a file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or the pack is damaged
+    /// (see [`Wordpack::read`]).
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// Writes a regular file to a tar archive, with a ustar header of fixed
+/// owner, mode and time
+fn write_entry(writer: &mut impl Write, name: &str, contents: &[u8]) -> io::Result<()> {
+    let mut header = [0u8; BLOCK];
+    let mut field = |offset: usize, value: &[u8]| {
+        header[offset..offset + value.len()].copy_from_slice(value);
+    };
+    if name.len() > 100 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("entry name {name} is too long"),
+        ));
+    }
+    field(0, name.as_bytes());
+    field(100, b"0000644\0");
+    field(108, b"0000000\0");
+    field(116, b"0000000\0");
+    field(124, format!("{:011o}\0", contents.len()).as_bytes());
+    field(136, b"00000000000\0");
+    field(156, b"0");
+    field(257, b"ustar\0");
+    field(263, b"00");
+    // The checksum is computed with its own field filled with spaces
+    field(148, b"        ");
+    let checksum: u32 = header.iter().map(|&byte| u32::from(byte)).sum();
+    header[148..156].copy_from_slice(format!("{checksum:06o}\0 ").as_bytes());
+
+    writer.write_all(&header)?;
+    writer.write_all(contents)?;
+    writer.write_all(&vec![
+        0;
+        contents.len().next_multiple_of(BLOCK) - contents.len()
+    ])
+}
+
+/// The names and contents of the regular files of a tar archive, in order
+fn read_entries(archive: &[u8]) -> io::Result<Vec<(String, Vec<u8>)>> {
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    while let Some(header) = archive.get(offset..offset + BLOCK) {
+        if header.iter().all(|&byte| byte == 0) {
+            return Ok(entries);
+        }
+        let octal = |field: &[u8]| {
+            let digits = std::str::from_utf8(field).ok()?;
+            usize::from_str_radix(digits.trim_matches([' ', '\0']), 8).ok()
+        };
+        let checksum = octal(&header[148..156]).ok_or_else(|| invalid("damaged wordpack"))?;
+        let sum: usize = header
+            .iter()
+            .enumerate()
+            .map(|(i, &byte)| {
+                if (148..156).contains(&i) {
+                    32
+                } else {
+                    usize::from(byte)
+                }
+            })
+            .sum();
+        if sum != checksum {
+            return Err(invalid("damaged wordpack"));
+        }
+
+        let size = octal(&header[124..136]).ok_or_else(|| invalid("damaged wordpack"))?;
+        let start = offset + BLOCK;
+        let contents = archive
+            .get(start..start + size)
+            .ok_or_else(|| invalid("wordpack is truncated"))?;
+        // Directories, links and the like aren't part of wordpacks
+        if matches!(header[156], b'0' | b'\0') {
+            let name_end = header[..100]
+                .iter()
+                .position(|&byte| byte == 0)
+                .unwrap_or(100);
+            let name = String::from_utf8_lossy(&header[..name_end]).into_owned();
+            entries.push((name, contents.to_vec()));
+        }
+        offset = start + size.next_multiple_of(BLOCK);
+    }
+    Err(invalid("wordpack is truncated"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sharp_s::SharpSPolicy;
+
+    fn pack() -> Wordpack {
+        Wordpack {
+            metadata: ListMetadata {
+                sharp_s: SharpSPolicy::NormalizeToSs,
+                words: Some(3),
+                ..ListMetadata::default()
+            },
+            answers: vec!["hallo".into(), "xray".into(), "welt".into()],
+            guesses: vec!["zzzzz".into()],
+            display_forms: vec!["x-ray".into()],
+            opening_book: Vec::new(),
+        }
+    }
+
+    fn written(pack: &Wordpack) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        pack.write(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// The uncompressed archive of a pack
+    fn archive(pack: &Wordpack) -> Vec<u8> {
+        let mut archive = Vec::new();
+        Zstd.decoder(Box::new(&written(pack)[..]))
+            .unwrap()
+            .read_to_end(&mut archive)
+            .unwrap();
+        archive
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let pack = pack();
+        assert_eq!(Wordpack::read(&written(&pack)[..]).unwrap(), pack);
+        // Reproducible
+        assert_eq!(written(&pack), written(&pack));
+    }
+
+    #[test]
+    fn test_manifest_lists_entries() {
+        let entries = read_entries(&archive(&pack())).unwrap();
+        let names: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "manifest.toml",
+                "answers.txt",
+                "guesses.txt",
+                "display_forms.txt"
+            ]
+        );
+        let manifest: Manifest =
+            toml::from_str(std::str::from_utf8(&entries[0].1).unwrap()).unwrap();
+        assert_eq!(manifest.format, FORMAT_VERSION);
+        assert_eq!(manifest.files["answers.txt"].words, 3);
+        assert_eq!(
+            manifest.files["answers.txt"].sha256,
+            Checksum::of(b"hallo\nxray\nwelt\n").to_string()
+        );
+    }
+
+    #[test]
+    fn test_damaged_packs() {
+        let mut archive = archive(&pack());
+        // Change a word of the answers, keeping the tar header intact
+        let position = archive.windows(5).position(|w| w == b"hallo").unwrap();
+        archive[position] = b'b';
+        let mut compressed = Vec::new();
+        let mut encoder = Zstd.encoder(Box::new(&mut compressed)).unwrap();
+        encoder.write_all(&archive).unwrap();
+        encoder.finish().unwrap();
+        let err = Wordpack::read(&compressed[..]).unwrap_err();
+        assert_eq!(err.to_string(), "answers.txt doesn't match its checksum");
+
+        let empty = Wordpack::default();
+        assert!(Wordpack::read(&written(&empty)[..]).is_err());
+        assert!(Wordpack::read(&b"not a pack"[..]).is_err());
+    }
+}